@@ -0,0 +1,113 @@
+//! This module defines the functionality used to collect the OpenSSF Best
+//! Practices badge level for each of the landscape items that provide a best
+//! practices project url (when applicable).
+
+use super::{cache::Cache, collector::Collector, retry::RetryPolicy, LandscapeData};
+use anyhow::{bail, format_err, Result};
+use chrono::Utc;
+use landscape2_core::data::{BestPracticesBadge, BestPracticesData};
+use lazy_static::lazy_static;
+use regex::Regex;
+use reqwest::StatusCode;
+use serde::Deserialize;
+use tracing::{debug, instrument};
+
+/// OpenSSF Best Practices API base url.
+const BEST_PRACTICES_API_URL: &str = "https://www.bestpractices.dev";
+
+/// File used to cache data collected from OpenSSF Best Practices.
+pub(crate) const BEST_PRACTICES_CACHE_FILE: &str = "best_practices.json";
+
+/// How long the OpenSSF Best Practices data in the cache is valid (in days).
+const BEST_PRACTICES_CACHE_TTL: i64 = 7;
+
+/// Maximum number of projects to process concurrently.
+const BEST_PRACTICES_MAX_CONCURRENCY: usize = 5;
+
+/// Badge levels supported (projects still in progress don't have a badge).
+const BADGE_LEVELS: [&str; 3] = ["passing", "silver", "gold"];
+
+/// Collect OpenSSF Best Practices badge level for each of the items in the
+/// landscape that provide a best practices url, reusing cached data whenever
+/// possible.
+#[instrument(skip_all, err)]
+pub(crate) async fn collect_best_practices_data(
+    cache: &Cache,
+    landscape_data: &LandscapeData,
+) -> Result<BestPracticesData> {
+    debug!("collecting projects badges from openssf best practices");
+
+    // Collect items best practices urls
+    let mut urls = vec![];
+    for item in &landscape_data.items {
+        if let Some(url) = &item.openssf_best_practices_url {
+            urls.push(url.clone());
+        }
+    }
+    urls.sort();
+    urls.dedup();
+
+    // Collect badges information, reusing cached data when available
+    let http_client = reqwest::Client::new();
+    let retry_policy = cache.retry_policy();
+    let collector = Collector {
+        cache,
+        cache_file: BEST_PRACTICES_CACHE_FILE,
+        cache_ttl: BEST_PRACTICES_CACHE_TTL,
+        max_concurrency: BEST_PRACTICES_MAX_CONCURRENCY,
+        description: "best practices badge",
+    };
+    let entries = urls.into_iter().map(|url| (url.clone(), url)).collect();
+    let best_practices_data = collector
+        .collect(entries, |url, _| {
+            let http_client = http_client.clone();
+            async move { collect_badge_data(http_client, retry_policy, &url).await }
+        })
+        .await?;
+
+    debug!("done!");
+    Ok(best_practices_data)
+}
+
+/// Collect project's badge data from OpenSSF Best Practices.
+#[instrument(skip(http_client), err)]
+async fn collect_badge_data(
+    http_client: reqwest::Client,
+    retry_policy: RetryPolicy,
+    url: &str,
+) -> Result<BestPracticesBadge> {
+    let project_id = get_project_id(url)?;
+    let req = http_client.get(format!("{BEST_PRACTICES_API_URL}/projects/{project_id}.json"));
+    let resp = retry_policy.send(req).await?;
+    if resp.status() != StatusCode::OK {
+        bail!("unexpected status code getting project: {}", resp.status());
+    }
+    let project: Project = resp.json().await?;
+
+    Ok(BestPracticesBadge {
+        generated_at: Utc::now(),
+        level: project.badge_level.filter(|level| BADGE_LEVELS.contains(&level.as_str())),
+    })
+}
+
+/// OpenSSF Best Practices project details (only the fields we are interested
+/// in).
+#[derive(Debug, Deserialize)]
+struct Project {
+    badge_level: Option<String>,
+}
+
+lazy_static! {
+    /// OpenSSF Best Practices project url regular expression.
+    static ref BEST_PRACTICES_URL: Regex =
+        Regex::new("^https://(?:www\\.)?(?:bestpractices\\.dev|bestpractices\\.coreinfrastructure\\.org)/(?:[a-zA-Z-]+/)?projects/(?P<id>\\d+)")
+            .expect("exprs in BEST_PRACTICES_URL to be valid");
+}
+
+/// Extract the project id from the best practices url provided.
+fn get_project_id(url: &str) -> Result<String> {
+    let c = BEST_PRACTICES_URL
+        .captures(url)
+        .ok_or_else(|| format_err!("invalid best practices url"))?;
+    Ok(c["id"].to_string())
+}
@@ -0,0 +1,269 @@
+//! This module defines the functionality used to collect information from
+//! Bitbucket Cloud for each of the landscape items repositories hosted there
+//! (when applicable). The information collected is mapped to the same
+//! structure used for the repositories hosted on GitHub.
+
+use super::{cache::Cache, collector::Collector, retry::RetryPolicy, LandscapeData};
+use anyhow::{bail, format_err, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use landscape2_core::data::{Commit, GithubData, RepositoryGithubData};
+use lazy_static::lazy_static;
+#[cfg(test)]
+use mockall::automock;
+use regex::Regex;
+use reqwest::StatusCode;
+use serde::Deserialize;
+use std::{env, sync::Arc};
+use tracing::{debug, instrument};
+
+/// Bitbucket API base url.
+const BITBUCKET_API_URL: &str = "https://api.bitbucket.org/2.0";
+
+/// File used to cache data collected from Bitbucket.
+pub(crate) const BITBUCKET_CACHE_FILE: &str = "bitbucket.json";
+
+/// Maximum number of repositories to process concurrently.
+const BITBUCKET_MAX_CONCURRENCY: usize = 5;
+
+/// Environment variable containing an optional Bitbucket access token.
+const BITBUCKET_TOKEN: &str = "BITBUCKET_TOKEN";
+
+/// Collect Bitbucket data for each of the items repositories in the landscape
+/// hosted on Bitbucket Cloud, reusing cached data whenever possible. Cached
+/// data is considered valid for `cache_ttl` days.
+#[instrument(skip_all, err)]
+pub(crate) async fn collect_bitbucket_data(
+    cache: &Cache,
+    landscape_data: &LandscapeData,
+    cache_ttl: i64,
+) -> Result<GithubData> {
+    debug!("collecting repositories information from bitbucket");
+
+    // Collect urls of the repositories to process
+    let mut urls = vec![];
+    for item in &landscape_data.items {
+        if let Some(repositories) = &item.repositories {
+            for repo in repositories {
+                if BITBUCKET_REPO_URL.is_match(&repo.url) {
+                    urls.push(repo.url.clone());
+                }
+            }
+        }
+    }
+    urls.sort();
+    urls.dedup();
+
+    // Collect repositories information, reusing cached data when available
+    let token = env::var(BITBUCKET_TOKEN).ok().filter(|token| !token.is_empty());
+    let bb: DynBB = Arc::new(BBApi::new(token, cache.retry_policy())?);
+    let collector = Collector {
+        cache,
+        cache_file: BITBUCKET_CACHE_FILE,
+        cache_ttl,
+        max_concurrency: BITBUCKET_MAX_CONCURRENCY,
+        description: "repository data from bitbucket",
+    };
+    let entries = urls.into_iter().map(|url| (url.clone(), url)).collect();
+    let bitbucket_data = collector
+        .collect(entries, |url, cached_repo: Option<RepositoryGithubData>| {
+            let bb = bb.clone();
+            async move {
+                let mut repo_data = collect_repository_data(bb, &url).await?;
+
+                // Keep the stars history collected in previous builds
+                repo_data.update_stars_history(cached_repo.and_then(|r| r.stars_history).as_ref());
+                Ok(repo_data)
+            }
+        })
+        .await?;
+
+    debug!("done!");
+    Ok(bitbucket_data)
+}
+
+/// Collect repository data from Bitbucket.
+#[instrument(skip(bb), err)]
+async fn collect_repository_data(bb: DynBB, repo_url: &str) -> Result<RepositoryGithubData> {
+    // Collect some information from Bitbucket
+    let (workspace, repo) = get_workspace_and_repo(repo_url)?;
+    let bb_repo = bb.get_repository(&workspace, &repo).await?;
+    let forks = bb.get_forks_count(&workspace, &repo).await?;
+    let watchers = bb.get_watchers_count(&workspace, &repo).await?;
+    let latest_commit = match &bb_repo.mainbranch {
+        Some(branch) => bb.get_latest_commit(&workspace, &repo, &branch.name).await?,
+        None => None,
+    };
+
+    // When the latest commit isn't available, we use the date of the last
+    // update of the repository instead
+    let html_url = bb_repo.links.html.href;
+    let latest_commit = latest_commit.unwrap_or_else(|| Commit {
+        ts: bb_repo.updated_on,
+        url: format!("{html_url}/commits"),
+    });
+
+    // Prepare repository instance using the information collected. Bitbucket
+    // does not support stars, so we use the number of watchers instead.
+    Ok(RepositoryGithubData {
+        generated_at: Utc::now(),
+        description: bb_repo.description,
+        forks: Some(forks),
+        latest_commit,
+        stars: watchers,
+        url: html_url,
+        ..Default::default()
+    })
+}
+
+/// Type alias to represent a BB trait object.
+type DynBB = Arc<dyn BB + Send + Sync>;
+
+/// Trait that defines some operations a BB implementation must support.
+#[async_trait]
+#[cfg_attr(test, automock)]
+trait BB {
+    /// Get number of repository forks.
+    async fn get_forks_count(&self, workspace: &str, repo: &str) -> Result<i64>;
+
+    /// Get latest commit in the branch provided.
+    async fn get_latest_commit(&self, workspace: &str, repo: &str, branch: &str) -> Result<Option<Commit>>;
+
+    /// Get repository.
+    async fn get_repository(&self, workspace: &str, repo: &str) -> Result<BBRepository>;
+
+    /// Get number of repository watchers.
+    async fn get_watchers_count(&self, workspace: &str, repo: &str) -> Result<i64>;
+}
+
+/// BB implementation backed by the Bitbucket API.
+struct BBApi {
+    http_client: reqwest::Client,
+    retry_policy: RetryPolicy,
+    token: Option<String>,
+}
+
+impl BBApi {
+    /// Create a new BBApi instance.
+    fn new(token: Option<String>, retry_policy: RetryPolicy) -> Result<Self> {
+        let user_agent = format!("{}/{}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"));
+        let http_client = reqwest::Client::builder().user_agent(user_agent).build()?;
+
+        Ok(Self {
+            http_client,
+            retry_policy,
+            token,
+        })
+    }
+
+    /// Send a GET request to the Bitbucket API endpoint provided and return
+    /// the response body deserialized.
+    async fn get<T: serde::de::DeserializeOwned>(&self, endpoint: &str) -> Result<T> {
+        let mut req = self.http_client.get(format!("{BITBUCKET_API_URL}{endpoint}"));
+        if let Some(token) = &self.token {
+            req = req.bearer_auth(token);
+        }
+        let resp = self.retry_policy.send(req).await?;
+        if resp.status() != StatusCode::OK {
+            bail!("unexpected status code requesting {endpoint}: {}", resp.status());
+        }
+        Ok(resp.json().await?)
+    }
+}
+
+#[async_trait]
+impl BB for BBApi {
+    /// [BB::get_forks_count]
+    #[instrument(skip(self), err)]
+    async fn get_forks_count(&self, workspace: &str, repo: &str) -> Result<i64> {
+        let page: BBPage<serde_json::Value> =
+            self.get(&format!("/repositories/{workspace}/{repo}/forks?pagelen=1")).await?;
+        page.size.ok_or_else(|| format_err!("forks count not available"))
+    }
+
+    /// [BB::get_latest_commit]
+    #[instrument(skip(self), err)]
+    async fn get_latest_commit(&self, workspace: &str, repo: &str, branch: &str) -> Result<Option<Commit>> {
+        let page: BBPage<BBCommit> = self
+            .get(&format!(
+                "/repositories/{workspace}/{repo}/commits/{branch}?pagelen=1"
+            ))
+            .await?;
+        Ok(page.values.into_iter().next().map(|commit| Commit {
+            ts: commit.date,
+            url: commit.links.html.href,
+        }))
+    }
+
+    /// [BB::get_repository]
+    #[instrument(skip(self), err)]
+    async fn get_repository(&self, workspace: &str, repo: &str) -> Result<BBRepository> {
+        self.get(&format!("/repositories/{workspace}/{repo}")).await
+    }
+
+    /// [BB::get_watchers_count]
+    #[instrument(skip(self), err)]
+    async fn get_watchers_count(&self, workspace: &str, repo: &str) -> Result<i64> {
+        let page: BBPage<serde_json::Value> =
+            self.get(&format!("/repositories/{workspace}/{repo}/watchers?pagelen=1")).await?;
+        page.size.ok_or_else(|| format_err!("watchers count not available"))
+    }
+}
+
+/// Bitbucket paginated response.
+#[derive(Debug, Clone, Deserialize)]
+struct BBPage<T> {
+    size: Option<i64>,
+    #[serde(default = "Vec::new")]
+    values: Vec<T>,
+}
+
+/// Bitbucket repository details.
+#[derive(Debug, Clone, Default, Deserialize)]
+struct BBRepository {
+    #[serde(default)]
+    description: String,
+    links: BBLinks,
+    mainbranch: Option<BBBranch>,
+    updated_on: Option<DateTime<Utc>>,
+}
+
+/// Bitbucket branch details.
+#[derive(Debug, Clone, Default, Deserialize)]
+struct BBBranch {
+    name: String,
+}
+
+/// Bitbucket commit details.
+#[derive(Debug, Clone, Default, Deserialize)]
+struct BBCommit {
+    date: Option<DateTime<Utc>>,
+    links: BBLinks,
+}
+
+/// Bitbucket resource links.
+#[derive(Debug, Clone, Default, Deserialize)]
+struct BBLinks {
+    html: BBLink,
+}
+
+/// Bitbucket link.
+#[derive(Debug, Clone, Default, Deserialize)]
+struct BBLink {
+    href: String,
+}
+
+lazy_static! {
+    /// Bitbucket repository url regular expression.
+    static ref BITBUCKET_REPO_URL: Regex =
+        Regex::new("^https://bitbucket.org/(?P<workspace>[^/]+)/(?P<repo>[^/]+)/?$")
+            .expect("exprs in BITBUCKET_REPO_URL to be valid");
+}
+
+/// Extract the workspace and repository from the repository url provided.
+fn get_workspace_and_repo(repo_url: &str) -> Result<(String, String)> {
+    let c = BITBUCKET_REPO_URL
+        .captures(repo_url)
+        .ok_or_else(|| format_err!("invalid repository url"))?;
+    Ok((c["workspace"].to_string(), c["repo"].to_string()))
+}
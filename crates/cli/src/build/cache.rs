@@ -1,21 +1,63 @@
-//! This module defines the cache used to cache files across builds.
+//! This module defines the cache used to cache files across builds, as well
+//! as the cache used to send conditional requests to external services.
 
+use super::retry::RetryPolicy;
 use anyhow::{bail, Result};
-use std::{fs, io::Write, path::PathBuf, time::SystemTime};
-use tracing::instrument;
+use base64::{engine::general_purpose::STANDARD as b64, Engine as _};
+use chrono::{DateTime, Utc};
+use fs4::fs_std::FileExt;
+use reqwest::{
+    header::{self, HeaderMap, HeaderName, HeaderValue},
+    Client, Method, Request, RequestBuilder, Response, StatusCode,
+};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    io::Write,
+    path::{Path, PathBuf},
+    process,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    time::{Duration, Instant, SystemTime},
+};
+use tracing::{info, instrument, warn};
 
 /// Path where the cache files will be written to inside the cache directory.
 const CACHE_PATH: &str = "landscape";
 
+/// File used to lock the cache directory, so that it's not used by multiple
+/// builds at the same time. An advisory lock is held on it, so the lock is
+/// released by the OS as soon as the build holding it exits, even when it
+/// doesn't exit cleanly.
+const LOCK_FILE: &str = ".lock";
+
+/// Maximum time to wait for the cache lock to be released by another build.
+const LOCK_MAX_WAIT: Duration = Duration::from_secs(60 * 60);
+
+/// Time to wait between attempts to acquire the cache lock.
+const LOCK_RETRY_INTERVAL: Duration = Duration::from_secs(2);
+
+/// How long the entries in the http cache are kept when they are not used
+/// (in days).
+const HTTP_CACHE_ENTRIES_TTL: i64 = 30;
+
 /// Cache used to store data collected from external services.
 #[derive(Debug, Clone, Default)]
 pub(crate) struct Cache {
     cache_dir: PathBuf,
+    offline: bool,
+    reads_disabled: bool,
+    refreshed_files: HashSet<String>,
+    retry_policy: RetryPolicy,
 }
 
 impl Cache {
-    /// Create a new Cache instance.
-    pub(crate) fn new(cache_dir: &Option<PathBuf>) -> Result<Self> {
+    /// Create a new Cache instance. In offline mode, the data available in the
+    /// cache must be used instead of collecting it from the external services.
+    pub(crate) fn new(cache_dir: &Option<PathBuf>, offline: bool) -> Result<Self> {
         // Try to use user's cache directory if no cache_dir has been provided
         let cache_dir = match cache_dir {
             Some(cache_dir) => Some(cache_dir.clone()),
@@ -27,7 +69,11 @@ impl Cache {
             if !cache_dir.exists() {
                 fs::create_dir_all(&cache_dir)?;
             }
-            return Ok(Self { cache_dir });
+            return Ok(Self {
+                cache_dir,
+                offline,
+                ..Default::default()
+            });
         }
 
         bail!(
@@ -35,9 +81,82 @@ impl Cache {
         );
     }
 
+    /// Don't read any of the files in the cache, so that all the data is
+    /// collected again (it is still written to the cache as usual).
+    pub(crate) fn with_reads_disabled(mut self) -> Self {
+        self.reads_disabled = true;
+        self
+    }
+
+    /// Don't read the cache files provided, so that the data they contain is
+    /// collected again (it is still written to the cache as usual).
+    pub(crate) fn with_refreshed_files(mut self, file_names: &[&str]) -> Self {
+        self.refreshed_files.extend(file_names.iter().map(ToString::to_string));
+        self
+    }
+
+    /// Set the policy used to retry the requests sent to external services
+    /// by the http caches using this cache.
+    pub(crate) fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Acquire an exclusive lock on the cache directory, waiting for any other
+    /// build using it to finish. The lock is released when dropped.
+    #[instrument(skip(self), err)]
+    pub(crate) async fn lock(&self) -> Result<CacheLock> {
+        let path = self.cache_dir.join(LOCK_FILE);
+        let file = fs::OpenOptions::new().create(true).truncate(false).write(true).open(&path)?;
+        let start = Instant::now();
+        let mut waiting = false;
+        loop {
+            // Try to acquire the lock (this fails if another build holds it)
+            if file.try_lock_exclusive()? {
+                file.set_len(0)?;
+                writeln!(&file, "{}", process::id())?;
+                return Ok(CacheLock { _file: file });
+            }
+
+            // Wait for the lock to be released
+            if start.elapsed() > LOCK_MAX_WAIT {
+                bail!(
+                    "timeout waiting for the cache lock to be released ({})",
+                    path.display()
+                );
+            }
+            if !waiting {
+                info!("cache directory in use by another build, waiting for it to finish..");
+                waiting = true;
+            }
+            tokio::time::sleep(LOCK_RETRY_INTERVAL).await;
+        }
+    }
+
+    /// Return the directory where the cache files are stored.
+    pub(crate) fn dir(&self) -> &Path {
+        &self.cache_dir
+    }
+
+    /// Check if the cache is being used in offline mode.
+    pub(crate) fn offline(&self) -> bool {
+        self.offline
+    }
+
+    /// Return the policy used to retry the requests sent to external
+    /// services.
+    pub(crate) fn retry_policy(&self) -> RetryPolicy {
+        self.retry_policy
+    }
+
     /// Read data from the cache file provided if available.
     #[instrument(skip(self), err)]
     pub(crate) fn read(&self, file_name: &str) -> Result<Option<(Option<SystemTime>, Vec<u8>)>> {
+        // Ignore the file if a refresh of its data has been requested
+        if self.reads_disabled || self.refreshed_files.contains(file_name) {
+            return Ok(None);
+        }
+
         // Check if the path exists
         let path = self.cache_dir.join(file_name);
         if !path.exists() {
@@ -51,12 +170,262 @@ impl Cache {
         Ok(Some((modified_at, fs::read(&path)?)))
     }
 
+    /// Read data in json format from the cache file provided. The default
+    /// value is returned when the file is not available.
+    pub(crate) fn read_json<T: DeserializeOwned + Default>(&self, file_name: &str) -> Result<T> {
+        match self.read(file_name)? {
+            Some((_, json_data)) => Ok(serde_json::from_slice(&json_data)?),
+            None => Ok(T::default()),
+        }
+    }
+
+    /// Read data in json format from the cache file provided, even when reads
+    /// have been disabled. This is used for the data accumulated across builds
+    /// that cannot be collected again. The default value is returned when the
+    /// file is not available.
+    pub(crate) fn read_history_json<T: DeserializeOwned + Default>(&self, file_name: &str) -> Result<T> {
+        let path = self.cache_dir.join(file_name);
+        if !path.exists() {
+            return Ok(T::default());
+        }
+        Ok(serde_json::from_slice(&fs::read(path)?)?)
+    }
+
     /// Write provided data to cache file.
     #[instrument(skip(self, data), err)]
     pub(crate) fn write(&self, file_name: &str, data: &[u8]) -> Result<()> {
+        // The data is written to a temporary file first and then renamed, so
+        // that readers never see a partially written file
         let path = self.cache_dir.join(file_name);
-        let mut file = fs::File::create(path)?;
+        let tmp_path = self.cache_dir.join(format!(".{file_name}.{}.tmp", process::id()));
+        let mut file = fs::File::create(&tmp_path)?;
         file.write_all(data)?;
+        fs::rename(tmp_path, path)?;
         Ok(())
     }
 }
+
+/// Exclusive lock on the cache directory (released when dropped, as the lock
+/// file is closed).
+#[derive(Debug)]
+pub(crate) struct CacheLock {
+    _file: fs::File,
+}
+
+/// Cache used to store the responses received from external services along
+/// with their validators (ETag and Last-Modified), so that they can be
+/// requested conditionally in future builds. Unchanged resources result in a
+/// `304 Not Modified` response, which usually doesn't count against the
+/// services rate limits. In offline mode, requests are never sent and only
+/// the responses available in the cache can be used. Requests are retried on
+/// network errors or when the service is not available, following the retry
+/// policy of the cache.
+#[derive(Debug)]
+pub(crate) struct HttpCache {
+    entries: Mutex<HashMap<String, HttpCacheEntry>>,
+    file_name: String,
+    offline: bool,
+    requests_sent: AtomicUsize,
+    retry_policy: RetryPolicy,
+    ttl: i64,
+}
+
+impl HttpCache {
+    /// Create a new HttpCache instance, loading the entries available in the
+    /// cache file provided.
+    pub(crate) fn new(cache: &Cache, file_name: &str) -> Self {
+        let mut entries = HashMap::new();
+        match cache.read(file_name) {
+            Ok(Some((_, json_data))) => match serde_json::from_slice(&json_data) {
+                Ok(cached_entries) => entries = cached_entries,
+                Err(err) => warn!("error parsing http cache file {file_name}: {err:?}"),
+            },
+            Ok(None) => {}
+            Err(err) => warn!("error reading http cache file {file_name}: {err:?}"),
+        }
+
+        Self {
+            entries: Mutex::new(entries),
+            file_name: file_name.to_string(),
+            offline: cache.offline(),
+            requests_sent: AtomicUsize::new(0),
+            retry_policy: cache.retry_policy,
+            ttl: 0,
+        }
+    }
+
+    /// Return the number of requests sent to the service so far (responses
+    /// reused from the cache without sending a request are not counted).
+    pub(crate) fn requests_sent(&self) -> usize {
+        self.requests_sent.load(Ordering::Relaxed)
+    }
+
+    /// Return the policy used to retry the requests sent to the service.
+    pub(crate) fn retry_policy(&self) -> RetryPolicy {
+        self.retry_policy
+    }
+
+    /// Set how long (in days) the cached responses are reused without
+    /// revalidating them with the service. By default they are always
+    /// revalidated.
+    pub(crate) fn with_ttl(mut self, ttl: i64) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Write the entries used recently to the cache.
+    #[instrument(skip_all, err)]
+    pub(crate) fn write(&self, cache: &Cache) -> Result<()> {
+        let mut entries = self.entries.lock().expect("not poisoned").clone();
+        entries
+            .retain(|_, entry| entry.used_at + chrono::Duration::days(HTTP_CACHE_ENTRIES_TTL) > Utc::now());
+        cache.write(&self.file_name, &serde_json::to_vec(&entries)?)
+    }
+
+    /// Send the request provided. GET requests are sent conditionally when a
+    /// previous response for the same url is available in the cache, which
+    /// will be reused if the resource has not been modified.
+    pub(crate) async fn send(&self, req: RequestBuilder) -> Result<HttpResponse> {
+        let (client, req) = req.build_split();
+        let mut req = req?;
+        let is_get = *req.method() == Method::GET;
+        let key = req.url().to_string();
+
+        // Add validators from the cached response (if available)
+        let cached_entry = if is_get {
+            self.entries.lock().expect("not poisoned").get(&key).cloned()
+        } else {
+            None
+        };
+        if let Some(entry) = &cached_entry {
+            // Reuse the cached response without sending the request if it
+            // was validated recently (or if we are offline)
+            let fresh = entry
+                .validated_at
+                .is_some_and(|validated_at| validated_at + chrono::Duration::days(self.ttl) > Utc::now());
+            if self.offline || fresh {
+                let mut entry = entry.clone();
+                entry.used_at = Utc::now();
+                let response = entry.to_response()?;
+                self.entries.lock().expect("not poisoned").insert(key, entry);
+                return Ok(response);
+            }
+
+            if let Some(etag) = &entry.etag {
+                req.headers_mut().insert(header::IF_NONE_MATCH, HeaderValue::from_str(etag)?);
+            }
+            if let Some(last_modified) = &entry.last_modified {
+                req.headers_mut().insert(header::IF_MODIFIED_SINCE, HeaderValue::from_str(last_modified)?);
+            }
+        }
+
+        if self.offline {
+            bail!("{key} not available in cache (offline mode)");
+        }
+
+        // Send request, reusing the cached response if not modified
+        let resp = self.execute(&client, req).await?;
+        let status = resp.status();
+        if status == StatusCode::NOT_MODIFIED {
+            if let Some(mut entry) = cached_entry {
+                entry.used_at = Utc::now();
+                entry.validated_at = Some(entry.used_at);
+                let response = entry.to_response()?;
+                self.entries.lock().expect("not poisoned").insert(key, entry);
+                return Ok(response);
+            }
+        }
+        let headers = resp.headers().clone();
+        let body = resp.bytes().await?.to_vec();
+
+        // Store response in the cache if it provides any validators
+        if is_get && status == StatusCode::OK {
+            let get_header =
+                |name: HeaderName| headers.get(name).and_then(|v| v.to_str().ok()).map(ToString::to_string);
+            let (etag, last_modified) = (get_header(header::ETAG), get_header(header::LAST_MODIFIED));
+            if etag.is_some() || last_modified.is_some() {
+                // Binary bodies (i.e. images) are stored base64 encoded
+                let (body, binary) = match String::from_utf8(body.clone()) {
+                    Ok(body) => (body, false),
+                    Err(_) => (b64.encode(&body), true),
+                };
+                let entry = HttpCacheEntry {
+                    body,
+                    binary,
+                    etag,
+                    last_modified,
+                    link: get_header(header::LINK),
+                    used_at: Utc::now(),
+                    validated_at: Some(Utc::now()),
+                };
+                self.entries.lock().expect("not poisoned").insert(key, entry);
+            }
+        }
+
+        Ok(HttpResponse {
+            status,
+            headers,
+            body,
+        })
+    }
+
+    /// Execute the request provided, retrying it (with an exponential
+    /// backoff) on network errors or when the service is not available.
+    async fn execute(&self, client: &Client, req: Request) -> Result<Response> {
+        let on_attempt = || {
+            self.requests_sent.fetch_add(1, Ordering::Relaxed);
+        };
+        Ok(self.retry_policy.execute(client, req, on_attempt).await?)
+    }
+}
+
+/// Http cache entry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct HttpCacheEntry {
+    body: String,
+    #[serde(default)]
+    binary: bool,
+    etag: Option<String>,
+    last_modified: Option<String>,
+    link: Option<String>,
+    used_at: DateTime<Utc>,
+    #[serde(default)]
+    validated_at: Option<DateTime<Utc>>,
+}
+
+impl HttpCacheEntry {
+    /// Create a new response from the cache entry.
+    fn to_response(&self) -> Result<HttpResponse> {
+        let mut headers = HeaderMap::new();
+        if let Some(link) = &self.link {
+            headers.insert(header::LINK, HeaderValue::from_str(link)?);
+        }
+
+        let body = if self.binary {
+            b64.decode(&self.body)?
+        } else {
+            self.body.clone().into_bytes()
+        };
+
+        Ok(HttpResponse {
+            status: StatusCode::OK,
+            headers,
+            body,
+        })
+    }
+}
+
+/// Http response (received from the service or reused from the cache).
+#[derive(Debug, Clone)]
+pub(crate) struct HttpResponse {
+    pub body: Vec<u8>,
+    pub headers: HeaderMap,
+    pub status: StatusCode,
+}
+
+impl HttpResponse {
+    /// Deserialize the response body as json.
+    pub(crate) fn json<T: DeserializeOwned>(&self) -> Result<T> {
+        Ok(serde_json::from_slice(&self.body)?)
+    }
+}
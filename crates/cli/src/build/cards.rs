@@ -0,0 +1,74 @@
+//! This module defines the functionality used to generate the social preview
+//! cards images of the landscape items (used to unfurl the items links on
+//! social platforms).
+
+use super::{logos::rasterize_svg, CARDS_PATH};
+use anyhow::Result;
+use askama_escape::{escape, Html};
+use base64::{engine::general_purpose::STANDARD as b64, Engine as _};
+use landscape2_core::{
+    data::{Item, LandscapeData},
+    settings::LandscapeSettings,
+};
+use std::{fs, path::Path};
+use tracing::{debug, instrument, warn};
+
+/// Background color used in the cards when no colors are provided in the
+/// landscape settings.
+const CARDS_DEFAULT_COLOR: &str = "rgba(0, 107, 204, 1)";
+
+/// Maximum number of characters of the item name displayed in the cards.
+const CARDS_NAME_MAX_LEN: usize = 24;
+
+/// Width (in pixels) of the cards images (they are 1200x630, the size
+/// recommended for Open Graph images).
+const CARDS_WIDTH: u32 = 1200;
+
+/// Generate a social preview card image for each of the landscape items,
+/// writing them to the cards path in the output directory and updating the
+/// card reference on each item.
+#[instrument(skip_all, err)]
+pub(crate) fn generate_items_cards(
+    landscape_data: &mut LandscapeData,
+    settings: &LandscapeSettings,
+    output_dir: &Path,
+) -> Result<()> {
+    debug!("generating items cards");
+
+    let color = settings.colors.as_ref().map_or(CARDS_DEFAULT_COLOR, |colors| colors.color1.as_str());
+    for item in &mut landscape_data.items {
+        match generate_item_card(item, &settings.foundation, color, output_dir) {
+            Ok(card) => item.social_card = Some(card),
+            Err(err) => warn!(?err, ?item.id, "error generating item card"),
+        }
+    }
+
+    debug!("done!");
+    Ok(())
+}
+
+/// Generate the social preview card image of the item provided, returning its
+/// path in the output directory.
+fn generate_item_card(item: &Item, foundation: &str, color: &str, output_dir: &Path) -> Result<String> {
+    // Prepare card content
+    let logo = fs::read(output_dir.join(&item.logo))?;
+    let mut name: String = item.name.chars().take(CARDS_NAME_MAX_LEN).collect();
+    if item.name.chars().count() > CARDS_NAME_MAX_LEN {
+        name.push('…');
+    }
+    let svg = format!(
+        r##"<svg xmlns="http://www.w3.org/2000/svg" width="1200" height="630" viewBox="0 0 1200 630"><rect width="1200" height="630" fill="{color}"/><rect x="40" y="40" width="1120" height="550" rx="24" fill="#fff"/><image x="100" y="140" width="350" height="350" preserveAspectRatio="xMidYMid meet" href="data:image/svg+xml;base64,{logo}"/><g font-family="sans-serif"><text x="520" y="290" font-size="64" font-weight="bold" fill="#1d1d1f">{name}</text><text x="520" y="370" font-size="34" fill="#6c757d">{category} / {subcategory}</text><text x="520" y="530" font-size="28" fill="{color}">{foundation} Landscape</text></g></svg>"##,
+        logo = b64.encode(logo),
+        name = escape(&name, Html),
+        category = escape(&item.category, Html),
+        subcategory = escape(&item.subcategory, Html),
+        foundation = escape(foundation, Html),
+    );
+
+    // Rasterize card and write it to the output directory
+    let card = rasterize_svg(svg.as_bytes(), CARDS_WIDTH, false)?;
+    let file_name = format!("{}.png", item.id);
+    fs::write(output_dir.join(CARDS_PATH).join(&file_name), card)?;
+
+    Ok(format!("{CARDS_PATH}/{file_name}"))
+}
@@ -0,0 +1,194 @@
+//! This module defines the functionality used to generate the Atom feed of
+//! the landscape changes (items added and maturity changes), so that they can
+//! be followed by subscribers. The changes are found by comparing the items
+//! with the ones in the previous build, which are kept in the cache.
+
+use super::cache::Cache;
+use anyhow::Result;
+use askama::Template;
+use chrono::{DateTime, SecondsFormat, Utc};
+use landscape2_core::{data::LandscapeData, settings::LandscapeSettings};
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, fs, path::Path};
+use tracing::{debug, instrument, warn};
+
+/// File used to cache the landscape changes, as well as the items of the
+/// previous build.
+pub(crate) const CHANGES_CACHE_FILE: &str = "changes.json";
+
+/// Maximum number of changes included in the feed.
+const CHANGES_FEED_MAX_ENTRIES: usize = 100;
+
+/// Generate the Atom feed of the landscape changes and write it to the output
+/// directory. The changes found since the previous build are added to the
+/// ones kept in the cache.
+#[instrument(skip_all, err)]
+pub(crate) fn generate_changes_feed(
+    cache: &Cache,
+    landscape_data: &LandscapeData,
+    settings: &LandscapeSettings,
+    output_dir: &Path,
+) -> Result<()> {
+    debug!("generating changes feed");
+
+    // Read changes found in previous builds (if available). The changes
+    // history is read even when the cache reads are disabled, as it can't be
+    // collected again and would be overwritten otherwise.
+    let mut changes_data: ChangesData = match cache.read_history_json(CHANGES_CACHE_FILE) {
+        Ok(changes_data) => changes_data,
+        Err(err) => {
+            warn!(?err, "error reading changes cache file");
+            ChangesData::default()
+        }
+    };
+
+    // Find changes since the previous build (when there is no previous build
+    // to compare with, all items would be reported as added, so no changes
+    // are recorded in that case)
+    let now = Utc::now();
+    let mut new_changes = vec![];
+    if !changes_data.items.is_empty() {
+        for item in &landscape_data.items {
+            let kind = match changes_data.items.get(&item.id) {
+                None => ChangeKind::Added,
+                Some(prev) if prev.maturity != item.maturity => ChangeKind::MaturityChanged {
+                    from: prev.maturity.clone(),
+                    to: item.maturity.clone(),
+                },
+                Some(_) => continue,
+            };
+            new_changes.push(Change {
+                category: item.category.clone(),
+                generated_at: now,
+                item_id: item.id.clone(),
+                item_name: item.name.clone(),
+                kind,
+                subcategory: item.subcategory.clone(),
+            });
+        }
+    }
+    new_changes.append(&mut changes_data.changes);
+    new_changes.truncate(CHANGES_FEED_MAX_ENTRIES);
+    changes_data.changes = new_changes;
+
+    // Update cache with the current items
+    changes_data.items = landscape_data
+        .items
+        .iter()
+        .map(|item| {
+            let snapshot = ItemSnapshot {
+                maturity: item.maturity.clone(),
+            };
+            (item.id.clone(), snapshot)
+        })
+        .collect();
+    match serde_json::to_vec(&changes_data) {
+        Ok(data) => {
+            if let Err(err) = cache.write(CHANGES_CACHE_FILE, &data) {
+                warn!(?err, "error writing changes cache file");
+            }
+        }
+        Err(err) => warn!(?err, "error serializing changes data"),
+    }
+
+    // Render feed and write it to the output directory
+    let landscape_url = settings.url.trim_end_matches('/');
+    let entries: Vec<FeedEntry> =
+        changes_data.changes.iter().map(|change| FeedEntry::new(change, landscape_url)).collect();
+    let updated = changes_data.changes.first().map_or(now, |change| change.generated_at);
+    let feed = ChangesFeed {
+        entries: &entries,
+        foundation: &settings.foundation,
+        landscape_url,
+        updated: updated.to_rfc3339_opts(SecondsFormat::Secs, true),
+    }
+    .render()?;
+    fs::write(output_dir.join("changes.atom"), feed)?;
+
+    debug!("done!");
+    Ok(())
+}
+
+/// Landscape changes data kept in the cache.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct ChangesData {
+    /// Changes found in previous builds (most recent first).
+    changes: Vec<Change>,
+    /// Items of the previous build, keyed by the item id.
+    items: BTreeMap<String, ItemSnapshot>,
+}
+
+/// Information about an item used to find changes between builds.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+struct ItemSnapshot {
+    maturity: Option<String>,
+}
+
+/// Change found in a landscape item.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Change {
+    category: String,
+    generated_at: DateTime<Utc>,
+    item_id: String,
+    item_name: String,
+    kind: ChangeKind,
+    subcategory: String,
+}
+
+/// Kind of change.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", tag = "type")]
+enum ChangeKind {
+    Added,
+    MaturityChanged {
+        from: Option<String>,
+        to: Option<String>,
+    },
+}
+
+/// Template for the changes Atom feed.
+#[derive(Debug, Clone, Template)]
+#[template(path = "changes.atom", escape = "html")]
+struct ChangesFeed<'a> {
+    entries: &'a [FeedEntry],
+    foundation: &'a str,
+    landscape_url: &'a str,
+    updated: String,
+}
+
+/// Entry of the changes feed.
+#[derive(Debug, Clone)]
+struct FeedEntry {
+    id: String,
+    link: String,
+    summary: String,
+    title: String,
+    updated: String,
+}
+
+impl FeedEntry {
+    /// Create a new feed entry from the change provided.
+    fn new(change: &Change, landscape_url: &str) -> Self {
+        let link = format!("{landscape_url}/?item={}", change.item_id);
+        let name = &change.item_name;
+        let (kind, title) = match &change.kind {
+            ChangeKind::Added => ("added", format!("{name} added to the landscape")),
+            ChangeKind::MaturityChanged { from, to } => {
+                let title = match (from, to) {
+                    (Some(from), Some(to)) => format!("{name} moved from {from} to {to}"),
+                    (None, Some(to)) => format!("{name} is now {to}"),
+                    (Some(from), None) => format!("{name} is no longer {from}"),
+                    (None, None) => format!("{name} maturity changed"),
+                };
+                ("maturity", title)
+            }
+        };
+        Self {
+            id: format!("{link}#{kind}-{}", change.generated_at.timestamp()),
+            link,
+            summary: format!("{title} ({} / {}).", change.category, change.subcategory),
+            title,
+            updated: change.generated_at.to_rfc3339_opts(SecondsFormat::Secs, true),
+        }
+    }
+}
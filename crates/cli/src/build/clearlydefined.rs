@@ -0,0 +1,205 @@
+//! This module defines the functionality used to collect license information
+//! from ClearlyDefined for the packages declared by the landscape items (when
+//! applicable).
+
+use super::{cache::Cache, collector::Collector, retry::RetryPolicy, LandscapeData};
+use anyhow::{bail, format_err, Result};
+use async_trait::async_trait;
+use chrono::Utc;
+use landscape2_core::data::{ClearlyDefinedData, ClearlyDefinedDefinition, ItemPackage, PackagesData};
+#[cfg(test)]
+use mockall::automock;
+use reqwest::StatusCode;
+use serde::Deserialize;
+use std::sync::Arc;
+use tracing::{debug, instrument};
+
+/// ClearlyDefined API base url.
+const CLEARLYDEFINED_API_URL: &str = "https://api.clearlydefined.io";
+
+/// File used to cache data collected from ClearlyDefined.
+pub(crate) const CLEARLYDEFINED_CACHE_FILE: &str = "clearlydefined.json";
+
+/// How long the ClearlyDefined data in the cache is valid (in days).
+const CLEARLYDEFINED_CACHE_TTL: i64 = 7;
+
+/// Maximum number of packages to process concurrently.
+const CLEARLYDEFINED_MAX_CONCURRENCY: usize = 5;
+
+/// Collect the ClearlyDefined definition of the primary package (the first one
+/// declared) of each of the items in the landscape, reusing cached data
+/// whenever possible. The latest version of the package collected from its
+/// registry is used, so packages without registry data are skipped.
+#[instrument(skip_all, err)]
+pub(crate) async fn collect_clearlydefined_data(
+    cache: &Cache,
+    landscape_data: &LandscapeData,
+    packages_data: &PackagesData,
+) -> Result<ClearlyDefinedData> {
+    debug!("collecting packages licenses from clearlydefined");
+
+    // Collect items primary packages (along with their latest version)
+    let mut packages: Vec<(&ItemPackage, &String)> = vec![];
+    for item in &landscape_data.items {
+        let Some(package) = item.packages.as_ref().and_then(|packages| packages.first()) else {
+            continue;
+        };
+        if let Some(version) = packages_data.get(&package.id()).and_then(|p| p.latest_version.as_ref()) {
+            packages.push((package, version));
+        }
+    }
+    packages.sort_by_key(|(p, _)| p.id());
+    packages.dedup_by_key(|(p, _)| p.id());
+
+    // Collect packages definitions, reusing cached data when available (and
+    // it matches the package latest version)
+    let cd: DynCD = Arc::new(CDApi::new(cache.retry_policy())?);
+    let collector = Collector {
+        cache,
+        cache_file: CLEARLYDEFINED_CACHE_FILE,
+        cache_ttl: CLEARLYDEFINED_CACHE_TTL,
+        max_concurrency: CLEARLYDEFINED_MAX_CONCURRENCY,
+        description: "clearlydefined definition",
+    };
+    let entries = packages
+        .into_iter()
+        .map(|(package, version)| (package.id(), (package, version)))
+        .collect();
+    let clearlydefined_data = collector
+        .collect(entries, |(package, version), _| {
+            let cd = cd.clone();
+            async move { collect_definition(cd, package, version).await }
+        })
+        .await?;
+
+    debug!("done!");
+    Ok(clearlydefined_data)
+}
+
+/// Collect package definition from ClearlyDefined.
+#[instrument(skip(cd), err)]
+async fn collect_definition(
+    cd: DynCD,
+    package: &ItemPackage,
+    version: &str,
+) -> Result<ClearlyDefinedDefinition> {
+    let coordinates = get_coordinates(package, version)?;
+    let definition = cd.get_definition(&coordinates).await?;
+
+    let (declared, discovered) = match definition.licensed {
+        Some(licensed) => (
+            licensed.declared.filter(|l| l != "NOASSERTION"),
+            licensed
+                .facets
+                .and_then(|f| f.core)
+                .and_then(|c| c.discovered)
+                .and_then(|d| d.expressions)
+                .filter(|e| !e.is_empty()),
+        ),
+        None => (None, None),
+    };
+
+    Ok(ClearlyDefinedDefinition {
+        generated_at: Utc::now(),
+        revision: version.to_string(),
+        declared,
+        discovered,
+    })
+}
+
+/// Get the ClearlyDefined coordinates (type/provider/namespace/name/revision)
+/// of the package provided.
+fn get_coordinates(package: &ItemPackage, version: &str) -> Result<String> {
+    let name = &package.name;
+    let coordinates = match package.registry.as_str() {
+        "crates" => format!("crate/cratesio/-/{name}/{version}"),
+        "maven" => {
+            let (group, artifact) =
+                name.split_once(':').ok_or_else(|| format_err!("invalid maven package name"))?;
+            format!("maven/mavencentral/{group}/{artifact}/{version}")
+        }
+        "npm" => match name.split_once('/') {
+            Some((scope, name)) => format!("npm/npmjs/{scope}/{name}/{version}"),
+            None => format!("npm/npmjs/-/{name}/{version}"),
+        },
+        "pypi" => format!("pypi/pypi/-/{name}/{version}"),
+        registry => bail!("unsupported package registry: {registry}"),
+    };
+    Ok(coordinates)
+}
+
+/// Type alias to represent a CD trait object.
+type DynCD = Arc<dyn CD + Send + Sync>;
+
+/// Trait that defines some operations a CD implementation must support.
+#[async_trait]
+#[cfg_attr(test, automock)]
+trait CD {
+    /// Get definition of the component identified by the coordinates
+    /// provided.
+    async fn get_definition(&self, coordinates: &str) -> Result<CDDefinition>;
+}
+
+/// CD implementation backed by the ClearlyDefined API.
+struct CDApi {
+    http_client: reqwest::Client,
+    retry_policy: RetryPolicy,
+}
+
+impl CDApi {
+    /// Create a new CDApi instance.
+    fn new(retry_policy: RetryPolicy) -> Result<Self> {
+        let user_agent = format!("{}/{}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"));
+        let http_client = reqwest::Client::builder().user_agent(user_agent).build()?;
+
+        Ok(Self {
+            http_client,
+            retry_policy,
+        })
+    }
+}
+
+#[async_trait]
+impl CD for CDApi {
+    /// [CD::get_definition]
+    #[instrument(skip(self), err)]
+    async fn get_definition(&self, coordinates: &str) -> Result<CDDefinition> {
+        let url = format!("{CLEARLYDEFINED_API_URL}/definitions/{coordinates}");
+        let resp = self.retry_policy.send(self.http_client.get(url)).await?;
+        if resp.status() != StatusCode::OK {
+            bail!("unexpected status code getting definition: {}", resp.status());
+        }
+        Ok(resp.json().await?)
+    }
+}
+
+/// ClearlyDefined definition details.
+#[derive(Debug, Clone, Default, Deserialize)]
+struct CDDefinition {
+    licensed: Option<CDLicensed>,
+}
+
+/// ClearlyDefined definition licensing details.
+#[derive(Debug, Clone, Default, Deserialize)]
+struct CDLicensed {
+    declared: Option<String>,
+    facets: Option<CDFacets>,
+}
+
+/// ClearlyDefined definition facets.
+#[derive(Debug, Clone, Default, Deserialize)]
+struct CDFacets {
+    core: Option<CDFacet>,
+}
+
+/// ClearlyDefined definition facet details.
+#[derive(Debug, Clone, Default, Deserialize)]
+struct CDFacet {
+    discovered: Option<CDDiscovered>,
+}
+
+/// ClearlyDefined licenses discovered in a facet.
+#[derive(Debug, Clone, Default, Deserialize)]
+struct CDDiscovered {
+    expressions: Option<Vec<String>>,
+}
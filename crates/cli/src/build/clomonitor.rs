@@ -4,7 +4,9 @@
 use super::cache::Cache;
 use anyhow::{bail, Result};
 use chrono::{DateTime, Utc};
+use landscape2_core::data::{ClomonitorScore, Item};
 use reqwest::StatusCode;
+use serde::Deserialize;
 
 /// How long the CLOMonitor data in the cache is valid (in days).
 const CLOMONITOR_CACHE_TTL: i64 = 7;
@@ -12,6 +14,18 @@ const CLOMONITOR_CACHE_TTL: i64 = 7;
 /// Foundations supported by CLOMonitor.
 const SUPPORTED_FOUNDATIONS: [&str; 2] = ["cncf", "lfaidata"];
 
+/// Return the name used to identify the item's project in CLOMonitor. The
+/// name configured explicitly in the data file is used when available.
+/// Otherwise, for items that are foundation projects (i.e. they have a
+/// maturity set), the name is derived from the item's name.
+pub(crate) fn project_name(item: &Item) -> Option<String> {
+    if let Some(clomonitor_name) = &item.clomonitor_name {
+        return Some(clomonitor_name.clone());
+    }
+    item.maturity.as_ref()?;
+    Some(item.name.trim().to_lowercase().replace(' ', "-"))
+}
+
 /// Fetch project's report summary in SVG format from CLOMonitor.
 pub(crate) async fn fetch_report_summary(
     cache: &Cache,
@@ -29,10 +43,13 @@ pub(crate) async fn fetch_report_summary(
     let cache_file = format!("clomonitor_{foundation}_{project_name}.svg");
     if let Ok(Some((Some(modified_at), cached_report_summary))) = cache.read(&cache_file) {
         let modified_at: DateTime<Utc> = modified_at.into();
-        if Utc::now() - chrono::Duration::days(CLOMONITOR_CACHE_TTL) < modified_at {
+        if cache.offline() || Utc::now() - chrono::Duration::days(CLOMONITOR_CACHE_TTL) < modified_at {
             return Ok(Some(cached_report_summary));
         }
     }
+    if cache.offline() {
+        return Ok(None);
+    }
 
     // Fetch report summary from CLOMonitor
     let url = format!("https://clomonitor.io/api/projects/{foundation}/{project_name}/report-summary");
@@ -50,3 +67,57 @@ pub(crate) async fn fetch_report_summary(
         ),
     }
 }
+
+/// Fetch project's score from CLOMonitor.
+pub(crate) async fn fetch_score(
+    cache: &Cache,
+    http_client: reqwest::Client,
+    foundation: &str,
+    project_name: &str,
+) -> Result<Option<ClomonitorScore>> {
+    // Check if the foundation provided is supported by CLOMonitor
+    let foundation = foundation.to_lowercase();
+    if !SUPPORTED_FOUNDATIONS.contains(&foundation.as_str()) {
+        return Ok(None);
+    }
+
+    // Use cached score (if available and not expired). Projects not found
+    // in CLOMonitor or without a score are cached as well, so that they are
+    // not requested again until the cached entry expires.
+    let cache_file = format!("clomonitor_{foundation}_{project_name}.json");
+    if let Ok(Some((Some(modified_at), cached_score))) = cache.read(&cache_file) {
+        let modified_at: DateTime<Utc> = modified_at.into();
+        if cache.offline() || Utc::now() - chrono::Duration::days(CLOMONITOR_CACHE_TTL) < modified_at {
+            if let Ok(score) = serde_json::from_slice(&cached_score) {
+                return Ok(score);
+            }
+        }
+    }
+    if cache.offline() {
+        return Ok(None);
+    }
+
+    // Fetch project details (including the score) from CLOMonitor
+    let url = format!("https://clomonitor.io/api/projects/{foundation}/{project_name}");
+    let resp = http_client.get(url).send().await?;
+    let score = match resp.status() {
+        StatusCode::OK => {
+            let project: Project = resp.json().await?;
+            project.score
+        }
+        StatusCode::NOT_FOUND => None,
+        _ => bail!(
+            "unexpected status code getting clomonitor project: {}",
+            resp.status()
+        ),
+    };
+    cache.write(&cache_file, &serde_json::to_vec_pretty(&score)?)?;
+
+    Ok(score)
+}
+
+/// CLOMonitor project details (only the fields we are interested in).
+#[derive(Debug, Deserialize)]
+struct Project {
+    score: Option<ClomonitorScore>,
+}
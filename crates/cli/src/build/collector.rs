@@ -0,0 +1,174 @@
+//! This module defines some functionality shared by the collectors of data
+//! from external services that keep a cached copy of the data collected for
+//! each entry (i.e. a repository, a package or a social account).
+
+use super::cache::Cache;
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use futures::stream::{self, StreamExt};
+use landscape2_core::data::{
+    BestPracticesBadge, ClearlyDefinedDefinition, ContainerImageData, FeedData, ItemPackage,
+    LinkedInOrganizationData, PackageData, RepositoryGithubData, SocialAccountData, SponsorshipData,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use std::{collections::BTreeMap, future::Future};
+use tracing::warn;
+
+/// Trait implemented by the data collected for each of the entries. The input
+/// used to collect the entry's data is provided to check if the cached data
+/// can still be used.
+pub(crate) trait CollectedData<I> {
+    /// Return the time the data was collected.
+    fn generated_at(&self) -> DateTime<Utc>;
+
+    /// Check if the data is still current for the input provided (in addition
+    /// to checking that it hasn't expired yet).
+    fn is_current(&self, _input: &I) -> bool {
+        true
+    }
+}
+
+/// Collector settings.
+pub(crate) struct Collector<'a> {
+    /// Cache used to read and write the data collected.
+    pub cache: &'a Cache,
+
+    /// File in the cache where the data collected is stored.
+    pub cache_file: &'static str,
+
+    /// How long the data in the cache is valid (in days).
+    pub cache_ttl: i64,
+
+    /// Maximum number of entries to process concurrently.
+    pub max_concurrency: usize,
+
+    /// Description of the data collected, used in the log messages.
+    pub description: &'static str,
+}
+
+impl Collector<'_> {
+    /// Collect the data of the entries provided (identified by their key) using
+    /// the function given, reusing the data cached in previous builds when it
+    /// hasn't expired yet. The previous cached data of the entry (if any) is
+    /// passed to the function, so that it can keep some of it when needed.
+    /// Entries whose data could not be collected are skipped. The data
+    /// collected is written to the cache once all entries have been processed.
+    pub(crate) async fn collect<I, D, F, Fut>(
+        &self,
+        entries: Vec<(String, I)>,
+        collect_entry: F,
+    ) -> Result<BTreeMap<String, D>>
+    where
+        D: CollectedData<I> + Serialize + DeserializeOwned,
+        F: Fn(I, Option<D>) -> Fut,
+        Fut: Future<Output = Result<D>>,
+    {
+        // Read cached data (if available)
+        let mut cached_data: BTreeMap<String, D> = BTreeMap::new();
+        match self.cache.read(self.cache_file) {
+            Ok(Some((_, json_data))) => match serde_json::from_slice(&json_data) {
+                Ok(data) => cached_data = data,
+                Err(err) => warn!(cache_file = self.cache_file, "error parsing cache file: {err:?}"),
+            },
+            Ok(None) => {}
+            Err(err) => warn!(cache_file = self.cache_file, "error reading cache file: {err:?}"),
+        }
+
+        // Collect entries data, reusing cached data when available
+        let collect_entry = &collect_entry;
+        let data: BTreeMap<String, D> = stream::iter(entries)
+            .map(|(key, input)| {
+                let cached_entry = cached_data.remove(&key);
+                async move {
+                    let result = match cached_entry {
+                        // Use cached data when available if it hasn't expired yet
+                        Some(entry)
+                            if entry.generated_at() + chrono::Duration::days(self.cache_ttl) > Utc::now()
+                                && entry.is_current(&input) =>
+                        {
+                            Ok(entry)
+                        }
+                        // Otherwise we pull it from the corresponding service
+                        cached_entry => collect_entry(input, cached_entry).await,
+                    };
+                    (key, result)
+                }
+            })
+            .buffer_unordered(self.max_concurrency)
+            .collect::<Vec<(String, Result<D>)>>()
+            .await
+            .into_iter()
+            .filter_map(|(key, result)| match result {
+                Ok(entry) => Some((key, entry)),
+                Err(err) => {
+                    warn!(?err, ?key, "error collecting {}", self.description);
+                    None
+                }
+            })
+            .collect();
+
+        // Write data (in json format) to cache
+        self.cache.write(self.cache_file, &serde_json::to_vec_pretty(&data)?)?;
+
+        Ok(data)
+    }
+}
+
+impl<I> CollectedData<I> for BestPracticesBadge {
+    fn generated_at(&self) -> DateTime<Utc> {
+        self.generated_at
+    }
+}
+
+impl<'a> CollectedData<(&'a ItemPackage, &'a String)> for ClearlyDefinedDefinition {
+    fn generated_at(&self) -> DateTime<Utc> {
+        self.generated_at
+    }
+
+    /// The definition must match the package latest version.
+    fn is_current(&self, (_, version): &(&'a ItemPackage, &'a String)) -> bool {
+        self.revision == **version
+    }
+}
+
+impl<I> CollectedData<I> for ContainerImageData {
+    fn generated_at(&self) -> DateTime<Utc> {
+        self.generated_at
+    }
+}
+
+impl<I> CollectedData<I> for FeedData {
+    fn generated_at(&self) -> DateTime<Utc> {
+        self.generated_at
+    }
+}
+
+impl<I> CollectedData<I> for LinkedInOrganizationData {
+    fn generated_at(&self) -> DateTime<Utc> {
+        self.generated_at
+    }
+}
+
+impl<I> CollectedData<I> for PackageData {
+    fn generated_at(&self) -> DateTime<Utc> {
+        self.generated_at
+    }
+}
+
+impl<I> CollectedData<I> for RepositoryGithubData {
+    fn generated_at(&self) -> DateTime<Utc> {
+        self.generated_at
+    }
+}
+
+impl<I> CollectedData<I> for SocialAccountData {
+    fn generated_at(&self) -> DateTime<Utc> {
+        self.generated_at
+    }
+}
+
+impl<I> CollectedData<I> for SponsorshipData {
+    fn generated_at(&self) -> DateTime<Utc> {
+        self.generated_at
+    }
+}
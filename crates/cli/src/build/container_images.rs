@@ -0,0 +1,214 @@
+//! This module defines the functionality used to collect information from the
+//! container registries (Docker Hub, GitHub Container Registry and Quay) for
+//! each of the container images declared in the landscape items (when
+//! applicable).
+
+use super::{cache::Cache, collector::Collector, retry::RetryPolicy, LandscapeData};
+use anyhow::{bail, Result};
+use async_trait::async_trait;
+use chrono::Utc;
+use landscape2_core::data::{ContainerImageData, ContainerImageRef, ContainerImagesData};
+use lazy_static::lazy_static;
+#[cfg(test)]
+use mockall::automock;
+use regex::Regex;
+use reqwest::StatusCode;
+use serde::Deserialize;
+use std::{collections::BTreeMap, sync::Arc};
+use tracing::{debug, instrument};
+
+/// File used to cache data collected from the container registries.
+pub(crate) const CONTAINER_IMAGES_CACHE_FILE: &str = "container_images.json";
+
+/// How long the container images data in the cache is valid (in days).
+const CONTAINER_IMAGES_CACHE_TTL: i64 = 7;
+
+/// Maximum number of container images to process concurrently.
+const CONTAINER_IMAGES_MAX_CONCURRENCY: usize = 5;
+
+/// Collect registry data for each of the container images declared in the
+/// landscape items, reusing cached data whenever possible.
+#[instrument(skip_all, err)]
+pub(crate) async fn collect_container_images_data(
+    cache: &Cache,
+    landscape_data: &LandscapeData,
+) -> Result<ContainerImagesData> {
+    debug!("collecting container images information from registries");
+
+    // Collect the repositories of the container images declared in the
+    // landscape items (different tags or digests of the same image share the
+    // repository, so its data is only collected once)
+    let mut images = BTreeMap::new();
+    for item in &landscape_data.items {
+        if let Some(container_images) = &item.container_images {
+            for image in container_images {
+                if let Ok(image_ref) = ContainerImageRef::parse(image) {
+                    images.insert(image_ref.repository(), image_ref);
+                }
+            }
+        }
+    }
+
+    // Collect images information, reusing cached data when available
+    let cr: DynCR = Arc::new(CRApi::new(cache.retry_policy())?);
+    let collector = Collector {
+        cache,
+        cache_file: CONTAINER_IMAGES_CACHE_FILE,
+        cache_ttl: CONTAINER_IMAGES_CACHE_TTL,
+        max_concurrency: CONTAINER_IMAGES_MAX_CONCURRENCY,
+        description: "container image data",
+    };
+    let entries = images.into_iter().collect();
+    let container_images_data = collector
+        .collect(entries, |image_ref, _| {
+            let cr = cr.clone();
+            async move { collect_image_data(cr, &image_ref).await }
+        })
+        .await?;
+
+    debug!("done!");
+    Ok(container_images_data)
+}
+
+/// Collect container image data from the corresponding registry.
+#[instrument(skip(cr), err)]
+async fn collect_image_data(cr: DynCR, image_ref: &ContainerImageRef) -> Result<ContainerImageData> {
+    let (namespace, name) = (image_ref.namespace.as_str(), image_ref.name.as_str());
+    let (pulls, stars) = match image_ref.registry.as_str() {
+        "docker.io" => cr.get_docker_hub_image(namespace, name).await?,
+        "ghcr.io" => cr.get_ghcr_image(namespace, name).await?,
+        "quay.io" => cr.get_quay_image(namespace, name).await?,
+        host => bail!("unsupported container registry: {host}"),
+    };
+
+    Ok(ContainerImageData {
+        generated_at: Utc::now(),
+        pulls,
+        stars,
+    })
+}
+
+/// Type alias to represent the pulls and stars counts of a container image.
+type ImageInfo = (Option<u64>, Option<u64>);
+
+/// Type alias to represent a CR trait object.
+type DynCR = Arc<dyn CR + Send + Sync>;
+
+/// Trait that defines some operations a CR implementation must support.
+#[async_trait]
+#[cfg_attr(test, automock)]
+trait CR {
+    /// Get image information from Docker Hub (total pulls and stars).
+    async fn get_docker_hub_image(&self, namespace: &str, name: &str) -> Result<ImageInfo>;
+
+    /// Get image information from GitHub Container Registry (total
+    /// downloads, stars are not available).
+    async fn get_ghcr_image(&self, namespace: &str, name: &str) -> Result<ImageInfo>;
+
+    /// Get image information from Quay (pulls in the last 30 days, stars are
+    /// not available).
+    async fn get_quay_image(&self, namespace: &str, name: &str) -> Result<ImageInfo>;
+}
+
+/// CR implementation backed by the container registries APIs.
+struct CRApi {
+    http_client: reqwest::Client,
+    retry_policy: RetryPolicy,
+}
+
+impl CRApi {
+    /// Create a new CRApi instance.
+    fn new(retry_policy: RetryPolicy) -> Result<Self> {
+        let user_agent = format!("{}/{}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"));
+        let http_client = reqwest::Client::builder().user_agent(user_agent).build()?;
+
+        Ok(Self {
+            http_client,
+            retry_policy,
+        })
+    }
+}
+
+#[async_trait]
+impl CR for CRApi {
+    /// [CR::get_docker_hub_image]
+    #[instrument(skip(self), err)]
+    async fn get_docker_hub_image(&self, namespace: &str, name: &str) -> Result<ImageInfo> {
+        let url = format!("https://hub.docker.com/v2/repositories/{namespace}/{name}");
+        let resp = self.retry_policy.send(self.http_client.get(url)).await?;
+        if resp.status() != StatusCode::OK {
+            bail!(
+                "unexpected status code getting docker hub repository: {}",
+                resp.status()
+            );
+        }
+        let repository: DockerHubRepository = resp.json().await?;
+        Ok((Some(repository.pull_count), Some(repository.star_count)))
+    }
+
+    /// [CR::get_ghcr_image]
+    #[instrument(skip(self), err)]
+    async fn get_ghcr_image(&self, namespace: &str, name: &str) -> Result<ImageInfo> {
+        // GHCR doesn't provide an API to get the downloads count of public
+        // images, so we extract it from the package page (the namespace can be
+        // an organization or a user)
+        for kind in ["orgs", "users"] {
+            let url = format!("https://github.com/{kind}/{namespace}/packages/container/package/{name}");
+            let resp = self.retry_policy.send(self.http_client.get(url)).await?;
+            match resp.status() {
+                StatusCode::OK => {
+                    let page = resp.text().await?;
+                    let pulls = GHCR_TOTAL_DOWNLOADS
+                        .captures(&page)
+                        .and_then(|c| c["count"].replace(',', "").parse().ok());
+                    return Ok((pulls, None));
+                }
+                StatusCode::NOT_FOUND => continue,
+                status => bail!("unexpected status code getting ghcr package page: {status}"),
+            }
+        }
+        bail!("ghcr package not found")
+    }
+
+    /// [CR::get_quay_image]
+    #[instrument(skip(self), err)]
+    async fn get_quay_image(&self, namespace: &str, name: &str) -> Result<ImageInfo> {
+        let url = format!("https://quay.io/api/v1/repository/{namespace}/{name}?includeStats=true");
+        let resp = self.retry_policy.send(self.http_client.get(url)).await?;
+        if resp.status() != StatusCode::OK {
+            bail!(
+                "unexpected status code getting quay repository: {}",
+                resp.status()
+            );
+        }
+        let repository: QuayRepository = resp.json().await?;
+        let pulls = repository.stats.map(|stats| stats.iter().map(|s| s.count).sum());
+        Ok((pulls, None))
+    }
+}
+
+/// Docker Hub repository details.
+#[derive(Debug, Deserialize)]
+struct DockerHubRepository {
+    pull_count: u64,
+    star_count: u64,
+}
+
+/// Quay repository details.
+#[derive(Debug, Deserialize)]
+struct QuayRepository {
+    stats: Option<Vec<QuayStat>>,
+}
+
+/// Quay repository daily stat.
+#[derive(Debug, Deserialize)]
+struct QuayStat {
+    count: u64,
+}
+
+lazy_static! {
+    /// GHCR package page total downloads regular expression.
+    static ref GHCR_TOTAL_DOWNLOADS: Regex =
+        Regex::new(r#"Total downloads</span>\s*<h3 title="(?P<count>[\d,]+)""#)
+            .expect("exprs in GHCR_TOTAL_DOWNLOADS to be valid");
+}
@@ -1,13 +1,15 @@
 //! This module defines some types used to represent the information collected
 //! from Crunchbase for each of the landscape items (when applicable), as well
-//! as the functionality used to collect that information.
+//! as the organizations data provider used to collect that information.
 
-use super::{cache::Cache, LandscapeData};
+use super::{
+    cache::{Cache, HttpCache},
+    organizations::OP,
+};
 use anyhow::{bail, format_err, Result};
 use async_trait::async_trait;
 use chrono::{Datelike, NaiveDate, Utc};
-use futures::stream::{self, StreamExt};
-use landscape2_core::data::{Acquisition, CrunchbaseData, FundingRound, Organization};
+use landscape2_core::data::{AcquiredBy, Acquisition, FundingRound, Organization};
 use lazy_static::lazy_static;
 use leaky_bucket::RateLimiter;
 #[cfg(test)]
@@ -15,14 +17,15 @@ use mockall::automock;
 use regex::Regex;
 use reqwest::{header, StatusCode};
 use serde::{Deserialize, Serialize};
-use std::{collections::BTreeMap, env, sync::Arc, time::Duration};
-use tracing::{debug, instrument, warn};
+use std::{env, sync::Arc, time::Duration};
+use tracing::{instrument, warn};
 
 /// File used to cache data collected from Crunchbase.
-const CRUNCHBASE_CACHE_FILE: &str = "crunchbase.json";
+pub(crate) const CRUNCHBASE_CACHE_FILE: &str = "crunchbase.json";
 
-/// How long the Crunchbase data in the cache is valid (in days).
-const CRUNCHBASE_CACHE_TTL: i64 = 7;
+/// File used to cache the Crunchbase API responses (used in conditional
+/// requests).
+pub(crate) const CRUNCHBASE_HTTP_CACHE_FILE: &str = "crunchbase_http.json";
 
 /// Environment variable containing the Crunchbase API key.
 const CRUNCHBASE_API_KEY: &str = "CRUNCHBASE_API_KEY";
@@ -30,95 +33,51 @@ const CRUNCHBASE_API_KEY: &str = "CRUNCHBASE_API_KEY";
 /// Interval for the rate limiter used when sending requests to the CB API.
 const CRUNCHBASE_RATE_LIMITER_INTERVAL: Duration = Duration::from_millis(300);
 
-/// Collect Crunchbase data for each of the items orgs in the landscape,
-/// reusing cached data whenever possible.
-#[instrument(skip_all, err)]
-pub(crate) async fn collect_crunchbase_data(
-    cache: &Cache,
-    landscape_data: &LandscapeData,
-) -> Result<CrunchbaseData> {
-    debug!("collecting organizations information from crunchbase (this may take a while)");
-
-    // Read cached data (if available)
-    let mut cached_data: Option<CrunchbaseData> = None;
-    match cache.read(CRUNCHBASE_CACHE_FILE) {
-        Ok(Some((_, json_data))) => match serde_json::from_slice(&json_data) {
-            Ok(crunchbase_data) => cached_data = Some(crunchbase_data),
-            Err(err) => warn!("error parsing crunchbase cache file: {err:?}"),
-        },
-        Ok(None) => {}
-        Err(err) => warn!("error reading crunchbase cache file: {err:?}"),
-    }
+/// OP implementation backed by Crunchbase.
+pub(crate) struct CBProvider {
+    cb: DynCB,
+    http_cache: Arc<HttpCache>,
+    limiter: RateLimiter,
+}
 
-    // Setup Crunchbase API client if an api key was provided
-    let api_key = match env::var(CRUNCHBASE_API_KEY) {
-        Ok(api_key) if !api_key.is_empty() => Some(api_key),
-        Ok(_) | Err(_) => None,
-    };
-    let cb: Option<DynCB> = if let Some(api_key) = api_key {
-        Some(Arc::new(CBApi::new(&api_key)?))
-    } else {
-        warn!("crunchbase api key not provided: no information will be collected from crunchbase");
-        None
-    };
+impl CBProvider {
+    /// Create a new CBProvider instance. None is returned when the Crunchbase
+    /// API key has not been provided.
+    pub(crate) fn new(cache: &Cache) -> Result<Option<Self>> {
+        let api_key = match env::var(CRUNCHBASE_API_KEY) {
+            Ok(api_key) if !api_key.is_empty() => api_key,
+            Ok(_) | Err(_) => {
+                warn!("crunchbase api key not provided: no information will be collected from crunchbase");
+                return Ok(None);
+            }
+        };
+        let http_cache = Arc::new(HttpCache::new(cache, CRUNCHBASE_HTTP_CACHE_FILE));
+
+        Ok(Some(Self {
+            cb: Arc::new(CBApi::new(&api_key, http_cache.clone())?),
+            http_cache,
+            limiter: RateLimiter::builder().initial(1).interval(CRUNCHBASE_RATE_LIMITER_INTERVAL).build(),
+        }))
+    }
+}
 
-    // Collect items Crunchbase urls
-    let mut urls = vec![];
-    for item in &landscape_data.items {
-        if let Some(url) = &item.crunchbase_url {
-            urls.push(url);
-        }
+#[async_trait]
+impl OP for CBProvider {
+    /// [OP::get_organization]
+    async fn get_organization(&self, cb_url: &str) -> Result<Organization> {
+        self.limiter.acquire_one().await;
+        collect_organization_data(self.cb.clone(), cb_url).await
     }
-    urls.sort();
-    urls.dedup();
-
-    // Collect information from Crunchbase, reusing cached data when available
-    let limiter = RateLimiter::builder().initial(1).interval(CRUNCHBASE_RATE_LIMITER_INTERVAL).build();
-    let crunchbase_data: CrunchbaseData = stream::iter(urls)
-        .map(|url| async {
-            let url = url.clone();
-
-            // Use cached data when available if it hasn't expired yet
-            if let Some(cached_org) = cached_data.as_ref().and_then(|cache| {
-                cache.get(&url).and_then(|org| {
-                    if org.generated_at + chrono::Duration::days(CRUNCHBASE_CACHE_TTL) > Utc::now() {
-                        Some(org)
-                    } else {
-                        None
-                    }
-                })
-            }) {
-                (url, Ok(cached_org.clone()))
-            }
-            // Otherwise we pull it from Crunchbase if a key was provided
-            else if let Some(cb) = cb.clone() {
-                limiter.acquire_one().await;
-                (url.clone(), collect_organization_data(cb, &url).await)
-            } else {
-                (url.clone(), Err(format_err!("no api key provided")))
-            }
-        })
-        .buffer_unordered(1)
-        .collect::<BTreeMap<String, Result<Organization>>>()
-        .await
-        .into_iter()
-        .filter_map(|(url, result)| {
-            if let Ok(crunchbase_data) = result {
-                Some((url, crunchbase_data))
-            } else {
-                None
-            }
-        })
-        .collect();
 
-    // Write data (in json format) to cache
-    cache.write(
-        CRUNCHBASE_CACHE_FILE,
-        &serde_json::to_vec_pretty(&crunchbase_data)?,
-    )?;
+    /// [OP::api_calls]
+    fn api_calls(&self) -> usize {
+        self.http_cache.requests_sent()
+    }
 
-    debug!("done!");
-    Ok(crunchbase_data)
+    /// [OP::write_cache]
+    fn write_cache(&self, cache: &Cache) -> Result<()> {
+        self.http_cache.write(cache)
+    }
 }
 
 /// Collect organization data from Crunchbase.
@@ -149,11 +108,27 @@ async fn collect_organization_data(cb: DynCB, cb_url: &str) -> Result<Organizati
         })
         .filter(|vec: &Vec<Acquisition>| !vec.is_empty());
 
-    // Prepare funding rounds
+    // Prepare acquisition of the organization (if it has been acquired, the
+    // most recent acquisition is used)
+    let acquired_by = cb_org
+        .cards
+        .acquirer_acquisitions
+        .and_then(|cb_acquisitions| {
+            cb_acquisitions.into_iter().max_by_key(|a| a.announced_on.as_ref().and_then(|d| d.value))
+        })
+        .map(new_acquired_by_from);
+
+    // Prepare funding rounds (sorted by announcement date, so that they can
+    // be used as a funding timeline)
     let funding_rounds = cb_org
         .cards
         .raised_funding_rounds
-        .map(|cb_funding_rounds| cb_funding_rounds.into_iter().map(new_funding_round_from).collect())
+        .map(|cb_funding_rounds| {
+            let mut funding_rounds: Vec<FundingRound> =
+                cb_funding_rounds.into_iter().map(new_funding_round_from).collect();
+            funding_rounds.sort_by_key(|fr| fr.announced_on);
+            funding_rounds
+        })
         .filter(|vec: &Vec<FundingRound>| !vec.is_empty());
 
     // Prepare number of employees
@@ -176,6 +151,7 @@ async fn collect_organization_data(cb: DynCB, cb_url: &str) -> Result<Organizati
     // Prepare organization instance using the information collected
     Ok(Organization {
         generated_at: Utc::now(),
+        acquired_by,
         acquisitions,
         city: get_location_value(&cb_org.cards.headquarters_address, "city"),
         company_type: cb_org.properties.company_type,
@@ -213,12 +189,13 @@ trait CB {
 
 /// CB implementation backed by the Crunchbase API.
 struct CBApi {
+    http_cache: Arc<HttpCache>,
     http_client: reqwest::Client,
 }
 
 impl CBApi {
     /// Create a new CBApi instance.
-    fn new(key: &str) -> Result<Self> {
+    fn new(key: &str, http_cache: Arc<HttpCache>) -> Result<Self> {
         // Setup HTTP client ready to make requests to the Crunchbase API
         let user_agent = format!("{}/{}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"));
         let mut headers = header::HeaderMap::new();
@@ -226,7 +203,10 @@ impl CBApi {
         let http_client =
             reqwest::Client::builder().user_agent(user_agent).default_headers(headers).build()?;
 
-        Ok(Self { http_client })
+        Ok(Self {
+            http_cache,
+            http_client,
+        })
     }
 }
 
@@ -237,6 +217,7 @@ impl CB for CBApi {
     async fn get_organization(&self, permalink: &str) -> Result<CBOrganizationEntity> {
         let cards = &[
             "acquiree_acquisitions",
+            "acquirer_acquisitions",
             "headquarters_address",
             "raised_funding_rounds",
         ]
@@ -258,11 +239,11 @@ impl CB for CBApi {
         let url = format!(
             "{CRUNCHBASE_API_URL}/entities/organizations/{permalink}?card_ids={cards}&field_ids={fields}"
         );
-        let response = self.http_client.get(url).send().await?;
-        if response.status() != StatusCode::OK {
-            bail!("unexpected status code: {:?}", response.status());
+        let response = self.http_cache.send(self.http_client.get(url)).await?;
+        if response.status != StatusCode::OK {
+            bail!("unexpected status code: {:?}", response.status);
         }
-        let org_entity: CBOrganizationEntity = response.json().await?;
+        let org_entity: CBOrganizationEntity = response.json()?;
         Ok(org_entity)
     }
 }
@@ -306,6 +287,7 @@ struct CBValue {
 #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 struct CBCards {
     acquiree_acquisitions: Option<Vec<CBAcquisition>>,
+    acquirer_acquisitions: Option<Vec<CBAcquisition>>,
     headquarters_address: Option<Vec<CBAddress>>,
     raised_funding_rounds: Option<Vec<CBFundingRound>>,
 }
@@ -313,6 +295,7 @@ struct CBCards {
 #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 struct CBAcquisition {
     acquiree_identifier: Option<CBAcquireeIdentifier>,
+    acquirer_identifier: Option<CBAcquireeIdentifier>,
     announced_on: Option<CBAcquisitionAnnouncedOn>,
     price: Option<CBAcquisitionPrice>,
 }
@@ -348,6 +331,7 @@ struct CBLocationIdentifier {
 struct CBFundingRound {
     announced_on: Option<NaiveDate>,
     investment_type: Option<String>,
+    lead_investor_identifiers: Option<Vec<CBEntityIdentifier>>,
     money_raised: Option<CBMoneyRaised>,
 }
 
@@ -383,6 +367,15 @@ fn get_permalink(cb_url: &str) -> Result<String> {
     Ok(c["permalink"].to_string())
 }
 
+/// Create a new AcquiredBy instance from the Crunchbase data provided.
+fn new_acquired_by_from(cba: CBAcquisition) -> AcquiredBy {
+    AcquiredBy {
+        acquirer_cb_permalink: cba.acquirer_identifier.as_ref().and_then(|i| i.permalink.clone()),
+        acquirer_name: cba.acquirer_identifier.and_then(|i| i.value.clone()),
+        announced_on: cba.announced_on.and_then(|a| a.value),
+    }
+}
+
 /// Create a new Acquisition instance from the Crunchbase data provided.
 fn new_acquisition_from(cba: CBAcquisition) -> Acquisition {
     Acquisition {
@@ -399,5 +392,9 @@ fn new_funding_round_from(cbfr: CBFundingRound) -> FundingRound {
         amount: cbfr.money_raised.and_then(|p| p.value_usd),
         announced_on: cbfr.announced_on,
         kind: cbfr.investment_type,
+        lead_investors: cbfr
+            .lead_investor_identifiers
+            .map(|identifiers| identifiers.into_iter().filter_map(|i| i.value).collect::<Vec<_>>())
+            .filter(|lead_investors| !lead_investors.is_empty()),
     }
 }
@@ -1,18 +1,23 @@
 //! This module defines the functionality to generate the `items.csv`
-//! file from the information available in the landscape.
+//! file (and the Parquet export) from the information available in the
+//! landscape. It also provides support for generating the `items.json` file
+//! in the format used by the legacy landscapeapp.
 
 use super::{data, LandscapeData};
 use crate::build::data::DATE_FORMAT;
 use anyhow::Result;
+use arrow::datatypes::FieldRef;
 use chrono::NaiveDate;
-use serde::Serialize;
+use parquet::{arrow::ArrowWriter, basic::Compression, file::properties::WriterProperties};
+use serde::{Deserialize, Serialize};
+use serde_arrow::schema::{SchemaLike, TracingOptions};
 use std::fs::File;
 
 /// Item information used for each record in the CSV file.
 ///
 /// The order in which the values in Item are defined is the order in which
 /// they will be appear in the CSV file as headers.
-#[derive(Default, Serialize)]
+#[derive(Default, Serialize, Deserialize)]
 struct Item {
     name: String,
     organization: Option<String>,
@@ -134,8 +139,8 @@ impl From<&data::Item> for Item {
         // GitHub values
         if let Some(repo) = di.primary_repository() {
             if let Some(gh_data) = &repo.github_data {
-                item.github_contributors_count = Some(gh_data.contributors.count);
-                item.github_contributors_link = Some(gh_data.contributors.url.clone());
+                item.github_contributors_count = gh_data.contributors.as_ref().map(|c| c.count);
+                item.github_contributors_link = gh_data.contributors.as_ref().map(|c| c.url.clone());
                 item.github_description = Some(gh_data.description.clone());
                 item.github_latest_commit_link = Some(gh_data.latest_commit.url.clone());
                 item.github_repo = Some(gh_data.url.clone());
@@ -195,10 +200,117 @@ impl From<&data::Item> for Item {
 
 /// Generate CSV file with some information about each item.
 pub(crate) fn generate_items_csv(mut w: csv::Writer<File>, landscape_data: &LandscapeData) -> Result<()> {
-    let mut items: Vec<Item> = landscape_data.items.iter().map(Item::from).collect();
-    items.sort_by_key(|i| i.name.to_lowercase());
+    let items = prepare_items(landscape_data);
     items.iter().try_for_each(|i| w.serialize(i))?;
     w.flush()?;
 
     Ok(())
 }
+
+/// Generate Parquet file with some information about each item (the same
+/// information included in the CSV file).
+pub(crate) fn generate_items_parquet(file: File, landscape_data: &LandscapeData) -> Result<()> {
+    // Prepare record batch from items
+    let items = prepare_items(landscape_data);
+    let fields = Vec::<FieldRef>::from_type::<Item>(TracingOptions::default())?;
+    let batch = serde_arrow::to_record_batch(&fields, &items)?;
+
+    // Write record batch to file
+    let props = WriterProperties::builder().set_compression(Compression::SNAPPY).build();
+    let mut w = ArrowWriter::try_new(file, batch.schema(), Some(props))?;
+    w.write(&batch)?;
+    w.close()?;
+
+    Ok(())
+}
+
+/// Item information in the legacy landscapeapp `items.json` format.
+#[derive(Default, Serialize)]
+struct LegacyItem {
+    id: String,
+    name: String,
+    category: String,
+    subcategory: String,
+    path: String,
+    homepage_url: String,
+    logo: String,
+    href: String,
+    oss: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    twitter: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crunchbase: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    repo_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    project: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    relation: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    member: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    organization: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    headquarters: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    amount: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stars: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    license: Option<String>,
+}
+
+impl LegacyItem {
+    /// Create a new legacy item from the data item provided.
+    fn new(di: &data::Item, landscape_url: &str) -> Self {
+        // Most of the values needed are already available in the CSV item
+        let item = Item::from(di);
+
+        LegacyItem {
+            amount: item.funding,
+            category: item.category.clone(),
+            crunchbase: item.crunchbase_url,
+            description: item.description,
+            headquarters: item.headquarters,
+            homepage_url: item.homepage,
+            href: item.logo.clone(),
+            id: di.id.clone(),
+            license: item.license,
+            logo: format!("{}/{}", landscape_url.trim_end_matches('/'), item.logo),
+            member: item.member,
+            name: item.name,
+            organization: item.organization,
+            oss: item.oss.unwrap_or_default(),
+            path: format!("{} / {}", item.category, item.subcategory),
+            project: di.maturity.clone(),
+            relation: item.relation,
+            repo_url: di.primary_repository().map(|r| r.url.clone()),
+            stars: item.github_stars,
+            subcategory: item.subcategory,
+            twitter: item.twitter,
+        }
+    }
+}
+
+/// Generate the items.json file in the legacy landscapeapp format.
+pub(crate) fn generate_items_legacy_json(
+    file: File,
+    landscape_data: &LandscapeData,
+    landscape_url: &str,
+) -> Result<()> {
+    let mut items: Vec<LegacyItem> =
+        landscape_data.items.iter().map(|i| LegacyItem::new(i, landscape_url)).collect();
+    items.sort_by_key(|i| i.name.to_lowercase());
+    serde_json::to_writer(file, &items)?;
+
+    Ok(())
+}
+
+/// Prepare the export items from the landscape data, sorted by name.
+fn prepare_items(landscape_data: &LandscapeData) -> Vec<Item> {
+    let mut items: Vec<Item> = landscape_data.items.iter().map(Item::from).collect();
+    items.sort_by_key(|i| i.name.to_lowercase());
+    items
+}
@@ -0,0 +1,106 @@
+//! This module defines the functionality used to generate the landscape
+//! favicons and touch icons from the favicon image (or the header logo) during
+//! the build.
+
+use super::{
+    logos::{embed_raster_logo, rasterize_svg, RASTER_LOGOS_EXTENSIONS},
+    IMAGES_PATH,
+};
+use anyhow::{bail, Result};
+use landscape2_core::settings::{Icon, Icons};
+use std::{fs, path::Path};
+use tracing::{debug, instrument};
+
+/// Size (in pixels) of the Apple touch icon.
+const APPLE_TOUCH_ICON_SIZE: u32 = 180;
+
+/// Sizes (in pixels) of the images included in the favicon.ico file.
+const FAVICON_ICO_SIZES: [u32; 3] = [16, 32, 48];
+
+/// Sizes (in pixels) of the favicons generated in PNG format.
+const FAVICON_PNG_SIZES: [u32; 4] = [16, 32, 192, 512];
+
+/// Generate the favicons and the Apple touch icon from the image provided
+/// (path relative to the output directory), writing them to the images path
+/// in the output directory.
+#[instrument(skip(output_dir), err)]
+pub(crate) fn generate_icons(image_path: &str, output_dir: &Path) -> Result<Icons> {
+    debug!("generating icons");
+
+    // Read source image, embedding it in an SVG document if it is a raster image
+    let image = fs::read(output_dir.join(image_path))?;
+    let extension = Path::new(image_path)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .unwrap_or_default()
+        .to_lowercase();
+    let svg_data = if extension == "svg" {
+        image
+    } else if RASTER_LOGOS_EXTENSIONS.contains(&extension.as_str()) {
+        embed_raster_logo(&image, &extension)?
+    } else {
+        bail!("unsupported image format: {extension}");
+    };
+
+    // Helper function to write the icon provided to the images path
+    let write_icon = |file_name: &str, data: &[u8]| -> Result<String> {
+        let icon_path = Path::new(IMAGES_PATH).join(file_name);
+        fs::write(output_dir.join(&icon_path), data)?;
+        Ok(icon_path.to_string_lossy().into_owned())
+    };
+
+    // PNG favicons
+    let mut png = vec![];
+    for size in FAVICON_PNG_SIZES {
+        let data = rasterize_svg(&svg_data, size, true)?;
+        let path = write_icon(&format!("favicon-{size}x{size}.png"), &data)?;
+        png.push(Icon { path, size });
+    }
+
+    // Apple touch icon
+    let data = rasterize_svg(&svg_data, APPLE_TOUCH_ICON_SIZE, true)?;
+    let apple_touch_icon = write_icon("apple-touch-icon.png", &data)?;
+
+    // favicon.ico
+    let mut ico_images = vec![];
+    for size in FAVICON_ICO_SIZES {
+        ico_images.push((size, rasterize_svg(&svg_data, size, true)?));
+    }
+    let favicon_ico = write_icon("favicon.ico", &encode_ico(&ico_images)?)?;
+
+    debug!("done!");
+    Ok(Icons {
+        apple_touch_icon,
+        favicon_ico,
+        png,
+    })
+}
+
+/// Encode the PNG images provided (along with their size) in an ICO file.
+fn encode_ico(images: &[(u32, Vec<u8>)]) -> Result<Vec<u8>> {
+    // Header
+    let mut ico = vec![];
+    ico.extend_from_slice(&0u16.to_le_bytes()); // Reserved
+    ico.extend_from_slice(&1u16.to_le_bytes()); // Image type (icon)
+    ico.extend_from_slice(&u16::try_from(images.len())?.to_le_bytes());
+
+    // Directory entries
+    let mut offset = 6 + 16 * images.len();
+    for (size, data) in images {
+        // A value of 0 means 256 pixels
+        let size = u8::try_from(*size).unwrap_or(0);
+        ico.extend_from_slice(&[size, size, 0, 0]); // Width, height, palette, reserved
+        ico.extend_from_slice(&1u16.to_le_bytes()); // Color planes
+        ico.extend_from_slice(&32u16.to_le_bytes()); // Bits per pixel
+        ico.extend_from_slice(&u32::try_from(data.len())?.to_le_bytes());
+        ico.extend_from_slice(&u32::try_from(offset)?.to_le_bytes());
+        offset += data.len();
+    }
+
+    // Images data
+    for (_, data) in images {
+        ico.extend_from_slice(data);
+    }
+
+    Ok(ico)
+}
@@ -0,0 +1,184 @@
+//! This module defines the functionality used to collect the latest posts
+//! published in the blog or news feeds (RSS or Atom) of the landscape items
+//! (when applicable).
+
+use super::{cache::Cache, collector::Collector, retry::RetryPolicy, LandscapeData};
+use anyhow::{bail, Result};
+use chrono::{DateTime, Utc};
+use landscape2_core::data::{FeedData, FeedPost, FeedsData};
+use reqwest::StatusCode;
+use serde::Deserialize;
+use tracing::{debug, instrument};
+
+/// File used to cache data collected from the items feeds.
+pub(crate) const FEEDS_CACHE_FILE: &str = "feeds.json";
+
+/// How long the feeds data in the cache is valid (in days).
+const FEEDS_CACHE_TTL: i64 = 1;
+
+/// Maximum number of feeds to process concurrently.
+const FEEDS_MAX_CONCURRENCY: usize = 10;
+
+/// Maximum number of posts to collect from each feed.
+const FEEDS_MAX_POSTS: usize = 5;
+
+/// Collect the latest posts published in the feeds of the items in the
+/// landscape, reusing cached data whenever possible.
+#[instrument(skip_all, err)]
+pub(crate) async fn collect_feeds_data(cache: &Cache, landscape_data: &LandscapeData) -> Result<FeedsData> {
+    debug!("collecting items feeds posts");
+
+    // Collect items feeds urls
+    let mut urls = vec![];
+    for item in &landscape_data.items {
+        if let Some(url) = &item.feed_url {
+            urls.push(url.clone());
+        }
+    }
+    urls.sort();
+    urls.dedup();
+
+    // Collect feeds posts, reusing cached data when available
+    let user_agent = format!("{}/{}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"));
+    let http_client = reqwest::Client::builder().user_agent(user_agent).build()?;
+    let retry_policy = cache.retry_policy();
+    let collector = Collector {
+        cache,
+        cache_file: FEEDS_CACHE_FILE,
+        cache_ttl: FEEDS_CACHE_TTL,
+        max_concurrency: FEEDS_MAX_CONCURRENCY,
+        description: "feed posts",
+    };
+    let entries = urls.into_iter().map(|url| (url.clone(), url)).collect();
+    let feeds_data = collector
+        .collect(entries, |url, _| {
+            let http_client = http_client.clone();
+            async move { collect_feed_data(http_client, retry_policy, &url).await }
+        })
+        .await?;
+
+    debug!("done!");
+    Ok(feeds_data)
+}
+
+/// Collect the latest posts published in the feed provided.
+#[instrument(skip(http_client), err)]
+async fn collect_feed_data(
+    http_client: reqwest::Client,
+    retry_policy: RetryPolicy,
+    url: &str,
+) -> Result<FeedData> {
+    let resp = retry_policy.send(http_client.get(url)).await?;
+    if resp.status() != StatusCode::OK {
+        bail!("unexpected status code getting feed: {}", resp.status());
+    }
+    let raw_feed = resp.text().await?;
+
+    Ok(FeedData {
+        generated_at: Utc::now(),
+        posts: parse_feed(&raw_feed)?,
+    })
+}
+
+/// Parse the RSS or Atom feed provided, returning its most recent posts.
+fn parse_feed(raw_feed: &str) -> Result<Vec<FeedPost>> {
+    // Try RSS first, falling back to Atom
+    let mut posts: Vec<FeedPost> = if let Ok(rss) = quick_xml::de::from_str::<RssFeed>(raw_feed) {
+        rss.channel
+            .items
+            .into_iter()
+            .filter_map(|item| {
+                Some(FeedPost {
+                    title: item.title?,
+                    url: item.link?,
+                    published_at: item
+                        .pub_date
+                        .and_then(|v| DateTime::parse_from_rfc2822(v.trim()).ok())
+                        .map(|ts| ts.with_timezone(&Utc)),
+                })
+            })
+            .collect()
+    } else {
+        let atom: AtomFeed = quick_xml::de::from_str(raw_feed)?;
+        atom.entries
+            .into_iter()
+            .filter_map(|entry| {
+                let link = entry
+                    .links
+                    .iter()
+                    .find(|l| l.rel.as_deref().unwrap_or("alternate") == "alternate")
+                    .or(entry.links.first())?;
+                Some(FeedPost {
+                    title: entry.title?.value,
+                    url: link.href.clone(),
+                    published_at: entry
+                        .published
+                        .or(entry.updated)
+                        .and_then(|v| DateTime::parse_from_rfc3339(v.trim()).ok())
+                        .map(|ts| ts.with_timezone(&Utc)),
+                })
+            })
+            .collect()
+    };
+
+    // Keep only the most recent posts
+    posts.sort_by(|a, b| b.published_at.cmp(&a.published_at));
+    posts.truncate(FEEDS_MAX_POSTS);
+
+    Ok(posts)
+}
+
+/// RSS feed.
+#[derive(Debug, Deserialize)]
+struct RssFeed {
+    channel: RssChannel,
+}
+
+/// RSS channel details.
+#[derive(Debug, Deserialize)]
+struct RssChannel {
+    #[serde(rename = "item", default)]
+    items: Vec<RssItem>,
+}
+
+/// RSS item details.
+#[derive(Debug, Deserialize)]
+struct RssItem {
+    link: Option<String>,
+    #[serde(rename = "pubDate")]
+    pub_date: Option<String>,
+    title: Option<String>,
+}
+
+/// Atom feed.
+#[derive(Debug, Deserialize)]
+struct AtomFeed {
+    #[serde(rename = "entry", default)]
+    entries: Vec<AtomEntry>,
+}
+
+/// Atom entry details.
+#[derive(Debug, Deserialize)]
+struct AtomEntry {
+    #[serde(rename = "link", default)]
+    links: Vec<AtomLink>,
+    published: Option<String>,
+    title: Option<AtomText>,
+    updated: Option<String>,
+}
+
+/// Atom link details.
+#[derive(Debug, Deserialize)]
+struct AtomLink {
+    #[serde(rename = "@href")]
+    href: String,
+    #[serde(rename = "@rel")]
+    rel: Option<String>,
+}
+
+/// Atom text construct (it may include some attributes, like the type).
+#[derive(Debug, Deserialize)]
+struct AtomText {
+    #[serde(rename = "$text", default)]
+    value: String,
+}
@@ -0,0 +1,288 @@
+//! This module defines the functionality used to collect information from
+//! Gitea compatible forges (i.e. Codeberg) for each of the landscape items
+//! repositories hosted on them (when applicable). The information collected is
+//! mapped to the same structure used for the repositories hosted on GitHub.
+
+use super::{cache::Cache, collector::Collector, retry::RetryPolicy, LandscapeData};
+use anyhow::{bail, format_err, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use landscape2_core::data::{Commit, GithubData, Release, RepositoryGithubData};
+use lazy_static::lazy_static;
+#[cfg(test)]
+use mockall::automock;
+use regex::Regex;
+use reqwest::{header::HeaderMap, StatusCode};
+use serde::Deserialize;
+use std::{collections::BTreeMap, env, sync::Arc};
+use tracing::{debug, instrument};
+
+/// File used to cache data collected from Gitea forges.
+pub(crate) const GITEA_CACHE_FILE: &str = "gitea.json";
+
+/// Environment variable containing a comma separated list of extra hosts
+/// running Gitea compatible forges (Codeberg and gitea.com are supported by
+/// default).
+const GITEA_HOSTS: &str = "GITEA_HOSTS";
+
+/// Gitea compatible forges supported by default.
+const DEFAULT_GITEA_HOSTS: [&str; 2] = ["codeberg.org", "gitea.com"];
+
+/// Maximum number of repositories to process concurrently.
+const GITEA_MAX_CONCURRENCY: usize = 5;
+
+/// Collect Gitea data for each of the items repositories in the landscape
+/// hosted on a Gitea compatible forge, reusing cached data whenever possible.
+/// Cached data is considered valid for `cache_ttl` days.
+#[instrument(skip_all, err)]
+pub(crate) async fn collect_gitea_data(
+    cache: &Cache,
+    landscape_data: &LandscapeData,
+    cache_ttl: i64,
+) -> Result<GithubData> {
+    debug!("collecting repositories information from gitea forges");
+
+    // Collect urls of the repositories to process
+    let hosts = get_hosts();
+    let mut urls = vec![];
+    for item in &landscape_data.items {
+        if let Some(repositories) = &item.repositories {
+            for repo in repositories {
+                if let Ok((host, _, _)) = parse_repo_url(&repo.url) {
+                    if hosts.contains(&host) {
+                        urls.push(repo.url.clone());
+                    }
+                }
+            }
+        }
+    }
+    urls.sort();
+    urls.dedup();
+
+    // Collect repositories information, reusing cached data when available
+    let gt: DynGT = Arc::new(GTApi::new(cache.retry_policy())?);
+    let collector = Collector {
+        cache,
+        cache_file: GITEA_CACHE_FILE,
+        cache_ttl,
+        max_concurrency: GITEA_MAX_CONCURRENCY,
+        description: "repository data from gitea",
+    };
+    let entries = urls.into_iter().map(|url| (url.clone(), url)).collect();
+    let gitea_data = collector
+        .collect(entries, |url, cached_repo: Option<RepositoryGithubData>| {
+            let gt = gt.clone();
+            async move {
+                let mut repo_data = collect_repository_data(gt, &url).await?;
+
+                // Keep the stars history collected in previous builds
+                repo_data.update_stars_history(cached_repo.and_then(|r| r.stars_history).as_ref());
+                Ok(repo_data)
+            }
+        })
+        .await?;
+
+    debug!("done!");
+    Ok(gitea_data)
+}
+
+/// Collect repository data from the corresponding Gitea forge.
+#[instrument(skip(gt), err)]
+async fn collect_repository_data(gt: DynGT, repo_url: &str) -> Result<RepositoryGithubData> {
+    // Collect some information from the forge
+    let (host, owner, repo) = parse_repo_url(repo_url)?;
+    let api_url = format!("https://{host}/api/v1/repos/{owner}/{repo}");
+    let gt_repo = gt.get_repository(&api_url).await?;
+    let (latest_commit, commits_count) = gt.get_commit(&api_url, &gt_repo.default_branch, 1).await?;
+    let first_commit = match commits_count {
+        Some(count) if count > 1 => Some(gt.get_commit(&api_url, &gt_repo.default_branch, count).await?.0),
+        _ => None,
+    };
+    let languages = gt.get_languages(&api_url).await?;
+    let latest_release = gt.get_latest_release(&api_url).await?;
+
+    // Prepare repository instance using the information collected
+    Ok(RepositoryGithubData {
+        archived: Some(gt_repo.archived),
+        generated_at: Utc::now(),
+        description: gt_repo.description,
+        first_commit,
+        forks: Some(gt_repo.forks_count),
+        languages,
+        latest_commit,
+        latest_release,
+        license: gt_repo.licenses.and_then(|licenses| licenses.into_iter().next()),
+        participation_stats: vec![],
+        stars: gt_repo.stars_count,
+        topics: gt_repo.topics.unwrap_or_default(),
+        url: gt_repo.html_url,
+        ..Default::default()
+    })
+}
+
+/// Type alias to represent a GT trait object.
+type DynGT = Arc<dyn GT + Send + Sync>;
+
+/// Trait that defines some operations a GT implementation must support.
+#[async_trait]
+#[cfg_attr(test, automock)]
+trait GT {
+    /// Get the commit at the position provided (starting from the latest one)
+    /// in the branch given, as well as the number of commits in that branch.
+    async fn get_commit(&self, api_url: &str, ref_: &str, position: usize)
+        -> Result<(Commit, Option<usize>)>;
+
+    /// Get languages used in repository.
+    async fn get_languages(&self, api_url: &str) -> Result<Option<BTreeMap<String, i64>>>;
+
+    /// Get latest release.
+    async fn get_latest_release(&self, api_url: &str) -> Result<Option<Release>>;
+
+    /// Get repository.
+    async fn get_repository(&self, api_url: &str) -> Result<GTRepository>;
+}
+
+/// GT implementation backed by the Gitea API.
+struct GTApi {
+    http_client: reqwest::Client,
+    retry_policy: RetryPolicy,
+}
+
+impl GTApi {
+    /// Create a new GTApi instance.
+    fn new(retry_policy: RetryPolicy) -> Result<Self> {
+        let user_agent = format!("{}/{}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"));
+        let http_client = reqwest::Client::builder().user_agent(user_agent).build()?;
+
+        Ok(Self {
+            http_client,
+            retry_policy,
+        })
+    }
+}
+
+#[async_trait]
+impl GT for GTApi {
+    /// [GT::get_commit]
+    #[instrument(skip(self), err)]
+    async fn get_commit(
+        &self,
+        api_url: &str,
+        ref_: &str,
+        position: usize,
+    ) -> Result<(Commit, Option<usize>)> {
+        let url = format!("{api_url}/commits?sha={ref_}&limit=1&page={position}&stat=false&files=false");
+        let resp = self.retry_policy.send(self.http_client.get(url)).await?;
+        if resp.status() != StatusCode::OK {
+            bail!("unexpected status code getting commits: {}", resp.status());
+        }
+        let commits_count = get_total_count(resp.headers());
+        let commit =
+            resp.json::<Vec<GTCommit>>().await?.pop().ok_or_else(|| format_err!("no commits found"))?;
+
+        Ok((
+            Commit {
+                ts: commit.created,
+                url: commit.html_url,
+            },
+            commits_count,
+        ))
+    }
+
+    /// [GT::get_languages]
+    #[instrument(skip(self), err)]
+    async fn get_languages(&self, api_url: &str) -> Result<Option<BTreeMap<String, i64>>> {
+        let resp = self.retry_policy.send(self.http_client.get(format!("{api_url}/languages"))).await?;
+        if resp.status() != StatusCode::OK {
+            return Ok(None);
+        }
+        Ok(Some(resp.json().await?))
+    }
+
+    /// [GT::get_latest_release]
+    #[instrument(skip(self), err)]
+    async fn get_latest_release(&self, api_url: &str) -> Result<Option<Release>> {
+        let resp = self.retry_policy.send(self.http_client.get(format!("{api_url}/releases/latest"))).await?;
+        match resp.status() {
+            StatusCode::OK => {
+                let release: GTRelease = resp.json().await?;
+                Ok(Some(Release {
+                    ts: release.published_at,
+                    url: release.html_url,
+                    tag: Some(release.tag_name),
+                }))
+            }
+            StatusCode::NOT_FOUND => Ok(None),
+            status => bail!("unexpected status code getting latest release: {status}"),
+        }
+    }
+
+    /// [GT::get_repository]
+    #[instrument(skip(self), err)]
+    async fn get_repository(&self, api_url: &str) -> Result<GTRepository> {
+        let resp = self.retry_policy.send(self.http_client.get(api_url)).await?;
+        if resp.status() != StatusCode::OK {
+            bail!("unexpected status code getting repository: {}", resp.status());
+        }
+        Ok(resp.json().await?)
+    }
+}
+
+/// Gitea repository details.
+#[derive(Debug, Clone, Default, Deserialize)]
+struct GTRepository {
+    archived: bool,
+    default_branch: String,
+    description: String,
+    forks_count: i64,
+    html_url: String,
+    licenses: Option<Vec<String>>,
+    stars_count: i64,
+    topics: Option<Vec<String>>,
+}
+
+/// Gitea commit details.
+#[derive(Debug, Clone, Default, Deserialize)]
+struct GTCommit {
+    created: Option<DateTime<Utc>>,
+    html_url: String,
+}
+
+/// Gitea release details.
+#[derive(Debug, Clone, Default, Deserialize)]
+struct GTRelease {
+    html_url: String,
+    published_at: Option<DateTime<Utc>>,
+    tag_name: String,
+}
+
+lazy_static! {
+    /// Repository url regular expression.
+    static ref REPO_URL: Regex =
+        Regex::new("^https://(?P<host>[^/]+)/(?P<owner>[^/]+)/(?P<repo>[^/]+?)(\\.git)?/?$")
+            .expect("exprs in REPO_URL to be valid");
+}
+
+/// Get the hosts of the Gitea compatible forges supported.
+fn get_hosts() -> Vec<String> {
+    let mut hosts: Vec<String> = DEFAULT_GITEA_HOSTS.iter().map(ToString::to_string).collect();
+    if let Ok(extra_hosts) = env::var(GITEA_HOSTS) {
+        hosts.extend(extra_hosts.split(',').map(|h| h.trim().to_string()).filter(|h| !h.is_empty()));
+    }
+    hosts
+}
+
+/// Return the total number of results available from the headers provided.
+fn get_total_count(headers: &HeaderMap) -> Option<usize> {
+    headers.get("x-total-count")?.to_str().ok()?.parse().ok()
+}
+
+/// Extract the host, owner and repository from the repository url provided.
+fn parse_repo_url(repo_url: &str) -> Result<(String, String, String)> {
+    let c = REPO_URL.captures(repo_url).ok_or_else(|| format_err!("invalid repository url"))?;
+    Ok((
+        c["host"].to_string(),
+        c["owner"].to_string(),
+        c["repo"].to_string(),
+    ))
+}
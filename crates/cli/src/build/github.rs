@@ -2,37 +2,103 @@
 //! from GitHub for each of the landscape items repositories (when applicable),
 //! as well as the functionality used to collect that information.
 
-use super::{cache::Cache, LandscapeData};
-use anyhow::{format_err, Result};
+use super::{
+    cache::{Cache, HttpCache, HttpResponse},
+    progress::Progress,
+    report::{BuildReport, ServiceStats},
+    LandscapeData,
+};
+use anyhow::{bail, format_err, Result};
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
-use deadpool::unmanaged::{Object, Pool};
 use futures::stream::{self, StreamExt};
-use landscape2_core::data::{Commit, Contributors, GithubData, Release, RepositoryGithubData};
+use landscape2_core::data::{
+    Commit, ContributionIssues, Contributor, Contributors, GithubData, Release, RepositoryGithubData,
+    SecurityAdvisories, SecurityAdvisory,
+};
 use lazy_static::lazy_static;
 #[cfg(test)]
 use mockall::automock;
-use octorust::auth::Credentials;
 use octorust::types::{FullRepository, ParticipationStats};
 use regex::Regex;
-use reqwest::header::{self, HeaderMap, HeaderValue};
+use reqwest::{
+    header::{self, HeaderMap, HeaderValue},
+    StatusCode,
+};
+use serde::Deserialize;
+use serde_json::json;
 use std::collections::BTreeMap;
 use std::env;
+use std::ops::Deref;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::Notify;
 use tracing::{debug, instrument, warn};
 
 /// File used to cache data collected from GitHub.
-const GITHUB_CACHE_FILE: &str = "github.json";
+pub(crate) const GITHUB_CACHE_FILE: &str = "github.json";
 
-/// How long the GitHub data in the cache is valid (in days).
-const GITHUB_CACHE_TTL: i64 = 7;
+/// File used to cache the GitHub API responses (used in conditional requests).
+pub(crate) const GITHUB_HTTP_CACHE_FILE: &str = "github_http.json";
+
+/// Maximum number of releases to collect for each repository.
+const GITHUB_MAX_RELEASES: usize = 10;
+
+/// Maximum time to wait for the rate limit to be reset before giving up
+/// (GitHub resets the rate limit every hour).
+const GITHUB_RATE_LIMIT_MAX_WAIT: Duration = Duration::from_secs(65 * 60);
+
+/// Number of requests left in a token's rate limit below which it is
+/// considered close to exhaustion. When all tokens are in this situation,
+/// requests are paused until their rate limit is reset.
+const GITHUB_RATE_LIMIT_MIN_REMAINING: i64 = 50;
+
+/// Rate limit resource tracked to select the token to use for each request
+/// (most of the requests sent to the GitHub API are accounted against it).
+const GITHUB_RATE_LIMIT_RESOURCE: &str = "core";
+
+/// Number of repositories to request in a single GraphQL API call.
+const GITHUB_GRAPHQL_BATCH_SIZE: usize = 25;
+
+/// Number of issues requested per page when counting the open issues with a
+/// given label.
+const GITHUB_ISSUES_PER_PAGE: usize = 100;
+
+/// Label used to flag issues suitable for first-time contributors.
+const GITHUB_GOOD_FIRST_ISSUE_LABEL: &str = "good first issue";
+
+/// Label used to flag issues where help from contributors is wanted.
+const GITHUB_HELP_WANTED_LABEL: &str = "help wanted";
+
+/// Number of top contributors to collect for each repository.
+const GITHUB_TOP_CONTRIBUTORS: usize = 10;
 
 /// Environment variable containing a comma separated list of GitHub tokens.
-const GITHUB_TOKENS: &str = "GITHUB_TOKENS";
+pub(crate) const GITHUB_TOKENS: &str = "GITHUB_TOKENS";
+
+/// Environment variable containing a comma separated list of GitHub tokens
+/// for the GitHub Enterprise Server instance.
+const GITHUB_ENTERPRISE_TOKENS: &str = "GITHUB_ENTERPRISE_TOKENS";
+
+/// Environment variable containing the url of a GitHub Enterprise Server
+/// instance (i.e. https://github.example.com).
+const GITHUB_ENTERPRISE_URL: &str = "GITHUB_ENTERPRISE_URL";
 
 /// Collect GitHub data for each of the items repositories in the landscape,
-/// reusing cached data whenever possible.
-#[instrument(skip_all, err)]
-pub(crate) async fn collect_github_data(cache: &Cache, landscape_data: &LandscapeData) -> Result<GithubData> {
+/// reusing cached data whenever possible. When `graphql` is enabled, the
+/// GraphQL API is used to get the details of several repositories at once.
+/// Cached data is considered valid for `cache_ttl` days. The number of
+/// concurrent requests is limited by the tokens available and, optionally, by
+/// `max_concurrency`.
+#[instrument(skip(cache, landscape_data, report), err)]
+pub(crate) async fn collect_github_data(
+    cache: &Cache,
+    landscape_data: &LandscapeData,
+    graphql: bool,
+    cache_ttl: i64,
+    max_concurrency: Option<usize>,
+    report: &BuildReport,
+) -> Result<GithubData> {
     debug!("collecting repositories information from github (this may take a while)");
 
     // Read cached data (if available)
@@ -46,110 +112,165 @@ pub(crate) async fn collect_github_data(cache: &Cache, landscape_data: &Landscap
         Err(err) => warn!("error reading github cache file: {err:?}"),
     }
 
-    // Setup GitHub API clients pool if any tokens have been provided
-    let tokens: Option<Vec<String>> = match env::var(GITHUB_TOKENS) {
-        Ok(tokens) if !tokens.is_empty() => Some(tokens.split(',').map(ToString::to_string).collect()),
-        Ok(_) | Err(_) => None,
-    };
-    let gh_pool: Option<Pool<DynGH>> = if let Some(tokens) = &tokens {
-        let mut gh_clients: Vec<DynGH> = vec![];
-        for token in tokens {
-            let gh = Box::new(GHApi::new(token)?);
-            gh_clients.push(gh);
-        }
-        Some(Pool::from(gh_clients))
-    } else {
-        warn!("github tokens not provided: no information will be collected from github");
-        None
-    };
+    // Setup the GitHub instances to collect data from (github.com and the
+    // GitHub Enterprise Server instance, when configured)
+    let mut instances = vec![GithubInstance::github_com()];
+    if let Some(instance) = GithubInstance::enterprise() {
+        instances.push(instance);
+    }
+
+    // Collect repositories information from each of the instances
+    let http_cache = Arc::new(HttpCache::new(cache, GITHUB_HTTP_CACHE_FILE));
+    let mut github_data = GithubData::new();
+    let mut stats = ServiceStats::default();
+    for instance in &instances {
+        // Setup GitHub API clients pool if any tokens have been provided
+        let gh_pool: Option<GHPool> = if let Some(tokens) = &instance.tokens {
+            let mut gh_clients: Vec<DynGH> = vec![];
+            for token in tokens {
+                let gh = Box::new(GHApi::new(token, instance, http_cache.clone())?);
+                gh_clients.push(gh);
+            }
+            Some(GHPool::new(gh_clients))
+        } else {
+            warn!(
+                instance = ?instance.web_url,
+                "github tokens not provided: no information will be collected from github"
+            );
+            None
+        };
 
-    // Collect urls of the repositories to process
-    let mut urls = vec![];
-    for item in &landscape_data.items {
-        if let Some(repositories) = &item.repositories {
-            for repo in repositories {
-                if GITHUB_REPO_URL.is_match(&repo.url) {
-                    urls.push(&repo.url);
+        // Collect urls of the repositories to process
+        let mut urls = vec![];
+        for item in &landscape_data.items {
+            if let Some(repositories) = &item.repositories {
+                for repo in repositories {
+                    if instance.is_repo_url(&repo.url) {
+                        urls.push(&repo.url);
+                    }
                 }
             }
         }
-    }
-    urls.sort();
-    urls.dedup();
+        urls.sort();
+        urls.dedup();
 
-    // Collect repositories information from GitHub, reusing cached data when available
-    let concurrency = if let Some(tokens) = tokens {
-        tokens.len()
-    } else {
-        1
-    };
-    let github_data: GithubData = stream::iter(urls)
-        .map(|url| async {
-            let url = url.clone();
-
-            // Use cached data when available if it hasn't expired yet
-            if let Some(cached_repo) = cached_data.as_ref().and_then(|cache| {
-                cache.get(&url).and_then(|repo| {
-                    if repo.generated_at + chrono::Duration::days(GITHUB_CACHE_TTL) > Utc::now() {
-                        Some(repo)
-                    } else {
-                        None
-                    }
-                })
-            }) {
-                (url, Ok(cached_repo.clone()))
+        // Use cached data when available if it hasn't expired yet
+        let mut pending_urls = vec![];
+        for url in urls {
+            match cached_data.as_ref().and_then(|cache| cache.get(url)) {
+                Some(cached_repo)
+                    if cached_repo.generated_at + chrono::Duration::days(cache_ttl) > Utc::now() =>
+                {
+                    github_data.insert(url.clone(), cached_repo.clone());
+                    stats.cached += 1;
+                }
+                _ => pending_urls.push(url),
             }
-            // Otherwise we pull it from GitHub if any tokens were provided
-            else if let Some(gh_pool) = &gh_pool {
-                let gh = gh_pool.get().await.expect("token -when available-");
-                (url.clone(), collect_repository_data(gh, &url).await)
-            } else {
-                (url.clone(), Err(format_err!("no tokens provided")))
+        }
+
+        // Otherwise we pull it from GitHub if any tokens were provided. When
+        // the GraphQL API is used, repositories are processed in batches.
+        if let Some(gh_pool) = &gh_pool {
+            let mut concurrency = instance.tokens.as_ref().map_or(1, Vec::len);
+            if let Some(max_concurrency) = max_concurrency {
+                concurrency = concurrency.min(max_concurrency);
             }
-        })
-        .buffer_unordered(concurrency)
-        .collect::<BTreeMap<String, Result<RepositoryGithubData>>>()
-        .await
-        .into_iter()
-        .filter_map(|(url, result)| {
-            if let Ok(github_data) = result {
-                Some((url, github_data))
-            } else {
-                None
+            let batch_size = if graphql { GITHUB_GRAPHQL_BATCH_SIZE } else { 1 };
+            let cached_data = cached_data.as_ref();
+            let progress = &Progress::new("collecting github data", pending_urls.len());
+            let results: Vec<(String, Result<RepositoryGithubData>)> =
+                stream::iter(pending_urls.chunks(batch_size))
+                    .map(|urls| async move {
+                        let gh = gh_pool.get().await;
+                        let results = if graphql {
+                            collect_repositories_data_graphql(gh, urls, cached_data).await
+                        } else {
+                            let url = urls[0];
+                            vec![(url.clone(), collect_repository_data(gh, url).await)]
+                        };
+                        progress.inc(urls.len());
+                        results
+                    })
+                    .buffer_unordered(concurrency)
+                    .collect::<Vec<Vec<(String, Result<RepositoryGithubData>)>>>()
+                    .await
+                    .into_iter()
+                    .flatten()
+                    .collect();
+
+            for (url, result) in results {
+                match result {
+                    Ok(mut repo_data) => {
+                        // Keep the stars history collected in previous builds
+                        let previous_history = cached_data.and_then(|cache| cache.get(&url));
+                        repo_data
+                            .update_stars_history(previous_history.and_then(|r| r.stars_history.as_ref()));
+                        github_data.insert(url, repo_data);
+                        stats.collected += 1;
+                    }
+                    Err(err) => {
+                        warn!(?err, ?url, "error collecting repository data");
+                        report.add_error("github", &url, &err);
+                        stats.errors += 1;
+                    }
+                }
             }
-        })
-        .collect();
+        }
+    }
+
+    stats.api_calls = http_cache.requests_sent();
+    report.set_service_stats("github", stats);
 
     // Write data (in json format) to cache
     cache.write(GITHUB_CACHE_FILE, &serde_json::to_vec_pretty(&github_data)?)?;
+    if let Err(err) = http_cache.write(cache) {
+        warn!(?err, "error writing github http cache");
+    }
 
     debug!("done!");
     Ok(github_data)
 }
 
 /// Collect repository data from GitHub.
-#[instrument(skip_all, err)]
-async fn collect_repository_data(gh: Object<DynGH>, repo_url: &str) -> Result<RepositoryGithubData> {
+#[instrument(skip_all, fields(%repo_url), err)]
+async fn collect_repository_data(gh: GHPoolObject<'_>, repo_url: &str) -> Result<RepositoryGithubData> {
     // Collect some information from GitHub
     let (owner, repo) = get_owner_and_repo(repo_url)?;
     let gh_repo = gh.get_repository(&owner, &repo).await?;
     let contributors_count = gh.get_contributors_count(&owner, &repo).await?;
-    let first_commit = gh.get_first_commit(&owner, &repo, &gh_repo.default_branch).await?;
+    let contribution_issues = ContributionIssues {
+        good_first_issue: gh.get_open_issues_count(&owner, &repo, GITHUB_GOOD_FIRST_ISSUE_LABEL).await?,
+        help_wanted: gh.get_open_issues_count(&owner, &repo, GITHUB_HELP_WANTED_LABEL).await?,
+    };
+    let top_contributors = gh.get_top_contributors(&owner, &repo, GITHUB_TOP_CONTRIBUTORS).await?;
+    let first_commit = gh.get_first_commit(&owner, &repo, &gh_repo.default_branch, None).await?;
     let languages = gh.get_languages(&owner, &repo).await?;
     let latest_commit = gh.get_latest_commit(&owner, &repo, &gh_repo.default_branch).await?;
     let latest_release = gh.get_latest_release(&owner, &repo).await?;
-    let participation_stats = gh.get_participation_stats(&owner, &repo).await?.all;
+    let participation_stats =
+        gh.get_participation_stats(&owner, &repo).await?.map(|s| s.all).unwrap_or_default();
+    let releases = gh.get_releases(&owner, &repo, GITHUB_MAX_RELEASES).await?;
+    let security_advisories = gh.get_security_advisories(&owner, &repo).await?;
 
     // Prepare repository instance using the information collected
     Ok(RepositoryGithubData {
         generated_at: Utc::now(),
-        contributors: Contributors {
+        contributors: Some(Contributors {
             count: contributors_count,
-            url: format!("https://github.com/{owner}/{repo}/graphs/contributors"),
-        },
+            url: format!("{}/graphs/contributors", gh_repo.html_url),
+            top: if top_contributors.is_empty() {
+                None
+            } else {
+                Some(top_contributors)
+            },
+        }),
+        archived: Some(gh_repo.archived),
+        contribution_issues: Some(contribution_issues),
         description: gh_repo.description,
         first_commit,
+        forks: Some(gh_repo.forks_count),
         languages,
+        languages_breakdown: None,
         latest_commit,
         latest_release,
         license: gh_repo.license.and_then(|l| {
@@ -160,15 +281,277 @@ async fn collect_repository_data(gh: Object<DynGH>, repo_url: &str) -> Result<Re
             }
         }),
         participation_stats,
+        releases: if releases.is_empty() { None } else { Some(releases) },
+        security_advisories,
         stars: gh_repo.stargazers_count,
+        stars_history: None,
         topics: gh_repo.topics,
         url: gh_repo.html_url,
     })
 }
 
+/// Check if the url provided belongs to a repository hosted on any of the
+/// GitHub instances available.
+pub(crate) fn is_github_repo_url(url: &str) -> bool {
+    GithubInstance::github_com().is_repo_url(url)
+        || GithubInstance::enterprise().is_some_and(|instance| instance.is_repo_url(url))
+}
+
+/// Collect data for the repositories provided from GitHub, using the GraphQL
+/// API to get the details of all of them in a single request. Some of the
+/// information is only available in the REST API, so it's collected from it
+/// (see `collect_repository_data_from_gql` for details).
+#[instrument(skip_all)]
+async fn collect_repositories_data_graphql(
+    gh: GHPoolObject<'_>,
+    repos_urls: &[&String],
+    cached_data: Option<&GithubData>,
+) -> Vec<(String, Result<RepositoryGithubData>)> {
+    let mut results = vec![];
+
+    // Extract owner and repository from the urls provided
+    let mut repos = vec![];
+    for url in repos_urls {
+        match get_owner_and_repo(url) {
+            Ok(owner_and_repo) => repos.push(((*url).clone(), owner_and_repo)),
+            Err(err) => results.push(((*url).clone(), Err(err))),
+        }
+    }
+
+    // Get repositories details from the GraphQL API
+    let owners_and_repos: Vec<(String, String)> = repos.iter().map(|(_, r)| r.clone()).collect();
+    let gql_repos = match gh.get_repositories_graphql(&owners_and_repos).await {
+        Ok(gql_repos) => gql_repos,
+        Err(err) => {
+            let err = format!("{err:#}");
+            for (url, _) in repos {
+                results.push((url, Err(format_err!("error getting repositories batch: {err}"))));
+            }
+            return results;
+        }
+    };
+
+    // Complete repositories data with the information from the REST API
+    for ((url, (owner, repo)), gql_repo) in repos.into_iter().zip(gql_repos) {
+        let result = if let Some(gql_repo) = gql_repo {
+            // The first commit of a repository doesn't change, so we reuse it
+            // when it's available in the cache
+            let first_commit =
+                cached_data.and_then(|cache| cache.get(&url)).and_then(|r| r.first_commit.clone());
+            collect_repository_data_from_gql(&gh, &owner, &repo, gql_repo, first_commit).await
+        } else {
+            Err(format_err!("repository not found"))
+        };
+        results.push((url, result));
+    }
+
+    results
+}
+
+/// Collect repository data from GitHub, using the details obtained from the
+/// GraphQL API and the information only available in the REST API. The
+/// GraphQL API doesn't provide the following information, so these REST API
+/// calls cannot be avoided:
+///
+/// - Contributors count and top contributors (one request each).
+/// - Participation stats (one request, retried while GitHub computes them).
+/// - Published security advisories (one request).
+/// - First commit (one request locating it from the commits count provided
+///   by the GraphQL API, only when it's not available in the cache).
+#[instrument(skip(gh, gql_repo, first_commit), err)]
+async fn collect_repository_data_from_gql(
+    gh: &DynGH,
+    owner: &str,
+    repo: &str,
+    gql_repo: GQLRepository,
+    first_commit: Option<Commit>,
+) -> Result<RepositoryGithubData> {
+    // Collect some information from GitHub
+    let first_commit = match (first_commit, &gql_repo.default_branch_ref) {
+        (Some(first_commit), _) => Some(first_commit),
+        (None, Some(default_branch)) => {
+            let commits_count = default_branch
+                .target
+                .as_ref()
+                .and_then(|target| target.history.as_ref())
+                .map(|history| history.total_count);
+            gh.get_first_commit(owner, repo, &default_branch.name, commits_count).await?
+        }
+        (None, None) => None,
+    };
+    let contributors_count = gh.get_contributors_count(owner, repo).await?;
+    let top_contributors = gh.get_top_contributors(owner, repo, GITHUB_TOP_CONTRIBUTORS).await?;
+    let participation_stats =
+        gh.get_participation_stats(owner, repo).await?.map(|s| s.all).unwrap_or_default();
+    let security_advisories = gh.get_security_advisories(owner, repo).await?;
+
+    // Prepare repository instance using the information collected
+    let latest_commit = gql_repo
+        .default_branch_ref
+        .and_then(|branch| branch.target)
+        .and_then(|target| target.history)
+        .and_then(|history| history.nodes.into_iter().next())
+        .map(|commit| Commit {
+            ts: Some(commit.committed_date),
+            url: commit.url,
+        })
+        .ok_or_else(|| format_err!("latest commit not found"))?;
+    let languages: BTreeMap<String, i64> =
+        gql_repo.languages.edges.into_iter().map(|edge| (edge.node.name, edge.size)).collect();
+    let releases: Vec<Release> = gql_repo
+        .releases
+        .nodes
+        .into_iter()
+        .filter(|release| !release.is_draft)
+        .map(Release::from)
+        .collect();
+    Ok(RepositoryGithubData {
+        generated_at: Utc::now(),
+        contributors: Some(Contributors {
+            count: contributors_count,
+            url: format!("{}/graphs/contributors", gql_repo.url),
+            top: if top_contributors.is_empty() {
+                None
+            } else {
+                Some(top_contributors)
+            },
+        }),
+        archived: Some(gql_repo.is_archived),
+        contribution_issues: Some(ContributionIssues {
+            good_first_issue: gql_repo.good_first_issues.total_count,
+            help_wanted: gql_repo.help_wanted_issues.total_count,
+        }),
+        description: gql_repo.description.unwrap_or_default(),
+        first_commit,
+        forks: Some(gql_repo.fork_count),
+        languages: Some(languages),
+        languages_breakdown: None,
+        latest_commit,
+        latest_release: gql_repo.latest_release.map(Release::from),
+        license: gql_repo.license_info.and_then(|l| {
+            if l.spdx_id.as_deref() == Some("NOASSERTION") {
+                None
+            } else {
+                Some(l.name)
+            }
+        }),
+        participation_stats,
+        releases: if releases.is_empty() { None } else { Some(releases) },
+        security_advisories,
+        stars: gql_repo.stargazer_count,
+        stars_history: None,
+        topics: gql_repo.repository_topics.nodes.into_iter().map(|node| node.topic.name).collect(),
+        url: gql_repo.url,
+    })
+}
+
 /// GitHub API base url.
 const GITHUB_API_URL: &str = "https://api.github.com";
 
+/// GitHub instance to collect data from (github.com or a GitHub Enterprise
+/// Server instance).
+struct GithubInstance {
+    api_url: String,
+    graphql_url: String,
+    tokens: Option<Vec<String>>,
+    web_url: String,
+}
+
+impl GithubInstance {
+    /// Create a new GithubInstance for github.com.
+    fn github_com() -> Self {
+        Self {
+            api_url: GITHUB_API_URL.to_string(),
+            graphql_url: format!("{GITHUB_API_URL}/graphql"),
+            tokens: get_tokens(GITHUB_TOKENS),
+            web_url: "https://github.com".to_string(),
+        }
+    }
+
+    /// Create a new GithubInstance for the GitHub Enterprise Server instance
+    /// configured (if any).
+    fn enterprise() -> Option<Self> {
+        let web_url = env::var(GITHUB_ENTERPRISE_URL).ok().filter(|url| !url.is_empty())?;
+        let web_url = web_url.trim_end_matches('/').to_string();
+
+        Some(Self {
+            api_url: format!("{web_url}/api/v3"),
+            graphql_url: format!("{web_url}/api/graphql"),
+            tokens: get_tokens(GITHUB_ENTERPRISE_TOKENS),
+            web_url,
+        })
+    }
+
+    /// Check if the repository url provided belongs to this instance.
+    fn is_repo_url(&self, url: &str) -> bool {
+        REPO_URL.captures(url).is_some_and(|c| c["base"] == self.web_url)
+    }
+}
+
+/// Repository fields requested for each repository in GraphQL API calls.
+const GITHUB_GRAPHQL_REPOSITORY_FIELDS: &str = "
+fragment RepositoryFields on Repository {
+    defaultBranchRef {
+        name
+        target {
+            ... on Commit {
+                history(first: 1) {
+                    totalCount
+                    nodes {
+                        committedDate
+                        url
+                    }
+                }
+            }
+        }
+    }
+    description
+    forkCount
+    goodFirstIssues: issues(states: OPEN, labels: [\"good first issue\"]) {
+        totalCount
+    }
+    helpWantedIssues: issues(states: OPEN, labels: [\"help wanted\"]) {
+        totalCount
+    }
+    isArchived
+    languages(first: 100) {
+        edges {
+            size
+            node {
+                name
+            }
+        }
+    }
+    latestRelease {
+        isDraft
+        publishedAt
+        tagName
+        url
+    }
+    licenseInfo {
+        name
+        spdxId
+    }
+    releases(first: 10, orderBy: {field: CREATED_AT, direction: DESC}) {
+        nodes {
+            isDraft
+            publishedAt
+            tagName
+            url
+        }
+    }
+    repositoryTopics(first: 100) {
+        nodes {
+            topic {
+                name
+            }
+        }
+    }
+    stargazerCount
+    url
+}
+";
+
 /// Type alias to represent a GH trait object.
 type DynGH = Box<dyn GH + Send + Sync>;
 
@@ -179,8 +562,15 @@ trait GH {
     /// Get number of repository contributors.
     async fn get_contributors_count(&self, owner: &str, repo: &str) -> Result<usize>;
 
-    /// Get first commit.
-    async fn get_first_commit(&self, owner: &str, repo: &str, ref_: &str) -> Result<Option<Commit>>;
+    /// Get first commit. When the number of commits is known, it's used to
+    /// locate the first one directly (saving a request).
+    async fn get_first_commit(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+        commits_count: Option<usize>,
+    ) -> Result<Option<Commit>>;
 
     /// Get languages used in repository.
     async fn get_languages(&self, owner: &str, repo: &str) -> Result<Option<BTreeMap<String, i64>>>;
@@ -191,32 +581,212 @@ trait GH {
     /// Get latest release.
     async fn get_latest_release(&self, owner: &str, repo: &str) -> Result<Option<Release>>;
 
-    /// Get participation stats.
-    async fn get_participation_stats(&self, owner: &str, repo: &str) -> Result<ParticipationStats>;
+    /// Get number of open issues with the label provided.
+    async fn get_open_issues_count(&self, owner: &str, repo: &str, label: &str) -> Result<usize>;
+
+    /// Get participation stats (None when GitHub hasn't computed them yet).
+    async fn get_participation_stats(&self, owner: &str, repo: &str) -> Result<Option<ParticipationStats>>;
+
+    /// Get the most recent releases published (up to the count provided).
+    async fn get_releases(&self, owner: &str, repo: &str, count: usize) -> Result<Vec<Release>>;
 
     /// Get repository.
     async fn get_repository(&self, owner: &str, repo: &str) -> Result<FullRepository>;
+
+    /// Get the details of the repositories provided (owner and name) using a
+    /// single GraphQL API request. Repositories are returned in the same
+    /// order they were provided (None when they were not found).
+    async fn get_repositories_graphql(
+        &self,
+        repos: &[(String, String)],
+    ) -> Result<Vec<Option<GQLRepository>>>;
+
+    /// Get published security advisories (count and latest one).
+    async fn get_security_advisories(&self, owner: &str, repo: &str) -> Result<Option<SecurityAdvisories>>;
+
+    /// Get the top contributors (up to the count provided).
+    async fn get_top_contributors(&self, owner: &str, repo: &str, count: usize) -> Result<Vec<Contributor>>;
+
+    /// Get the rate limit status of the token used by this instance.
+    fn rate_limit(&self) -> RateLimit;
+}
+
+/// Pool of GH instances (one per token available). Instances are handed out
+/// based on the rate limit budget left in their tokens, in a round-robin
+/// fashion when their budgets are similar. The budget of the instances in use
+/// is tracked as well, so that a token close to exhaust its rate limit is not
+/// handed out while others still have budget left (we wait for them to be
+/// returned instead). When all tokens are close to exhaust their rate limit,
+/// requests are paused until it is reset.
+struct GHPool {
+    gh_clients: Vec<DynGH>,
+    returned: Notify,
+    state: Mutex<GHPoolState>,
+}
+
+/// GHPool state (instances in use and next instance in the round-robin).
+struct GHPoolState {
+    in_use: Vec<bool>,
+    next: usize,
+}
+
+impl GHPool {
+    /// Create a new GHPool instance.
+    fn new(gh_clients: Vec<DynGH>) -> Self {
+        Self {
+            returned: Notify::new(),
+            state: Mutex::new(GHPoolState {
+                in_use: vec![false; gh_clients.len()],
+                next: 0,
+            }),
+            gh_clients,
+        }
+    }
+
+    /// Get the GH instance with the largest rate limit budget left, waiting
+    /// for one to be available if all of them are in use (or if the ones
+    /// available are close to exhaust their rate limit and the ones in use
+    /// are not).
+    async fn get(&self) -> GHPoolObject<'_> {
+        let (idx, pool_budget) = loop {
+            // Subscribe before checking the state, so that instances returned
+            // in the meantime are not missed
+            let returned = self.returned.notified();
+            if let Some(selected) = self.select() {
+                break selected;
+            }
+            returned.await;
+        };
+
+        // Pause until the rate limit is reset if all tokens in the pool are
+        // close to exhaust it
+        if pool_budget < GITHUB_RATE_LIMIT_MIN_REMAINING {
+            let rate_limit = self.gh_clients[idx].rate_limit();
+            if let Some(wait) = rate_limit.reset_wait() {
+                warn!(
+                    remaining = rate_limit.budget(),
+                    wait_secs = wait.as_secs(),
+                    "all github tokens close to exhaust their rate limit, pausing until it's reset"
+                );
+                tokio::time::sleep(wait.min(GITHUB_RATE_LIMIT_MAX_WAIT)).await;
+            }
+        }
+
+        GHPoolObject { pool: self, idx }
+    }
+
+    /// Select the instance to hand out (if any), marking it as in use. The
+    /// index of the instance is returned along with the largest budget left
+    /// in the pool (instances in use included).
+    fn select(&self) -> Option<(usize, i64)> {
+        let mut state = self.state.lock().expect("not poisoned");
+        let budgets: Vec<i64> = self.gh_clients.iter().map(|gh| gh.rate_limit().budget()).collect();
+        let pool_budget = budgets.iter().copied().max().unwrap_or_default();
+
+        // Pick the available instance with the largest budget, starting from
+        // the next one in the round-robin so that ties are used in turns
+        let count = budgets.len();
+        let mut selected: Option<usize> = None;
+        for idx in (0..count).map(|i| (state.next + i) % count) {
+            if !state.in_use[idx] && selected.map_or(true, |selected| budgets[idx] > budgets[selected]) {
+                selected = Some(idx);
+            }
+        }
+        let idx = selected?;
+
+        // Wait for the instances in use when the one selected is close to
+        // exhaust its rate limit and some of them are not
+        if budgets[idx] < GITHUB_RATE_LIMIT_MIN_REMAINING && pool_budget >= GITHUB_RATE_LIMIT_MIN_REMAINING {
+            return None;
+        }
+
+        state.in_use[idx] = true;
+        state.next = (idx + 1) % count;
+        Some((idx, pool_budget))
+    }
+}
+
+/// GH instance taken from a GHPool. It's returned to the pool when dropped.
+struct GHPoolObject<'a> {
+    pool: &'a GHPool,
+    idx: usize,
+}
+
+impl Deref for GHPoolObject<'_> {
+    type Target = DynGH;
+
+    fn deref(&self) -> &Self::Target {
+        &self.pool.gh_clients[self.idx]
+    }
+}
+
+impl Drop for GHPoolObject<'_> {
+    fn drop(&mut self) {
+        self.pool.state.lock().expect("not poisoned").in_use[self.idx] = false;
+        self.pool.returned.notify_waiters();
+    }
+}
+
+/// Rate limit status of a GitHub token, as reported in the last response
+/// received from the GitHub API.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct RateLimit {
+    remaining: Option<i64>,
+    reset: Option<i64>,
+}
+
+impl RateLimit {
+    /// Create a new RateLimit instance from the headers provided (if they
+    /// include the rate limit information). Only the `core` resource budget
+    /// is tracked, so the headers of responses accounted against other
+    /// resources (i.e. `search` or `graphql`) are ignored.
+    fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        let resource = headers.get("x-ratelimit-resource").and_then(|v| v.to_str().ok());
+        if resource.is_some_and(|resource| resource != GITHUB_RATE_LIMIT_RESOURCE) {
+            return None;
+        }
+        let get_header =
+            |name: &str| headers.get(name).and_then(|v| v.to_str().ok()).and_then(|v| v.parse().ok());
+        let remaining = get_header("x-ratelimit-remaining")?;
+        Some(Self {
+            remaining: Some(remaining),
+            reset: get_header("x-ratelimit-reset"),
+        })
+    }
+
+    /// Number of requests left until the rate limit is reset. When it's not
+    /// known yet, or when the rate limit has already been reset, the budget
+    /// is considered to be complete.
+    fn budget(&self) -> i64 {
+        match (self.remaining, self.reset) {
+            (Some(remaining), Some(reset)) if reset > Utc::now().timestamp() => remaining,
+            (Some(remaining), None) => remaining,
+            _ => i64::MAX,
+        }
+    }
+
+    /// Time left until the rate limit is reset (if known).
+    fn reset_wait(&self) -> Option<Duration> {
+        let reset = self.reset?;
+        let wait = u64::try_from(reset - Utc::now().timestamp()).ok()?;
+        Some(Duration::from_secs(wait + 1))
+    }
 }
 
 /// GH implementation backed by the GitHub API.
 struct GHApi {
-    gh_client: octorust::Client,
+    api_url: String,
+    graphql_url: String,
+    http_cache: Arc<HttpCache>,
     http_client: reqwest::Client,
+    rate_limit: Mutex<RateLimit>,
 }
 
 impl GHApi {
     /// Create a new GHApi instance.
-    fn new(token: &str) -> Result<Self> {
-        // Setup octorust GitHub API client
-        let user_agent = format!("{}/{}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"));
-        let gh_client = octorust::Client::custom(
-            user_agent.clone(),
-            Credentials::Token(token.to_string()),
-            reqwest_middleware::ClientBuilder::new(reqwest_octorust::Client::builder().build()?).build(),
-        );
-
+    fn new(token: &str, instance: &GithubInstance, http_cache: Arc<HttpCache>) -> Result<Self> {
         // Setup HTTP client ready to make requests to the GitHub API
-        // (for some operations that cannot be done with the octorust client)
+        let user_agent = format!("{}/{}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"));
         let mut headers = HeaderMap::new();
         headers.insert(
             header::ACCEPT,
@@ -234,10 +804,46 @@ impl GHApi {
             reqwest::Client::builder().user_agent(user_agent).default_headers(headers).build()?;
 
         Ok(Self {
-            gh_client,
+            api_url: instance.api_url.clone(),
+            graphql_url: instance.graphql_url.clone(),
+            http_cache,
             http_client,
+            rate_limit: Mutex::new(RateLimit::default()),
         })
     }
+
+    /// Send the request provided (conditionally when possible), keeping
+    /// track of the rate limit budget left. If the rate limit has been
+    /// exceeded, wait until it's reset and try again (only once).
+    async fn send(&self, req: reqwest::RequestBuilder) -> Result<HttpResponse> {
+        let retry_req = req.try_clone();
+        let response = self.http_cache.send(req).await?;
+        self.update_rate_limit(&response.headers);
+        if let (Some(retry_req), Some(wait)) =
+            (retry_req, rate_limit_wait(response.status, &response.headers))
+        {
+            if wait > GITHUB_RATE_LIMIT_MAX_WAIT {
+                bail!("rate limit exceeded (reset in {}s)", wait.as_secs());
+            }
+            warn!(
+                wait_secs = wait.as_secs(),
+                "rate limit exceeded, waiting until it's reset"
+            );
+            tokio::time::sleep(wait).await;
+            let response = self.http_cache.send(retry_req).await?;
+            self.update_rate_limit(&response.headers);
+            return Ok(response);
+        }
+        Ok(response)
+    }
+
+    /// Update the rate limit status of the token from the response headers
+    /// provided (responses reused from the cache don't include them).
+    fn update_rate_limit(&self, headers: &HeaderMap) {
+        if let Some(rate_limit) = RateLimit::from_headers(headers) {
+            *self.rate_limit.lock().expect("not poisoned") = rate_limit;
+        }
+    }
 }
 
 #[async_trait]
@@ -245,84 +851,505 @@ impl GH for GHApi {
     /// [GH::get_contributors_count]
     #[instrument(skip(self), err)]
     async fn get_contributors_count(&self, owner: &str, repo: &str) -> Result<usize> {
-        let url = format!("{GITHUB_API_URL}/repos/{owner}/{repo}/contributors?per_page=1&anon=true");
-        let response = self.http_client.head(url).send().await?;
-        let count = get_last_page(response.headers())?.unwrap_or(1);
+        let url = format!(
+            "{}/repos/{owner}/{repo}/contributors?per_page=1&anon=true",
+            self.api_url
+        );
+        let response = self.send(self.http_client.head(url)).await?;
+        let count = get_last_page(&response.headers)?.unwrap_or(1);
         Ok(count)
     }
 
     /// [GH::get_first_commit]
-    #[allow(clippy::cast_possible_wrap)]
     #[instrument(skip(self), err)]
-    async fn get_first_commit(&self, owner: &str, repo: &str, ref_: &str) -> Result<Option<Commit>> {
-        // Get last commits page
-        let url = format!("{GITHUB_API_URL}/repos/{owner}/{repo}/commits?sha={ref_}&per_page=1");
-        let response = self.http_client.head(url).send().await?;
-        let last_page = get_last_page(response.headers())?.unwrap_or(1);
+    async fn get_first_commit(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+        commits_count: Option<usize>,
+    ) -> Result<Option<Commit>> {
+        // Get last commits page (unless the number of commits is known)
+        let url = format!(
+            "{}/repos/{owner}/{repo}/commits?sha={ref_}&per_page=1",
+            self.api_url
+        );
+        let last_page = match commits_count {
+            Some(commits_count) => commits_count.max(1),
+            None => {
+                let response = self.send(self.http_client.head(&url)).await?;
+                get_last_page(&response.headers)?.unwrap_or(1)
+            }
+        };
 
         // Get first repository commit and return it if found
-        if let Some(commit) = self
-            .gh_client
-            .repos()
-            .list_commits(owner, repo, ref_, "", "", None, None, 1, last_page as i64)
-            .await?
-            .body
-            .pop()
-        {
-            return Ok(Some(new_commit_from(commit)));
+        let response = self.send(self.http_client.get(format!("{url}&page={last_page}"))).await?;
+        match response.status {
+            StatusCode::OK => {}
+            // Empty repositories don't have any commits
+            StatusCode::CONFLICT => return Ok(None),
+            status => bail!("unexpected status code listing commits: {status}"),
         }
-        Ok(None)
+        let commit = response.json::<Vec<GHCommit>>()?.pop().map(Commit::from);
+        Ok(commit)
     }
 
     /// [GH::get_languages]
     #[instrument(skip(self), err)]
     async fn get_languages(&self, owner: &str, repo: &str) -> Result<Option<BTreeMap<String, i64>>> {
-        let url = format!("{GITHUB_API_URL}/repos/{owner}/{repo}/languages");
-        let languages: BTreeMap<String, i64> = self.http_client.get(url).send().await?.json().await?;
+        let url = format!("{}/repos/{owner}/{repo}/languages", self.api_url);
+        let languages: BTreeMap<String, i64> = self.send(self.http_client.get(url)).await?.json()?;
         Ok(Some(languages))
     }
 
     /// [GH::get_latest_commit]
     #[instrument(skip(self), err)]
     async fn get_latest_commit(&self, owner: &str, repo: &str, ref_: &str) -> Result<Commit> {
-        let response = self.gh_client.repos().get_commit(owner, repo, 1, 1, ref_).await?;
-        Ok(new_commit_from(response.body))
+        // Only one page of the commit files is requested, as they aren't used
+        let url = format!("{}/repos/{owner}/{repo}/commits/{ref_}?per_page=1", self.api_url);
+        let response = self.send(self.http_client.get(url)).await?;
+        if response.status != StatusCode::OK {
+            bail!(
+                "unexpected status code getting latest commit: {}",
+                response.status
+            );
+        }
+        Ok(Commit::from(response.json::<GHCommit>()?))
     }
 
     /// [GH::get_latest_release]
     #[instrument(skip(self), err)]
     async fn get_latest_release(&self, owner: &str, repo: &str) -> Result<Option<Release>> {
-        match self.gh_client.repos().get_latest_release(owner, repo).await {
-            Ok(response) => Ok(Some(new_release_from(response.body))),
-            Err(err) => {
-                if err.to_string().to_lowercase().contains("not found") {
+        let url = format!("{}/repos/{owner}/{repo}/releases/latest", self.api_url);
+        let response = self.send(self.http_client.get(url)).await?;
+        match response.status {
+            StatusCode::OK => {}
+            StatusCode::NOT_FOUND => return Ok(None),
+            status => bail!("unexpected status code getting latest release: {status}"),
+        }
+        let release: GHRelease = response.json()?;
+        Ok(Some(Release::from(release)))
+    }
+
+    /// [GH::get_open_issues_count]
+    #[instrument(skip(self), err)]
+    async fn get_open_issues_count(&self, owner: &str, repo: &str, label: &str) -> Result<usize> {
+        // The issues list endpoint is used instead of the search one, as the
+        // latter has a much lower rate limit. This endpoint includes labeled
+        // pull requests as well, so they are filtered out while going through
+        // the pages (so that the count matches the GraphQL one)
+        let url = format!("{}/repos/{owner}/{repo}/issues", self.api_url);
+        let per_page = GITHUB_ISSUES_PER_PAGE.to_string();
+        let mut count = 0;
+        let mut page = 1;
+        loop {
+            let req = self.http_client.get(&url).query(&[
+                ("labels", label),
+                ("state", "open"),
+                ("per_page", per_page.as_str()),
+                ("page", page.to_string().as_str()),
+            ]);
+            let response = self.send(req).await?;
+            if response.status != StatusCode::OK {
+                bail!("unexpected status code listing issues: {}", response.status);
+            }
+            let issues: Vec<GHIssue> = response.json()?;
+            count += issues.iter().filter(|issue| issue.pull_request.is_none()).count();
+            if issues.len() < GITHUB_ISSUES_PER_PAGE {
+                break;
+            }
+            page += 1;
+        }
+        Ok(count)
+    }
+
+    /// [GH::get_participation_stats]
+    #[instrument(skip(self), err)]
+    async fn get_participation_stats(&self, owner: &str, repo: &str) -> Result<Option<ParticipationStats>> {
+        let url = format!("{}/repos/{owner}/{repo}/stats/participation", self.api_url);
+
+        // Statistics are computed in the background by GitHub when they
+        // haven't been requested recently, so they may not be ready yet. In
+        // that case we wait a bit and try again (following the retry policy)
+        let retry_policy = self.http_cache.retry_policy();
+        let mut retry = 0;
+        loop {
+            let response = self.send(self.http_client.get(&url)).await?;
+            match response.status {
+                StatusCode::OK => return Ok(Some(response.json()?)),
+                StatusCode::ACCEPTED if retry < retry_policy.max_retries => {
+                    tokio::time::sleep(retry_policy.delay(retry)).await;
+                    retry += 1;
+                }
+                StatusCode::ACCEPTED => {
+                    debug!("participation stats not available yet");
                     return Ok(None);
                 }
-                Err(err.into())
+                status => bail!("unexpected status code getting participation stats: {status}"),
             }
         }
     }
 
-    /// [GH::get_participation_stats]
+    /// [GH::get_releases]
     #[instrument(skip(self), err)]
-    async fn get_participation_stats(&self, owner: &str, repo: &str) -> Result<ParticipationStats> {
-        let response = self.gh_client.repos().get_participation_stats(owner, repo).await?;
-        Ok(response.body)
+    async fn get_releases(&self, owner: &str, repo: &str, count: usize) -> Result<Vec<Release>> {
+        let url = format!("{}/repos/{owner}/{repo}/releases?per_page={count}", self.api_url);
+        let response = self.send(self.http_client.get(url)).await?;
+        if response.status != StatusCode::OK {
+            bail!("unexpected status code getting releases: {}", response.status);
+        }
+        let releases = response
+            .json::<Vec<GHRelease>>()?
+            .into_iter()
+            .filter(|release| !release.draft)
+            .map(Release::from)
+            .collect();
+        Ok(releases)
     }
 
     /// [GH::get_repository]
     #[instrument(skip(self), err)]
     async fn get_repository(&self, owner: &str, repo: &str) -> Result<FullRepository> {
-        let response = self.gh_client.repos().get(owner, repo).await?;
-        Ok(response.body)
+        let url = format!("{}/repos/{owner}/{repo}", self.api_url);
+        let response = self.send(self.http_client.get(url)).await?;
+        if response.status != StatusCode::OK {
+            bail!("unexpected status code getting repository: {}", response.status);
+        }
+        response.json()
+    }
+
+    /// [GH::get_repositories_graphql]
+    #[instrument(skip(self), err)]
+    async fn get_repositories_graphql(
+        &self,
+        repos: &[(String, String)],
+    ) -> Result<Vec<Option<GQLRepository>>> {
+        // Prepare query, using an alias for each of the repositories requested
+        let mut params = vec![];
+        let mut fields = vec![];
+        let mut variables = serde_json::Map::new();
+        for (i, (owner, repo)) in repos.iter().enumerate() {
+            params.push(format!("$owner{i}: String!, $name{i}: String!"));
+            fields.push(format!(
+                "r{i}: repository(owner: $owner{i}, name: $name{i}) {{ ...RepositoryFields }}"
+            ));
+            variables.insert(format!("owner{i}"), owner.clone().into());
+            variables.insert(format!("name{i}"), repo.clone().into());
+        }
+        let query = format!(
+            "query({}) {{ {} }} {GITHUB_GRAPHQL_REPOSITORY_FIELDS}",
+            params.join(", "),
+            fields.join(" ")
+        );
+
+        // Send request and process response
+        let body = json!({ "query": query, "variables": variables });
+        let response = self.send(self.http_client.post(&self.graphql_url).json(&body)).await?;
+        if response.status != StatusCode::OK {
+            bail!("unexpected status code querying graphql api: {}", response.status);
+        }
+        let response: GQLResponse = response.json()?;
+        let Some(mut data) = response.data else {
+            let errors: Vec<String> = response.errors.into_iter().map(|e| e.message).collect();
+            bail!("error querying graphql api: {}", errors.join(", "));
+        };
+        let gql_repos = (0..repos.len()).map(|i| data.remove(&format!("r{i}")).flatten()).collect();
+
+        Ok(gql_repos)
+    }
+
+    /// [GH::get_security_advisories]
+    #[instrument(skip(self), err)]
+    async fn get_security_advisories(&self, owner: &str, repo: &str) -> Result<Option<SecurityAdvisories>> {
+        let url = format!(
+            "{}/repos/{owner}/{repo}/security-advisories?state=published&sort=published&direction=desc&per_page=1",
+            self.api_url
+        );
+        let response = self.send(self.http_client.get(url)).await?;
+        match response.status {
+            StatusCode::OK => {}
+            StatusCode::NOT_FOUND | StatusCode::FORBIDDEN => return Ok(None),
+            status => bail!("unexpected status code getting security advisories: {status}"),
+        }
+        let last_page = get_last_page(&response.headers)?;
+        let latest = response.json::<Vec<GHSecurityAdvisory>>()?.pop();
+        let count = if latest.is_some() {
+            last_page.unwrap_or(1)
+        } else {
+            0
+        };
+        Ok(Some(SecurityAdvisories {
+            count,
+            latest: latest.map(|advisory| SecurityAdvisory {
+                id: advisory.ghsa_id,
+                url: advisory.html_url,
+                published_at: advisory.published_at,
+                severity: advisory.severity,
+                summary: advisory.summary,
+            }),
+        }))
+    }
+
+    /// [GH::get_top_contributors]
+    #[instrument(skip(self), err)]
+    async fn get_top_contributors(&self, owner: &str, repo: &str, count: usize) -> Result<Vec<Contributor>> {
+        let url = format!(
+            "{}/repos/{owner}/{repo}/contributors?per_page={count}",
+            self.api_url
+        );
+        let response = self.send(self.http_client.get(url)).await?;
+        match response.status {
+            StatusCode::OK => {}
+            // Repositories with a large history may not provide contributors
+            // information through the API
+            StatusCode::NO_CONTENT | StatusCode::FORBIDDEN => return Ok(vec![]),
+            status => bail!("unexpected status code getting top contributors: {status}"),
+        }
+        let contributors = response
+            .json::<Vec<GHContributor>>()?
+            .into_iter()
+            .map(|contributor| Contributor {
+                contributions: contributor.contributions,
+                login: contributor.login,
+                url: contributor.html_url,
+            })
+            .collect();
+        Ok(contributors)
+    }
+
+    /// [GH::rate_limit]
+    fn rate_limit(&self) -> RateLimit {
+        *self.rate_limit.lock().expect("not poisoned")
     }
 }
 
+/// GitHub commit details.
+#[derive(Debug, Deserialize)]
+struct GHCommit {
+    commit: GHCommitDetails,
+    html_url: String,
+}
+
+/// GitHub commit git details.
+#[derive(Debug, Deserialize)]
+struct GHCommitDetails {
+    author: Option<GHCommitAuthor>,
+}
+
+/// GitHub commit author details.
+#[derive(Debug, Deserialize)]
+struct GHCommitAuthor {
+    date: DateTime<Utc>,
+}
+
+impl From<GHCommit> for Commit {
+    fn from(value: GHCommit) -> Self {
+        Commit {
+            url: value.html_url,
+            ts: value.commit.author.map(|author| author.date),
+        }
+    }
+}
+
+/// GitHub contributor details.
+#[derive(Debug, Deserialize)]
+struct GHContributor {
+    contributions: usize,
+    html_url: String,
+    login: String,
+}
+
+/// GitHub issue details (only the fields we are interested in).
+#[derive(Debug, Deserialize)]
+struct GHIssue {
+    pull_request: Option<serde_json::Value>,
+}
+
+/// GitHub GraphQL API response.
+#[derive(Debug, Deserialize)]
+struct GQLResponse {
+    data: Option<BTreeMap<String, Option<GQLRepository>>>,
+    #[serde(default)]
+    errors: Vec<GQLError>,
+}
+
+/// GitHub GraphQL API error.
+#[derive(Debug, Deserialize)]
+struct GQLError {
+    message: String,
+}
+
+/// GitHub GraphQL API list of nodes.
+#[derive(Debug, Deserialize)]
+struct GQLNodes<T> {
+    nodes: Vec<T>,
+}
+
+/// GitHub GraphQL API repository details.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GQLRepository {
+    default_branch_ref: Option<GQLBranch>,
+    description: Option<String>,
+    fork_count: i64,
+    good_first_issues: GQLCount,
+    help_wanted_issues: GQLCount,
+    is_archived: bool,
+    languages: GQLLanguages,
+    latest_release: Option<GQLRelease>,
+    license_info: Option<GQLLicense>,
+    releases: GQLNodes<GQLRelease>,
+    repository_topics: GQLNodes<GQLRepositoryTopic>,
+    stargazer_count: i64,
+    url: String,
+}
+
+/// GitHub GraphQL API branch details.
+#[derive(Debug, Deserialize)]
+struct GQLBranch {
+    name: String,
+    target: Option<GQLBranchTarget>,
+}
+
+/// GitHub GraphQL API branch target details (only commits are supported).
+#[derive(Debug, Deserialize)]
+struct GQLBranchTarget {
+    history: Option<GQLCommitHistory>,
+}
+
+/// GitHub GraphQL API commit history (only the latest commit is requested).
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GQLCommitHistory {
+    total_count: usize,
+    nodes: Vec<GQLCommit>,
+}
+
+/// GitHub GraphQL API commit details.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GQLCommit {
+    committed_date: DateTime<Utc>,
+    url: String,
+}
+
+/// GitHub GraphQL API repository languages.
+#[derive(Debug, Deserialize)]
+struct GQLLanguages {
+    edges: Vec<GQLLanguageEdge>,
+}
+
+/// GitHub GraphQL API repository language (size in bytes).
+#[derive(Debug, Deserialize)]
+struct GQLLanguageEdge {
+    node: GQLName,
+    size: i64,
+}
+
+/// GitHub GraphQL API license details.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GQLLicense {
+    name: String,
+    spdx_id: Option<String>,
+}
+
+/// GitHub GraphQL API release details.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GQLRelease {
+    is_draft: bool,
+    published_at: Option<DateTime<Utc>>,
+    tag_name: String,
+    url: String,
+}
+
+impl From<GQLRelease> for Release {
+    fn from(value: GQLRelease) -> Self {
+        Release {
+            ts: value.published_at,
+            url: value.url,
+            tag: Some(value.tag_name),
+        }
+    }
+}
+
+/// GitHub GraphQL API repository topic.
+#[derive(Debug, Deserialize)]
+struct GQLRepositoryTopic {
+    topic: GQLName,
+}
+
+/// GitHub GraphQL API connection total count.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GQLCount {
+    total_count: usize,
+}
+
+/// GitHub GraphQL API named entity.
+#[derive(Debug, Deserialize)]
+struct GQLName {
+    name: String,
+}
+
+/// GitHub release details.
+#[derive(Debug, Deserialize)]
+struct GHRelease {
+    draft: bool,
+    html_url: String,
+    published_at: Option<DateTime<Utc>>,
+    tag_name: String,
+}
+
+impl From<GHRelease> for Release {
+    fn from(value: GHRelease) -> Self {
+        Release {
+            ts: value.published_at,
+            url: value.html_url,
+            tag: Some(value.tag_name),
+        }
+    }
+}
+
+/// GitHub repository security advisory details.
+#[derive(Debug, Deserialize)]
+struct GHSecurityAdvisory {
+    ghsa_id: String,
+    html_url: String,
+    published_at: Option<DateTime<Utc>>,
+    severity: Option<String>,
+    summary: Option<String>,
+}
+
 lazy_static! {
-    /// GitHub repository url regular expression.
-    pub(crate) static ref GITHUB_REPO_URL: Regex =
-        Regex::new("^https://github.com/(?P<owner>[^/]+)/(?P<repo>[^/]+)/?$")
-            .expect("exprs in GITHUB_REPO_URL to be valid");
+    /// GitHub repository url regular expression (github.com or GitHub
+    /// Enterprise Server).
+    static ref REPO_URL: Regex =
+        Regex::new("^(?P<base>https?://[^/]+)/(?P<owner>[^/]+)/(?P<repo>[^/]+)/?$")
+            .expect("exprs in REPO_URL to be valid");
+}
+
+/// Return how long we need to wait for the rate limit to be reset when the
+/// response status and headers provided indicate that it has been exceeded.
+fn rate_limit_wait(status: StatusCode, headers: &HeaderMap) -> Option<Duration> {
+    if status != StatusCode::FORBIDDEN && status != StatusCode::TOO_MANY_REQUESTS {
+        return None;
+    }
+    let get_header =
+        |name: &str| headers.get(name).and_then(|v| v.to_str().ok()).and_then(|v| v.parse().ok());
+    if let Some(retry_after) = get_header("retry-after") {
+        return Some(Duration::from_secs(retry_after));
+    }
+    if get_header("x-ratelimit-remaining") != Some(0) {
+        return None;
+    }
+    let reset: i64 = headers
+        .get("x-ratelimit-reset")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.parse().ok())?;
+    let wait = u64::try_from(reset - Utc::now().timestamp()).unwrap_or_default();
+    Some(Duration::from_secs(wait + 1))
 }
 
 /// Return the last page of results available from the headers provided.
@@ -338,28 +1365,16 @@ fn get_last_page(headers: &HeaderMap) -> Result<Option<usize>> {
     Ok(None)
 }
 
-/// Extract the owner and repository from the repository url provided.
-fn get_owner_and_repo(repo_url: &str) -> Result<(String, String)> {
-    let c = GITHUB_REPO_URL.captures(repo_url).ok_or_else(|| format_err!("invalid repository url"))?;
-    Ok((c["owner"].to_string(), c["repo"].to_string()))
-}
-
-/// Create a new commit instance from the octorust commit data provided.
-fn new_commit_from(value: octorust::types::CommitDataType) -> Commit {
-    let mut commit = Commit {
-        url: value.html_url,
-        ts: None,
-    };
-    if let Some(author) = value.commit.author {
-        commit.ts = Some(DateTime::parse_from_rfc3339(&author.date).expect("date to be valid").into());
+/// Get the list of tokens available in the environment variable provided.
+pub(crate) fn get_tokens(var: &str) -> Option<Vec<String>> {
+    match env::var(var) {
+        Ok(tokens) if !tokens.is_empty() => Some(tokens.split(',').map(ToString::to_string).collect()),
+        Ok(_) | Err(_) => None,
     }
-    commit
 }
 
-/// Create a new release instance from the octorust release data provided.
-fn new_release_from(value: octorust::types::Release) -> Release {
-    Release {
-        ts: value.published_at,
-        url: value.html_url,
-    }
+/// Extract the owner and repository from the repository url provided.
+fn get_owner_and_repo(repo_url: &str) -> Result<(String, String)> {
+    let c = REPO_URL.captures(repo_url).ok_or_else(|| format_err!("invalid repository url"))?;
+    Ok((c["owner"].to_string(), c["repo"].to_string()))
 }
@@ -0,0 +1,223 @@
+//! This module defines the functionality used to collect some information
+//! from LinkedIn (followers and employees range) for each of the landscape
+//! items that provide a LinkedIn url (when applicable).
+
+use super::{cache::Cache, collector::Collector, retry::RetryPolicy, LandscapeData};
+use anyhow::{bail, format_err, Result};
+use async_trait::async_trait;
+use chrono::Utc;
+use landscape2_core::data::{LinkedInData, LinkedInOrganizationData};
+use lazy_static::lazy_static;
+#[cfg(test)]
+use mockall::automock;
+use regex::Regex;
+use reqwest::{header, StatusCode};
+use serde::Deserialize;
+use std::{env, sync::Arc};
+use tracing::{debug, instrument, warn};
+
+/// File used to cache data collected from LinkedIn.
+pub(crate) const LINKEDIN_CACHE_FILE: &str = "linkedin.json";
+
+/// How long the LinkedIn data in the cache is valid (in days).
+const LINKEDIN_CACHE_TTL: i64 = 7;
+
+/// Environment variable containing the LinkedIn API access token.
+const LINKEDIN_ACCESS_TOKEN: &str = "LINKEDIN_ACCESS_TOKEN";
+
+/// Maximum number of organizations to process concurrently.
+const LINKEDIN_MAX_CONCURRENCY: usize = 5;
+
+/// Collect LinkedIn data for each of the items in the landscape that provide
+/// a LinkedIn url, reusing cached data whenever possible.
+#[instrument(skip_all, err)]
+pub(crate) async fn collect_linkedin_data(
+    cache: &Cache,
+    landscape_data: &LandscapeData,
+) -> Result<LinkedInData> {
+    debug!("collecting organizations information from linkedin");
+
+    // Setup LinkedIn API http client if an access token was provided
+    let li: DynLI = match env::var(LINKEDIN_ACCESS_TOKEN) {
+        Ok(token) if !token.is_empty() => Arc::new(LIApi::new(&token, cache.retry_policy())?),
+        Ok(_) | Err(_) => {
+            warn!("linkedin access token not provided: no information will be collected from linkedin");
+            return Ok(LinkedInData::default());
+        }
+    };
+
+    // Collect items LinkedIn urls
+    let mut urls = vec![];
+    for item in &landscape_data.items {
+        if let Some(url) = &item.linkedin_url {
+            urls.push(url.clone());
+        }
+    }
+    urls.sort();
+    urls.dedup();
+
+    // Collect information from LinkedIn, reusing cached data when available
+    let collector = Collector {
+        cache,
+        cache_file: LINKEDIN_CACHE_FILE,
+        cache_ttl: LINKEDIN_CACHE_TTL,
+        max_concurrency: LINKEDIN_MAX_CONCURRENCY,
+        description: "linkedin organization data",
+    };
+    let entries = urls.into_iter().map(|url| (url.clone(), url)).collect();
+    let linkedin_data = collector
+        .collect(entries, |url, _| {
+            let li = li.clone();
+            async move { collect_organization_data(li, &url).await }
+        })
+        .await?;
+
+    debug!("done!");
+    Ok(linkedin_data)
+}
+
+/// Collect organization data from LinkedIn.
+#[instrument(skip(li), err)]
+async fn collect_organization_data(li: DynLI, linkedin_url: &str) -> Result<LinkedInOrganizationData> {
+    // Get organization details and followers count from LinkedIn
+    let vanity_name = get_vanity_name(linkedin_url)?;
+    let li_org = li.get_organization(&vanity_name).await?;
+    let followers = li.get_followers_count(li_org.id).await?;
+
+    // Prepare number of employees
+    let (num_employees_min, num_employees_max) = match li_org.staff_count_range {
+        Some(value) => match value.as_str() {
+            "SIZE_1" => (Some(1), Some(1)),
+            "SIZE_2_TO_10" => (Some(2), Some(10)),
+            "SIZE_11_TO_50" => (Some(11), Some(50)),
+            "SIZE_51_TO_200" => (Some(51), Some(200)),
+            "SIZE_201_TO_500" => (Some(201), Some(500)),
+            "SIZE_501_TO_1000" => (Some(501), Some(1000)),
+            "SIZE_1001_TO_5000" => (Some(1001), Some(5000)),
+            "SIZE_5001_TO_10000" => (Some(5001), Some(10000)),
+            "SIZE_10001_OR_MORE" => (Some(10001), None),
+            _ => (None, None),
+        },
+        None => (None, None),
+    };
+
+    Ok(LinkedInOrganizationData {
+        generated_at: Utc::now(),
+        followers: Some(followers),
+        num_employees_max,
+        num_employees_min,
+    })
+}
+
+/// LinkedIn API base url.
+const LINKEDIN_API_URL: &str = "https://api.linkedin.com/v2";
+
+/// Type alias to represent a LI trait object.
+type DynLI = Arc<dyn LI + Send + Sync>;
+
+/// Trait that defines some operations a LI implementation must support.
+#[async_trait]
+#[cfg_attr(test, automock)]
+trait LI {
+    /// Get number of followers of the organization provided.
+    async fn get_followers_count(&self, organization_id: u64) -> Result<u64>;
+
+    /// Get organization information.
+    async fn get_organization(&self, vanity_name: &str) -> Result<LIOrganization>;
+}
+
+/// LI implementation backed by the LinkedIn API.
+struct LIApi {
+    http_client: reqwest::Client,
+    retry_policy: RetryPolicy,
+}
+
+impl LIApi {
+    /// Create a new LIApi instance.
+    fn new(token: &str, retry_policy: RetryPolicy) -> Result<Self> {
+        // Setup HTTP client ready to make requests to the LinkedIn API
+        let user_agent = format!("{}/{}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"));
+        let mut headers = header::HeaderMap::new();
+        headers.insert(
+            header::AUTHORIZATION,
+            header::HeaderValue::from_str(&format!("Bearer {token}"))?,
+        );
+        let http_client =
+            reqwest::Client::builder().user_agent(user_agent).default_headers(headers).build()?;
+
+        Ok(Self {
+            http_client,
+            retry_policy,
+        })
+    }
+}
+
+#[async_trait]
+impl LI for LIApi {
+    /// [LI::get_followers_count]
+    #[instrument(skip(self), err)]
+    async fn get_followers_count(&self, organization_id: u64) -> Result<u64> {
+        let url = format!("{LINKEDIN_API_URL}/networkSizes/urn:li:organization:{organization_id}");
+        let req = self.http_client.get(url).query(&[("edgeType", "CompanyFollowedByMember")]);
+        let resp = self.retry_policy.send(req).await?;
+        if resp.status() != StatusCode::OK {
+            bail!(
+                "unexpected status code getting followers count: {}",
+                resp.status()
+            );
+        }
+        let network_size: LINetworkSize = resp.json().await?;
+
+        Ok(network_size.first_degree_size)
+    }
+
+    /// [LI::get_organization]
+    #[instrument(skip(self), err)]
+    async fn get_organization(&self, vanity_name: &str) -> Result<LIOrganization> {
+        let req = self
+            .http_client
+            .get(format!("{LINKEDIN_API_URL}/organizations"))
+            .query(&[("q", "vanityName"), ("vanityName", vanity_name)]);
+        let resp = self.retry_policy.send(req).await?;
+        if resp.status() != StatusCode::OK {
+            bail!("unexpected status code getting organization: {}", resp.status());
+        }
+        let results: LIOrganizationsResults = resp.json().await?;
+
+        results.elements.into_iter().next().ok_or_else(|| format_err!("organization not found"))
+    }
+}
+
+/// LinkedIn organizations search results.
+#[derive(Debug, Clone, Deserialize)]
+struct LIOrganizationsResults {
+    elements: Vec<LIOrganization>,
+}
+
+/// LinkedIn organization details.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LIOrganization {
+    id: u64,
+    staff_count_range: Option<String>,
+}
+
+/// LinkedIn network size details.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LINetworkSize {
+    first_degree_size: u64,
+}
+
+lazy_static! {
+    /// LinkedIn organization url regular expression.
+    static ref LINKEDIN_URL: Regex =
+        Regex::new("^https://(?:www\\.)?linkedin\\.com/company/(?P<vanity_name>[^/?#]+)/?")
+            .expect("exprs in LINKEDIN_URL to be valid");
+}
+
+/// Extract the organization vanity name from the LinkedIn url provided.
+fn get_vanity_name(linkedin_url: &str) -> Result<String> {
+    let c = LINKEDIN_URL.captures(linkedin_url).ok_or_else(|| format_err!("invalid linkedin url"))?;
+    Ok(c["vanity_name"].to_string())
+}
@@ -1,31 +1,118 @@
 //! This module provides some helper functions to prepare logos to be displayed
 //! on the landscape web application.
 
-use super::settings::LogosViewbox;
-use anyhow::{bail, Result};
+use super::{cache::HttpCache, settings::LogosViewbox};
+use anyhow::{bail, format_err, Result};
+use base64::{engine::general_purpose::STANDARD as b64, Engine as _};
 use clap::Args;
 use lazy_static::lazy_static;
-use regex::bytes::Regex;
+use regex::bytes::{Captures, Regex};
 use reqwest::StatusCode;
+use resvg::tiny_skia;
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 use std::{
+    collections::HashMap,
     fs,
     path::{Path, PathBuf},
 };
-use usvg::{NodeExt, Rect, TreeParsing};
+use tracing::{debug, warn};
+use usvg::{fontdb, NodeExt, Rect, TreeParsing, TreeTextToPath};
+
+/// Raster image formats supported for logos. Raster logos are embedded in an
+/// SVG document, so that all logos can be handled the same way.
+pub(crate) const RASTER_LOGOS_EXTENSIONS: [&str; 4] = ["jpeg", "jpg", "png", "webp"];
+
+/// Size (in pixels) of the largest side of the logos PNG previews.
+const LOGOS_PREVIEW_SIZE: u32 = 200;
 
 lazy_static! {
+    /// Fonts database used to render the text in the logos previews.
+    static ref FONTS_DB: fontdb::Database = {
+        let mut fonts_db = fontdb::Database::new();
+        fonts_db.load_system_fonts();
+        fonts_db
+    };
+
+    /// Regular expression used to remove the SVG logos' comments.
+    static ref SVG_COMMENTS: Regex = Regex::new(r"(?s)<!--.*?-->").expect("exprs in SVG_COMMENTS to be valid");
+
+    /// Regular expression used to remove the event handlers attributes (i.e.
+    /// onload) from the SVG logos.
+    static ref SVG_EVENT_HANDLERS: Regex =
+        Regex::new(r#"(?i)\s+on[a-z]+\s*=\s*(?:"[^"]*"|'[^']*'|[^\s>]+)"#)
+            .expect("exprs in SVG_EVENT_HANDLERS to be valid");
+
+    /// Regular expression used to match the references (href attributes) in
+    /// the SVG logos.
+    static ref SVG_HREFS: Regex =
+        Regex::new(r#"(?i)\s+(?:xlink:)?href\s*=\s*(?:"(?P<dq>[^"]*)"|'(?P<sq>[^']*)')"#)
+            .expect("exprs in SVG_HREFS to be valid");
+
+    /// Regular expression used to remove the metadata and editor specific
+    /// elements (i.e. Inkscape or Sodipodi) from the SVG logos.
+    static ref SVG_METADATA: Regex =
+        Regex::new(concat!(
+            r"(?s)<(?:metadata|sodipodi:namedview|inkscape:[a-z-]+)\b[^>]*?",
+            r"(?:/>|>.*?</(?:metadata|sodipodi:namedview|inkscape:[a-z-]+)>)"
+        ))
+        .expect("exprs in SVG_METADATA to be valid");
+
+    /// Regular expression used to reduce the precision of the numbers in the
+    /// SVG logos to three decimals.
+    static ref SVG_NUMBERS_PRECISION: Regex =
+        Regex::new(r"(\d\.\d{3})\d+").expect("exprs in SVG_NUMBERS_PRECISION to be valid");
+
+    /// Regular expression used to match the SVG logos' root element.
+    static ref SVG_ROOT: Regex = Regex::new(r"(?s)<svg\b[^>]*>").expect("exprs in SVG_ROOT to be valid");
+
+    /// Regular expressions used to extract the SVG logos' dimensions.
+    static ref SVG_WIDTH: Regex =
+        Regex::new(r#"\swidth="(?P<value>[0-9.]+)(?:px)?""#).expect("exprs in SVG_WIDTH to be valid");
+    static ref SVG_HEIGHT: Regex =
+        Regex::new(r#"\sheight="(?P<value>[0-9.]+)(?:px)?""#).expect("exprs in SVG_HEIGHT to be valid");
+
+    /// Regular expression used to remove the scripts and foreign objects from
+    /// the SVG logos.
+    static ref SVG_SCRIPTS: Regex =
+        Regex::new(r"(?is)<(?:script|foreignObject)\b[^>]*?(?:/>|>.*?</(?:script|foreignObject)\s*>)")
+            .expect("exprs in SVG_SCRIPTS to be valid");
+
+    /// Regular expression used to match the resources referenced from the SVG
+    /// logos' styles.
+    static ref SVG_STYLES_URLS: Regex =
+        Regex::new(r#"(?i)url\(\s*['"]?(?P<url>[^)'"]*)['"]?\s*\)"#)
+            .expect("exprs in SVG_STYLES_URLS to be valid");
+
+    /// Regular expression used to remove the whitespace between the SVG logos'
+    /// tags.
+    static ref SVG_WHITESPACE: Regex = Regex::new(r">\s+<").expect("exprs in SVG_WHITESPACE to be valid");
+
     /// Regular expression used to remove the SVG logos' title.
     static ref SVG_TITLE: Regex = Regex::new("<title>.*</title>",).expect("exprs in SVG_TITLE to be valid");
 
+    /// Regular expression used to remove the SVG logos' XML declaration.
+    static ref SVG_XML_DECLARATION: Regex =
+        Regex::new(r"<\?xml[^>]*\?>").expect("exprs in SVG_XML_DECLARATION to be valid");
+
     /// Regular expression used to update the SVG logos' viewbox.
     static ref SVG_VIEWBOX: Regex = Regex::new(r#"viewBox="[0-9. ]*""#).expect("expr in SVG_VIEWBOX to be valid");
+
+    /// Regular expression used to extract the SVG logos' viewbox values.
+    static ref SVG_VIEWBOX_VALUES: Regex = Regex::new(concat!(
+        r#"viewBox="\s*(?P<x>-?[0-9.]+)[\s,]+(?P<y>-?[0-9.]+)"#,
+        r#"[\s,]+(?P<width>[0-9.]+)[\s,]+(?P<height>[0-9.]+)\s*""#
+    ))
+    .expect("expr in SVG_VIEWBOX_VALUES to be valid");
 }
 
 /// Landscape logos source.
+///
+/// Both a local path and a base url can be provided. In that case, logos are
+/// looked up in the local path first, falling back to the url when they are
+/// not found there.
 #[derive(Args, Clone, Default)]
-#[group(required = true, multiple = false)]
+#[group(required = true, multiple = true)]
 pub struct LogosSource {
     /// Local path where the logos are stored.
     #[arg(long)]
@@ -42,30 +129,75 @@ pub(crate) struct Logo {
     pub data: Vec<u8>,
     pub extension: String,
     pub digest: String,
+    pub preview: Option<Vec<u8>>,
 }
 
-/// Get logo from the source provided and apply some modifications to it when
-/// applicable.
-pub(crate) async fn prepare_logo(
-    http_client: reqwest::Client,
-    logos_source: &LogosSource,
+/// Logos prepared in previous builds, indexed by the digest of their source
+/// (see [source_digest]).
+pub(crate) type PreparedLogos = HashMap<String, PreparedLogo>;
+
+/// Represents some information about a logo prepared in a previous build,
+/// which allows reusing it when its source hasn't changed.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct PreparedLogo {
+    pub digest: String,
+    pub extension: String,
+    pub preview: bool,
+}
+
+impl From<&Logo> for PreparedLogo {
+    fn from(logo: &Logo) -> Self {
+        Self {
+            digest: logo.digest.clone(),
+            extension: logo.extension.clone(),
+            preview: logo.preview.is_some(),
+        }
+    }
+}
+
+/// Calculate the digest of the logo source data provided. The options used
+/// to prepare the logo are taken into account as well, as the logo prepared
+/// will be different if they change.
+pub(crate) fn source_digest(logo_data: &[u8], logos_viewbox: &LogosViewbox, file_name: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(logo_data);
+    hasher.update(file_name.as_bytes());
+    hasher.update(serde_json::to_vec(logos_viewbox).unwrap_or_default());
+    hex::encode(hasher.finalize())
+}
+
+/// Apply some modifications to the logo data provided when applicable.
+pub(crate) fn prepare_logo(
+    mut logo_data: Vec<u8>,
     logos_viewbox: &LogosViewbox,
     file_name: &str,
 ) -> Result<Logo> {
-    // Get logo from the source provided
-    let mut logo_data = get_logo(http_client.clone(), logos_source, file_name).await?;
-
-    // Apply some modifications to the logo if it is an SVG file
-    let extension = Path::new(file_name)
+    // Embed the logo in an SVG document if it is a raster image
+    let mut extension = Path::new(file_name)
         .extension()
         .and_then(|ext| ext.to_str())
         .unwrap_or_default()
         .to_lowercase();
-    if extension == "svg" {
+    if RASTER_LOGOS_EXTENSIONS.contains(&extension.as_str()) {
+        logo_data = embed_raster_logo(&logo_data, &extension)?;
+        extension = "svg".to_string();
+    }
+    // Apply some modifications to the logo if it is an SVG file
+    else if extension == "svg" {
+        // Remove potentially harmful content (logos are often contributed by
+        // third parties)
+        logo_data = sanitize_svg(&logo_data);
+
         // Remove title if present (some identical logos are using a different
         // title, so we do this before computing the digest)
         logo_data = SVG_TITLE.replace(&logo_data, b"").into_owned();
 
+        // Optimize logo to reduce its size
+        logo_data = optimize_svg(&logo_data);
+
+        // Add viewbox if missing, so that all logos scale the same way
+        logo_data = add_missing_svg_viewbox(&logo_data);
+
         // Update viewbox to the smallest rectangle in which the object fits
         if logos_viewbox.adjust && !logos_viewbox.exclude.contains(&file_name.to_string()) {
             if let Ok(Some(bounding_box)) = get_svg_bounding_box(&logo_data) {
@@ -82,44 +214,263 @@ pub(crate) async fn prepare_logo(
                 }
             }
         }
+
+        // Add some padding around the logo if requested
+        if let Some(padding) = logos_viewbox.padding.filter(|padding| *padding > 0.0) {
+            logo_data = add_svg_padding(&logo_data, padding);
+        }
     }
 
     // Calculate digest
     let digest = hex::encode(Sha256::digest(&logo_data));
 
+    // Prepare PNG preview
+    let preview = if extension == "svg" {
+        match rasterize_svg(&logo_data, LOGOS_PREVIEW_SIZE, false) {
+            Ok(preview) => Some(preview),
+            Err(err) => {
+                warn!(?err, ?file_name, "error preparing logo preview");
+                None
+            }
+        }
+    } else {
+        None
+    };
+
     Ok(Logo {
         data: logo_data,
         extension,
         digest,
+        preview,
     })
 }
 
+/// Sanitize the SVG logo provided, removing scripts, event handlers and
+/// references to external resources. Only references to fragments in the same
+/// document and embedded images are kept.
+fn sanitize_svg(svg_data: &[u8]) -> Vec<u8> {
+    let svg_data = SVG_SCRIPTS.replace_all(svg_data, b"");
+    let svg_data = SVG_EVENT_HANDLERS.replace_all(&svg_data, b"");
+    let svg_data = SVG_HREFS.replace_all(&svg_data, |caps: &Captures| {
+        let href = caps.name("dq").or(caps.name("sq")).map(|m| m.as_bytes()).unwrap_or_default();
+        if is_safe_reference(href) {
+            caps[0].to_vec()
+        } else {
+            vec![]
+        }
+    });
+    let svg_data = SVG_STYLES_URLS.replace_all(&svg_data, |caps: &Captures| {
+        if is_safe_reference(&caps["url"]) {
+            caps[0].to_vec()
+        } else {
+            b"none".to_vec()
+        }
+    });
+
+    svg_data.into_owned()
+}
+
+/// Check if the reference provided points to a fragment in the same document or
+/// to an embedded image.
+fn is_safe_reference(reference: &[u8]) -> bool {
+    let reference = reference.trim_ascii().to_ascii_lowercase();
+    reference.starts_with(b"#") || reference.starts_with(b"data:image/")
+}
+
+/// Optimize the SVG logo provided, removing some content not needed to render
+/// it (comments, metadata, editor specific elements, etc) and reducing the
+/// precision of the numbers used.
+fn optimize_svg(svg_data: &[u8]) -> Vec<u8> {
+    let svg_data = SVG_XML_DECLARATION.replace_all(svg_data, b"");
+    let svg_data = SVG_COMMENTS.replace_all(&svg_data, b"");
+    let svg_data = SVG_METADATA.replace_all(&svg_data, b"");
+    let svg_data = SVG_NUMBERS_PRECISION.replace_all(&svg_data, b"$1");
+    let svg_data = SVG_WHITESPACE.replace_all(&svg_data, b"><");
+
+    svg_data.trim_ascii().to_vec()
+}
+
+/// Add a viewbox to the SVG logo provided if it doesn't have one, using its
+/// width and height (when available).
+fn add_missing_svg_viewbox(svg_data: &[u8]) -> Vec<u8> {
+    let Some(root) = SVG_ROOT.find(svg_data) else {
+        return svg_data.to_vec();
+    };
+    let root = root.as_bytes();
+    if root.windows(8).any(|w| w == b"viewBox=") {
+        return svg_data.to_vec();
+    }
+
+    // Get logo dimensions from the root element
+    let get_dimension = |re: &Regex| {
+        re.captures(root).and_then(|c| std::str::from_utf8(&c["value"]).ok()?.parse::<f64>().ok())
+    };
+    let (Some(width), Some(height)) = (get_dimension(&SVG_WIDTH), get_dimension(&SVG_HEIGHT)) else {
+        return svg_data.to_vec();
+    };
+
+    let viewbox = format!(r#"<svg viewBox="0 0 {width} {height}""#);
+    SVG_ROOT
+        .replace(svg_data, |c: &Captures| {
+            let mut root = viewbox.as_bytes().to_vec();
+            root.extend_from_slice(&c[0][4..]);
+            root
+        })
+        .into_owned()
+}
+
+/// Add some padding around the SVG logo provided by enlarging its viewbox
+/// (padding is a percentage of the logo's largest side).
+fn add_svg_padding(svg_data: &[u8], padding: f64) -> Vec<u8> {
+    SVG_VIEWBOX_VALUES
+        .replace(svg_data, |c: &Captures| {
+            let get_value = |name: &str| {
+                std::str::from_utf8(&c[name]).ok().and_then(|v| v.parse::<f64>().ok()).unwrap_or_default()
+            };
+            let (x, y, width, height) = (
+                get_value("x"),
+                get_value("y"),
+                get_value("width"),
+                get_value("height"),
+            );
+            let padding = width.max(height) * padding / 100.0;
+            format!(
+                r#"viewBox="{} {} {} {}""#,
+                x - padding,
+                y - padding,
+                width + 2.0 * padding,
+                height + 2.0 * padding
+            )
+            .into_bytes()
+        })
+        .into_owned()
+}
+
+/// Embed the raster logo provided in an SVG document, using the image
+/// dimensions as viewbox.
+pub(crate) fn embed_raster_logo(logo_data: &[u8], extension: &str) -> Result<Vec<u8>> {
+    let size = imagesize::blob_size(logo_data)?;
+    let media_type = if extension == "jpg" { "jpeg" } else { extension };
+    let svg = format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 {width} {height}"><image width="{width}" height="{height}" href="data:image/{media_type};base64,{data}"/></svg>"#,
+        width = size.width,
+        height = size.height,
+        data = b64.encode(logo_data),
+    );
+
+    Ok(svg.into_bytes())
+}
+
+/// Generate a placeholder logo for the item name provided. The logo displays
+/// the item's initials on a background whose color is derived from the name,
+/// so the same placeholder is generated for a given name on every build.
+pub(crate) fn generate_placeholder_logo(name: &str) -> Logo {
+    // Prepare initials from the first two words of the name
+    let mut initials: String = name
+        .split_whitespace()
+        .filter_map(|word| word.chars().find(|c| c.is_alphanumeric()))
+        .take(2)
+        .flat_map(char::to_uppercase)
+        .collect();
+    if initials.is_empty() {
+        initials = "?".to_string();
+    }
+
+    // Pick background color hue from the name digest
+    let name_digest = Sha256::digest(name.as_bytes());
+    let hue = u16::from_be_bytes([name_digest[0], name_digest[1]]) % 360;
+
+    let svg = format!(
+        r##"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 100 100"><rect width="100" height="100" rx="10" fill="hsl({hue},55%,45%)"/><text x="50" y="50" dominant-baseline="central" text-anchor="middle" font-family="sans-serif" font-size="40" font-weight="bold" fill="#fff">{initials}</text></svg>"##
+    );
+    let data = svg.into_bytes();
+    let digest = hex::encode(Sha256::digest(&data));
+    let preview = rasterize_svg(&data, LOGOS_PREVIEW_SIZE, false).ok();
+
+    Logo {
+        data,
+        extension: "svg".to_string(),
+        digest,
+        preview,
+    }
+}
+
 /// Get logo content from the corresponding source.
 #[allow(clippy::similar_names)]
-async fn get_logo(
+pub(crate) async fn get_logo(
     http_client: reqwest::Client,
+    http_cache: &HttpCache,
     logos_source: &LogosSource,
     file_name: &str,
 ) -> Result<Vec<u8>> {
     // Try from path
     if let Some(path) = &logos_source.logos_path {
-        return fs::read(path.join(file_name)).map_err(Into::into);
+        match fs::read(path.join(file_name)) {
+            Ok(logo_data) => return Ok(logo_data),
+            Err(err) if logos_source.logos_url.is_some() => {
+                debug!(
+                    ?err,
+                    ?file_name,
+                    "logo not available in logos path, trying logos url"
+                );
+            }
+            Err(err) => return Err(err.into()),
+        }
     };
 
     // Try from url
     if let Some(logos_url) = &logos_source.logos_url {
         let logos_url = logos_url.trim_end_matches('/');
         let logo_url = format!("{logos_url}/{file_name}");
-        let resp = http_client.get(logo_url).send().await?;
-        if resp.status() != StatusCode::OK {
-            bail!("unexpected status code getting logo: {}", resp.status());
-        }
-        return Ok(resp.bytes().await?.to_vec());
+        return fetch_logo(&http_client, http_cache, &logo_url).await;
     };
 
     bail!("logos path or url not provided");
 }
 
+/// Fetch logo from the url provided. Requests are sent conditionally when the
+/// logo is available in the http cache, and they are retried by it on network
+/// errors or when the server is not available.
+async fn fetch_logo(
+    http_client: &reqwest::Client,
+    http_cache: &HttpCache,
+    logo_url: &str,
+) -> Result<Vec<u8>> {
+    let resp = http_cache.send(http_client.get(logo_url)).await?;
+    if resp.status != StatusCode::OK {
+        bail!("unexpected status code getting logo: {}", resp.status);
+    }
+    Ok(resp.body)
+}
+
+/// Rasterize the SVG document provided into a PNG image, scaling it so that
+/// its largest side matches the size provided. When `square` is set, the image
+/// is centered in a square canvas of the size provided.
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_precision_loss,
+    clippy::cast_sign_loss
+)]
+pub(crate) fn rasterize_svg(svg_data: &[u8], size: u32, square: bool) -> Result<Vec<u8>> {
+    let opt = usvg::Options::default();
+    let mut tree = usvg::Tree::from_data(svg_data, &opt)?;
+    tree.convert_text(&FONTS_DB);
+
+    let scale = size as f32 / tree.size.width().max(tree.size.height());
+    let width = ((tree.size.width() * scale).ceil() as u32).min(size);
+    let height = ((tree.size.height() * scale).ceil() as u32).min(size);
+    let (canvas_width, canvas_height) = if square { (size, size) } else { (width, height) };
+    let mut pixmap = tiny_skia::Pixmap::new(canvas_width, canvas_height)
+        .ok_or_else(|| format_err!("invalid image size"))?;
+    let transform = tiny_skia::Transform::from_scale(scale, scale).post_translate(
+        (canvas_width - width) as f32 / 2.0,
+        (canvas_height - height) as f32 / 2.0,
+    );
+    resvg::Tree::from_usvg(&tree).render(transform, &mut pixmap.as_mut());
+
+    Ok(pixmap.encode_png()?)
+}
+
 /// Get SVG bounding box (smallest rectangle in which the object fits).
 fn get_svg_bounding_box(svg_data: &[u8]) -> Result<Option<Rect>> {
     let opt = usvg::Options::default();
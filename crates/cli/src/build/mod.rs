@@ -1,12 +1,38 @@
 //! This module defines the functionality of the build CLI subcommand.
 
 use self::{
-    cache::Cache,
-    crunchbase::collect_crunchbase_data,
-    export::generate_items_csv,
-    github::collect_github_data,
-    logos::{prepare_logo, LogosSource},
+    best_practices::{collect_best_practices_data, BEST_PRACTICES_CACHE_FILE},
+    bitbucket::{collect_bitbucket_data, BITBUCKET_CACHE_FILE},
+    cache::{Cache, HttpCache},
+    cards::generate_items_cards,
+    changes::generate_changes_feed,
+    clearlydefined::{collect_clearlydefined_data, CLEARLYDEFINED_CACHE_FILE},
+    container_images::{collect_container_images_data, CONTAINER_IMAGES_CACHE_FILE},
+    crunchbase::CRUNCHBASE_HTTP_CACHE_FILE,
+    export::{generate_items_csv, generate_items_legacy_json, generate_items_parquet},
+    favicons::generate_icons,
+    feeds::{collect_feeds_data, FEEDS_CACHE_FILE},
+    gitea::{collect_gitea_data, GITEA_CACHE_FILE},
+    github::{collect_github_data, GITHUB_CACHE_FILE, GITHUB_HTTP_CACHE_FILE},
+    linkedin::{collect_linkedin_data, LINKEDIN_CACHE_FILE},
+    logos::{
+        generate_placeholder_logo, get_logo, prepare_logo, source_digest, Logo, LogosSource, PreparedLogo,
+        PreparedLogos,
+    },
+    organizations::{collect_organizations_data, organizations_cache_file},
+    packages::{collect_packages_data, PACKAGES_CACHE_FILE},
+    pdf::export_landscape_pdf,
+    plan::print_plan,
+    progress::Progress,
     projects::{generate_projects_csv, ProjectsMd},
+    pwa::generate_pwa_files,
+    qr_codes::{generate_items_qr_codes, generate_qr_code},
+    remote_cache::new_remote_cache,
+    report::{BuildReport, ServiceStats},
+    retry::{RetryPolicy, DEFAULT_BACKOFF, DEFAULT_JITTER, DEFAULT_MAX_RETRIES},
+    sitemap::{generate_robots_txt, generate_sitemap},
+    social::{collect_social_data, SOCIAL_CACHE_FILE},
+    sponsorships::{collect_sponsorships_data, SPONSORSHIPS_CACHE_FILE},
 };
 use crate::{
     build::{
@@ -15,9 +41,10 @@ use crate::{
     },
     serve::{self, serve},
 };
-use anyhow::{bail, Context, Result};
+use anyhow::{bail, format_err, Context, Result};
 use askama::Template;
 use base64::{engine::general_purpose::STANDARD as b64, Engine as _};
+use flate2::write::GzEncoder;
 use futures::stream::{self, StreamExt};
 use headless_chrome::{
     browser,
@@ -26,23 +53,30 @@ use headless_chrome::{
     Browser, LaunchOptions,
 };
 use landscape2_core::{
-    data::{self, CrunchbaseData, DataSource, GithubData, Item, LandscapeData},
-    datasets::{embed::EmbedView, full::Full, Datasets, NewDatasetsInput},
+    data::{
+        self, ClomonitorScore, CrunchbaseData, DataSource, FeedsData, GithubData, GithubOrgsData, Item,
+        ItemsActivity, LandscapeData, LinkedInData, PackagesData, SocialData, SponsorshipsData,
+    },
+    datasets::{embed::EmbedView, full::Full, Datasets, NewDatasetsInput, Versioned},
     games::{GamesSource, LandscapeGames},
     guide::{GuideSource, LandscapeGuide},
-    settings::{self, Analytics, Colors, LandscapeSettings, LogosViewbox, Osano, SettingsSource},
+    settings::{
+        self, Analytics, Colors, CustomHtml, I18n, Images, LandscapeSettings, LogosViewbox, Meta, Osano,
+        SettingsSource, Theme, TranslationsCatalog,
+    },
 };
-use qrcode::render::svg;
 use reqwest::StatusCode;
 use rust_embed::{EmbeddedFile, RustEmbed};
+use serde::Serialize;
 use std::{
-    collections::{BTreeMap, HashMap},
+    collections::{BTreeMap, HashMap, HashSet},
     ffi::OsStr,
     fs::{self, File},
-    io::Write,
+    io::{self, BufReader, BufWriter, Read, Write},
     net::TcpListener,
     path::{Path, PathBuf},
     sync::Arc,
+    thread,
     time::{Duration, Instant},
 };
 use tokio::sync::Mutex;
@@ -50,13 +84,48 @@ use tracing::{debug, error, info, instrument, trace, warn};
 use url::Url;
 
 mod api;
-mod cache;
+mod best_practices;
+mod bitbucket;
+pub(crate) mod cache;
+mod cards;
+mod changes;
+mod clearlydefined;
 mod clomonitor;
+mod collector;
+mod container_images;
 mod crunchbase;
 mod export;
+mod favicons;
+mod feeds;
+mod gitea;
 mod github;
+mod linkedin;
 mod logos;
+mod organizations;
+mod packages;
+mod pdf;
+mod plan;
+pub mod profile;
+mod progress;
 mod projects;
+mod pwa;
+mod qr_codes;
+mod remote_cache;
+mod report;
+mod retry;
+mod sitemap;
+mod social;
+mod sponsorships;
+pub mod watch;
+
+/// Brotli compressor buffer size.
+const BROTLI_BUFFER_SIZE: usize = 4096;
+
+/// Brotli compressor window size (log2).
+const BROTLI_LGWIN: u32 = 22;
+
+/// Brotli compressor quality (0-11).
+const BROTLI_QUALITY: u32 = 11;
 
 /// Maximum number of CLOMonitor reports summaries to fetch concurrently.
 const CLOMONITOR_MAX_CONCURRENCY: usize = 10;
@@ -64,6 +133,9 @@ const CLOMONITOR_MAX_CONCURRENCY: usize = 10;
 /// Path where the API data files will be written to in the output directory.
 const API_PATH: &str = "api";
 
+/// Path where the items social cards will be written to in the output dir.
+const CARDS_PATH: &str = "cards";
+
 /// Path where the datasets will be written to in the output directory.
 const DATASETS_PATH: &str = "data";
 
@@ -76,12 +148,33 @@ const EMBED_PATH: &str = "embed";
 /// Path where some images will be written to in the output directory.
 const IMAGES_PATH: &str = "images";
 
+/// Path where the full dataset shards will be written to in the datasets
+/// directory.
+const FULL_SHARDS_PATH: &str = "full";
+
+/// Path where the per item datasets will be written to in the datasets directory.
+const ITEMS_PATH: &str = "items";
+
 /// Path where the item logos will be written to in the output directory.
 const LOGOS_PATH: &str = "logos";
 
+/// Path where the items QR codes will be written to in the output directory.
+const QR_CODES_PATH: &str = "qr_codes";
+
 /// Path where the data sources files will be written to in the output dir.
 const SOURCES_PATH: &str = "sources";
 
+/// File used to cache the logos fetched from a remote source.
+const LOGOS_HTTP_CACHE_FILE: &str = "logos_http.json";
+
+/// File used to cache the remote files referenced in the settings (images,
+/// translations catalog and custom html snippets).
+const SETTINGS_FILES_HTTP_CACHE_FILE: &str = "settings_files_http.json";
+
+/// File used to cache the information about the logos prepared, so that they
+/// can be reused in future builds when their source hasn't changed.
+const PREPARED_LOGOS_CACHE_FILE: &str = "prepared_logos.json";
+
 /// Maximum number of logos to prepare concurrently.
 const PREPARE_LOGOS_MAX_CONCURRENCY: usize = 20;
 
@@ -106,33 +199,243 @@ struct WebappAssets;
 /// Build arguments.
 #[derive(clap::Args)]
 pub struct BuildArgs {
+    /// Base path where the landscape will be hosted (overrides the value set
+    /// in the settings file, if any).
+    #[arg(long)]
+    pub base_path: Option<String>,
+
     /// Cache directory.
     #[arg(long)]
     pub cache_dir: Option<PathBuf>,
 
+    /// Maximum number of tasks to run concurrently when preparing logos and
+    /// collecting data from GitHub and Crunchbase (by default it depends on
+    /// the number of CPUs available and the services limits). Please note
+    /// that the number of concurrent requests to GitHub is also limited by the
+    /// number of tokens provided.
+    #[arg(long, visible_alias = "jobs", value_parser = clap::value_parser!(u16).range(1..))]
+    pub concurrency: Option<u16>,
+
     /// Data source.
     #[command(flatten)]
     pub data_source: DataSource,
 
+    /// Load and validate the landscape data and settings, and display what
+    /// would be collected from external services and written to the output
+    /// directory, without actually doing it.
+    #[arg(long, default_value_t = false, conflicts_with = "watch")]
+    pub dry_run: bool,
+
+    /// Export the landscape items to a CSV file at the path provided (in
+    /// addition to the items.csv file included in the output directory).
+    #[arg(long)]
+    pub export_csv: Option<PathBuf>,
+
+    /// Export the landscape items to a Parquet file at the path provided
+    /// (same fields as the CSV file).
+    #[arg(long)]
+    pub export_parquet: Option<PathBuf>,
+
+    /// Export the landscape grid (categories and subcategories with the items
+    /// logos) to a PDF file at the path provided (Chrome/Chromium required).
+    #[arg(long)]
+    pub export_pdf: Option<PathBuf>,
+
+    /// Fail the build if any of the items logos cannot be prepared (by
+    /// default a placeholder logo is used instead).
+    #[arg(long, default_value_t = false, conflicts_with = "skip_logos")]
+    pub fail_on_missing_logos: bool,
+
     /// Games source.
     #[command(flatten)]
     pub games_source: GamesSource,
 
+    /// Generate a social preview card image (PNG) for each of the items.
+    #[arg(long, default_value_t = false)]
+    pub generate_cards: bool,
+
+    /// Generate a QR code (SVG and PNG) pointing to the detail view of each
+    /// of the items.
+    #[arg(long, default_value_t = false)]
+    pub generate_items_qr_codes: bool,
+
+    /// Use the GitHub GraphQL API to collect repositories data (several
+    /// repositories are requested at once, reducing the number of calls).
+    #[arg(long, default_value_t = false)]
+    pub github_graphql: bool,
+
     /// Guide source.
     #[command(flatten)]
     pub guide_source: GuideSource,
 
+    /// Generate an items.json file in the format used by the legacy
+    /// landscapeapp (for compatibility with existing tools).
+    #[arg(long, default_value_t = false)]
+    pub legacy_items_json: bool,
+
+    /// How long the logos fetched from remote urls are reused without
+    /// checking if they have been modified (in days). By default they are
+    /// revalidated on every build using conditional requests.
+    #[arg(long, default_value_t = 0)]
+    pub logos_cache_ttl: i64,
+
     /// Logos source.
     #[command(flatten)]
     pub logos_source: LogosSource,
 
+    /// Don't reuse any of the data available in the cache (it is collected
+    /// again and written to the cache as usual). Please note that this
+    /// discards the repositories stars history accumulated in previous builds
+    /// (the landscape changes history used in the changes feed is kept).
+    #[arg(long, default_value_t = false, conflicts_with = "offline")]
+    pub no_cache: bool,
+
+    /// Build the landscape without accessing the network. Only local data
+    /// sources can be used, and the data collected from external services
+    /// (as well as remote logos and settings files) is read from the cache.
+    #[arg(long, default_value_t = false)]
+    pub offline: bool,
+
+    /// How long the organizations data collected from Crunchbase (or from
+    /// the organizations data API) is cached (in days).
+    #[arg(long, alias = "crunchbase-cache-ttl", default_value_t = 7)]
+    pub organizations_cache_ttl: i64,
+
     /// Output directory to write files to.
     #[arg(long)]
     pub output_dir: PathBuf,
 
+    /// How long the packages data collected from the registries is cached (in
+    /// days).
+    #[arg(long, default_value_t = 7)]
+    pub packages_cache_ttl: i64,
+
+    /// Record the timing of the build phases and of the items processed, and
+    /// write it to the file provided (in Chrome trace event format).
+    #[arg(long, conflicts_with = "watch")]
+    pub profile: Option<PathBuf>,
+
+    /// How long the repositories data collected from GitHub, Gitea and
+    /// Bitbucket is cached (in days).
+    #[arg(long, alias = "github-cache-ttl", default_value_t = 7)]
+    pub repositories_cache_ttl: i64,
+
+    /// Force refreshing the data collected from the service provided,
+    /// ignoring the cached data (can be provided multiple times).
+    #[arg(long, value_enum, conflicts_with = "offline")]
+    pub refresh: Vec<RefreshService>,
+
+    /// Url of a remote cache shared across builds (i.e. s3://bucket/prefix
+    /// or gs://bucket/prefix). Its files are downloaded to the cache directory
+    /// before building the landscape, and the updated ones are uploaded back
+    /// once the build has finished.
+    #[arg(long, conflicts_with = "offline")]
+    pub remote_cache_url: Option<String>,
+
+    /// Print a summary of the build report (written to the build-report.json
+    /// file in the output directory) when the build finishes.
+    #[arg(long, default_value_t = false)]
+    pub report_summary: bool,
+
+    /// Maximum number of times a request to an external service (i.e.
+    /// GitHub, Crunchbase or remote logos) is retried on network errors or
+    /// when the service is not available.
+    #[arg(long, default_value_t = DEFAULT_MAX_RETRIES)]
+    pub retries: u32,
+
+    /// Delay before retrying a failed request to an external service for the
+    /// first time (in milliseconds). It is doubled on each retry.
+    #[arg(long, default_value_t = DEFAULT_BACKOFF)]
+    pub retry_backoff: u64,
+
+    /// Ratio of the delay between retries that is randomized (between 0 and
+    /// 1), so that concurrent requests are not retried at the same time.
+    #[arg(long, default_value_t = DEFAULT_JITTER)]
+    pub retry_jitter: f64,
+
     /// Settings source.
     #[command(flatten)]
     pub settings_source: SettingsSource,
+
+    /// Skip collecting organizations data from Crunchbase (or from the
+    /// organizations data API), which can be useful to build the landscape
+    /// quickly while iterating on its data or settings.
+    #[arg(long, default_value_t = false)]
+    pub skip_crunchbase: bool,
+
+    /// Skip collecting repositories data from GitHub, which can be useful to
+    /// build the landscape quickly while iterating on its data or settings.
+    #[arg(long, default_value_t = false)]
+    pub skip_github: bool,
+
+    /// Skip preparing the items logos (a placeholder logo is used for all of
+    /// them), which can be useful to build the landscape quickly while
+    /// iterating on its data or settings.
+    #[arg(long, default_value_t = false)]
+    pub skip_logos: bool,
+
+    /// Split the full dataset in one file per category (in addition to the
+    /// full dataset file), which can be useful for very large landscapes.
+    #[arg(long, default_value_t = false)]
+    pub split_full_dataset: bool,
+
+    /// Fail the build if any of the items cannot be processed (i.e. logos that
+    /// cannot be prepared, or repositories and organizations whose data cannot
+    /// be collected). By default these errors are reported as warnings and
+    /// the build goes on.
+    #[arg(long, default_value_t = false)]
+    pub strict: bool,
+
+    /// Watch local data sources for changes and rebuild the landscape.
+    #[arg(long, default_value_t = false)]
+    pub watch: bool,
+}
+
+impl BuildArgs {
+    /// Return the policy used to retry the requests sent to external services.
+    fn retry_policy(&self) -> RetryPolicy {
+        RetryPolicy {
+            max_retries: self.retries,
+            backoff: Duration::from_millis(self.retry_backoff),
+            jitter: self.retry_jitter,
+        }
+    }
+
+    /// Return the cache TTL to use for the service provided, which is zero
+    /// when a refresh of its data has been requested.
+    fn cache_ttl(&self, service: RefreshService, ttl: i64) -> i64 {
+        if self.refresh.contains(&service) {
+            0
+        } else {
+            ttl
+        }
+    }
+}
+
+/// External services whose cached data can be refreshed selectively.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum RefreshService {
+    /// Organizations data (collected from Crunchbase or the organizations
+    /// data API).
+    Crunchbase,
+
+    /// Repositories data collected from GitHub (as well as from Gitea and
+    /// Bitbucket).
+    Github,
+
+    /// Logos fetched from a remote url.
+    Logos,
+}
+
+impl RefreshService {
+    /// Http cache file used to send conditional requests to the service.
+    fn http_cache_file(self) -> &'static str {
+        match self {
+            RefreshService::Crunchbase => CRUNCHBASE_HTTP_CACHE_FILE,
+            RefreshService::Github => GITHUB_HTTP_CACHE_FILE,
+            RefreshService::Logos => LOGOS_HTTP_CACHE_FILE,
+        }
+    }
 }
 
 /// Build landscape website.
@@ -140,59 +443,288 @@ pub struct BuildArgs {
 pub async fn build(args: &BuildArgs) -> Result<()> {
     info!("building landscape website..");
     let start = Instant::now();
+    let report = BuildReport::new();
 
     // Check required web assets are present
     check_web_assets()?;
 
-    // Setup output directory, creating it when needed
-    setup_output_dir(&args.output_dir)?;
+    // Setup output directory, creating it when needed (nothing is written to
+    // it in dry run mode)
+    if !args.dry_run {
+        setup_output_dir(&args.output_dir)?;
+    }
 
-    // Setup cache
-    let cache = Cache::new(&args.cache_dir)?;
+    // Check only local data sources are used when building offline
+    if args.offline {
+        check_offline_sources(args)?;
+    }
+
+    // Setup cache, ignoring the cached data that must be refreshed
+    let mut cache = Cache::new(&args.cache_dir, args.offline)?.with_retry_policy(args.retry_policy());
+    if args.no_cache {
+        cache = cache.with_reads_disabled();
+    }
+    let refreshed_files: Vec<&str> = args.refresh.iter().map(|service| service.http_cache_file()).collect();
+    cache = cache.with_refreshed_files(&refreshed_files);
+
+    // Lock the cache directory to prevent other builds from using it at the
+    // same time (the lock is held until the build finishes). In dry run mode
+    // the cache is only read, so it's not locked.
+    let _cache_lock = if args.dry_run {
+        None
+    } else {
+        Some(cache.lock().await?)
+    };
+
+    // Setup remote cache (if provided), downloading its files to the cache
+    // directory
+    let remote_cache = match &args.remote_cache_url {
+        Some(url) if !args.dry_run => {
+            let rc = new_remote_cache(url).await?;
+            if let Err(err) = remote_cache::pull(&rc, cache.dir()).await {
+                warn!(?err, "error downloading files from remote cache");
+            }
+            Some(rc)
+        }
+        _ => None,
+    };
 
     // Get landscape data from the source provided
     let mut landscape_data = LandscapeData::new(&args.data_source).await?;
 
     // Get landscape settings from the source provided
     let mut settings = LandscapeSettings::new(&args.settings_source).await?;
+    if let Some(base_path) = &args.base_path {
+        settings.set_base_path(base_path).context("invalid base path provided")?;
+    }
+    if let Err(err) = settings.validate_against_data(&landscape_data) {
+        warn!(
+            ?err,
+            "landscape settings reference values not found in the landscape data"
+        );
+    }
+
+    // Display the build plan and stop here when in dry run mode
+    if args.dry_run {
+        print_plan(args, &cache, &landscape_data, &settings);
+        info!("dry run completed, nothing has been written to the output directory");
+        return Ok(());
+    }
 
     // Prepare games data and copy it to the output directory
     let games = prepare_games_data(&args.games_source, &args.output_dir).await?;
 
     // Prepare guide and copy it to the output directory
     let guide = prepare_guide(&args.guide_source, &args.output_dir).await?;
-
-    // Prepare items logos and copy them to the output directory
-    prepare_items_logos(
-        &args.logos_source,
-        &settings.logos_viewbox,
-        &mut landscape_data,
-        &args.output_dir,
-    )
-    .await?;
+    if let Some(Err(err)) = guide.as_ref().map(|guide| guide.validate_against_data(&landscape_data)) {
+        warn!(
+            ?err,
+            "landscape guide references values not found in the landscape data"
+        );
+    }
 
     // Fetch some settings images and update their urls to the local copy
-    prepare_settings_images(&mut settings, &args.output_dir).await?;
+    let settings_http_cache = HttpCache::new(&cache, SETTINGS_FILES_HTTP_CACHE_FILE);
+    prepare_settings_images(&settings_http_cache, &mut settings, &args.output_dir).await?;
+
+    // Fetch the translations catalog (if needed) and validate it
+    prepare_translations(&settings_http_cache, &mut settings).await?;
+
+    // Prepare items logos and copy them to the output directory (or use
+    // placeholder logos when asked to skip this step). They are independent
+    // from the data collected from external services, so both are done
+    // concurrently.
+    let logos = async {
+        let phase_start = Instant::now();
+        let logos = if args.skip_logos {
+            prepare_items_placeholder_logos(&landscape_data, &args.output_dir)?
+        } else {
+            prepare_items_logos(
+                &cache,
+                &args.logos_source,
+                args.logos_cache_ttl,
+                args.concurrency.map(usize::from),
+                &settings.logos_viewbox,
+                &landscape_data,
+                &args.output_dir,
+                args.fail_on_missing_logos,
+                &report,
+            )
+            .await?
+        };
+        report.add_phase("logos", phase_start);
+        Ok::<_, anyhow::Error>(logos)
+    };
 
-    // Collect data from external services
-    let (crunchbase_data, github_data) = tokio::try_join!(
-        collect_crunchbase_data(&cache, &landscape_data),
-        collect_github_data(&cache, &landscape_data)
-    )?;
+    // Collect data from external services (or read it from the cache when
+    // building offline), skipping the services we were asked to
+    let external_data = async {
+        let phase_start = Instant::now();
+        let (
+            crunchbase_data,
+            mut github_data,
+            gitea_data,
+            bitbucket_data,
+            best_practices_data,
+            packages_data,
+            container_images_data,
+            linkedin_data,
+            sponsorships_data,
+            social_data,
+            feeds_data,
+        ) = if args.offline {
+            (
+                if args.skip_crunchbase {
+                    CrunchbaseData::default()
+                } else {
+                    cache.read_json(organizations_cache_file(&settings.organizations_data))?
+                },
+                if args.skip_github {
+                    GithubData::default()
+                } else {
+                    cache.read_json(GITHUB_CACHE_FILE)?
+                },
+                cache.read_json(GITEA_CACHE_FILE)?,
+                cache.read_json(BITBUCKET_CACHE_FILE)?,
+                cache.read_json(BEST_PRACTICES_CACHE_FILE)?,
+                cache.read_json(PACKAGES_CACHE_FILE)?,
+                cache.read_json(CONTAINER_IMAGES_CACHE_FILE)?,
+                cache.read_json(LINKEDIN_CACHE_FILE)?,
+                cache.read_json(SPONSORSHIPS_CACHE_FILE)?,
+                cache.read_json(SOCIAL_CACHE_FILE)?,
+                cache.read_json(FEEDS_CACHE_FILE)?,
+            )
+        } else {
+            tokio::try_join!(
+                async {
+                    if args.skip_crunchbase {
+                        return Ok(CrunchbaseData::default());
+                    }
+                    collect_organizations_data(
+                        &cache,
+                        &landscape_data,
+                        &settings.organizations_data,
+                        args.cache_ttl(RefreshService::Crunchbase, args.organizations_cache_ttl),
+                        args.concurrency.map(usize::from),
+                        &report,
+                    )
+                    .await
+                },
+                async {
+                    if args.skip_github {
+                        return Ok(GithubData::default());
+                    }
+                    collect_github_data(
+                        &cache,
+                        &landscape_data,
+                        args.github_graphql,
+                        args.cache_ttl(RefreshService::Github, args.repositories_cache_ttl),
+                        args.concurrency.map(usize::from),
+                        &report,
+                    )
+                    .await
+                },
+                collect_gitea_data(
+                    &cache,
+                    &landscape_data,
+                    args.cache_ttl(RefreshService::Github, args.repositories_cache_ttl),
+                ),
+                collect_bitbucket_data(
+                    &cache,
+                    &landscape_data,
+                    args.cache_ttl(RefreshService::Github, args.repositories_cache_ttl),
+                ),
+                collect_best_practices_data(&cache, &landscape_data),
+                collect_packages_data(&cache, &landscape_data, args.packages_cache_ttl),
+                collect_container_images_data(&cache, &landscape_data),
+                collect_linkedin_data(&cache, &landscape_data),
+                collect_sponsorships_data(&cache, &landscape_data),
+                collect_social_data(&cache, &landscape_data),
+                collect_feeds_data(&cache, &landscape_data)
+            )?
+        };
+
+        // Repositories hosted on Gitea forges or Bitbucket are handled like
+        // GitHub ones
+        github_data.extend(gitea_data);
+        github_data.extend(bitbucket_data);
+
+        // Collect license information from ClearlyDefined (it relies on the
+        // packages versions collected from the registries)
+        let clearlydefined_data = if args.offline {
+            cache.read_json(CLEARLYDEFINED_CACHE_FILE)?
+        } else {
+            collect_clearlydefined_data(&cache, &landscape_data, &packages_data).await?
+        };
+        report.add_phase("external data", phase_start);
+        Ok::<_, anyhow::Error>((
+            crunchbase_data,
+            github_data,
+            best_practices_data,
+            clearlydefined_data,
+            packages_data,
+            container_images_data,
+            linkedin_data,
+            sponsorships_data,
+            social_data,
+            feeds_data,
+        ))
+    };
+    let (
+        logos,
+        (
+            crunchbase_data,
+            github_data,
+            best_practices_data,
+            clearlydefined_data,
+            packages_data,
+            container_images_data,
+            linkedin_data,
+            sponsorships_data,
+            social_data,
+            feeds_data,
+        ),
+    ) = tokio::try_join!(logos, external_data)?;
+    report.check_errors(&["logos"], args.strict)?;
+    report.check_errors(&["crunchbase", "github"], args.strict)?;
+    set_items_logos(&mut landscape_data, &logos);
+
+    // Generate items social preview cards (if requested)
+    if args.generate_cards {
+        generate_items_cards(&mut landscape_data, &settings, &args.output_dir)?;
+    }
+
+    // Generate items QR codes (if requested)
+    if args.generate_items_qr_codes {
+        generate_items_qr_codes(&mut landscape_data, &settings, &args.output_dir)?;
+    }
 
     // Enrich landscape data with some extra information from the settings and
     // external services
+    landscape_data.add_best_practices_data(&best_practices_data);
+    landscape_data.add_clearlydefined_data(&clearlydefined_data);
+    landscape_data.add_container_images_data(&container_images_data);
     landscape_data.add_crunchbase_data(&crunchbase_data);
-    landscape_data.add_featured_items_data(&settings);
+    landscape_data.add_feeds_data(&feeds_data);
     landscape_data.add_github_data(&github_data);
+    landscape_data.add_linkedin_data(&linkedin_data);
     landscape_data.add_member_subcategory(&settings.members_category);
     landscape_data.add_tags(&settings);
     landscape_data.set_enduser_flag(&settings);
+    landscape_data.set_member_level(&settings);
+    landscape_data.set_repositories_status(&settings);
+    // Featured items rules may depend on fields set in the previous steps
+    landscape_data.add_featured_items_data(&settings);
+    warn_acquired_members(&landscape_data, &settings.members_category);
+    warn_unknown_tags(&landscape_data, &settings);
 
     // Collect CLOMonitor reports summaries and copy them to the output directory
+    let phase_start = Instant::now();
     collect_clomonitor_reports(&cache, &mut landscape_data, &settings, &args.output_dir).await?;
+    report.add_phase("clomonitor", phase_start);
 
     // Generate API data files
+    let phase_start = Instant::now();
     generate_api(
         &ApiSources {
             landscape_data: &landscape_data,
@@ -200,49 +732,141 @@ pub async fn build(args: &BuildArgs) -> Result<()> {
         },
         &args.output_dir,
     )?;
+    report.add_phase("api", phase_start);
 
     // Generate QR code
     let qr_code = generate_qr_code(&settings.url, &args.output_dir)?;
 
-    // Generate datasets for web application
-    let datasets = generate_datasets(
-        &NewDatasetsInput {
-            crunchbase_data: &crunchbase_data,
-            games: &games,
-            github_data: &github_data,
-            guide: &guide,
-            landscape_data: &landscape_data,
-            qr_code: &qr_code,
-            settings: &settings,
-        },
-        &args.output_dir,
-    )?;
+    // Generate datasets for web application, copying the embed and web
+    // application assets files to the output directory in the meantime
+    let phase_start = Instant::now();
+    let datasets = thread::scope(|scope| {
+        let assets = scope.spawn(|| {
+            copy_embed_assets(&args.output_dir)?;
+            copy_webapp_assets(&args.output_dir)
+        });
+        let datasets = generate_datasets(
+            &NewDatasetsInput {
+                crunchbase_data: &crunchbase_data,
+                games: &games,
+                github_data: &github_data,
+                guide: &guide,
+                landscape_data: &landscape_data,
+                linkedin_data: &linkedin_data,
+                packages_data: &packages_data,
+                qr_code: &qr_code,
+                settings: &settings,
+                social_data: &social_data,
+                sponsorships_data: &sponsorships_data,
+            },
+            args.split_full_dataset,
+            &args.output_dir,
+        )?;
+        assets.join().map_err(|_| format_err!("error copying assets to the output directory"))??;
+        Ok::<_, anyhow::Error>(datasets)
+    })?;
+    report.add_phase("datasets and assets", phase_start);
+
+    // Prepare custom html snippets to be injected in the index document
+    let custom_html = prepare_custom_html(&settings_http_cache, &settings.custom_html).await?;
+    if let Err(err) = settings_http_cache.write(&cache) {
+        warn!(?err, "error writing settings files http cache");
+    }
 
     // Render index and embed-item html files and write them to the output dir
-    render_index_html(&settings.analytics, &datasets, &settings.osano, &args.output_dir)?;
+    let phase_start = Instant::now();
+    render_index_html(
+        &settings.analytics,
+        &custom_html,
+        &datasets,
+        &settings.meta,
+        &settings.osano,
+        &settings.theme,
+        &settings.url,
+        &args.output_dir,
+    )?;
     render_embed_item_html(&settings.colors, &args.output_dir)?;
-
-    // Copy embed and web application assets files to the output directory
-    copy_embed_assets(&args.output_dir)?;
-    copy_webapp_assets(&args.output_dir)?;
+    report.add_phase("html", phase_start);
 
     // Generate items.csv file
+    let phase_start = Instant::now();
     generate_items_csv_file(&landscape_data, &args.output_dir)?;
+    if let Some(export_csv) = &args.export_csv {
+        export_items_csv_file(&landscape_data, export_csv)?;
+    }
+    if let Some(export_parquet) = &args.export_parquet {
+        export_items_parquet_file(&landscape_data, export_parquet)?;
+    }
+    if let Some(export_pdf) = &args.export_pdf {
+        export_landscape_pdf(&landscape_data, &settings, &args.output_dir, export_pdf)?;
+    }
+
+    // Generate legacy items.json file
+    if args.legacy_items_json {
+        generate_items_legacy_json_file(&landscape_data, &settings.url, &args.output_dir)?;
+    }
 
     // Generate projects.* files
     generate_projects_files(&landscape_data, &args.output_dir)?;
+    report.add_phase("exports", phase_start);
+
+    // Generate sitemap.xml and robots.txt files
+    generate_sitemap(&landscape_data, &settings, &args.output_dir)?;
+    generate_robots_txt(&settings, &args.output_dir)?;
+
+    // Generate Atom feed of the landscape changes
+    generate_changes_feed(&cache, &landscape_data, &settings, &args.output_dir)?;
+
+    // Generate web app manifest and service worker (it must be done once all
+    // the files it pre-caches have been written)
+    generate_pwa_files(&settings, &args.output_dir)?;
 
     // Prepare landscape screenshot (in PNG and PDF formats)
     if let Some(width) = &settings.screenshot_width {
+        let phase_start = Instant::now();
         prepare_screenshot(*width, &args.output_dir).await?;
+        report.add_phase("screenshot", phase_start);
     }
 
     // Copy data sources files to the output directory
     copy_data_sources_files(args, &args.output_dir).await?;
 
+    // Upload the cache files updated during the build to the remote cache
+    if let Some(rc) = &remote_cache {
+        if let Err(err) = remote_cache::push(rc, cache.dir()).await {
+            warn!(?err, "error uploading files to remote cache");
+        }
+    }
+
+    // Write build report to the output directory
+    if let Err(err) = report.write(&args.output_dir, start.elapsed()) {
+        warn!(?err, "error writing build report");
+    }
+
     let duration = start.elapsed().as_secs_f64();
     info!("landscape website built! (took: {:.3}s)", duration);
     display_success_msg(&args.output_dir.to_string_lossy());
+    if args.report_summary {
+        report.print_summary();
+    }
+
+    Ok(())
+}
+
+/// Check the data sources provided are all local, as remote ones cannot be
+/// fetched when building offline.
+fn check_offline_sources(args: &BuildArgs) -> Result<()> {
+    let remote_sources = [
+        ("data", &args.data_source.data_url),
+        ("settings", &args.settings_source.settings_url),
+        ("guide", &args.guide_source.guide_url),
+        ("games", &args.games_source.games_url),
+    ];
+    for (kind, url) in remote_sources {
+        if url.is_some() {
+            bail!("{kind} url cannot be used in offline mode, please provide a local file instead");
+        }
+    }
 
     Ok(())
 }
@@ -259,8 +883,8 @@ fn check_web_assets() -> Result<()> {
     Ok(())
 }
 
-/// Collect projects CLOMonitor reports summaries and copy them to the output
-/// directory.
+/// Collect projects CLOMonitor scores and reports summaries, copying the
+/// latter to the output directory.
 #[instrument(skip_all, err)]
 async fn collect_clomonitor_reports(
     cache: &Cache,
@@ -270,16 +894,28 @@ async fn collect_clomonitor_reports(
 ) -> Result<()> {
     debug!("collecting clomonitor reports");
 
-    // Fetch CLOMonitor reports summaries and copy them to the output directory
+    // Fetch CLOMonitor scores and reports summaries, copying the latter to the
+    // output directory
     let http_client = reqwest::Client::new();
     let foundation = &settings.foundation.to_lowercase();
     let reports_summaries: Mutex<HashMap<String, String>> = Mutex::new(HashMap::new());
+    let scores: Mutex<HashMap<String, ClomonitorScore>> = Mutex::new(HashMap::new());
     stream::iter(landscape_data.items.iter())
         .for_each_concurrent(CLOMONITOR_MAX_CONCURRENCY, |item| async {
-            // Item must contain the project name as used in CLOMonitor
-            let Some(project_name) = &item.clomonitor_name else {
+            // Get the name used to identify the project in CLOMonitor
+            let Some(project_name) = clomonitor::project_name(item) else {
                 return;
             };
+            let project_name = &project_name;
+
+            // Fetch score
+            match clomonitor::fetch_score(cache, http_client.clone(), foundation, project_name).await {
+                Ok(Some(score)) => {
+                    scores.lock().await.insert(item.id.clone(), score);
+                }
+                Ok(None) => {}
+                Err(err) => error!(?err, ?foundation, ?project_name, "error fetching score"),
+            }
 
             // Fetch report summary
             let http_client = http_client.clone();
@@ -317,12 +953,16 @@ async fn collect_clomonitor_reports(
         .await;
 
     // Update clomonitor_report_summary field in landscape items with the path
-    // of the SVG image
+    // of the SVG image and clomonitor_score with the score collected
     let reports_summaries = reports_summaries.lock().await;
+    let scores = scores.lock().await;
     for item in &mut landscape_data.items {
         if let Some(report_summary) = reports_summaries.get(&item.id) {
             item.clomonitor_report_summary = Some(report_summary.clone());
         }
+        if let Some(score) = scores.get(&item.id) {
+            item.clomonitor_score = Some(score.clone());
+        }
     }
 
     debug!("done!");
@@ -472,9 +1112,8 @@ fn generate_api(input: &ApiSources, output_dir: &Path) -> Result<()> {
             fs::create_dir_all(parent_path)?;
         }
 
-        // Write data file
-        let mut file = File::create(endpoint_full_path)?;
-        file.write_all(data.as_bytes())?;
+        // Write data file (only if it has changed)
+        write_file_if_changed(&endpoint_full_path, data.as_bytes())?;
     }
 
     Ok(())
@@ -485,37 +1124,132 @@ fn generate_api(input: &ApiSources, output_dir: &Path) -> Result<()> {
 /// the datasets will be embedded in the index document, and the rest will be
 /// written to the DATASETS_PATH in the output directory.
 #[instrument(skip_all, err)]
-fn generate_datasets(input: &NewDatasetsInput, output_dir: &Path) -> Result<Datasets> {
+fn generate_datasets(
+    input: &NewDatasetsInput,
+    split_full_dataset: bool,
+    output_dir: &Path,
+) -> Result<Datasets> {
     debug!("generating datasets");
 
-    let datasets = Datasets::new(input);
+    let mut datasets = Datasets::new(input);
     let datasets_path = output_dir.join(DATASETS_PATH);
 
+    // Full dataset shards (one per category, referenced from the base dataset)
+    let shards_path = datasets_path.join(FULL_SHARDS_PATH);
+    let mut shards_files = HashSet::new();
+    if split_full_dataset {
+        if !shards_path.exists() {
+            fs::create_dir(&shards_path)?;
+        }
+        for category in &input.landscape_data.categories {
+            let shard = filter_full_dataset(&datasets.full, |i| i.category == category.name);
+            let shard_file_name = format!("{}.json", category.normalized_name);
+            write_json_if_changed(&shards_path.join(&shard_file_name), &Versioned::new(&shard))?;
+            datasets.base.full_dataset_shards.insert(
+                category.name.clone(),
+                format!("{FULL_SHARDS_PATH}/{shard_file_name}"),
+            );
+            shards_files.insert(shard_file_name);
+        }
+    }
+    remove_stale_json_files(&shards_path, &shards_files)?;
+
     // Base
-    let mut base_file = File::create(datasets_path.join("base.json"))?;
-    base_file.write_all(&serde_json::to_vec(&datasets.base)?)?;
+    let base_path = datasets_path.join("base.json");
+    if write_json_if_changed(&base_path, &Versioned::new(&datasets.base))?
+        || !precompressed_variants_exist(&base_path)
+    {
+        write_precompressed_variants(&base_path)?;
+    }
 
     // Embed
+    write_json_if_changed(&datasets_path.join("embed.json"), &datasets.embed.index())?;
     for (key, view) in &datasets.embed.views {
-        let mut embed_file = File::create(datasets_path.join(format!("embed_{key}.json")))?;
-        embed_file.write_all(&serde_json::to_vec(&view)?)?;
+        write_json_if_changed(
+            &datasets_path.join(format!("embed_{key}.json")),
+            &Versioned::new(view),
+        )?;
 
         let view_full_dataset = prepare_view_full_dataset(&datasets.full, view);
-        let mut embed_full_file = File::create(datasets_path.join(format!("embed_full_{key}.json")))?;
-        embed_full_file.write_all(&serde_json::to_vec(&view_full_dataset)?)?;
+        write_json_if_changed(
+            &datasets_path.join(format!("embed_full_{key}.json")),
+            &Versioned::new(&view_full_dataset),
+        )?;
     }
 
     // Full
-    let mut full_file = File::create(datasets_path.join("full.json"))?;
-    full_file.write_all(&serde_json::to_vec(&datasets.full)?)?;
+    let full_path = datasets_path.join("full.json");
+    if write_json_if_changed(&full_path, &Versioned::new(&datasets.full))?
+        || !precompressed_variants_exist(&full_path)
+    {
+        write_precompressed_variants(&full_path)?;
+    }
+
+    // Items (full detail per item)
+    let items_path = datasets_path.join(ITEMS_PATH);
+    if !items_path.exists() {
+        fs::create_dir(&items_path)?;
+    }
+    let mut items_files = HashSet::new();
+    for item in &datasets.full.items {
+        let item_file_name = format!("{}.json", item.id);
+        write_json_if_changed(&items_path.join(&item_file_name), &item)?;
+        items_files.insert(item_file_name);
+    }
+    remove_stale_json_files(&items_path, &items_files)?;
+
+    // Search
+    write_json_if_changed(
+        &datasets_path.join("search.json"),
+        &Versioned::new(&datasets.search),
+    )?;
 
     // Stats
-    let mut stats_file = File::create(datasets_path.join("stats.json"))?;
-    stats_file.write_all(&serde_json::to_vec(&datasets.stats)?)?;
+    write_json_if_changed(
+        &datasets_path.join("stats.json"),
+        &Versioned::new(&datasets.stats),
+    )?;
 
     Ok(datasets)
 }
 
+/// Export the landscape items to the CSV file provided.
+#[instrument(skip_all, err)]
+fn export_items_csv_file(landscape_data: &LandscapeData, file: &Path) -> Result<()> {
+    debug!("exporting items csv file");
+
+    let w = csv::Writer::from_path(file).context("error creating csv export file")?;
+    generate_items_csv(w, landscape_data)?;
+
+    Ok(())
+}
+
+/// Export the landscape items to the Parquet file provided.
+#[instrument(skip_all, err)]
+fn export_items_parquet_file(landscape_data: &LandscapeData, file: &Path) -> Result<()> {
+    debug!("exporting items parquet file");
+
+    let file = File::create(file).context("error creating parquet export file")?;
+    generate_items_parquet(file, landscape_data)?;
+
+    Ok(())
+}
+
+/// Generate the legacy items.json file from the landscape data.
+#[instrument(skip_all, err)]
+fn generate_items_legacy_json_file(
+    landscape_data: &LandscapeData,
+    landscape_url: &str,
+    output_dir: &Path,
+) -> Result<()> {
+    debug!("generating legacy items json file");
+
+    let file = File::create(output_dir.join(DATASETS_PATH).join("items.json"))?;
+    generate_items_legacy_json(file, landscape_data, landscape_url)?;
+
+    Ok(())
+}
+
 /// Generate the items.csv file from the landscape data.
 #[instrument(skip_all, err)]
 fn generate_items_csv_file(landscape_data: &LandscapeData, output_dir: &Path) -> Result<()> {
@@ -548,27 +1282,6 @@ fn generate_projects_files(landscape_data: &LandscapeData, output_dir: &Path) ->
     Ok(())
 }
 
-/// Generate QR code and copy it to output directory.
-#[instrument(skip(output_dir), err)]
-fn generate_qr_code(url: &String, output_dir: &Path) -> Result<String> {
-    debug!("generating qr code");
-
-    // Generate QR code
-    let code = qrcode::QrCode::new(url.as_bytes())?;
-    let svg = code
-        .render()
-        .min_dimensions(200, 200)
-        .dark_color(svg::Color("#000000"))
-        .light_color(svg::Color("#ffffff"))
-        .build();
-
-    // Write QR code (SVG) to output dir
-    let svg_path = Path::new(IMAGES_PATH).join("qr_code.svg");
-    File::create(output_dir.join(&svg_path))?.write_all(svg.as_bytes())?;
-
-    Ok(svg_path.to_string_lossy().into_owned())
-}
-
 /// Prepare games data and copy it to the output directory.
 #[instrument(skip_all, err)]
 async fn prepare_games_data(games_source: &GamesSource, output_dir: &Path) -> Result<Option<LandscapeGames>> {
@@ -578,6 +1291,10 @@ async fn prepare_games_data(games_source: &GamesSource, output_dir: &Path) -> Re
         return Ok(None);
     };
 
+    // All games data
+    let path = output_dir.join(DATASETS_PATH).join("games.json");
+    File::create(path)?.write_all(&serde_json::to_vec(&games)?)?;
+
     // Quiz game data
     if let Some(quiz) = &games.quiz {
         let path = output_dir.join(DATASETS_PATH).join("quiz.json");
@@ -601,80 +1318,280 @@ async fn prepare_guide(guide_source: &GuideSource, output_dir: &Path) -> Result<
     Ok(Some(guide))
 }
 
-/// Prepare items logos and copy them to the output directory, updating the
-/// logo reference on each landscape item.
+/// Logos prepared for each landscape item, keyed by the item id (logo, dark
+/// logo and PNG preview paths in the output directory).
+type ItemsLogos = HashMap<String, (Option<String>, Option<String>, Option<String>)>;
+
+/// Prepare items logos (including the dark variants when available) and copy
+/// them to the output directory, returning the logos prepared for each item
+/// (they can be set on the landscape items using `set_items_logos`). If
+/// `fail_on_missing_logos` is set, an error is returned when any of the logos
+/// cannot be prepared.
 #[instrument(skip_all, err)]
+#[allow(clippy::too_many_arguments)]
 async fn prepare_items_logos(
+    cache: &Cache,
     logos_source: &LogosSource,
+    logos_cache_ttl: i64,
+    max_concurrency: Option<usize>,
     logos_viewbox: &LogosViewbox,
-    landscape_data: &mut LandscapeData,
+    landscape_data: &LandscapeData,
     output_dir: &Path,
-) -> Result<()> {
+    fail_on_missing_logos: bool,
+    report: &BuildReport,
+) -> Result<ItemsLogos> {
     debug!("preparing logos");
 
     // Get logos from the source and copy them to the output directory
-    let mut concurrency = num_cpus::get();
-    if concurrency > PREPARE_LOGOS_MAX_CONCURRENCY {
-        concurrency = PREPARE_LOGOS_MAX_CONCURRENCY;
-    }
+    let concurrency = max_concurrency.unwrap_or_else(|| num_cpus::get().min(PREPARE_LOGOS_MAX_CONCURRENCY));
     let http_client = reqwest::Client::new();
+    let http_cache = Arc::new(HttpCache::new(cache, LOGOS_HTTP_CACHE_FILE).with_ttl(logos_cache_ttl));
     let logos_source = Arc::new(logos_source.clone());
     let logos_viewbox = Arc::new(logos_viewbox.clone());
-    let logos: HashMap<String, Option<String>> = stream::iter(landscape_data.items.iter())
+
+    // Load the logos prepared in previous builds whose files are still
+    // available in the output directory, so that they can be reused when
+    // their source hasn't changed
+    let mut prepared_logos: PreparedLogos = cache.read_json(PREPARED_LOGOS_CACHE_FILE).unwrap_or_default();
+    prepared_logos.retain(|_, prepared_logo| {
+        output_dir.join(logo_path(prepared_logo)).exists()
+            && logo_preview_path(prepared_logo).map_or(true, |path| output_dir.join(path).exists())
+    });
+    let prepared_logos = Arc::new(prepared_logos);
+
+    let mut missing_logos = vec![];
+    let mut new_prepared_logos = PreparedLogos::new();
+    let mut stats = ServiceStats::default();
+    let progress = Progress::new("preparing logos", landscape_data.items.len());
+    let logos: ItemsLogos = stream::iter(landscape_data.items.iter())
         .map(|item| async {
-            // Prepare logo
-            let http_client = http_client.clone();
-            let logos_source = logos_source.clone();
-            let logos_viewbox = logos_viewbox.clone();
-            let file_name = item.logo.clone();
-            let logo = match tokio::spawn(async move {
-                prepare_logo(http_client, &logos_source, &logos_viewbox, &file_name).await
-            })
+            let mut item_missing_logos = vec![];
+            let mut item_prepared_logos = vec![];
+
+            // Use a placeholder logo if the item's logo could not be prepared
+            let logo = match prepare_item_logo(
+                http_client.clone(),
+                http_cache.clone(),
+                logos_source.clone(),
+                logos_viewbox.clone(),
+                prepared_logos.clone(),
+                output_dir.to_path_buf(),
+                &item.logo,
+                report,
+            )
             .await
             {
-                Ok(Ok(logo)) => logo,
-                Ok(Err(err)) => {
-                    error!(?err, ?item.logo, "error preparing logo");
-                    return (item.id.clone(), None);
+                Some((source_digest, prepared_logo)) => {
+                    item_prepared_logos.push((source_digest, prepared_logo.clone()));
+                    Some(prepared_logo)
                 }
-                Err(err) => {
-                    error!(?err, ?item.logo, "error executing prepare_logo task");
-                    return (item.id.clone(), None);
+                None => {
+                    item_missing_logos.push(item.logo.clone());
+                    let placeholder_logo = generate_placeholder_logo(&item.name);
+                    match write_logo(&placeholder_logo, output_dir) {
+                        Ok(()) => Some(PreparedLogo::from(&placeholder_logo)),
+                        Err(err) => {
+                            error!(?err, "error writing placeholder logo to output dir");
+                            None
+                        }
+                    }
                 }
             };
-
-            // Copy logo to output dir using the digest(+.extenstion) as filename
-            let file_name = format!("{}.{}", logo.digest, logo.extension);
-            let mut file = match File::create(output_dir.join(LOGOS_PATH).join(&file_name)) {
-                Ok(file) => file,
-                Err(err) => {
-                    error!(?err, ?file_name, "error creating logo file in output dir");
-                    return (item.id.clone(), None);
+            let logo_png = logo.as_ref().and_then(logo_preview_path);
+            let logo = logo.as_ref().map(logo_path);
+
+            let logo_dark = match &item.logo_dark {
+                Some(logo_dark) => {
+                    let logo_dark_prepared = prepare_item_logo(
+                        http_client.clone(),
+                        http_cache.clone(),
+                        logos_source.clone(),
+                        logos_viewbox.clone(),
+                        prepared_logos.clone(),
+                        output_dir.to_path_buf(),
+                        logo_dark,
+                        report,
+                    )
+                    .await;
+                    match logo_dark_prepared {
+                        Some((source_digest, prepared_logo)) => {
+                            let path = logo_path(&prepared_logo);
+                            item_prepared_logos.push((source_digest, prepared_logo));
+                            Some(path)
+                        }
+                        None => {
+                            item_missing_logos.push(logo_dark.clone());
+                            None
+                        }
+                    }
                 }
-            };
-            if let Err(err) = file.write_all(&logo.data) {
-                error!(?err, ?file_name, "error writing logo to file in output dir");
+                None => None,
             };
 
-            (item.id.clone(), Some(format!("{LOGOS_PATH}/{file_name}")))
+            progress.inc(1);
+            (
+                item.id.clone(),
+                (logo, logo_dark, logo_png),
+                item_missing_logos,
+                item_prepared_logos,
+            )
         })
         .buffer_unordered(concurrency)
-        .collect()
-        .await;
+        .collect::<Vec<_>>()
+        .await
+        .into_iter()
+        .map(|(item_id, logos, item_missing_logos, item_prepared_logos)| {
+            for (source_digest, _) in &item_prepared_logos {
+                if prepared_logos.contains_key(source_digest) {
+                    stats.cached += 1;
+                } else {
+                    stats.collected += 1;
+                }
+            }
+            stats.errors += item_missing_logos.len();
+            missing_logos.extend(item_missing_logos);
+            new_prepared_logos.extend(item_prepared_logos);
+            (item_id, logos)
+        })
+        .collect();
+    stats.api_calls = http_cache.requests_sent();
+    report.set_service_stats("logos", stats);
+    if let Err(err) = http_cache.write(cache) {
+        warn!(?err, "error writing logos http cache");
+    }
+    match serde_json::to_vec(&new_prepared_logos) {
+        Ok(data) => {
+            if let Err(err) = cache.write(PREPARED_LOGOS_CACHE_FILE, &data) {
+                warn!(?err, "error writing prepared logos cache");
+            }
+        }
+        Err(err) => warn!(?err, "error serializing prepared logos"),
+    }
+
+    // Fail if some logos could not be prepared and we were asked to do so
+    if fail_on_missing_logos && !missing_logos.is_empty() {
+        missing_logos.sort();
+        bail!("some logos could not be prepared: {}", missing_logos.join(", "));
+    }
+
+    debug!("done!");
+    Ok(logos)
+}
 
-    // Update logo field in landscape items to logo digest path
+/// Use a placeholder logo for all the landscape items, writing them to the
+/// output directory and returning the logos prepared for each item.
+#[instrument(skip_all, err)]
+fn prepare_items_placeholder_logos(landscape_data: &LandscapeData, output_dir: &Path) -> Result<ItemsLogos> {
+    debug!("preparing placeholder logos");
+
+    let mut logos = ItemsLogos::new();
+    for item in &landscape_data.items {
+        let placeholder_logo = generate_placeholder_logo(&item.name);
+        write_logo(&placeholder_logo, output_dir)?;
+        let prepared_logo = PreparedLogo::from(&placeholder_logo);
+        logos.insert(
+            item.id.clone(),
+            (
+                Some(logo_path(&prepared_logo)),
+                None,
+                logo_preview_path(&prepared_logo),
+            ),
+        );
+    }
+
+    debug!("done!");
+    Ok(logos)
+}
+
+/// Update the logo fields of the landscape items to the logos prepared.
+fn set_items_logos(landscape_data: &mut LandscapeData, logos: &ItemsLogos) {
     for item in &mut landscape_data.items {
-        item.logo = if let Some(Some(logo)) = logos.get(&item.id) {
-            logo.clone()
-        } else {
-            String::new()
+        let (logo, logo_dark, logo_png) = logos.get(&item.id).cloned().unwrap_or_default();
+        item.logo = logo.unwrap_or_default();
+        item.logo_dark = logo_dark;
+        item.logo_png = logo_png;
+    }
+}
+
+/// Prepare the logo provided and write it to the output directory, reusing
+/// the logo prepared in a previous build when its source hasn't changed. The
+/// digest of the logo source is returned along with the logo prepared (errors
+/// are logged, returning None in that case).
+#[instrument(skip_all, fields(%logo))]
+#[allow(clippy::too_many_arguments)]
+async fn prepare_item_logo(
+    http_client: reqwest::Client,
+    http_cache: Arc<HttpCache>,
+    logos_source: Arc<LogosSource>,
+    logos_viewbox: Arc<LogosViewbox>,
+    prepared_logos: Arc<PreparedLogos>,
+    output_dir: PathBuf,
+    logo: &str,
+    report: &BuildReport,
+) -> Option<(String, PreparedLogo)> {
+    let file_name = logo.to_string();
+    match tokio::spawn(async move {
+        // Get logo from the source provided
+        let logo_data = get_logo(http_client, &http_cache, &logos_source, &file_name).await?;
+
+        // Reuse the logo prepared in a previous build if available
+        let source_digest = source_digest(&logo_data, &logos_viewbox, &file_name);
+        if let Some(prepared_logo) = prepared_logos.get(&source_digest) {
+            return Ok((source_digest, prepared_logo.clone()));
+        }
+
+        // Otherwise prepare it and write it to the output directory
+        let logo = prepare_logo(logo_data, &logos_viewbox, &file_name)?;
+        write_logo(&logo, &output_dir)?;
+        Ok::<_, anyhow::Error>((source_digest, PreparedLogo::from(&logo)))
+    })
+    .await
+    {
+        Ok(Ok(logo_prepared)) => Some(logo_prepared),
+        Ok(Err(err)) => {
+            warn!(?err, ?logo, "error preparing logo");
+            report.add_error("logos", logo, &err);
+            None
+        }
+        Err(err) => {
+            warn!(?err, ?logo, "error executing prepare_logo task");
+            report.add_error("logos", logo, &err.into());
+            None
+        }
+    }
+}
+
+/// Write the logo provided (and its PNG preview, if available) to the output
+/// directory. Logos are named after their digest, so they are only written
+/// when they are not available yet.
+fn write_logo(logo: &Logo, output_dir: &Path) -> Result<()> {
+    let prepared_logo = PreparedLogo::from(logo);
+
+    let path = output_dir.join(logo_path(&prepared_logo));
+    if !path.exists() {
+        fs::write(path, &logo.data)?;
+    }
+    if let (Some(preview), Some(preview_path)) = (&logo.preview, logo_preview_path(&prepared_logo)) {
+        let preview_path = output_dir.join(preview_path);
+        if !preview_path.exists() {
+            fs::write(preview_path, preview)?;
         }
     }
 
-    debug!("done!");
     Ok(())
 }
 
+/// Return the path of the logo provided in the output directory.
+fn logo_path(logo: &PreparedLogo) -> String {
+    format!("{LOGOS_PATH}/{}.{}", logo.digest, logo.extension)
+}
+
+/// Return the path of the PNG preview of the logo provided in the output
+/// directory (if available).
+fn logo_preview_path(logo: &PreparedLogo) -> Option<String> {
+    logo.preview.then(|| format!("{LOGOS_PATH}/{}.png", logo.digest))
+}
+
 /// Prepare landscape screenshot (in PNG and PDF formats).
 #[allow(clippy::cast_precision_loss, clippy::items_after_statements)]
 #[instrument(skip(output_dir), err)]
@@ -697,11 +1614,14 @@ async fn prepare_screenshot(width: u32, output_dir: &Path) -> Result<()> {
     let server = tokio::spawn(async {
         let args = serve::ServeArgs {
             addr: svr_addr_copy,
+            base_path: None,
+            build: None,
             graceful_shutdown: false,
             landscape_dir,
             silent: true,
+            watch: false,
         };
-        serve(&args).await
+        serve(&args, None).await
     });
 
     // Setup headless browser and navigate to screenshot url
@@ -768,22 +1688,93 @@ async fn prepare_screenshot(width: u32, output_dir: &Path) -> Result<()> {
     Ok(())
 }
 
+/// Prepare the custom html snippets defined in the settings, fetching the ones
+/// referenced by url. The snippets returned are always provided inline.
+#[instrument(skip_all, err)]
+async fn prepare_custom_html(http_cache: &HttpCache, custom_html: &Option<CustomHtml>) -> Result<CustomHtml> {
+    let Some(custom_html) = custom_html else {
+        return Ok(CustomHtml::default());
+    };
+    debug!("preparing custom html snippets");
+
+    Ok(CustomHtml {
+        footer: get_custom_html_snippet(http_cache, &custom_html.footer, &custom_html.footer_url).await?,
+        header: get_custom_html_snippet(http_cache, &custom_html.header, &custom_html.header_url).await?,
+        ..Default::default()
+    })
+}
+
+/// Get the content of a custom html snippet, fetching it when a url is provided.
+async fn get_custom_html_snippet(
+    http_cache: &HttpCache,
+    html: &Option<String>,
+    url: &Option<String>,
+) -> Result<Option<String>> {
+    let Some(url) = url else {
+        return Ok(html.clone());
+    };
+
+    let snippet = fetch_settings_file(http_cache, url).await?;
+    Ok(Some(
+        String::from_utf8(snippet).context("invalid custom html snippet")?,
+    ))
+}
+
+/// Fetch a remote file referenced in the settings using the http cache
+/// provided, so that it can be reused when building offline.
+async fn fetch_settings_file(http_cache: &HttpCache, url: &str) -> Result<Vec<u8>> {
+    let resp = http_cache.send(reqwest::Client::new().get(url)).await?;
+    if resp.status != StatusCode::OK {
+        bail!("unexpected status ({}) received when fetching {url}", resp.status);
+    }
+
+    Ok(resp.body)
+}
+
+/// Fetch the translations catalog from the url provided in the settings (if
+/// any) and validate it.
+#[instrument(skip_all, err)]
+async fn prepare_translations(http_cache: &HttpCache, settings: &mut LandscapeSettings) -> Result<()> {
+    let Some(i18n) = &mut settings.i18n else {
+        return Ok(());
+    };
+    let Some(url) = i18n.translations_url.take() else {
+        return Ok(());
+    };
+    debug!("preparing translations");
+
+    // Fetch translations catalog
+    let raw_data = fetch_settings_file(http_cache, &url).await?;
+    let translations: TranslationsCatalog =
+        serde_yaml::from_slice(&raw_data).context("invalid translations yaml file")?;
+
+    // Validate catalog
+    I18n::validate_translations(&translations).context("the translations catalog provided is not valid")?;
+    i18n.translations = Some(translations);
+
+    Ok(())
+}
+
 /// Fetch some settings images, copy them to the output directory and update
 /// their urls to the local copy.
 #[instrument(skip_all, err)]
-async fn prepare_settings_images(settings: &mut LandscapeSettings, output_dir: &Path) -> Result<()> {
+async fn prepare_settings_images(
+    http_cache: &HttpCache,
+    settings: &mut LandscapeSettings,
+    output_dir: &Path,
+) -> Result<()> {
     // Helper function to process the image provided
-    async fn process_image(url: &Option<String>, output_dir: &Path) -> Result<Option<String>> {
+    async fn process_image(
+        http_cache: &HttpCache,
+        url: &Option<String>,
+        output_dir: &Path,
+    ) -> Result<Option<String>> {
         let Some(url) = url else {
             return Ok(None);
         };
 
         // Fetch image from url
-        let resp = reqwest::get(url).await?;
-        if resp.status() != StatusCode::OK {
-            bail!("unexpected status ({}) code getting logo {url}", resp.status());
-        }
-        let img = resp.bytes().await?.to_vec();
+        let img = fetch_settings_file(http_cache, url).await?;
 
         // Write image to output dir
         let url = Url::parse(url).context("invalid image url")?;
@@ -800,32 +1791,46 @@ async fn prepare_settings_images(settings: &mut LandscapeSettings, output_dir: &
 
     // Header
     if let Some(header) = &mut settings.header {
-        header.logo = process_image(&header.logo, output_dir).await?;
+        header.logo = process_image(http_cache, &header.logo, output_dir).await?;
     };
 
     // Footer
     if let Some(footer) = &mut settings.footer {
-        footer.logo = process_image(&footer.logo, output_dir).await?;
+        footer.logo = process_image(http_cache, &footer.logo, output_dir).await?;
     };
 
     // Other images
     if let Some(images) = &mut settings.images {
-        images.favicon = process_image(&images.favicon, output_dir).await?;
+        images.favicon = process_image(http_cache, &images.favicon, output_dir).await?;
     };
 
+    // Generate icons from the favicon (or the header logo if not available)
+    let icons_source = settings
+        .images
+        .as_ref()
+        .and_then(|images| images.favicon.clone())
+        .or(settings.header.as_ref().and_then(|header| header.logo.clone()));
+    if let Some(icons_source) = icons_source {
+        match generate_icons(&icons_source, output_dir) {
+            Ok(icons) => settings.images.get_or_insert_with(Images::default).icons = Some(icons),
+            Err(err) => warn!(?err, "error generating icons"),
+        }
+    }
+
     Ok(())
 }
 
 /// Prepare view full dataset creating a stripped down version of the full
 /// dataset with only the data needed for the provided embed view.
 fn prepare_view_full_dataset(full: &Full, view: &EmbedView) -> Full {
+    filter_full_dataset(full, |fi| view.items.iter().any(|vi| vi.id == fi.id))
+}
+
+/// Prepare a subset of the full dataset that includes only the items matching
+/// the predicate provided (and the data related to them).
+fn filter_full_dataset(full: &Full, predicate: impl Fn(&Item) -> bool) -> Full {
     // Items
-    let mut items: Vec<Item> = vec![];
-    for fi in &full.items {
-        if view.items.iter().any(|vi| vi.id == fi.id) {
-            items.push(fi.clone());
-        }
-    }
+    let items: Vec<Item> = full.items.iter().filter(|fi| predicate(fi)).cloned().collect();
 
     // Crunchbase data
     let mut crunchbase_data: CrunchbaseData = BTreeMap::new();
@@ -840,6 +1845,14 @@ fn prepare_view_full_dataset(full: &Full, view: &EmbedView) -> Full {
         }
     }
 
+    // Feeds data
+    let mut feeds_data: FeedsData = BTreeMap::new();
+    for (url, feed_data) in &full.feeds_data {
+        if items.iter().any(|i| i.feed_url.as_ref() == Some(url)) {
+            feeds_data.insert(url.clone(), feed_data.clone());
+        }
+    }
+
     // GitHub data
     let mut github_data: GithubData = BTreeMap::new();
     for (url, repo_github_data) in &full.github_data {
@@ -853,10 +1866,81 @@ fn prepare_view_full_dataset(full: &Full, view: &EmbedView) -> Full {
         }
     }
 
+    // GitHub organizations data
+    let mut github_orgs_data: GithubOrgsData = BTreeMap::new();
+    for (url, org_data) in &full.github_orgs_data {
+        if items.iter().any(|i| org_data.items.contains(&i.id)) {
+            github_orgs_data.insert(url.clone(), org_data.clone());
+        }
+    }
+
+    // Items activity
+    let mut items_activity: ItemsActivity = BTreeMap::new();
+    for (id, activity) in &full.items_activity {
+        if items.iter().any(|i| i.id == *id) {
+            items_activity.insert(id.clone(), activity.clone());
+        }
+    }
+
+    // LinkedIn data
+    let mut linkedin_data: LinkedInData = BTreeMap::new();
+    for (url, org_linkedin_data) in &full.linkedin_data {
+        if items.iter().any(|i| {
+            let Some(linkedin_url) = &i.linkedin_url else {
+                return false;
+            };
+            linkedin_url == url
+        }) {
+            linkedin_data.insert(url.clone(), org_linkedin_data.clone());
+        }
+    }
+
+    // News
+    let news = full.news.iter().filter(|p| items.iter().any(|i| i.id == p.item_id)).cloned().collect();
+
+    // Packages data
+    let mut packages: PackagesData = BTreeMap::new();
+    for (id, package_data) in &full.packages {
+        if items.iter().any(|i| {
+            if let Some(item_packages) = &i.packages {
+                return item_packages.iter().any(|p| p.id() == *id);
+            }
+            false
+        }) {
+            packages.insert(id.clone(), package_data.clone());
+        }
+    }
+
+    // Social data
+    let mut social: SocialData = BTreeMap::new();
+    for (url, account_data) in &full.social {
+        if items.iter().any(|i| i.social_accounts().any(|(_, account_url)| account_url == url)) {
+            social.insert(url.clone(), account_data.clone());
+        }
+    }
+
+    // Sponsorships data
+    let mut sponsorships_data: SponsorshipsData = BTreeMap::new();
+    for (url, sponsorship_data) in &full.sponsorships_data {
+        if items.iter().any(|i| {
+            i.github_sponsors_url.as_ref() == Some(url) || i.open_collective_url.as_ref() == Some(url)
+        }) {
+            sponsorships_data.insert(url.clone(), sponsorship_data.clone());
+        }
+    }
+
     Full {
         crunchbase_data,
+        feeds_data,
         github_data,
+        github_orgs_data,
         items,
+        items_activity,
+        linkedin_data,
+        news,
+        packages,
+        social,
+        sponsorships_data,
     }
 }
 
@@ -865,27 +1949,48 @@ fn prepare_view_full_dataset(full: &Full, view: &EmbedView) -> Full {
 #[template(path = "index.html", escape = "none")]
 struct IndexHtml<'a> {
     analytics: &'a Option<Analytics>,
+    custom_html: &'a CustomHtml,
     datasets: &'a Datasets,
+    meta: &'a Option<Meta>,
     osano: &'a Option<Osano>,
+    theme: &'a Option<Theme>,
+    title: String,
+    url: &'a str,
 }
 
 /// Render index html file and write it to the output directory.
 #[instrument(skip_all, err)]
+#[allow(clippy::too_many_arguments)]
 fn render_index_html(
     analytics: &Option<Analytics>,
+    custom_html: &CustomHtml,
     datasets: &Datasets,
+    meta: &Option<Meta>,
     osano: &Option<Osano>,
+    theme: &Option<Theme>,
+    url: &str,
     output_dir: &Path,
 ) -> Result<()> {
     debug!("rendering index.html file");
 
+    let title = meta
+        .as_ref()
+        .and_then(|meta| meta.title.clone())
+        .unwrap_or_else(|| format!("{} Landscape", datasets.base.foundation));
     let html = IndexHtml {
         analytics,
+        custom_html,
         datasets,
+        meta,
         osano,
+        theme,
+        title,
+        url: url.trim_end_matches('/'),
     }
     .render()?;
-    File::create(output_dir.join("index.html"))?.write_all(html.as_bytes())?;
+    let index_path = output_dir.join("index.html");
+    File::create(&index_path)?.write_all(html.as_bytes())?;
+    write_precompressed_variants(&index_path)?;
 
     Ok(())
 }
@@ -909,6 +2014,130 @@ fn render_embed_item_html(colors: &Option<Colors>, output_dir: &Path) -> Result<
     Ok(())
 }
 
+/// Write the data provided to the file at the given path, unless it already
+/// contains the same data. This allows incremental builds to leave unchanged
+/// outputs untouched. Returns true if the file was written.
+fn write_file_if_changed(path: &Path, data: &[u8]) -> Result<bool> {
+    if fs::read(path).is_ok_and(|current_data| current_data == data) {
+        return Ok(false);
+    }
+    fs::write(path, data)?;
+    Ok(true)
+}
+
+/// Remove the json files in the directory provided that are not in the list of
+/// files to keep. This prevents outputs generated by previous builds that are
+/// no longer needed (i.e. items removed from the landscape) from being served
+/// when the output directory is reused.
+fn remove_stale_json_files(path: &Path, files_to_keep: &HashSet<String>) -> Result<()> {
+    if !path.exists() {
+        return Ok(());
+    }
+    for entry in fs::read_dir(path)? {
+        let file_path = entry?.path();
+        if file_path.extension() != Some(OsStr::new("json")) {
+            continue;
+        }
+        let Some(file_name) = file_path.file_name().and_then(OsStr::to_str) else {
+            continue;
+        };
+        if !files_to_keep.contains(file_name) {
+            trace!(?file_path, "removing stale file");
+            fs::remove_file(&file_path)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Serialize the value provided as json and write it to the file at the path
+/// given if its content has changed (returns true when it has been written).
+/// The value is serialized straight to a temporary file next to it, so that
+/// large datasets don't need to be fully buffered in memory.
+fn write_json_if_changed<T: Serialize>(path: &Path, value: &T) -> Result<bool> {
+    let mut tmp_path = path.as_os_str().to_owned();
+    tmp_path.push(".tmp");
+    let tmp_path = PathBuf::from(tmp_path);
+
+    // Serialize value to the temporary file
+    let mut w = BufWriter::new(File::create(&tmp_path)?);
+    serde_json::to_writer(&mut w, value)?;
+    w.flush()?;
+    drop(w);
+
+    // Replace the current file only if the content has changed
+    if files_are_equal(&tmp_path, path)? {
+        fs::remove_file(&tmp_path)?;
+        return Ok(false);
+    }
+    fs::rename(&tmp_path, path)?;
+    Ok(true)
+}
+
+/// Check if the content of the files provided is the same (the second one
+/// may not exist). Files are compared in chunks, without reading them fully.
+fn files_are_equal(path1: &Path, path2: &Path) -> Result<bool> {
+    let Ok(metadata2) = fs::metadata(path2) else {
+        return Ok(false);
+    };
+    if fs::metadata(path1)?.len() != metadata2.len() {
+        return Ok(false);
+    }
+
+    let mut r1 = BufReader::new(File::open(path1)?);
+    let mut r2 = BufReader::new(File::open(path2)?);
+    let mut buf1 = [0; 8192];
+    let mut buf2 = [0; 8192];
+    loop {
+        let n = r1.read(&mut buf1)?;
+        if n == 0 {
+            return Ok(true);
+        }
+        r2.read_exact(&mut buf2[..n])?;
+        if buf1[..n] != buf2[..n] {
+            return Ok(false);
+        }
+    }
+}
+
+/// Check if the precompressed variants of the file provided exist.
+fn precompressed_variants_exist(path: &Path) -> bool {
+    ["gz", "br"].iter().all(|extension| {
+        let mut variant_path = path.as_os_str().to_owned();
+        variant_path.push(format!(".{extension}"));
+        PathBuf::from(variant_path).exists()
+    })
+}
+
+/// Write gzip and brotli precompressed variants of the file provided, so that
+/// static hosts and CDNs supporting them can serve smaller payloads. They are
+/// written next to the original file (using the `.gz` and `.br` extensions),
+/// which is read from disk in a streaming fashion.
+fn write_precompressed_variants(path: &Path) -> Result<()> {
+    let variant_path = |extension: &str| {
+        let mut variant_path = path.as_os_str().to_owned();
+        variant_path.push(format!(".{extension}"));
+        PathBuf::from(variant_path)
+    };
+
+    // Gzip
+    let mut gz_encoder = GzEncoder::new(File::create(variant_path("gz"))?, flate2::Compression::best());
+    io::copy(&mut BufReader::new(File::open(path)?), &mut gz_encoder)?;
+    gz_encoder.finish()?;
+
+    // Brotli
+    let mut br_encoder = brotli::CompressorWriter::new(
+        File::create(variant_path("br"))?,
+        BROTLI_BUFFER_SIZE,
+        BROTLI_QUALITY,
+        BROTLI_LGWIN,
+    );
+    io::copy(&mut BufReader::new(File::open(path)?), &mut br_encoder)?;
+    br_encoder.into_inner().flush()?;
+
+    Ok(())
+}
+
 /// Setup output directory, creating it as well as any of the other required
 /// paths inside it when needed.
 #[instrument(err)]
@@ -922,11 +2151,13 @@ fn setup_output_dir(output_dir: &Path) -> Result<()> {
 
     for path in &[
         API_PATH,
+        CARDS_PATH,
         DATASETS_PATH,
         DOCS_PATH,
         EMBED_PATH,
         IMAGES_PATH,
         LOGOS_PATH,
+        QR_CODES_PATH,
         SOURCES_PATH,
     ] {
         let path = output_dir.join(path);
@@ -938,6 +2169,41 @@ fn setup_output_dir(output_dir: &Path) -> Result<()> {
     Ok(())
 }
 
+/// Emit a warning for each of the members organizations that appear to have
+/// been acquired, according to the data collected from Crunchbase.
+fn warn_acquired_members(landscape_data: &LandscapeData, members_category: &Option<String>) {
+    let Some(members_category) = members_category else {
+        return;
+    };
+
+    for item in landscape_data.items.iter().filter(|i| i.category == *members_category) {
+        if let Some(acquired_by) = item.crunchbase_data.as_ref().and_then(|org| org.acquired_by.as_ref()) {
+            warn!(
+                member = ?item.name,
+                acquirer = ?acquired_by.acquirer_name,
+                announced_on = ?acquired_by.announced_on,
+                "member organization appears to have been acquired"
+            );
+        }
+    }
+}
+
+/// Emit a warning for each of the items owned by a TAG that is not defined in
+/// the settings tags section.
+fn warn_unknown_tags(landscape_data: &LandscapeData, settings: &LandscapeSettings) {
+    let Some(tags) = &settings.tags else {
+        return;
+    };
+
+    for item in &landscape_data.items {
+        if let Some(tag) = &item.tag {
+            if !tags.contains_key(tag) {
+                warn!(item = ?item.name, ?tag, "item's TAG not found in settings tags");
+            }
+        }
+    }
+}
+
 /// Find an available port to listen on.
 fn find_available_port() -> Option<u16> {
     (9000..10000).find(|port| TcpListener::bind(("127.0.0.1", *port)).is_ok())
@@ -0,0 +1,349 @@
+//! This module defines the functionality used to collect information about
+//! the organizations behind the landscape items (when applicable), using the
+//! organizations data provider configured in the settings (Crunchbase by
+//! default).
+
+use super::{
+    cache::Cache,
+    crunchbase::{CBProvider, CRUNCHBASE_CACHE_FILE},
+    progress::Progress,
+    report::{BuildReport, ServiceStats},
+    retry::RetryPolicy,
+    LandscapeData,
+};
+use anyhow::{bail, format_err, Result};
+use async_trait::async_trait;
+use chrono::Utc;
+use futures::stream::{self, StreamExt};
+use landscape2_core::{
+    data::{CrunchbaseData, Organization},
+    settings::OrganizationsDataProvider,
+};
+use reqwest::StatusCode;
+use serde::Deserialize;
+use std::{
+    collections::HashMap,
+    fs,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+use tracing::{debug, instrument, warn};
+
+/// File used to cache data collected from the organizations data API.
+const ORGANIZATIONS_API_CACHE_FILE: &str = "organizations_api.json";
+
+/// File used to cache data read from the organizations data CSV file.
+const ORGANIZATIONS_CSV_CACHE_FILE: &str = "organizations_csv.json";
+
+/// Maximum number of organizations to process concurrently when using a
+/// provider that supports it.
+const ORGANIZATIONS_MAX_CONCURRENCY: usize = 5;
+
+/// Collect organizations data for each of the items in the landscape using
+/// the provider configured, reusing cached data whenever possible. Cached
+/// data is considered valid for `cache_ttl` days. When `max_concurrency` is
+/// provided, it overrides the concurrency supported by the provider.
+#[instrument(skip_all, err)]
+pub(crate) async fn collect_organizations_data(
+    cache: &Cache,
+    landscape_data: &LandscapeData,
+    provider_settings: &OrganizationsDataProvider,
+    cache_ttl: i64,
+    max_concurrency: Option<usize>,
+    report: &BuildReport,
+) -> Result<CrunchbaseData> {
+    debug!("collecting organizations information (this may take a while)");
+
+    // Read cached data (if available). Data read from CSV files is always
+    // refreshed, as it's cheap to get it again.
+    let cache_file = organizations_cache_file(provider_settings);
+    let cache_ttl = match provider_settings {
+        OrganizationsDataProvider::Csv { .. } => 0,
+        _ => cache_ttl,
+    };
+    let mut cached_data: Option<CrunchbaseData> = None;
+    match cache.read(cache_file) {
+        Ok(Some((_, json_data))) => match serde_json::from_slice(&json_data) {
+            Ok(organizations_data) => cached_data = Some(organizations_data),
+            Err(err) => warn!("error parsing organizations cache file: {err:?}"),
+        },
+        Ok(None) => {}
+        Err(err) => warn!("error reading organizations cache file: {err:?}"),
+    }
+
+    // Setup organizations data provider
+    let op: Option<DynOP> = match provider_settings {
+        OrganizationsDataProvider::Api { url } => {
+            Some(Arc::new(APIProvider::new(url, cache.retry_policy())?))
+        }
+        OrganizationsDataProvider::Crunchbase => CBProvider::new(cache)?.map(|p| Arc::new(p) as DynOP),
+        OrganizationsDataProvider::Csv { source } => {
+            Some(Arc::new(CSVProvider::new(source, cache.retry_policy()).await?))
+        }
+    };
+
+    // Collect items Crunchbase urls (used to identify organizations)
+    let mut urls = vec![];
+    for item in &landscape_data.items {
+        if let Some(url) = &item.crunchbase_url {
+            urls.push(url);
+        }
+    }
+    urls.sort();
+    urls.dedup();
+
+    // Collect information from the provider, reusing cached data when available
+    let max_concurrency = match (&op, max_concurrency) {
+        (Some(_), Some(max_concurrency)) => max_concurrency,
+        (Some(op), None) => op.max_concurrency(),
+        (None, _) => 1,
+    };
+    let progress = Progress::new("collecting organizations data", urls.len());
+    let results: Vec<(String, bool, Option<Result<Organization>>)> = stream::iter(urls)
+        .map(|url| async {
+            let url = url.clone();
+
+            // Use cached data when available if it hasn't expired yet
+            if let Some(cached_org) = cached_data.as_ref().and_then(|cache| {
+                cache.get(&url).and_then(|org| {
+                    if org.generated_at + chrono::Duration::days(cache_ttl) > Utc::now() {
+                        Some(org)
+                    } else {
+                        None
+                    }
+                })
+            }) {
+                progress.inc(1);
+                (url, true, Some(Ok(cached_org.clone())))
+            }
+            // Otherwise we pull it from the provider (when available)
+            else if let Some(op) = op.clone() {
+                let result = op.get_organization(&url).await;
+                progress.inc(1);
+                (url, false, Some(result))
+            } else {
+                progress.inc(1);
+                (url, false, None)
+            }
+        })
+        .buffer_unordered(max_concurrency)
+        .collect()
+        .await;
+
+    // Keep the organizations data collected, recording any errors found in
+    // the build report
+    let mut organizations_data = CrunchbaseData::new();
+    let mut stats = ServiceStats::default();
+    for (url, cached, result) in results {
+        match result {
+            Some(Ok(organization_data)) => {
+                if cached {
+                    stats.cached += 1;
+                } else {
+                    stats.collected += 1;
+                }
+                organizations_data.insert(url, organization_data);
+            }
+            Some(Err(err)) => {
+                report.add_error("crunchbase", &url, &err);
+                stats.errors += 1;
+            }
+            None => {}
+        }
+    }
+    stats.api_calls = op.as_ref().map_or(0, |op| op.api_calls());
+    report.set_service_stats("crunchbase", stats);
+
+    // Write data (in json format) to cache
+    cache.write(cache_file, &serde_json::to_vec_pretty(&organizations_data)?)?;
+    if let Some(op) = op {
+        if let Err(err) = op.write_cache(cache) {
+            warn!(?err, "error writing organizations data provider cache");
+        }
+    }
+
+    debug!("done!");
+    Ok(organizations_data)
+}
+
+/// Return the file used to cache the data collected using the organizations
+/// data provider configured.
+pub(crate) fn organizations_cache_file(provider_settings: &OrganizationsDataProvider) -> &'static str {
+    match provider_settings {
+        OrganizationsDataProvider::Api { .. } => ORGANIZATIONS_API_CACHE_FILE,
+        OrganizationsDataProvider::Crunchbase => CRUNCHBASE_CACHE_FILE,
+        OrganizationsDataProvider::Csv { .. } => ORGANIZATIONS_CSV_CACHE_FILE,
+    }
+}
+
+/// Type alias to represent an OP trait object.
+pub(crate) type DynOP = Arc<dyn OP + Send + Sync>;
+
+/// Trait that defines some operations an organizations data provider (OP)
+/// must support.
+#[async_trait]
+pub(crate) trait OP {
+    /// Get the data of the organization identified by the Crunchbase url
+    /// provided.
+    async fn get_organization(&self, cb_url: &str) -> Result<Organization>;
+
+    /// Maximum number of organizations to process concurrently.
+    fn max_concurrency(&self) -> usize {
+        1
+    }
+
+    /// Number of requests sent to the provider so far.
+    fn api_calls(&self) -> usize {
+        0
+    }
+
+    /// Write any provider specific data to the cache.
+    fn write_cache(&self, _cache: &Cache) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// OP implementation backed by a foundation maintained API. The API is
+/// expected to return the organization details in json format when the
+/// Crunchbase url is provided in the `crunchbase_url` query parameter.
+struct APIProvider {
+    http_client: reqwest::Client,
+    requests_sent: AtomicUsize,
+    retry_policy: RetryPolicy,
+    url: String,
+}
+
+impl APIProvider {
+    /// Create a new APIProvider instance.
+    fn new(url: &str, retry_policy: RetryPolicy) -> Result<Self> {
+        let user_agent = format!("{}/{}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"));
+        let http_client = reqwest::Client::builder().user_agent(user_agent).build()?;
+
+        Ok(Self {
+            http_client,
+            requests_sent: AtomicUsize::new(0),
+            retry_policy,
+            url: url.to_string(),
+        })
+    }
+}
+
+#[async_trait]
+impl OP for APIProvider {
+    /// [OP::get_organization]
+    #[instrument(skip(self), err)]
+    async fn get_organization(&self, cb_url: &str) -> Result<Organization> {
+        let req = self.http_client.get(&self.url).query(&[("crunchbase_url", cb_url)]).build()?;
+        let on_attempt = || {
+            self.requests_sent.fetch_add(1, Ordering::Relaxed);
+        };
+        let resp = self.retry_policy.execute(&self.http_client, req, on_attempt).await?;
+        match resp.status() {
+            StatusCode::OK => Ok(resp.json::<ProviderOrganization>().await?.into()),
+            StatusCode::NOT_FOUND => bail!("organization not found"),
+            status => bail!("unexpected status code getting organization: {status}"),
+        }
+    }
+
+    /// [OP::max_concurrency]
+    fn max_concurrency(&self) -> usize {
+        ORGANIZATIONS_MAX_CONCURRENCY
+    }
+
+    /// [OP::api_calls]
+    fn api_calls(&self) -> usize {
+        self.requests_sent.load(Ordering::Relaxed)
+    }
+}
+
+/// OP implementation backed by a CSV file maintained manually. Each row must
+/// include the `crunchbase_url` column, which is used to identify the
+/// organization.
+struct CSVProvider {
+    organizations: HashMap<String, ProviderOrganization>,
+}
+
+impl CSVProvider {
+    /// Create a new CSVProvider instance from the source provided (local
+    /// path or url).
+    async fn new(source: &str, retry_policy: RetryPolicy) -> Result<Self> {
+        let raw_data = if source.starts_with("http://") || source.starts_with("https://") {
+            let resp = retry_policy.send(reqwest::Client::new().get(source)).await?;
+            if resp.status() != StatusCode::OK {
+                bail!(
+                    "unexpected status code getting organizations csv file: {}",
+                    resp.status()
+                );
+            }
+            resp.text().await?
+        } else {
+            fs::read_to_string(source)?
+        };
+
+        let mut organizations = HashMap::new();
+        let mut reader = csv::Reader::from_reader(raw_data.as_bytes());
+        for record in reader.deserialize() {
+            let org: ProviderOrganization = record?;
+            if let Some(cb_url) = &org.crunchbase_url {
+                organizations.insert(cb_url.clone(), org);
+            }
+        }
+
+        Ok(Self { organizations })
+    }
+}
+
+#[async_trait]
+impl OP for CSVProvider {
+    /// [OP::get_organization]
+    async fn get_organization(&self, cb_url: &str) -> Result<Organization> {
+        let org = self.organizations.get(cb_url).ok_or_else(|| format_err!("organization not found"))?;
+        Ok(org.clone().into())
+    }
+
+    /// [OP::max_concurrency]
+    fn max_concurrency(&self) -> usize {
+        ORGANIZATIONS_MAX_CONCURRENCY
+    }
+}
+
+/// Organization details returned by the API and CSV providers.
+#[derive(Debug, Clone, Deserialize)]
+struct ProviderOrganization {
+    city: Option<String>,
+    company_type: Option<String>,
+    country: Option<String>,
+    crunchbase_url: Option<String>,
+    description: Option<String>,
+    funding: Option<i64>,
+    homepage_url: Option<String>,
+    linkedin_url: Option<String>,
+    name: Option<String>,
+    num_employees_max: Option<i64>,
+    num_employees_min: Option<i64>,
+    region: Option<String>,
+    twitter_url: Option<String>,
+}
+
+impl From<ProviderOrganization> for Organization {
+    fn from(org: ProviderOrganization) -> Self {
+        Organization {
+            generated_at: Utc::now(),
+            city: org.city,
+            company_type: org.company_type,
+            country: org.country,
+            description: org.description,
+            funding: org.funding,
+            homepage_url: org.homepage_url,
+            linkedin_url: org.linkedin_url,
+            name: org.name,
+            num_employees_max: org.num_employees_max,
+            num_employees_min: org.num_employees_min,
+            region: org.region,
+            twitter_url: org.twitter_url,
+            ..Default::default()
+        }
+    }
+}
@@ -0,0 +1,252 @@
+//! This module defines the functionality used to collect information from the
+//! package registries (crates.io, npm, PyPI and Maven Central) for each of the
+//! packages declared in the landscape items (when applicable).
+
+use super::{cache::Cache, collector::Collector, retry::RetryPolicy, LandscapeData};
+use anyhow::{bail, format_err, Result};
+use async_trait::async_trait;
+use chrono::Utc;
+use landscape2_core::data::{ItemPackage, PackageData, PackagesData};
+#[cfg(test)]
+use mockall::automock;
+use reqwest::StatusCode;
+use serde::{de::DeserializeOwned, Deserialize};
+use std::sync::Arc;
+use tracing::{debug, instrument};
+
+/// File used to cache data collected from the package registries.
+pub(crate) const PACKAGES_CACHE_FILE: &str = "packages.json";
+
+/// Maximum number of packages to process concurrently.
+const PACKAGES_MAX_CONCURRENCY: usize = 5;
+
+/// Collect registry data for each of the packages declared in the landscape
+/// items, reusing cached data whenever possible (data in the cache is valid
+/// for the number of days provided).
+#[instrument(skip(cache, landscape_data), err)]
+pub(crate) async fn collect_packages_data(
+    cache: &Cache,
+    landscape_data: &LandscapeData,
+    cache_ttl: i64,
+) -> Result<PackagesData> {
+    debug!("collecting packages information from registries");
+
+    // Collect packages declared in the landscape items
+    let mut packages: Vec<&ItemPackage> = vec![];
+    for item in &landscape_data.items {
+        if let Some(item_packages) = &item.packages {
+            packages.extend(item_packages);
+        }
+    }
+    packages.sort_by_key(|p| p.id());
+    packages.dedup_by_key(|p| p.id());
+
+    // Collect packages information, reusing cached data when available
+    let pr: DynPR = Arc::new(PRApi::new(cache.retry_policy())?);
+    let collector = Collector {
+        cache,
+        cache_file: PACKAGES_CACHE_FILE,
+        cache_ttl,
+        max_concurrency: PACKAGES_MAX_CONCURRENCY,
+        description: "package data",
+    };
+    let entries = packages.into_iter().map(|package| (package.id(), package)).collect();
+    let packages_data = collector
+        .collect(entries, |package, _| {
+            let pr = pr.clone();
+            async move { collect_package_data(pr, package).await }
+        })
+        .await?;
+
+    debug!("done!");
+    Ok(packages_data)
+}
+
+/// Collect package data from the corresponding registry.
+#[instrument(skip(pr), err)]
+async fn collect_package_data(pr: DynPR, package: &ItemPackage) -> Result<PackageData> {
+    let name = &package.name;
+    let (downloads, latest_version) = match package.registry.as_str() {
+        "crates" => pr.get_crates_package(name).await?,
+        "maven" => pr.get_maven_package(name).await?,
+        "npm" => pr.get_npm_package(name).await?,
+        "pypi" => pr.get_pypi_package(name).await?,
+        registry => bail!("unsupported package registry: {registry}"),
+    };
+
+    Ok(PackageData {
+        generated_at: Utc::now(),
+        name: package.name.clone(),
+        registry: package.registry.clone(),
+        downloads,
+        latest_version,
+    })
+}
+
+/// Type alias to represent the downloads count and latest version of a
+/// package.
+type PackageInfo = (Option<u64>, Option<String>);
+
+/// Type alias to represent a PR trait object.
+type DynPR = Arc<dyn PR + Send + Sync>;
+
+/// Trait that defines some operations a PR implementation must support.
+#[async_trait]
+#[cfg_attr(test, automock)]
+trait PR {
+    /// Get package information from crates.io (total downloads).
+    async fn get_crates_package(&self, name: &str) -> Result<PackageInfo>;
+
+    /// Get package information from Maven Central (downloads are not
+    /// available).
+    async fn get_maven_package(&self, name: &str) -> Result<PackageInfo>;
+
+    /// Get package information from npm (downloads in the last month).
+    async fn get_npm_package(&self, name: &str) -> Result<PackageInfo>;
+
+    /// Get package information from PyPI (downloads in the last month).
+    async fn get_pypi_package(&self, name: &str) -> Result<PackageInfo>;
+}
+
+/// PR implementation backed by the package registries APIs.
+struct PRApi {
+    http_client: reqwest::Client,
+    retry_policy: RetryPolicy,
+}
+
+impl PRApi {
+    /// Create a new PRApi instance.
+    fn new(retry_policy: RetryPolicy) -> Result<Self> {
+        let user_agent = format!("{}/{}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"));
+        let http_client = reqwest::Client::builder().user_agent(user_agent).build()?;
+
+        Ok(Self {
+            http_client,
+            retry_policy,
+        })
+    }
+
+    /// Send a GET request to the url provided and return the response body
+    /// deserialized.
+    async fn get<T: DeserializeOwned>(&self, url: &str) -> Result<T> {
+        let resp = self.retry_policy.send(self.http_client.get(url)).await?;
+        if resp.status() != StatusCode::OK {
+            bail!("unexpected status code requesting {url}: {}", resp.status());
+        }
+        Ok(resp.json().await?)
+    }
+}
+
+#[async_trait]
+impl PR for PRApi {
+    /// [PR::get_crates_package]
+    #[instrument(skip(self), err)]
+    async fn get_crates_package(&self, name: &str) -> Result<PackageInfo> {
+        let resp: CratesResponse = self.get(&format!("https://crates.io/api/v1/crates/{name}")).await?;
+        let latest_version = resp.krate.max_stable_version.or(Some(resp.krate.max_version));
+        Ok((Some(resp.krate.downloads), latest_version))
+    }
+
+    /// [PR::get_maven_package]
+    #[instrument(skip(self), err)]
+    async fn get_maven_package(&self, name: &str) -> Result<PackageInfo> {
+        let (group, artifact) =
+            name.split_once(':').ok_or_else(|| format_err!("invalid maven package name"))?;
+        let url =
+            format!("https://search.maven.org/solrsearch/select?q=g:{group}+AND+a:{artifact}&rows=1&wt=json");
+        let resp: MavenResponse = self.get(&url).await?;
+        let doc = resp.response.docs.into_iter().next().ok_or_else(|| format_err!("package not found"))?;
+        Ok((None, Some(doc.latest_version)))
+    }
+
+    /// [PR::get_npm_package]
+    #[instrument(skip(self), err)]
+    async fn get_npm_package(&self, name: &str) -> Result<PackageInfo> {
+        let latest: NpmLatest = self.get(&format!("https://registry.npmjs.org/{name}/latest")).await?;
+        let downloads: NpmDownloads = self
+            .get(&format!(
+                "https://api.npmjs.org/downloads/point/last-month/{name}"
+            ))
+            .await?;
+        Ok((Some(downloads.downloads), Some(latest.version)))
+    }
+
+    /// [PR::get_pypi_package]
+    #[instrument(skip(self), err)]
+    async fn get_pypi_package(&self, name: &str) -> Result<PackageInfo> {
+        let package: PypiPackage = self.get(&format!("https://pypi.org/pypi/{name}/json")).await?;
+        let downloads: PypiDownloads =
+            self.get(&format!("https://pypistats.org/api/packages/{name}/recent")).await?;
+        Ok((Some(downloads.data.last_month), Some(package.info.version)))
+    }
+}
+
+/// crates.io crate response.
+#[derive(Debug, Deserialize)]
+struct CratesResponse {
+    #[serde(rename = "crate")]
+    krate: CratesCrate,
+}
+
+/// crates.io crate details.
+#[derive(Debug, Deserialize)]
+struct CratesCrate {
+    downloads: u64,
+    max_stable_version: Option<String>,
+    max_version: String,
+}
+
+/// Maven Central search response.
+#[derive(Debug, Deserialize)]
+struct MavenResponse {
+    response: MavenDocs,
+}
+
+/// Maven Central search results.
+#[derive(Debug, Deserialize)]
+struct MavenDocs {
+    docs: Vec<MavenDoc>,
+}
+
+/// Maven Central artifact details.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MavenDoc {
+    latest_version: String,
+}
+
+/// npm package downloads.
+#[derive(Debug, Deserialize)]
+struct NpmDownloads {
+    downloads: u64,
+}
+
+/// npm package latest version details.
+#[derive(Debug, Deserialize)]
+struct NpmLatest {
+    version: String,
+}
+
+/// PyPI package downloads.
+#[derive(Debug, Deserialize)]
+struct PypiDownloads {
+    data: PypiDownloadsData,
+}
+
+/// PyPI package recent downloads counts.
+#[derive(Debug, Deserialize)]
+struct PypiDownloadsData {
+    last_month: u64,
+}
+
+/// PyPI package details.
+#[derive(Debug, Deserialize)]
+struct PypiPackage {
+    info: PypiPackageInfo,
+}
+
+/// PyPI package information.
+#[derive(Debug, Deserialize)]
+struct PypiPackageInfo {
+    version: String,
+}
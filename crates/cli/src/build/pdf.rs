@@ -0,0 +1,141 @@
+//! This module defines the functionality used to export the landscape grid
+//! (categories and subcategories with the items logos) to a paginated PDF
+//! document, which can be used in printed materials like reports. The PDF is
+//! printed from an html document using a headless browser, so logos in SVG
+//! format are kept as vector graphics.
+
+use super::DOCS_PATH;
+use anyhow::{bail, format_err, Context, Result};
+use askama::Template;
+use headless_chrome::{browser, types::PrintToPdfOptions, Browser, LaunchOptions};
+use landscape2_core::{data::LandscapeData, settings::LandscapeSettings};
+use std::{fs, path::Path, time::Duration};
+use tracing::{debug, instrument};
+use url::Url;
+
+/// File the html document used to print the PDF is written to (in the docs
+/// path of the output directory). It is removed once the PDF is ready.
+const PDF_HTML_FILE: &str = "landscape-pdf.html";
+
+/// Maximum time to wait for the browser to print the PDF document.
+const PDF_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// Export the landscape grid to a PDF document at the path provided. One page
+/// (at least) is used for each category.
+#[instrument(skip(landscape_data, settings, output_dir), err)]
+pub(crate) fn export_landscape_pdf(
+    landscape_data: &LandscapeData,
+    settings: &LandscapeSettings,
+    output_dir: &Path,
+    file: &Path,
+) -> Result<()> {
+    debug!("exporting landscape pdf");
+
+    // Check if Chrome/Chromium is available
+    if browser::default_executable().is_err() {
+        bail!("chrome/chromium not found, it is required to export the landscape to pdf");
+    }
+
+    // Render html document and write it to the output directory (logos are
+    // referenced from it using paths relative to the docs path)
+    let html = LandscapePdfHtml {
+        categories: prepare_categories(landscape_data),
+        title: format!("{} Landscape", settings.foundation),
+    }
+    .render()?;
+    let html_path = output_dir.join(DOCS_PATH).join(PDF_HTML_FILE);
+    fs::write(&html_path, html)?;
+
+    // Print html document to pdf using a headless browser
+    let result = print_to_pdf(&html_path);
+    fs::remove_file(&html_path)?;
+    fs::write(file, result?).context("error writing pdf export file")?;
+
+    debug!("done!");
+    Ok(())
+}
+
+/// Print the html document provided to pdf using a headless browser.
+fn print_to_pdf(html_path: &Path) -> Result<Vec<u8>> {
+    let options = LaunchOptions {
+        idle_browser_timeout: PDF_TIMEOUT,
+        sandbox: false,
+        ..Default::default()
+    };
+    let browser = Browser::new(options)?;
+    let tab = browser.new_tab()?;
+    tab.set_default_timeout(PDF_TIMEOUT);
+    let url = Url::from_file_path(html_path.canonicalize()?)
+        .map_err(|()| format_err!("invalid html document path"))?;
+    tab.navigate_to(url.as_str())?.wait_until_navigated()?;
+
+    // The page size and margins are defined in the html document
+    let pdf_data = tab
+        .print_to_pdf(Some(PrintToPdfOptions {
+            prefer_css_page_size: Some(true),
+            print_background: Some(true),
+            ..Default::default()
+        }))
+        .context("error printing landscape to pdf")?;
+
+    Ok(pdf_data)
+}
+
+/// Prepare the categories to include in the PDF document, with the items of
+/// each of their subcategories (empty ones are skipped).
+fn prepare_categories(landscape_data: &LandscapeData) -> Vec<Category> {
+    landscape_data
+        .categories
+        .iter()
+        .map(|category| Category {
+            name: category.name.clone(),
+            subcategories: category
+                .subcategories
+                .iter()
+                .map(|subcategory| Subcategory {
+                    name: subcategory.name.clone(),
+                    items: landscape_data
+                        .items
+                        .iter()
+                        .filter(|item| item.category == category.name && item.subcategory == subcategory.name)
+                        .map(|item| Item {
+                            logo: format!("../{}", item.logo),
+                            name: item.name.clone(),
+                        })
+                        .collect(),
+                })
+                .filter(|subcategory| !subcategory.items.is_empty())
+                .collect(),
+        })
+        .filter(|category| !category.subcategories.is_empty())
+        .collect()
+}
+
+/// Template for the html document used to print the landscape PDF.
+#[derive(Debug, Clone, Template)]
+#[template(path = "landscape-pdf.html")]
+struct LandscapePdfHtml {
+    categories: Vec<Category>,
+    title: String,
+}
+
+/// Category information used in the PDF document.
+#[derive(Debug, Clone)]
+struct Category {
+    name: String,
+    subcategories: Vec<Subcategory>,
+}
+
+/// Subcategory information used in the PDF document.
+#[derive(Debug, Clone)]
+struct Subcategory {
+    name: String,
+    items: Vec<Item>,
+}
+
+/// Item information used in the PDF document.
+#[derive(Debug, Clone)]
+struct Item {
+    logo: String,
+    name: String,
+}
@@ -0,0 +1,165 @@
+//! This module defines the functionality used to display the build plan in
+//! dry run mode: what would be collected from external services and written
+//! to the output directory, without actually doing it.
+
+use super::{
+    cache::Cache,
+    github::{is_github_repo_url, GITHUB_CACHE_FILE},
+    organizations::organizations_cache_file,
+    BuildArgs, LandscapeData, LandscapeSettings, RefreshService, API_PATH, CARDS_PATH, DATASETS_PATH,
+    DOCS_PATH, EMBED_PATH, FULL_SHARDS_PATH, IMAGES_PATH, ITEMS_PATH, LOGOS_PATH, QR_CODES_PATH,
+    SOURCES_PATH,
+};
+use chrono::{DateTime, Utc};
+use landscape2_core::data::{CrunchbaseData, GithubData};
+use std::collections::BTreeSet;
+
+/// Display the build plan for the landscape data and settings provided.
+pub(crate) fn print_plan(
+    args: &BuildArgs,
+    cache: &Cache,
+    landscape_data: &LandscapeData,
+    settings: &LandscapeSettings,
+) {
+    println!(
+        "Landscape data: {} items loaded and validated",
+        landscape_data.items.len()
+    );
+    println!("Landscape settings: loaded and validated\n");
+
+    // Logos
+    let logos: BTreeSet<&String> = landscape_data
+        .items
+        .iter()
+        .flat_map(|item| [Some(&item.logo), item.logo_dark.as_ref()])
+        .flatten()
+        .collect();
+    if args.skip_logos {
+        println!("Logos: skipped (placeholder logos would be used)");
+    } else {
+        let source = match (&args.logos_source.logos_path, &args.logos_source.logos_url) {
+            (Some(path), _) => path.display().to_string(),
+            (None, Some(url)) => url.clone(),
+            (None, None) => "-".to_string(),
+        };
+        println!("Logos: {} would be prepared (source: {source})", logos.len());
+    }
+
+    // GitHub
+    let repos_urls: BTreeSet<&String> = landscape_data
+        .items
+        .iter()
+        .flat_map(|item| item.repositories.iter().flatten())
+        .map(|repo| &repo.url)
+        .filter(|url| is_github_repo_url(url))
+        .collect();
+    if args.skip_github {
+        println!("GitHub: skipped");
+    } else {
+        let cached_data: GithubData = cache.read_json(GITHUB_CACHE_FILE).unwrap_or_default();
+        let ttl = args.cache_ttl(RefreshService::Github, args.repositories_cache_ttl);
+        let cached = repos_urls
+            .iter()
+            .filter(|url| cached_data.get(**url).is_some_and(|repo| is_fresh(repo.generated_at, ttl)))
+            .count();
+        print_service_plan("GitHub", "repositories", repos_urls.len(), cached, args.offline);
+    }
+
+    // Crunchbase
+    let crunchbase_urls: BTreeSet<&String> =
+        landscape_data.items.iter().filter_map(|item| item.crunchbase_url.as_ref()).collect();
+    if args.skip_crunchbase {
+        println!("Crunchbase: skipped");
+    } else {
+        let cache_file = organizations_cache_file(&settings.organizations_data);
+        let cached_data: CrunchbaseData = cache.read_json(cache_file).unwrap_or_default();
+        let ttl = args.cache_ttl(RefreshService::Crunchbase, args.organizations_cache_ttl);
+        let cached = crunchbase_urls
+            .iter()
+            .filter(|url| cached_data.get(**url).is_some_and(|org| is_fresh(org.generated_at, ttl)))
+            .count();
+        print_service_plan(
+            "Crunchbase",
+            "organizations",
+            crunchbase_urls.len(),
+            cached,
+            args.offline,
+        );
+    }
+
+    // Other services
+    println!(
+        "Other services: data would be {} (Gitea, Bitbucket, best practices, packages, container images, \
+         LinkedIn, sponsorships, social, feeds, ClearlyDefined and CLOMonitor)",
+        if args.offline {
+            "read from the cache"
+        } else {
+            "collected"
+        }
+    );
+
+    // Output
+    println!(
+        "\nOutput directory: {} (nothing has been written)\n",
+        args.output_dir.display()
+    );
+    let mut paths = vec![
+        format!("{API_PATH}/"),
+        format!("{DATASETS_PATH}/ (base, full, embed, search and stats datasets)"),
+        format!(
+            "{DATASETS_PATH}/{ITEMS_PATH}/ ({} items)",
+            landscape_data.items.len()
+        ),
+        format!("{DOCS_PATH}/"),
+        format!("{EMBED_PATH}/"),
+        format!("{IMAGES_PATH}/"),
+        format!("{LOGOS_PATH}/"),
+        format!("{SOURCES_PATH}/"),
+        "index.html and web application assets".to_string(),
+        "items.csv".to_string(),
+        "changes.atom".to_string(),
+        "manifest.webmanifest".to_string(),
+        "robots.txt".to_string(),
+        "sitemap.xml".to_string(),
+        "sw.js".to_string(),
+    ];
+    if args.split_full_dataset {
+        paths.push(format!("{DATASETS_PATH}/{FULL_SHARDS_PATH}/"));
+    }
+    if args.generate_cards {
+        paths.push(format!("{CARDS_PATH}/"));
+    }
+    if args.generate_items_qr_codes {
+        paths.push(format!("{QR_CODES_PATH}/"));
+    }
+    if args.legacy_items_json {
+        paths.push(format!("{DATASETS_PATH}/items.json"));
+    }
+    if settings.screenshot_width.is_some() {
+        paths.push("landscape screenshot (png and pdf)".to_string());
+    }
+    println!("Files that would be written:");
+    for path in paths {
+        println!("  - {path}");
+    }
+    for path in [&args.export_csv, &args.export_parquet, &args.export_pdf].into_iter().flatten() {
+        println!("  - {} (export)", path.display());
+    }
+}
+
+/// Check if some data collected at the time provided is still fresh.
+fn is_fresh(generated_at: DateTime<Utc>, ttl: i64) -> bool {
+    generated_at + chrono::Duration::days(ttl) > Utc::now()
+}
+
+/// Display the plan for the service provided.
+fn print_service_plan(service: &str, kind: &str, total: usize, cached: usize, offline: bool) {
+    if offline {
+        println!("{service}: {total} {kind} (data would be read from the cache)");
+    } else {
+        println!(
+            "{service}: {total} {kind} ({cached} cached, {} would be fetched)",
+            total - cached
+        );
+    }
+}
@@ -0,0 +1,171 @@
+//! This module defines the functionality used to profile the build. The
+//! timing of the spans recorded during the build (i.e. build phases or items
+//! processed) is written to a file in the Chrome trace event format, which
+//! can be loaded in tools like Perfetto, chrome://tracing or Speedscope
+//! (flamegraph view).
+
+use anyhow::Result;
+use serde_json::{json, Value};
+use std::{
+    collections::BTreeMap,
+    env, fmt, fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::{Arc, Mutex},
+    time::Instant,
+};
+use tracing::{
+    field::{Field, Visit},
+    span::{Attributes, Id, Record},
+    Subscriber,
+};
+use tracing_subscriber::{
+    filter::{LevelFilter, Targets},
+    layer::{Context, SubscriberExt},
+    registry::LookupSpan,
+    util::SubscriberInitExt,
+    Layer,
+};
+
+/// Setup logging, recording as well the timing of the spans of the build so
+/// that they can be written to the file provided once it finishes.
+#[must_use]
+pub fn init_tracing_with_profile(file: &Path) -> ProfileWriter {
+    let events = Arc::new(Mutex::new(vec![]));
+
+    // Logs are filtered using RUST_LOG, as when profiling is not enabled
+    let log_targets = env::var("RUST_LOG")
+        .ok()
+        .and_then(|targets| Targets::from_str(&targets).ok())
+        .unwrap_or_else(|| Targets::new().with_default(LevelFilter::INFO));
+
+    // Only the spans of landscape2 are recorded
+    let profile_targets = Targets::new().with_target("landscape2", LevelFilter::TRACE);
+
+    tracing_subscriber::registry()
+        .with(tracing_subscriber::fmt::layer().with_filter(log_targets))
+        .with(
+            ProfileLayer {
+                events: events.clone(),
+                start: Instant::now(),
+            }
+            .with_filter(profile_targets),
+        )
+        .init();
+
+    ProfileWriter {
+        events,
+        file: file.to_path_buf(),
+    }
+}
+
+/// Writer used to write the events recorded by the profile layer to a file.
+#[derive(Debug)]
+pub struct ProfileWriter {
+    events: Arc<Mutex<Vec<Value>>>,
+    file: PathBuf,
+}
+
+impl ProfileWriter {
+    /// Write the events recorded so far to the profile file.
+    pub fn write(&self) -> Result<()> {
+        let events = self.events.lock().expect("not poisoned");
+        let trace = json!({
+            "displayTimeUnit": "ms",
+            "traceEvents": *events,
+        });
+        fs::write(&self.file, serde_json::to_vec(&trace)?)?;
+
+        Ok(())
+    }
+}
+
+/// Layer that records the timing of the spans, as well as their fields, as
+/// Chrome trace events.
+struct ProfileLayer {
+    events: Arc<Mutex<Vec<Value>>>,
+    start: Instant,
+}
+
+impl<S> Layer<S> for ProfileLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        let mut fields = FieldsVisitor::default();
+        attrs.record(&mut fields);
+        span.extensions_mut().insert(SpanTiming {
+            fields: fields.0,
+            start: Instant::now(),
+        });
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        let mut extensions = span.extensions_mut();
+        if let Some(timing) = extensions.get_mut::<SpanTiming>() {
+            let mut fields = FieldsVisitor(std::mem::take(&mut timing.fields));
+            values.record(&mut fields);
+            timing.fields = fields.0;
+        }
+    }
+
+    fn on_close(&self, id: Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(&id) else {
+            return;
+        };
+        let extensions = span.extensions();
+        let Some(timing) = extensions.get::<SpanTiming>() else {
+            return;
+        };
+
+        // Each span is recorded as an async event in its own track, as spans
+        // processing items concurrently overlap in time
+        let mut args = timing.fields.clone();
+        if let Some(parent) = span.parent() {
+            args.insert("parent".to_string(), parent.name().to_string());
+        }
+        let event = |phase: &str, ts: u128| {
+            json!({
+                "args": args,
+                "cat": span.metadata().target(),
+                "id": id.into_u64(),
+                "name": span.name(),
+                "ph": phase,
+                "pid": 1,
+                "tid": 1,
+                "ts": ts,
+            })
+        };
+        let start = timing.start.duration_since(self.start).as_micros();
+        let end = start + timing.start.elapsed().as_micros();
+        let mut events = self.events.lock().expect("not poisoned");
+        events.push(event("b", start));
+        events.push(event("e", end));
+    }
+}
+
+/// Timing information of a span, stored in its extensions.
+struct SpanTiming {
+    fields: BTreeMap<String, String>,
+    start: Instant,
+}
+
+/// Visitor used to collect the fields of a span.
+#[derive(Default)]
+struct FieldsVisitor(BTreeMap<String, String>);
+
+impl Visit for FieldsVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.insert(field.name().to_string(), value.to_string());
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.0.insert(field.name().to_string(), format!("{value:?}"));
+    }
+}
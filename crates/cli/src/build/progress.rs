@@ -0,0 +1,98 @@
+//! This module defines the functionality used to report the progress of some
+//! of the long running build phases (i.e. preparing logos or collecting data
+//! from GitHub), so that large builds don't appear to hang.
+
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    time::{Duration, Instant},
+};
+use tracing::info;
+
+/// Minimum time between progress logs.
+const PROGRESS_LOG_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Progress of a build phase. It is logged periodically as the items to
+/// process are done, including an estimation of the time left.
+#[derive(Debug)]
+pub(crate) struct Progress {
+    done: AtomicUsize,
+    last_log: Mutex<Instant>,
+    phase: &'static str,
+    start: Instant,
+    total: usize,
+}
+
+impl Progress {
+    /// Create a new Progress instance for the phase provided.
+    pub(crate) fn new(phase: &'static str, total: usize) -> Self {
+        let now = Instant::now();
+        Self {
+            done: AtomicUsize::new(0),
+            last_log: Mutex::new(now),
+            phase,
+            start: now,
+            total,
+        }
+    }
+
+    /// Record that the number of items provided have been done, logging the
+    /// progress if it hasn't been logged recently.
+    pub(crate) fn inc(&self, count: usize) {
+        let done = self.done.fetch_add(count, Ordering::Relaxed) + count;
+
+        // Log progress (unless it was logged recently)
+        {
+            let mut last_log = self.last_log.lock().expect("not poisoned");
+            if last_log.elapsed() < PROGRESS_LOG_INTERVAL {
+                return;
+            }
+            *last_log = Instant::now();
+        }
+        info!(
+            "{}: {done}/{} done ({:.0}%), eta: {}",
+            self.phase,
+            self.total,
+            percentage(done, self.total),
+            format_eta(self.eta(done)),
+        );
+    }
+
+    /// Estimate the time left to process all items, based on the time it
+    /// took to process the ones already done.
+    #[allow(clippy::cast_precision_loss)]
+    fn eta(&self, done: usize) -> Option<Duration> {
+        if done == 0 || done >= self.total {
+            return None;
+        }
+        let elapsed = self.start.elapsed().as_secs_f64();
+        let left = (self.total - done) as f64 * elapsed / done as f64;
+        Some(Duration::from_secs_f64(left))
+    }
+}
+
+/// Return the percentage of items done.
+#[allow(clippy::cast_precision_loss)]
+fn percentage(done: usize, total: usize) -> f64 {
+    if total == 0 {
+        return 100.0;
+    }
+    done as f64 * 100.0 / total as f64
+}
+
+/// Format the estimated time left in a human readable way.
+fn format_eta(eta: Option<Duration>) -> String {
+    let Some(eta) = eta else {
+        return "-".to_string();
+    };
+    let secs = eta.as_secs();
+    if secs >= 3600 {
+        format!("{}h{}m", secs / 3600, (secs % 3600) / 60)
+    } else if secs >= 60 {
+        format!("{}m{}s", secs / 60, secs % 60)
+    } else {
+        format!("{secs}s")
+    }
+}
@@ -0,0 +1,158 @@
+//! This module defines the functionality used to generate the web app
+//! manifest and the service worker, so that the landscape can be installed as
+//! an app and used offline. The service worker pre-caches the web application
+//! assets and the base dataset, whereas the rest of the datasets and the logos
+//! are cached at runtime as they are requested.
+
+use super::{WebappAssets, DATASETS_PATH, IMAGES_PATH, LOGOS_PATH};
+use anyhow::Result;
+use askama::Template;
+use landscape2_core::settings::LandscapeSettings;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::{fs, io, path::Path};
+use tracing::{debug, instrument};
+
+/// Background color used in the web app manifest.
+const MANIFEST_BACKGROUND_COLOR: &str = "#ffffff";
+
+/// Paths whose files are cached at runtime by the service worker (using a
+/// stale-while-revalidate strategy).
+const RUNTIME_CACHE_PATHS: &[&str] = &[DATASETS_PATH, IMAGES_PATH, LOGOS_PATH];
+
+/// Generate the web app manifest and the service worker and write them to the
+/// output directory.
+#[instrument(skip_all, err)]
+pub(crate) fn generate_pwa_files(settings: &LandscapeSettings, output_dir: &Path) -> Result<()> {
+    debug!("generating pwa files");
+
+    // Web app manifest
+    let manifest = Manifest::new(settings);
+    fs::write(
+        output_dir.join("manifest.webmanifest"),
+        serde_json::to_vec_pretty(&manifest)?,
+    )?;
+
+    // Service worker
+    let urls = collect_precache_urls();
+    let sw = ServiceWorker {
+        runtime_cache_paths: RUNTIME_CACHE_PATHS,
+        urls: &urls,
+        version: &precache_version(&urls, output_dir)?,
+    }
+    .render()?;
+    fs::write(output_dir.join("sw.js"), sw)?;
+
+    debug!("done!");
+    Ok(())
+}
+
+/// Collect the urls of the files to pre-cache in the service worker (relative
+/// to the landscape url). Only the app shell (the index document and the web
+/// application assets) and the base dataset are pre-cached, so that installing
+/// the service worker stays cheap on large landscapes.
+fn collect_precache_urls() -> Vec<String> {
+    let mut urls = vec!["./".to_string(), format!("{DATASETS_PATH}/base.json")];
+
+    // Web application assets
+    for path in WebappAssets::iter() {
+        if path == "index.html" || path == ".keep" {
+            continue;
+        }
+        urls.push(path.into_owned());
+    }
+
+    urls
+}
+
+/// Return the version of the files to pre-cache, which is calculated from
+/// their content so that the service worker is updated when they change.
+fn precache_version(urls: &[String], output_dir: &Path) -> Result<String> {
+    let mut hasher = Sha256::new();
+    for url in urls {
+        hasher.update(url.as_bytes());
+        let path = if url == "./" {
+            output_dir.join("index.html")
+        } else {
+            output_dir.join(url)
+        };
+        if let Ok(mut file) = fs::File::open(path) {
+            io::copy(&mut file, &mut hasher)?;
+        }
+    }
+    Ok(hex::encode(hasher.finalize())[..16].to_string())
+}
+
+/// Web app manifest.
+#[derive(Debug, Clone, Serialize)]
+struct Manifest {
+    name: String,
+    short_name: String,
+    start_url: String,
+    scope: String,
+    display: String,
+    background_color: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    theme_color: Option<String>,
+    icons: Vec<ManifestIcon>,
+}
+
+impl Manifest {
+    /// Create a new web app manifest from the settings provided.
+    fn new(settings: &LandscapeSettings) -> Self {
+        let name = settings
+            .meta
+            .as_ref()
+            .and_then(|meta| meta.title.clone())
+            .unwrap_or_else(|| format!("{} Landscape", settings.foundation));
+        let theme_color = settings
+            .theme
+            .as_ref()
+            .and_then(|theme| theme.primary_color.clone())
+            .or_else(|| settings.colors.as_ref().map(|colors| colors.color1.clone()));
+        let icons = settings
+            .images
+            .as_ref()
+            .and_then(|images| images.icons.as_ref())
+            .map(|icons| {
+                icons
+                    .png
+                    .iter()
+                    .map(|icon| ManifestIcon {
+                        src: icon.path.clone(),
+                        sizes: format!("{0}x{0}", icon.size),
+                        r#type: "image/png".to_string(),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Self {
+            name,
+            short_name: settings.foundation.clone(),
+            start_url: "./".to_string(),
+            scope: "./".to_string(),
+            display: "standalone".to_string(),
+            background_color: MANIFEST_BACKGROUND_COLOR.to_string(),
+            theme_color,
+            icons,
+        }
+    }
+}
+
+/// Web app manifest icon.
+#[derive(Debug, Clone, Serialize)]
+struct ManifestIcon {
+    src: String,
+    sizes: String,
+    r#type: String,
+}
+
+/// Template for the service worker.
+#[derive(Debug, Clone, Template)]
+#[template(path = "sw.js", escape = "none")]
+struct ServiceWorker<'a> {
+    runtime_cache_paths: &'a [&'a str],
+    urls: &'a [String],
+    version: &'a str,
+}
@@ -0,0 +1,70 @@
+//! This module defines the functionality used to generate the QR codes of the
+//! landscape and its items (useful for printed materials, like the ones used
+//! at conferences).
+
+use super::{logos::rasterize_svg, IMAGES_PATH, QR_CODES_PATH};
+use anyhow::Result;
+use landscape2_core::{data::LandscapeData, settings::LandscapeSettings};
+use qrcode::render::svg;
+use std::{fs, path::Path};
+use tracing::{debug, instrument, warn};
+
+/// Size (in pixels) of the QR codes PNG images.
+const QR_CODE_PNG_SIZE: u32 = 600;
+
+/// Generate the landscape QR code (SVG and PNG) and write it to the output
+/// directory, returning the path of the SVG image.
+#[instrument(skip(output_dir), err)]
+pub(crate) fn generate_qr_code(url: &str, output_dir: &Path) -> Result<String> {
+    debug!("generating qr code");
+
+    let svg_path = Path::new(IMAGES_PATH).join("qr_code.svg");
+    write_qr_code(url, output_dir, &svg_path)?;
+
+    Ok(svg_path.to_string_lossy().into_owned())
+}
+
+/// Generate a QR code (SVG and PNG) pointing to the detail view of each of the
+/// landscape items, writing them to the QR codes path in the output directory
+/// and updating the QR code reference on each item.
+#[instrument(skip_all, err)]
+pub(crate) fn generate_items_qr_codes(
+    landscape_data: &mut LandscapeData,
+    settings: &LandscapeSettings,
+    output_dir: &Path,
+) -> Result<()> {
+    debug!("generating items qr codes");
+
+    let landscape_url = settings.url.trim_end_matches('/');
+    for item in &mut landscape_data.items {
+        let url = format!("{landscape_url}/?item={}", item.id);
+        let svg_path = Path::new(QR_CODES_PATH).join(format!("{}.svg", item.id));
+        match write_qr_code(&url, output_dir, &svg_path) {
+            Ok(()) => item.qr_code = Some(svg_path.to_string_lossy().into_owned()),
+            Err(err) => warn!(?err, ?item.id, "error generating item qr code"),
+        }
+    }
+
+    debug!("done!");
+    Ok(())
+}
+
+/// Generate a QR code for the url provided and write it to the path provided
+/// in the output directory (SVG), as well as a PNG version of it next to it.
+fn write_qr_code(url: &str, output_dir: &Path, svg_path: &Path) -> Result<()> {
+    // Generate QR code
+    let code = qrcode::QrCode::new(url.as_bytes())?;
+    let svg = code
+        .render()
+        .min_dimensions(200, 200)
+        .dark_color(svg::Color("#000000"))
+        .light_color(svg::Color("#ffffff"))
+        .build();
+
+    // Write QR code (SVG and PNG) to output dir
+    fs::write(output_dir.join(svg_path), svg.as_bytes())?;
+    let png = rasterize_svg(svg.as_bytes(), QR_CODE_PNG_SIZE, true)?;
+    fs::write(output_dir.join(svg_path.with_extension("png")), png)?;
+
+    Ok(())
+}
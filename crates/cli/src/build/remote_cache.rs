@@ -0,0 +1,371 @@
+//! This module defines the remote cache backends that can be used to share
+//! the cache across builds running on different machines (i.e. ephemeral CI
+//! runners). The files available in the remote cache are downloaded to the
+//! local cache directory before the build starts, and the ones that have been
+//! updated are uploaded back once it has finished.
+
+use crate::deploy::md5sum;
+use anyhow::{bail, format_err, Context, Result};
+use async_trait::async_trait;
+use aws_sdk_s3::primitives::ByteStream;
+use base64::{engine::general_purpose::STANDARD as b64, Engine as _};
+use futures::stream::{self, StreamExt};
+use gcp_auth::TokenProvider;
+use reqwest::StatusCode;
+use serde::Deserialize;
+use std::{collections::HashMap, fs, path::Path, sync::Arc};
+use tracing::{debug, instrument};
+use url::{form_urlencoded, Url};
+use walkdir::WalkDir;
+
+/// Google Cloud Storage JSON API base url.
+const GCS_API_URL: &str = "https://storage.googleapis.com/storage/v1";
+
+/// Google Cloud Storage upload API base url.
+const GCS_UPLOAD_API_URL: &str = "https://storage.googleapis.com/upload/storage/v1";
+
+/// OAuth scope required to read and write objects.
+const GCS_SCOPE: &str = "https://www.googleapis.com/auth/devstorage.read_write";
+
+/// Number of files to transfer concurrently.
+const TRANSFER_FILES_CONCURRENCY: usize = 20;
+
+/// Type alias to represent a file's checksum (MD5 digest in hex format).
+type Checksum = String;
+
+/// Type alias to represent a file's key (path relative to the cache dir).
+type Key = String;
+
+/// Setup the remote cache backend for the url provided. Supported urls are
+/// `s3://<BUCKET>/<PREFIX>` (AWS S3) and `gs://<BUCKET>/<PREFIX>` (Google
+/// Cloud Storage), where the prefix is optional.
+pub(crate) async fn new_remote_cache(url: &str) -> Result<DynRC> {
+    let url = Url::parse(url).context("invalid remote cache url")?;
+    let Some(bucket) = url.host_str() else {
+        bail!("remote cache url must include a bucket");
+    };
+    let prefix = url.path().trim_matches('/');
+
+    match url.scheme() {
+        "s3" => Ok(Box::new(S3RC::new(bucket, prefix).await)),
+        "gs" => Ok(Box::new(GcsRC::new(bucket, prefix).await?)),
+        scheme => bail!("unsupported remote cache url scheme: {scheme}"),
+    }
+}
+
+/// Download the files available in the remote cache to the local cache
+/// directory provided (files that are already up to date are skipped).
+#[instrument(skip_all, err)]
+pub(crate) async fn pull(rc: &DynRC, cache_dir: &Path) -> Result<()> {
+    debug!("downloading files from remote cache");
+
+    let remote_files = rc.list_files().await?;
+    let results: Vec<Result<()>> = stream::iter(remote_files)
+        .map(|(key, remote_checksum)| async move {
+            // Skip files that haven't changed
+            let file = cache_dir.join(&key);
+            if file.exists() && Some(md5sum(&file)?) == remote_checksum {
+                return Ok(());
+            }
+
+            // Download file
+            let data = rc.get_file(&key).await.context(format_err!("error downloading file {key}"))?;
+            if let Some(parent_path) = file.parent() {
+                fs::create_dir_all(parent_path)?;
+            }
+            fs::write(&file, data)?;
+
+            debug!(?key, "file downloaded");
+            Ok(())
+        })
+        .buffer_unordered(TRANSFER_FILES_CONCURRENCY)
+        .collect()
+        .await;
+
+    process_results(results)
+}
+
+/// Upload the files in the local cache directory provided to the remote cache
+/// (files that haven't changed are skipped).
+#[instrument(skip_all, err)]
+pub(crate) async fn push(rc: &DynRC, cache_dir: &Path) -> Result<()> {
+    debug!("uploading files to remote cache");
+
+    let remote_files = rc.list_files().await?;
+    let results: Vec<Result<()>> = stream::iter(WalkDir::new(cache_dir))
+        .map(|entry| async {
+            // Check if the entry is a regular file
+            let entry = entry?;
+            if !entry.file_type().is_file() {
+                return Ok(());
+            }
+
+            // Skip hidden files (i.e. the cache lock)
+            let file = entry.path();
+            let key = file.strip_prefix(cache_dir)?.to_string_lossy().to_string();
+            if key.starts_with('.') {
+                return Ok(());
+            }
+
+            // Skip files that haven't changed
+            if let Some(Some(remote_checksum)) = remote_files.get(&key) {
+                if md5sum(file)? == *remote_checksum {
+                    return Ok(());
+                }
+            }
+
+            // Upload file
+            rc.put_file(&key, file).await.context(format_err!("error uploading file {key}"))?;
+
+            debug!(?key, "file uploaded");
+            Ok(())
+        })
+        .buffer_unordered(TRANSFER_FILES_CONCURRENCY)
+        .collect()
+        .await;
+
+    process_results(results)
+}
+
+/// Process the results of the files transferred, returning an error that
+/// includes all the errors found (if any).
+fn process_results(results: Vec<Result<()>>) -> Result<()> {
+    let mut errors_found = false;
+    let mut errors = String::new();
+    for result in results {
+        if let Err(err) = result {
+            errors_found = true;
+            errors.push_str(&format!("- {err:?}\n"));
+        }
+    }
+    if errors_found {
+        bail!("{errors}");
+    }
+
+    Ok(())
+}
+
+/// Prepare the object key in the remote cache for the file key provided.
+fn object_key(prefix: &str, key: &str) -> String {
+    if prefix.is_empty() {
+        key.to_string()
+    } else {
+        format!("{prefix}/{key}")
+    }
+}
+
+/// Type alias to represent a RC trait object.
+pub(crate) type DynRC = Box<dyn RC + Send + Sync>;
+
+/// Trait that defines some operations a remote cache (RC) backend must
+/// support.
+#[async_trait]
+pub(crate) trait RC {
+    /// List the files available in the remote cache, returning their key and
+    /// checksum (when available).
+    async fn list_files(&self) -> Result<HashMap<Key, Option<Checksum>>>;
+
+    /// Get the content of the file identified by the key provided.
+    async fn get_file(&self, key: &str) -> Result<Vec<u8>>;
+
+    /// Upload the file provided to the remote cache using the key given.
+    async fn put_file(&self, key: &str, file: &Path) -> Result<()>;
+}
+
+/// RC implementation backed by AWS S3.
+struct S3RC {
+    client: aws_sdk_s3::Client,
+    bucket: String,
+    prefix: String,
+}
+
+impl S3RC {
+    /// Create a new S3RC instance.
+    async fn new(bucket: &str, prefix: &str) -> Self {
+        let config = aws_config::load_defaults(aws_config::BehaviorVersion::latest()).await;
+
+        Self {
+            client: aws_sdk_s3::Client::new(&config),
+            bucket: bucket.to_string(),
+            prefix: prefix.to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl RC for S3RC {
+    /// [RC::list_files]
+    async fn list_files(&self) -> Result<HashMap<Key, Option<Checksum>>> {
+        let mut files = HashMap::new();
+
+        let mut continuation_token = None;
+        loop {
+            let mut request = self.client.list_objects_v2().bucket(&self.bucket);
+            if !self.prefix.is_empty() {
+                request = request.prefix(format!("{}/", self.prefix));
+            }
+            if let Some(token) = continuation_token {
+                request = request.continuation_token(token);
+            }
+            let output = request.send().await?;
+            for object in output.contents.unwrap_or_default() {
+                let Some(object_key) = object.key else { continue };
+                let key = object_key.trim_start_matches(&self.prefix).trim_start_matches('/').to_string();
+                let checksum = object.e_tag.map(|etag| etag.trim_matches('"').to_string());
+                files.insert(key, checksum);
+            }
+            if !output.is_truncated.unwrap_or(false) {
+                break;
+            }
+            continuation_token = output.next_continuation_token;
+        }
+
+        Ok(files)
+    }
+
+    /// [RC::get_file]
+    async fn get_file(&self, key: &str) -> Result<Vec<u8>> {
+        let output = self
+            .client
+            .get_object()
+            .bucket(&self.bucket)
+            .key(object_key(&self.prefix, key))
+            .send()
+            .await?;
+        let data = output.body.collect().await?.into_bytes().to_vec();
+
+        Ok(data)
+    }
+
+    /// [RC::put_file]
+    async fn put_file(&self, key: &str, file: &Path) -> Result<()> {
+        self.client
+            .put_object()
+            .bucket(&self.bucket)
+            .key(object_key(&self.prefix, key))
+            .body(ByteStream::from_path(file).await?)
+            .send()
+            .await?;
+
+        Ok(())
+    }
+}
+
+/// RC implementation backed by Google Cloud Storage (JSON API).
+struct GcsRC {
+    http_client: reqwest::Client,
+    token_provider: Arc<dyn TokenProvider>,
+    bucket: String,
+    prefix: String,
+}
+
+impl GcsRC {
+    /// Create a new GcsRC instance.
+    async fn new(bucket: &str, prefix: &str) -> Result<Self> {
+        Ok(Self {
+            http_client: reqwest::Client::new(),
+            token_provider: gcp_auth::provider().await.context("error setting up gcp authentication")?,
+            bucket: bucket.to_string(),
+            prefix: prefix.to_string(),
+        })
+    }
+
+    /// Get an access token to authenticate requests (tokens are cached and
+    /// refreshed automatically by the provider).
+    async fn token(&self) -> Result<String> {
+        let token = self.token_provider.token(&[GCS_SCOPE]).await?;
+        Ok(token.as_str().to_string())
+    }
+}
+
+#[async_trait]
+impl RC for GcsRC {
+    /// [RC::list_files]
+    async fn list_files(&self) -> Result<HashMap<Key, Option<Checksum>>> {
+        let mut files = HashMap::new();
+
+        let url = format!("{GCS_API_URL}/b/{}/o", self.bucket);
+        let mut page_token: Option<String> = None;
+        loop {
+            let mut query = vec![("fields", "items(name,md5Hash),nextPageToken".to_string())];
+            if !self.prefix.is_empty() {
+                query.push(("prefix", format!("{}/", self.prefix)));
+            }
+            if let Some(token) = page_token {
+                query.push(("pageToken", token));
+            }
+            let resp =
+                self.http_client.get(&url).bearer_auth(self.token().await?).query(&query).send().await?;
+            if resp.status() != StatusCode::OK {
+                bail!("unexpected status code listing objects: {}", resp.status());
+            }
+            let output: ListObjectsOutput = resp.json().await?;
+            for object in output.items.unwrap_or_default() {
+                let key = object.name.trim_start_matches(&self.prefix).trim_start_matches('/').to_string();
+                // GCS provides the MD5 digest encoded in base64
+                let checksum =
+                    object.md5_hash.and_then(|md5_hash| b64.decode(md5_hash).ok()).map(hex::encode);
+                files.insert(key, checksum);
+            }
+            if output.next_page_token.is_none() {
+                break;
+            }
+            page_token = output.next_page_token;
+        }
+
+        Ok(files)
+    }
+
+    /// [RC::get_file]
+    async fn get_file(&self, key: &str) -> Result<Vec<u8>> {
+        let object_key = object_key(&self.prefix, key);
+        let object_key: String = form_urlencoded::byte_serialize(object_key.as_bytes()).collect();
+        let url = format!("{GCS_API_URL}/b/{}/o/{object_key}", self.bucket);
+        let resp = self
+            .http_client
+            .get(url)
+            .bearer_auth(self.token().await?)
+            .query(&[("alt", "media")])
+            .send()
+            .await?;
+        if resp.status() != StatusCode::OK {
+            bail!("unexpected status code getting object: {}", resp.status());
+        }
+
+        Ok(resp.bytes().await?.to_vec())
+    }
+
+    /// [RC::put_file]
+    async fn put_file(&self, key: &str, file: &Path) -> Result<()> {
+        let url = format!("{GCS_UPLOAD_API_URL}/b/{}/o", self.bucket);
+        let resp = self
+            .http_client
+            .post(url)
+            .bearer_auth(self.token().await?)
+            .query(&[("uploadType", "media"), ("name", &object_key(&self.prefix, key))])
+            .body(fs::read(file)?)
+            .send()
+            .await?;
+        if resp.status() != StatusCode::OK {
+            bail!("unexpected status code uploading object: {}", resp.status());
+        }
+
+        Ok(())
+    }
+}
+
+/// List objects response.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ListObjectsOutput {
+    items: Option<Vec<Object>>,
+    next_page_token: Option<String>,
+}
+
+/// Object information.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Object {
+    name: String,
+    md5_hash: Option<String>,
+}
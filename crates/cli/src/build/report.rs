@@ -0,0 +1,193 @@
+//! This module defines the build report, which includes some information
+//! about the build that can be used in CI to surface problems and trends
+//! (i.e. how long each phase took, the errors found while processing the
+//! items or how much of the external services data was reused from the
+//! cache).
+
+use anyhow::{bail, Result};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::Path,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+use tracing::warn;
+
+/// File the build report is written to in the output directory.
+pub(crate) const BUILD_REPORT_FILE: &str = "build-report.json";
+
+/// Build report. It can be shared across the build phases, which record
+/// their information in it as they run.
+#[derive(Debug, Default)]
+pub(crate) struct BuildReport {
+    data: Mutex<BuildReportData>,
+}
+
+impl BuildReport {
+    /// Create a new BuildReport instance.
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record an error found while processing the resource provided (i.e. an
+    /// item's logo or repository) of the given service.
+    pub(crate) fn add_error(&self, service: &str, resource: &str, err: &anyhow::Error) {
+        let mut data = self.data.lock().expect("not poisoned");
+        data.errors.push(ItemError {
+            service: service.to_string(),
+            resource: resource.to_string(),
+            error: format!("{err:#}"),
+        });
+    }
+
+    /// Check the errors recorded for the services provided. In strict mode,
+    /// an error including all of them is returned. Otherwise a warning
+    /// summarizing them is logged and the build goes on.
+    pub(crate) fn check_errors(&self, services: &[&str], strict: bool) -> Result<()> {
+        let data = self.data.lock().expect("not poisoned");
+        let errors: Vec<&ItemError> =
+            data.errors.iter().filter(|err| services.contains(&err.service.as_str())).collect();
+        if errors.is_empty() {
+            return Ok(());
+        }
+
+        if strict {
+            let mut errors_msg = String::new();
+            for err in errors {
+                errors_msg.push_str(&format!("- [{}] {}: {}\n", err.service, err.resource, err.error));
+            }
+            bail!("some items could not be processed (strict mode enabled):\n{errors_msg}");
+        }
+        warn!(
+            errors = errors.len(),
+            ?services,
+            "some items could not be processed (see the build report for details)"
+        );
+
+        Ok(())
+    }
+
+    /// Record that the phase provided has finished (it started at `start`).
+    pub(crate) fn add_phase(&self, name: &str, start: Instant) {
+        let mut data = self.data.lock().expect("not poisoned");
+        data.phases.push(Phase {
+            name: name.to_string(),
+            duration_ms: duration_ms(start.elapsed()),
+        });
+    }
+
+    /// Record the stats of the service provided.
+    pub(crate) fn set_service_stats(&self, service: &str, mut stats: ServiceStats) {
+        stats.cache_hit_rate = stats.calculate_cache_hit_rate();
+        let mut data = self.data.lock().expect("not poisoned");
+        data.services.insert(service.to_string(), stats);
+    }
+
+    /// Write the report to the output directory provided.
+    pub(crate) fn write(&self, output_dir: &Path, duration: Duration) -> Result<()> {
+        let mut data = self.data.lock().expect("not poisoned").clone();
+        data.generated_at = Utc::now();
+        data.duration_ms = duration_ms(duration);
+        fs::write(
+            output_dir.join(BUILD_REPORT_FILE),
+            serde_json::to_vec_pretty(&data)?,
+        )?;
+
+        Ok(())
+    }
+
+    /// Print a summary of the report.
+    pub(crate) fn print_summary(&self) {
+        let data = self.data.lock().expect("not poisoned");
+
+        println!("\nBuild phases:\n");
+        for phase in &data.phases {
+            println!("  {:<24} {:>8}ms", phase.name, phase.duration_ms);
+        }
+
+        println!("\nServices:\n");
+        println!(
+            "  {:<12} {:>8} {:>10} {:>8} {:>10} {:>10}",
+            "SERVICE", "CACHED", "COLLECTED", "ERRORS", "API CALLS", "HIT RATE"
+        );
+        for (service, stats) in &data.services {
+            println!(
+                "  {:<12} {:>8} {:>10} {:>8} {:>10} {:>10}",
+                service,
+                stats.cached,
+                stats.collected,
+                stats.errors,
+                stats.api_calls,
+                stats.cache_hit_rate.map_or("-".to_string(), |rate| format!("{:.1}%", rate * 100.0)),
+            );
+        }
+
+        if !data.errors.is_empty() {
+            println!("\nErrors ({}):\n", data.errors.len());
+            for err in &data.errors {
+                println!("  [{}] {}: {}", err.service, err.resource, err.error);
+            }
+        }
+    }
+}
+
+/// Build report data.
+#[derive(Debug, Clone, Default, Serialize)]
+struct BuildReportData {
+    generated_at: DateTime<Utc>,
+    duration_ms: u64,
+    phases: Vec<Phase>,
+    services: BTreeMap<String, ServiceStats>,
+    errors: Vec<ItemError>,
+}
+
+/// Build phase information.
+#[derive(Debug, Clone, Serialize)]
+struct Phase {
+    name: String,
+    duration_ms: u64,
+}
+
+/// Some stats about the data collected from an external service.
+#[derive(Debug, Clone, Default, Serialize)]
+pub(crate) struct ServiceStats {
+    /// Number of resources reused from the cache.
+    pub cached: usize,
+    /// Number of resources collected from the service.
+    pub collected: usize,
+    /// Number of resources that could not be collected.
+    pub errors: usize,
+    /// Number of requests sent to the service.
+    pub api_calls: usize,
+    /// Ratio of resources reused from the cache.
+    pub cache_hit_rate: Option<f64>,
+}
+
+impl ServiceStats {
+    /// Return the ratio of resources reused from the cache (if any resources
+    /// were processed).
+    #[allow(clippy::cast_precision_loss)]
+    fn calculate_cache_hit_rate(&self) -> Option<f64> {
+        let total = self.cached + self.collected + self.errors;
+        if total == 0 {
+            return None;
+        }
+        Some(self.cached as f64 / total as f64)
+    }
+}
+
+/// Error found while processing an item's resource.
+#[derive(Debug, Clone, Serialize)]
+struct ItemError {
+    service: String,
+    resource: String,
+    error: String,
+}
+
+/// Return the duration provided in milliseconds.
+fn duration_ms(duration: Duration) -> u64 {
+    u64::try_from(duration.as_millis()).unwrap_or(u64::MAX)
+}
@@ -0,0 +1,108 @@
+//! This module defines the policy used to retry the requests sent to external
+//! services (i.e. GitHub, Crunchbase or remote logos), so that transient
+//! errors don't result in missing data or failed builds.
+
+use rand::Rng;
+use reqwest::{Client, Request, RequestBuilder, Response, StatusCode};
+use std::time::Duration;
+use tracing::debug;
+
+/// Default maximum number of times a request is retried.
+pub(crate) const DEFAULT_MAX_RETRIES: u32 = 3;
+
+/// Default delay before retrying a request for the first time (in ms).
+pub(crate) const DEFAULT_BACKOFF: u64 = 500;
+
+/// Default jitter applied to the delay between retries.
+pub(crate) const DEFAULT_JITTER: f64 = 0.5;
+
+/// Policy used to retry requests on network errors or when the service is
+/// not available. The delay between retries grows exponentially.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct RetryPolicy {
+    /// Maximum number of times a request is retried.
+    pub max_retries: u32,
+    /// Delay before retrying a request for the first time (it is doubled on
+    /// each retry).
+    pub backoff: Duration,
+    /// Ratio of the delay that is randomized, so that concurrent requests
+    /// don't retry at the same time (i.e. with a jitter of 0.5, a delay of 1s
+    /// will be between 0.5s and 1.5s).
+    pub jitter: f64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: DEFAULT_MAX_RETRIES,
+            backoff: Duration::from_millis(DEFAULT_BACKOFF),
+            jitter: DEFAULT_JITTER,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Return the delay to wait before sending the retry provided (starting
+    /// from zero).
+    pub(crate) fn delay(&self, retry: u32) -> Duration {
+        let delay = self.backoff.saturating_mul(2u32.saturating_pow(retry));
+        let jitter = self.jitter.clamp(0.0, 1.0);
+        if jitter <= 0.0 {
+            return delay;
+        }
+        delay.mul_f64(rand::thread_rng().gen_range(1.0 - jitter..=1.0 + jitter))
+    }
+
+    /// Check if a request should be retried when the status code provided is
+    /// received.
+    pub(crate) fn is_retryable_status(status: StatusCode) -> bool {
+        status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS
+    }
+
+    /// Check if a request should be retried after the error provided.
+    pub(crate) fn is_retryable_error(err: &reqwest::Error) -> bool {
+        err.is_connect() || err.is_timeout() || err.is_request()
+    }
+
+    /// Build and send the request provided, retrying it following this policy
+    /// on network errors or when the service is not available.
+    pub(crate) async fn send(&self, req: RequestBuilder) -> reqwest::Result<Response> {
+        let (client, req) = req.build_split();
+        self.execute(&client, req?, || {}).await
+    }
+
+    /// Execute the request provided, retrying it following this policy on
+    /// network errors or when the service is not available. The function
+    /// provided is called before each attempt (e.g. to count the requests
+    /// sent to the service).
+    pub(crate) async fn execute(
+        &self,
+        client: &Client,
+        req: Request,
+        on_attempt: impl Fn(),
+    ) -> reqwest::Result<Response> {
+        let mut retry = 0;
+        loop {
+            // Requests whose body cannot be cloned are not retried
+            let Some(attempt_req) = req.try_clone() else {
+                on_attempt();
+                return client.execute(req).await;
+            };
+
+            on_attempt();
+            let result = client.execute(attempt_req).await;
+            let retryable = match &result {
+                Ok(resp) => Self::is_retryable_status(resp.status()),
+                Err(err) => Self::is_retryable_error(err),
+            };
+            if !retryable || retry >= self.max_retries {
+                return result;
+            }
+
+            let delay = self.delay(retry);
+            debug!(url = %req.url(), retry, ?delay, "retrying request");
+            tokio::time::sleep(delay).await;
+            retry += 1;
+        }
+    }
+}
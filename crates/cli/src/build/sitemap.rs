@@ -0,0 +1,77 @@
+//! This module defines the functionality used to generate the `sitemap.xml`
+//! file, which lists the landscape url as well as the deep links to each of
+//! the items, so that search engines can index them. The `robots.txt` file
+//! (referencing the sitemap) is generated here as well.
+
+use anyhow::Result;
+use askama::Template;
+use chrono::Utc;
+use landscape2_core::{data::LandscapeData, settings::LandscapeSettings};
+use std::{fmt::Write, fs, path::Path};
+use tracing::{debug, instrument, warn};
+
+/// Maximum number of urls allowed in a sitemap file.
+const SITEMAP_MAX_URLS: usize = 50_000;
+
+/// Template for the sitemap.xml file.
+#[derive(Debug, Clone, Template)]
+#[template(path = "sitemap.xml")]
+struct Sitemap<'a> {
+    lastmod: &'a str,
+    urls: &'a [String],
+}
+
+/// Generate the sitemap.xml file and write it to the output directory. It
+/// includes the landscape url and the url of the detail view of each item,
+/// built from the landscape url in the settings.
+#[instrument(skip_all, err)]
+pub(crate) fn generate_sitemap(
+    landscape_data: &LandscapeData,
+    settings: &LandscapeSettings,
+    output_dir: &Path,
+) -> Result<()> {
+    debug!("generating sitemap");
+
+    let landscape_url = settings.url.trim_end_matches('/');
+    let mut urls = vec![format!("{landscape_url}/")];
+    urls.extend(landscape_data.items.iter().map(|item| format!("{landscape_url}/?item={}", item.id)));
+    if urls.len() > SITEMAP_MAX_URLS {
+        warn!(
+            urls = urls.len(),
+            "sitemap exceeds the maximum number of urls allowed, some of them will be ignored"
+        );
+        urls.truncate(SITEMAP_MAX_URLS);
+    }
+
+    let lastmod = Utc::now().format("%Y-%m-%d").to_string();
+    let sitemap = Sitemap {
+        lastmod: &lastmod,
+        urls: &urls,
+    }
+    .render()?;
+    fs::write(output_dir.join("sitemap.xml"), sitemap)?;
+
+    Ok(())
+}
+
+/// Generate the robots.txt file from the configuration in the settings and
+/// write it to the output directory.
+#[instrument(skip_all, err)]
+pub(crate) fn generate_robots_txt(settings: &LandscapeSettings, output_dir: &Path) -> Result<()> {
+    debug!("generating robots.txt");
+
+    let mut robots_txt = String::from("User-agent: *\n");
+    if settings.robots.allow {
+        for path in &settings.robots.disallow {
+            writeln!(robots_txt, "Disallow: {path}")?;
+        }
+        robots_txt.push_str("Allow: /\n");
+    } else {
+        robots_txt.push_str("Disallow: /\n");
+    }
+    let landscape_url = settings.url.trim_end_matches('/');
+    writeln!(robots_txt, "\nSitemap: {landscape_url}/sitemap.xml")?;
+    fs::write(output_dir.join("robots.txt"), robots_txt)?;
+
+    Ok(())
+}
@@ -0,0 +1,310 @@
+//! This module defines the functionality used to collect the number of
+//! followers of the social accounts (Bluesky, Mastodon, Twitter/X and
+//! YouTube) declared by the landscape items (when applicable).
+
+use super::{cache::Cache, collector::Collector, retry::RetryPolicy, LandscapeData};
+use anyhow::{bail, format_err, Result};
+use async_trait::async_trait;
+use chrono::Utc;
+use landscape2_core::data::{SocialAccountData, SocialData};
+use lazy_static::lazy_static;
+#[cfg(test)]
+use mockall::automock;
+use regex::Regex;
+use reqwest::{RequestBuilder, StatusCode};
+use serde::{de::DeserializeOwned, Deserialize};
+use std::{env, sync::Arc};
+use tracing::{debug, instrument};
+
+/// File used to cache data collected from the social networks.
+pub(crate) const SOCIAL_CACHE_FILE: &str = "social.json";
+
+/// How long the social data in the cache is valid (in days).
+const SOCIAL_CACHE_TTL: i64 = 7;
+
+/// Maximum number of social accounts to process concurrently.
+const SOCIAL_MAX_CONCURRENCY: usize = 5;
+
+/// Environment variable containing the Twitter/X API bearer token.
+const TWITTER_BEARER_TOKEN: &str = "TWITTER_BEARER_TOKEN";
+
+/// Environment variable containing the YouTube Data API key.
+const YOUTUBE_API_KEY: &str = "YOUTUBE_API_KEY";
+
+/// Collect the number of followers of the social accounts declared by the
+/// items in the landscape, reusing cached data whenever possible.
+#[instrument(skip_all, err)]
+pub(crate) async fn collect_social_data(cache: &Cache, landscape_data: &LandscapeData) -> Result<SocialData> {
+    debug!("collecting social accounts information");
+
+    // Setup social networks client. Twitter/X and YouTube data can only be
+    // collected when the corresponding credentials are provided.
+    let sn: DynSN = Arc::new(SNApi::new(
+        env::var(TWITTER_BEARER_TOKEN).ok().filter(|v| !v.is_empty()),
+        env::var(YOUTUBE_API_KEY).ok().filter(|v| !v.is_empty()),
+        cache.retry_policy(),
+    )?);
+
+    // Collect items social accounts
+    let mut accounts = vec![];
+    for item in &landscape_data.items {
+        accounts.extend(item.social_accounts());
+    }
+    accounts.sort();
+    accounts.dedup();
+
+    // Collect social accounts information, reusing cached data when available
+    let collector = Collector {
+        cache,
+        cache_file: SOCIAL_CACHE_FILE,
+        cache_ttl: SOCIAL_CACHE_TTL,
+        max_concurrency: SOCIAL_MAX_CONCURRENCY,
+        description: "social account data",
+    };
+    let entries = accounts.into_iter().map(|(platform, url)| (url.clone(), (platform, url))).collect();
+    let social_data = collector
+        .collect(entries, |(platform, url), _| {
+            let sn = sn.clone();
+            async move { collect_account_data(sn, platform, &url).await }
+        })
+        .await?;
+
+    debug!("done!");
+    Ok(social_data)
+}
+
+/// Collect social account data from the corresponding social network.
+#[instrument(skip(sn), err)]
+async fn collect_account_data(sn: DynSN, platform: &str, url: &str) -> Result<SocialAccountData> {
+    let invalid_url = || format_err!("invalid {platform} url");
+    let followers = match platform {
+        "bluesky" => {
+            let c = BLUESKY_URL.captures(url).ok_or_else(invalid_url)?;
+            sn.get_bluesky_followers(&c["handle"]).await?
+        }
+        "mastodon" => {
+            let c = MASTODON_URL.captures(url).ok_or_else(invalid_url)?;
+            sn.get_mastodon_followers(&c["host"], &c["username"]).await?
+        }
+        "twitter" => {
+            let c = TWITTER_URL.captures(url).ok_or_else(invalid_url)?;
+            sn.get_twitter_followers(&c["username"]).await?
+        }
+        "youtube" => {
+            let c = YOUTUBE_URL.captures(url).ok_or_else(invalid_url)?;
+            let channel = match (c.name("handle"), c.name("channel_id")) {
+                (Some(handle), _) => YouTubeChannel::Handle(format!("@{}", handle.as_str())),
+                (None, Some(channel_id)) => YouTubeChannel::Id(channel_id.as_str().to_string()),
+                (None, None) => return Err(invalid_url()),
+            };
+            sn.get_youtube_subscribers(&channel).await?
+        }
+        platform => bail!("unsupported social network: {platform}"),
+    };
+
+    Ok(SocialAccountData {
+        generated_at: Utc::now(),
+        platform: platform.to_string(),
+        followers,
+    })
+}
+
+/// Bluesky public API base url.
+const BLUESKY_API_URL: &str = "https://public.api.bsky.app/xrpc";
+
+/// Twitter/X API base url.
+const TWITTER_API_URL: &str = "https://api.twitter.com/2";
+
+/// YouTube Data API base url.
+const YOUTUBE_API_URL: &str = "https://www.googleapis.com/youtube/v3";
+
+/// YouTube channel identifier.
+#[derive(Debug, Clone, PartialEq)]
+enum YouTubeChannel {
+    Handle(String),
+    Id(String),
+}
+
+/// Type alias to represent a SN trait object.
+type DynSN = Arc<dyn SN + Send + Sync>;
+
+/// Trait that defines some operations a SN (social networks) implementation
+/// must support.
+#[async_trait]
+#[cfg_attr(test, automock)]
+trait SN {
+    /// Get number of followers of a Bluesky account.
+    async fn get_bluesky_followers(&self, handle: &str) -> Result<Option<u64>>;
+
+    /// Get number of followers of a Mastodon account.
+    async fn get_mastodon_followers(&self, host: &str, username: &str) -> Result<Option<u64>>;
+
+    /// Get number of followers of a Twitter/X account.
+    async fn get_twitter_followers(&self, username: &str) -> Result<Option<u64>>;
+
+    /// Get number of subscribers of a YouTube channel.
+    async fn get_youtube_subscribers(&self, channel: &YouTubeChannel) -> Result<Option<u64>>;
+}
+
+/// SN implementation backed by the social networks APIs.
+struct SNApi {
+    http_client: reqwest::Client,
+    retry_policy: RetryPolicy,
+    twitter_token: Option<String>,
+    youtube_key: Option<String>,
+}
+
+impl SNApi {
+    /// Create a new SNApi instance.
+    fn new(
+        twitter_token: Option<String>,
+        youtube_key: Option<String>,
+        retry_policy: RetryPolicy,
+    ) -> Result<Self> {
+        let user_agent = format!("{}/{}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"));
+        let http_client = reqwest::Client::builder().user_agent(user_agent).build()?;
+
+        Ok(Self {
+            http_client,
+            retry_policy,
+            twitter_token,
+            youtube_key,
+        })
+    }
+
+    /// Send the request provided and return the response body deserialized.
+    async fn send<T: DeserializeOwned>(&self, req: RequestBuilder) -> Result<T> {
+        let resp = self.retry_policy.send(req).await?;
+        if resp.status() != StatusCode::OK {
+            bail!("unexpected status code getting account: {}", resp.status());
+        }
+        Ok(resp.json().await?)
+    }
+}
+
+#[async_trait]
+impl SN for SNApi {
+    /// [SN::get_bluesky_followers]
+    #[instrument(skip(self), err)]
+    async fn get_bluesky_followers(&self, handle: &str) -> Result<Option<u64>> {
+        let url = format!("{BLUESKY_API_URL}/app.bsky.actor.getProfile");
+        let profile: BlueskyProfile =
+            self.send(self.http_client.get(url).query(&[("actor", handle)])).await?;
+        Ok(profile.followers_count)
+    }
+
+    /// [SN::get_mastodon_followers]
+    #[instrument(skip(self), err)]
+    async fn get_mastodon_followers(&self, host: &str, username: &str) -> Result<Option<u64>> {
+        let url = format!("https://{host}/api/v1/accounts/lookup");
+        let account: MastodonAccount =
+            self.send(self.http_client.get(url).query(&[("acct", username)])).await?;
+        Ok(account.followers_count)
+    }
+
+    /// [SN::get_twitter_followers]
+    #[instrument(skip(self), err)]
+    async fn get_twitter_followers(&self, username: &str) -> Result<Option<u64>> {
+        let Some(token) = &self.twitter_token else {
+            bail!("twitter bearer token not provided");
+        };
+        let url = format!("{TWITTER_API_URL}/users/by/username/{username}");
+        let req = self.http_client.get(url).bearer_auth(token).query(&[("user.fields", "public_metrics")]);
+        let resp: TwitterUserResponse = self.send(req).await?;
+        Ok(resp.data.public_metrics.map(|m| m.followers_count))
+    }
+
+    /// [SN::get_youtube_subscribers]
+    #[instrument(skip(self), err)]
+    async fn get_youtube_subscribers(&self, channel: &YouTubeChannel) -> Result<Option<u64>> {
+        let Some(key) = &self.youtube_key else {
+            bail!("youtube api key not provided");
+        };
+        let (filter, value) = match channel {
+            YouTubeChannel::Handle(handle) => ("forHandle", handle.as_str()),
+            YouTubeChannel::Id(id) => ("id", id.as_str()),
+        };
+        let url = format!("{YOUTUBE_API_URL}/channels");
+        let req = self.http_client.get(url).query(&[
+            ("part", "statistics"),
+            (filter, value),
+            ("key", key.as_str()),
+        ]);
+        let resp: YouTubeChannelsResponse = self.send(req).await?;
+        let channel = resp.items.into_iter().next().ok_or_else(|| format_err!("channel not found"))?;
+        Ok(channel.statistics.subscriber_count.and_then(|v| v.parse().ok()))
+    }
+}
+
+/// Bluesky profile details.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BlueskyProfile {
+    followers_count: Option<u64>,
+}
+
+/// Mastodon account details.
+#[derive(Debug, Deserialize)]
+struct MastodonAccount {
+    followers_count: Option<u64>,
+}
+
+/// Twitter/X user lookup response.
+#[derive(Debug, Deserialize)]
+struct TwitterUserResponse {
+    data: TwitterUser,
+}
+
+/// Twitter/X user details.
+#[derive(Debug, Deserialize)]
+struct TwitterUser {
+    public_metrics: Option<TwitterPublicMetrics>,
+}
+
+/// Twitter/X user public metrics.
+#[derive(Debug, Deserialize)]
+struct TwitterPublicMetrics {
+    followers_count: u64,
+}
+
+/// YouTube channels list response.
+#[derive(Debug, Deserialize)]
+struct YouTubeChannelsResponse {
+    #[serde(default)]
+    items: Vec<YouTubeChannelDetails>,
+}
+
+/// YouTube channel details.
+#[derive(Debug, Deserialize)]
+struct YouTubeChannelDetails {
+    statistics: YouTubeChannelStatistics,
+}
+
+/// YouTube channel statistics (counts are returned as strings).
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct YouTubeChannelStatistics {
+    subscriber_count: Option<String>,
+}
+
+lazy_static! {
+    /// Bluesky profile url regular expression.
+    static ref BLUESKY_URL: Regex =
+        Regex::new("^https://bsky\\.app/profile/(?P<handle>[^/?#]+)/?$")
+            .expect("exprs in BLUESKY_URL to be valid");
+
+    /// Mastodon account url regular expression.
+    static ref MASTODON_URL: Regex =
+        Regex::new("^https://(?P<host>[^/]+)/@(?P<username>[^/?#]+)/?$")
+            .expect("exprs in MASTODON_URL to be valid");
+
+    /// Twitter/X account url regular expression.
+    static ref TWITTER_URL: Regex =
+        Regex::new("^https://(?:www\\.)?(?:twitter|x)\\.com/(?P<username>[^/?#]+)/?$")
+            .expect("exprs in TWITTER_URL to be valid");
+
+    /// YouTube channel url regular expression.
+    static ref YOUTUBE_URL: Regex =
+        Regex::new("^https://(?:www\\.)?youtube\\.com/(?:@(?P<handle>[^/?#]+)|channel/(?P<channel_id>[^/?#]+))/?$")
+            .expect("exprs in YOUTUBE_URL to be valid");
+}
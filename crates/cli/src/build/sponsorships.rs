@@ -0,0 +1,252 @@
+//! This module defines the functionality used to collect sponsorship
+//! information (backers and monthly budget) from GitHub Sponsors and Open
+//! Collective for each of the landscape items that provide the corresponding
+//! urls (when applicable).
+
+use super::{
+    cache::Cache,
+    collector::Collector,
+    github::{get_tokens, GITHUB_TOKENS},
+    LandscapeData,
+};
+use anyhow::{bail, format_err, Result};
+use async_trait::async_trait;
+use chrono::Utc;
+use landscape2_core::data::{SponsorshipData, SponsorshipsData};
+use lazy_static::lazy_static;
+#[cfg(test)]
+use mockall::automock;
+use regex::Regex;
+use reqwest::StatusCode;
+use serde::Deserialize;
+use serde_json::json;
+use std::sync::Arc;
+use tracing::{debug, instrument};
+
+/// File used to cache data collected from the sponsorship platforms.
+pub(crate) const SPONSORSHIPS_CACHE_FILE: &str = "sponsorships.json";
+
+/// How long the sponsorships data in the cache is valid (in days).
+const SPONSORSHIPS_CACHE_TTL: i64 = 7;
+
+/// Maximum number of sponsorship urls to process concurrently.
+const SPONSORSHIPS_MAX_CONCURRENCY: usize = 5;
+
+/// Collect sponsorship information for each of the items in the landscape
+/// that provide a GitHub Sponsors or Open Collective url, reusing cached data
+/// whenever possible.
+#[instrument(skip_all, err)]
+pub(crate) async fn collect_sponsorships_data(
+    cache: &Cache,
+    landscape_data: &LandscapeData,
+) -> Result<SponsorshipsData> {
+    debug!("collecting sponsorships information");
+
+    // Setup sponsorship platforms client. GitHub Sponsors data can only be
+    // collected when a GitHub token is available.
+    let gh_token = get_tokens(GITHUB_TOKENS).and_then(|tokens| tokens.into_iter().next());
+    let sp: DynSP = Arc::new(SPApi::new(gh_token)?);
+
+    // Collect items sponsorship urls
+    let mut urls = vec![];
+    for item in &landscape_data.items {
+        if let Some(url) = &item.github_sponsors_url {
+            urls.push(url.clone());
+        }
+        if let Some(url) = &item.open_collective_url {
+            urls.push(url.clone());
+        }
+    }
+    urls.sort();
+    urls.dedup();
+
+    // Collect sponsorship information, reusing cached data when available
+    let collector = Collector {
+        cache,
+        cache_file: SPONSORSHIPS_CACHE_FILE,
+        cache_ttl: SPONSORSHIPS_CACHE_TTL,
+        max_concurrency: SPONSORSHIPS_MAX_CONCURRENCY,
+        description: "sponsorship data",
+    };
+    let entries = urls.into_iter().map(|url| (url.clone(), url)).collect();
+    let sponsorships_data = collector
+        .collect(entries, |url, _| {
+            let sp = sp.clone();
+            async move { collect_sponsorship_data(sp, &url).await }
+        })
+        .await?;
+
+    debug!("done!");
+    Ok(sponsorships_data)
+}
+
+/// Collect sponsorship data from the platform the url provided belongs to.
+#[instrument(skip(sp), err)]
+async fn collect_sponsorship_data(sp: DynSP, url: &str) -> Result<SponsorshipData> {
+    if let Some(c) = GITHUB_SPONSORS_URL.captures(url) {
+        let sponsorable = sp.get_github_sponsorable(&c["login"]).await?;
+        return Ok(SponsorshipData {
+            generated_at: Utc::now(),
+            backers: sponsorable.sponsors.map(|s| s.total_count),
+            currency: Some("USD".to_string()),
+            monthly_budget: sponsorable.monthly_estimated_sponsors_income_in_cents.map(|v| v / 100),
+        });
+    }
+
+    if let Some(c) = OPEN_COLLECTIVE_URL.captures(url) {
+        let collective = sp.get_open_collective(&c["slug"]).await?;
+        return Ok(SponsorshipData {
+            generated_at: Utc::now(),
+            backers: collective.backers_count,
+            currency: collective.currency,
+            monthly_budget: collective.yearly_income.map(|v| v / 12 / 100),
+        });
+    }
+
+    bail!("unsupported sponsorship url")
+}
+
+/// GitHub GraphQL API url.
+const GITHUB_GRAPHQL_API_URL: &str = "https://api.github.com/graphql";
+
+/// Open Collective base url.
+const OPEN_COLLECTIVE_URL_BASE: &str = "https://opencollective.com";
+
+/// GraphQL query used to get the sponsors information of a GitHub user or
+/// organization.
+const GITHUB_SPONSORABLE_QUERY: &str = "
+    query($login: String!) {
+        repositoryOwner(login: $login) {
+            ... on Sponsorable {
+                monthlyEstimatedSponsorsIncomeInCents
+                sponsors {
+                    totalCount
+                }
+            }
+        }
+    }
+";
+
+/// Type alias to represent a SP trait object.
+type DynSP = Arc<dyn SP + Send + Sync>;
+
+/// Trait that defines some operations a SP (sponsorship platforms)
+/// implementation must support.
+#[async_trait]
+#[cfg_attr(test, automock)]
+trait SP {
+    /// Get sponsors information of a GitHub user or organization.
+    async fn get_github_sponsorable(&self, login: &str) -> Result<GHSponsorable>;
+
+    /// Get Open Collective collective information.
+    async fn get_open_collective(&self, slug: &str) -> Result<OCCollective>;
+}
+
+/// SP implementation backed by the GitHub and Open Collective APIs.
+struct SPApi {
+    gh_token: Option<String>,
+    http_client: reqwest::Client,
+}
+
+impl SPApi {
+    /// Create a new SPApi instance.
+    fn new(gh_token: Option<String>) -> Result<Self> {
+        let user_agent = format!("{}/{}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"));
+        let http_client = reqwest::Client::builder().user_agent(user_agent).build()?;
+
+        Ok(Self {
+            gh_token,
+            http_client,
+        })
+    }
+}
+
+#[async_trait]
+impl SP for SPApi {
+    /// [SP::get_github_sponsorable]
+    #[instrument(skip(self), err)]
+    async fn get_github_sponsorable(&self, login: &str) -> Result<GHSponsorable> {
+        let Some(gh_token) = &self.gh_token else {
+            bail!("github token not provided");
+        };
+        let body = json!({
+            "query": GITHUB_SPONSORABLE_QUERY,
+            "variables": { "login": login },
+        });
+        let resp = self
+            .http_client
+            .post(GITHUB_GRAPHQL_API_URL)
+            .bearer_auth(gh_token)
+            .json(&body)
+            .send()
+            .await?;
+        if resp.status() != StatusCode::OK {
+            bail!("unexpected status code getting sponsorable: {}", resp.status());
+        }
+        let gql_resp: GHSponsorableResponse = resp.json().await?;
+
+        gql_resp
+            .data
+            .repository_owner
+            .ok_or_else(|| format_err!("user or organization not found"))
+    }
+
+    /// [SP::get_open_collective]
+    #[instrument(skip(self), err)]
+    async fn get_open_collective(&self, slug: &str) -> Result<OCCollective> {
+        let resp = self.http_client.get(format!("{OPEN_COLLECTIVE_URL_BASE}/{slug}.json")).send().await?;
+        if resp.status() != StatusCode::OK {
+            bail!("unexpected status code getting collective: {}", resp.status());
+        }
+        Ok(resp.json().await?)
+    }
+}
+
+/// GitHub sponsorable GraphQL query response.
+#[derive(Debug, Clone, Deserialize)]
+struct GHSponsorableResponse {
+    data: GHSponsorableResponseData,
+}
+
+/// GitHub sponsorable GraphQL query response data.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GHSponsorableResponseData {
+    repository_owner: Option<GHSponsorable>,
+}
+
+/// GitHub sponsorable details.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GHSponsorable {
+    monthly_estimated_sponsors_income_in_cents: Option<i64>,
+    sponsors: Option<GHSponsors>,
+}
+
+/// GitHub sponsors details.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GHSponsors {
+    total_count: u64,
+}
+
+/// Open Collective collective details.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct OCCollective {
+    backers_count: Option<u64>,
+    currency: Option<String>,
+    yearly_income: Option<i64>,
+}
+
+lazy_static! {
+    /// GitHub Sponsors url regular expression.
+    static ref GITHUB_SPONSORS_URL: Regex =
+        Regex::new("^https://github\\.com/sponsors/(?P<login>[^/]+)/?$")
+            .expect("exprs in GITHUB_SPONSORS_URL to be valid");
+
+    /// Open Collective url regular expression.
+    static ref OPEN_COLLECTIVE_URL: Regex =
+        Regex::new("^https://(?:www\\.)?opencollective\\.com/(?P<slug>[^/]+)/?$")
+            .expect("exprs in OPEN_COLLECTIVE_URL to be valid");
+}
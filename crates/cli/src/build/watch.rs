@@ -0,0 +1,90 @@
+//! This module provides the functionality used to watch the local data sources
+//! files for changes, rebuilding the landscape website when needed.
+
+use super::{build, BuildArgs};
+use anyhow::{bail, Result};
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+use tracing::{error, info, instrument};
+
+/// Interval used to check if the watched files have changed.
+const WATCH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Modification time of each of the watched files.
+type Snapshot = BTreeMap<PathBuf, SystemTime>;
+
+/// Watch the local data sources files provided, rebuilding the landscape
+/// website every time any of them changes. The function provided is called
+/// after each successful rebuild (i.e. to reload the landscape in the browser).
+#[instrument(skip_all, err)]
+pub async fn watch(args: &BuildArgs, on_rebuild: impl Fn()) -> Result<()> {
+    // Get paths to watch (only local data sources files can be watched)
+    let paths: Vec<&Path> = [
+        &args.data_source.data_file,
+        &args.settings_source.settings_file,
+        &args.guide_source.guide_file,
+        &args.games_source.games_file,
+        &args.logos_source.logos_path,
+    ]
+    .into_iter()
+    .filter_map(|path| path.as_deref())
+    .collect();
+    if paths.is_empty() {
+        bail!("watch mode requires at least one local data source (i.e. --data-file or --logos-path)");
+    }
+
+    // Rebuild landscape when any of the paths change
+    info!("watching local data sources for changes (press ctrl+c to stop)");
+    let mut snapshot = take_snapshot(&paths);
+    loop {
+        tokio::time::sleep(WATCH_INTERVAL).await;
+
+        let new_snapshot = take_snapshot(&paths);
+        if new_snapshot == snapshot {
+            continue;
+        }
+        snapshot = new_snapshot;
+
+        // Data collected from external services is reused from the cache, so
+        // only the local changes will need to be processed
+        info!("changes detected, rebuilding landscape website..");
+        match build(args).await {
+            Ok(()) => on_rebuild(),
+            Err(err) => error!(?err, "error rebuilding landscape website"),
+        }
+    }
+}
+
+/// Take a snapshot of the modification time of the paths provided (and their
+/// contents when they are directories).
+fn take_snapshot(paths: &[&Path]) -> Snapshot {
+    let mut snapshot = Snapshot::new();
+    for path in paths {
+        add_to_snapshot(&mut snapshot, path);
+    }
+    snapshot
+}
+
+/// Add the path provided to the snapshot, walking it recursively when it is a
+/// directory. Paths that cannot be read are ignored, as they may be in the
+/// middle of being updated.
+fn add_to_snapshot(snapshot: &mut Snapshot, path: &Path) {
+    let Ok(metadata) = fs::metadata(path) else {
+        return;
+    };
+    if let Ok(modified) = metadata.modified() {
+        snapshot.insert(path.to_path_buf(), modified);
+    }
+    if metadata.is_dir() {
+        let Ok(entries) = fs::read_dir(path) else {
+            return;
+        };
+        for entry in entries.flatten() {
+            add_to_snapshot(snapshot, &entry.path());
+        }
+    }
+}
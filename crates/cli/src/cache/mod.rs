@@ -0,0 +1,299 @@
+//! This module defines the functionality of the cache CLI subcommand.
+
+use crate::build::cache::Cache;
+use anyhow::Result;
+use chrono::{DateTime, Duration, Utc};
+use clap::Subcommand;
+use std::{
+    collections::BTreeMap,
+    ffi::OsStr,
+    fs,
+    path::{Path, PathBuf},
+};
+use tracing::instrument;
+use walkdir::WalkDir;
+
+/// Fields that may contain the time an entry of a cache file was collected
+/// or used for the last time (in order of preference).
+const ENTRY_TIMESTAMP_FIELDS: [&str; 3] = ["generated_at", "validated_at", "used_at"];
+
+/// Cache command arguments.
+#[derive(clap::Args)]
+pub struct CacheArgs {
+    /// Cache directory.
+    #[arg(long, global = true)]
+    pub cache_dir: Option<PathBuf>,
+
+    /// Operation to run on the cache.
+    #[command(subcommand)]
+    pub operation: Operation,
+}
+
+/// Operation to run on the cache.
+#[derive(Subcommand)]
+pub enum Operation {
+    /// List the files in the cache, including their size and age.
+    List,
+
+    /// Delete stale files from the cache.
+    Prune(PruneArgs),
+
+    /// Display the cache size and the age of its entries per service.
+    Stats,
+}
+
+/// Prune operation arguments.
+#[derive(clap::Args)]
+pub struct PruneArgs {
+    /// Only display the files that would be deleted.
+    #[arg(long, default_value_t = false)]
+    pub dry_run: bool,
+
+    /// Delete the files that haven't been updated in the number of days
+    /// provided.
+    #[arg(long, default_value_t = 30)]
+    pub max_age: i64,
+
+    /// Delete all the files of the service provided, regardless of their age
+    /// (i.e. github, crunchbase, logos, clomonitor).
+    #[arg(long)]
+    pub service: Option<String>,
+}
+
+/// List the files in the cache.
+#[instrument(skip_all, err)]
+pub fn list(args: &CacheArgs) -> Result<()> {
+    let files = read_cache_files(args)?;
+
+    println!(
+        "{:<45} {:<18} {:>10} {:>8} {:>8}",
+        "FILE", "SERVICE", "SIZE", "AGE", "ENTRIES"
+    );
+    for file in &files {
+        println!(
+            "{:<45} {:<18} {:>10} {:>8} {:>8}",
+            file.key,
+            file.service,
+            format_size(file.size),
+            file.age().map_or("-".to_string(), format_age),
+            file.entries.as_ref().map_or("-".to_string(), |entries| entries.len().to_string()),
+        );
+    }
+
+    Ok(())
+}
+
+/// Delete stale files from the cache.
+#[instrument(skip_all, err)]
+pub fn prune(args: &CacheArgs, prune_args: &PruneArgs) -> Result<()> {
+    let files = read_cache_files(args)?;
+
+    let mut deleted_files = 0;
+    let mut freed_space = 0;
+    for file in &files {
+        // Check if the file is stale or belongs to the service provided
+        let stale = file.age().is_some_and(|age| age > Duration::days(prune_args.max_age));
+        let selected = prune_args.service.as_ref().is_some_and(|service| *service == file.service);
+        if !stale && !selected {
+            continue;
+        }
+
+        // Delete file (unless in dry run mode)
+        if !prune_args.dry_run {
+            fs::remove_file(&file.path)?;
+        }
+        println!(
+            "{} {}",
+            if prune_args.dry_run {
+                "would delete"
+            } else {
+                "deleted"
+            },
+            file.key
+        );
+        deleted_files += 1;
+        freed_space += file.size;
+    }
+
+    println!(
+        "\n{deleted_files} file(s) {}, {} freed",
+        if prune_args.dry_run {
+            "to delete"
+        } else {
+            "deleted"
+        },
+        format_size(freed_space)
+    );
+
+    Ok(())
+}
+
+/// Display the cache size and the age of its entries per service.
+#[instrument(skip_all, err)]
+pub fn stats(args: &CacheArgs) -> Result<()> {
+    let files = read_cache_files(args)?;
+
+    // Aggregate files information per service
+    let mut services: BTreeMap<&str, ServiceStats> = BTreeMap::new();
+    for file in &files {
+        let stats = services.entry(file.service.as_str()).or_default();
+        stats.files += 1;
+        stats.size += file.size;
+        for timestamp in file.entries.iter().flatten().flatten() {
+            let age = Utc::now() - *timestamp;
+            stats.entries += 1;
+            match age {
+                age if age < Duration::days(1) => stats.entries_1d += 1,
+                age if age < Duration::days(7) => stats.entries_7d += 1,
+                age if age < Duration::days(30) => stats.entries_30d += 1,
+                _ => stats.entries_older += 1,
+            }
+            stats.oldest_entry = stats.oldest_entry.max(Some(age));
+        }
+    }
+
+    // Display stats
+    let total_size: u64 = services.values().map(|stats| stats.size).sum();
+    println!(
+        "Cache size: {} ({} files)\n",
+        format_size(total_size),
+        files.len()
+    );
+    println!(
+        "{:<18} {:>6} {:>10} {:>8} {:>6} {:>6} {:>6} {:>6} {:>8}",
+        "SERVICE", "FILES", "SIZE", "ENTRIES", "<1D", "<7D", "<30D", "OLDER", "OLDEST"
+    );
+    for (service, stats) in &services {
+        println!(
+            "{:<18} {:>6} {:>10} {:>8} {:>6} {:>6} {:>6} {:>6} {:>8}",
+            service,
+            stats.files,
+            format_size(stats.size),
+            stats.entries,
+            stats.entries_1d,
+            stats.entries_7d,
+            stats.entries_30d,
+            stats.entries_older,
+            stats.oldest_entry.map_or("-".to_string(), format_age),
+        );
+    }
+
+    Ok(())
+}
+
+/// Cache file information.
+#[derive(Debug, Clone)]
+struct CacheFile {
+    key: String,
+    path: PathBuf,
+    service: String,
+    size: u64,
+    modified_at: Option<DateTime<Utc>>,
+    entries: Option<Vec<Option<DateTime<Utc>>>>,
+}
+
+impl CacheFile {
+    /// Return how long ago the file was modified.
+    fn age(&self) -> Option<Duration> {
+        self.modified_at.map(|modified_at| Utc::now() - modified_at)
+    }
+}
+
+/// Service stats.
+#[derive(Debug, Clone, Default)]
+struct ServiceStats {
+    files: usize,
+    size: u64,
+    entries: usize,
+    entries_1d: usize,
+    entries_7d: usize,
+    entries_30d: usize,
+    entries_older: usize,
+    oldest_entry: Option<Duration>,
+}
+
+/// Read the information of the files available in the cache.
+fn read_cache_files(args: &CacheArgs) -> Result<Vec<CacheFile>> {
+    let cache = Cache::new(&args.cache_dir, false)?;
+
+    let mut files = vec![];
+    for entry in WalkDir::new(cache.dir()).sort_by_file_name() {
+        let entry = entry?;
+        if !entry.file_type().is_file() {
+            continue;
+        }
+
+        let path = entry.path().to_path_buf();
+        let key = path.strip_prefix(cache.dir())?.to_string_lossy().to_string();
+        if key.starts_with('.') {
+            // Skip hidden files (i.e. the cache lock)
+            continue;
+        }
+        let md = entry.metadata()?;
+        files.push(CacheFile {
+            service: service(&key),
+            size: md.len(),
+            modified_at: md.modified().ok().map(Into::into),
+            entries: read_entries(&path),
+            key,
+            path,
+        });
+    }
+
+    Ok(files)
+}
+
+/// Read the timestamps of the entries in the cache file provided. Only json
+/// files containing an object whose values are the entries are supported.
+fn read_entries(path: &Path) -> Option<Vec<Option<DateTime<Utc>>>> {
+    if path.extension().and_then(OsStr::to_str) != Some("json") {
+        return None;
+    }
+    let data = fs::read(path).ok()?;
+    let serde_json::Value::Object(entries) = serde_json::from_slice(&data).ok()? else {
+        return None;
+    };
+
+    let timestamps = entries
+        .values()
+        .map(|entry| {
+            ENTRY_TIMESTAMP_FIELDS
+                .iter()
+                .find_map(|field| entry.get(*field).and_then(|value| value.as_str()))
+                .and_then(|timestamp| timestamp.parse().ok())
+        })
+        .collect();
+    Some(timestamps)
+}
+
+/// Return the service the cache file provided belongs to.
+fn service(key: &str) -> String {
+    let name = key.split('.').next().unwrap_or(key).trim_end_matches("_http");
+    match name {
+        name if name.starts_with("clomonitor_") => "clomonitor",
+        name if name.starts_with("organizations_") => "organizations",
+        name => name,
+    }
+    .to_string()
+}
+
+/// Format the age provided in a human readable way.
+fn format_age(age: Duration) -> String {
+    if age.num_days() > 0 {
+        format!("{}d", age.num_days())
+    } else if age.num_hours() > 0 {
+        format!("{}h", age.num_hours())
+    } else {
+        format!("{}m", age.num_minutes())
+    }
+}
+
+/// Format the size provided (in bytes) in a human readable way.
+#[allow(clippy::cast_precision_loss)]
+fn format_size(size: u64) -> String {
+    match size {
+        size if size >= 1024 * 1024 => format!("{:.1}MB", size as f64 / (1024.0 * 1024.0)),
+        size if size >= 1024 => format!("{:.1}KB", size as f64 / 1024.0),
+        size => format!("{size}B"),
+    }
+}
@@ -0,0 +1,224 @@
+//! This module defines the functionality of the deploy CLI subcommand for the
+//! Azure Blob Storage provider (static website).
+
+use super::{cache_control, md5sum, upload_objects, Checksum, Key, INDEX_DOCUMENT};
+use anyhow::{bail, Context, Result};
+use azure_core::auth::TokenCredential;
+use base64::{engine::general_purpose::STANDARD as b64, Engine as _};
+use mime_guess::mime;
+use reqwest::StatusCode;
+use serde::Deserialize;
+use std::{
+    collections::HashMap,
+    env, fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::Instant,
+};
+use tracing::{debug, info, instrument};
+use url::Url;
+
+/// Version of the Azure Storage REST API used.
+const AZURE_STORAGE_API_VERSION: &str = "2021-08-06";
+
+/// OAuth scope required to read and write blobs (identity based auth).
+const AZURE_STORAGE_SCOPE: &str = "https://storage.azure.com/.default";
+
+/// Environment variable used to provide a SAS token.
+const SAS_TOKEN_ENV_VAR: &str = "AZURE_STORAGE_SAS_TOKEN";
+
+/// Azure Blob Storage provider arguments.
+#[derive(clap::Args)]
+pub struct Args {
+    /// Storage account to copy the landscape website files to.
+    #[arg(long)]
+    pub account: String,
+
+    /// Container to copy the landscape website files to.
+    #[arg(long, default_value = "$web")]
+    pub container: String,
+
+    /// Location of the landscape website files (build subcommand output).
+    #[arg(long)]
+    pub landscape_dir: PathBuf,
+}
+
+/// Deploy landscape website to Azure Blob Storage.
+///
+/// Requests are authenticated using the SAS token provided in the
+/// AZURE_STORAGE_SAS_TOKEN environment variable when available. Otherwise, the
+/// default Azure credentials chain will be used (environment, managed identity
+/// or Azure CLI).
+#[instrument(skip_all, err)]
+pub async fn deploy(args: &Args) -> Result<()> {
+    info!("deploying landscape website..");
+    let start = Instant::now();
+
+    // Setup Azure Blob Storage client
+    let auth = match env::var(SAS_TOKEN_ENV_VAR) {
+        Ok(sas_token) if !sas_token.is_empty() => {
+            Auth::SasToken(sas_token.trim_start_matches('?').to_string())
+        }
+        _ => {
+            Auth::Identity(azure_identity::create_credential().context("error setting up azure credentials")?)
+        }
+    };
+    let azure_client = Client {
+        http_client: reqwest::Client::new(),
+        auth,
+        container_url: Url::parse(&format!("https://{}.blob.core.windows.net", args.account))?,
+        container: args.container.clone(),
+    };
+
+    // Get objects already deployed
+    let deployed_objects = azure_client.get_deployed_objects().await?;
+
+    // Upload landscape website files (except index document)
+    upload_objects(&args.landscape_dir, &deployed_objects, |key, file| {
+        let azure_client = &azure_client;
+        async move { azure_client.upload_object(&key, &file).await }
+    })
+    .await?;
+
+    // Upload index document if all the other files were uploaded successfully
+    let file = args.landscape_dir.join(INDEX_DOCUMENT);
+    let index_remote_checksum = deployed_objects.get(INDEX_DOCUMENT).and_then(|checksum| checksum.as_ref());
+    if index_remote_checksum != Some(&md5sum(&file)?) {
+        azure_client
+            .upload_object(INDEX_DOCUMENT, &file)
+            .await
+            .context("error uploading index document")?;
+        debug!("index document uploaded");
+    }
+
+    let duration = start.elapsed().as_secs_f64();
+    info!("landscape website deployed! (took: {:.3}s)", duration);
+
+    Ok(())
+}
+
+/// Authentication method used in the requests to Azure Blob Storage.
+enum Auth {
+    Identity(Arc<dyn TokenCredential>),
+    SasToken(String),
+}
+
+/// Azure Blob Storage client (REST API).
+struct Client {
+    http_client: reqwest::Client,
+    auth: Auth,
+    container_url: Url,
+    container: String,
+}
+
+impl Client {
+    /// Get deployed objects returning their key and checksum.
+    #[instrument(skip_all, err)]
+    async fn get_deployed_objects(&self) -> Result<HashMap<Key, Option<Checksum>>> {
+        let mut deployed_objects = HashMap::new();
+
+        let mut marker: Option<String> = None;
+        loop {
+            let mut url = self.url(None);
+            url.query_pairs_mut().append_pair("restype", "container").append_pair("comp", "list");
+            if let Some(marker) = &marker {
+                url.query_pairs_mut().append_pair("marker", marker);
+            }
+            let resp = self.authenticate(self.http_client.get(url)).await?.send().await?;
+            if resp.status() != StatusCode::OK {
+                bail!("unexpected status code listing blobs: {}", resp.status());
+            }
+            let output: EnumerationResults = quick_xml::de::from_str(&resp.text().await?)?;
+            for blob in output.blobs.blob {
+                // Azure provides the MD5 digest encoded in base64
+                let checksum =
+                    blob.properties.content_md5.and_then(|md5| b64.decode(md5).ok()).map(hex::encode);
+                deployed_objects.insert(blob.name, checksum);
+            }
+            match output.next_marker {
+                Some(next_marker) if !next_marker.is_empty() => marker = Some(next_marker),
+                _ => break,
+            }
+        }
+
+        Ok(deployed_objects)
+    }
+
+    /// Upload the file provided to the container using the key given. The
+    /// object's content type and cache control properties are set as well.
+    async fn upload_object(&self, key: &str, file: &Path) -> Result<()> {
+        let content_type = mime_guess::from_path(key).first().unwrap_or(mime::APPLICATION_OCTET_STREAM);
+        let req = self
+            .http_client
+            .put(self.url(Some(key)))
+            .header("x-ms-blob-type", "BlockBlob")
+            .header("x-ms-blob-content-type", content_type.essence_str())
+            .header("x-ms-blob-cache-control", cache_control(key))
+            .body(fs::read(file)?);
+        let resp = self.authenticate(req).await?.send().await?;
+        if resp.status() != StatusCode::CREATED {
+            bail!("unexpected status code uploading blob: {}", resp.status());
+        }
+
+        Ok(())
+    }
+
+    /// Prepare the url of the container (or of the blob when a key is
+    /// provided).
+    fn url(&self, key: Option<&str>) -> Url {
+        let mut url = self.container_url.clone();
+        if let Ok(mut segments) = url.path_segments_mut() {
+            segments.push(&self.container);
+            if let Some(key) = key {
+                segments.extend(key.split('/'));
+            }
+        }
+        if let Auth::SasToken(sas_token) = &self.auth {
+            url.set_query(Some(sas_token));
+        }
+        url
+    }
+
+    /// Add the authentication details to the request provided.
+    async fn authenticate(&self, req: reqwest::RequestBuilder) -> Result<reqwest::RequestBuilder> {
+        let req = req.header("x-ms-version", AZURE_STORAGE_API_VERSION);
+        match &self.auth {
+            Auth::Identity(credential) => {
+                let token = credential.get_token(&[AZURE_STORAGE_SCOPE]).await?;
+                Ok(req.bearer_auth(token.token.secret()))
+            }
+            Auth::SasToken(_) => Ok(req),
+        }
+    }
+}
+
+/// List blobs response.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct EnumerationResults {
+    blobs: Blobs,
+    next_marker: Option<String>,
+}
+
+/// Blobs list.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct Blobs {
+    #[serde(default)]
+    blob: Vec<Blob>,
+}
+
+/// Blob information.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct Blob {
+    name: String,
+    properties: BlobProperties,
+}
+
+/// Blob properties.
+#[derive(Debug, Deserialize)]
+struct BlobProperties {
+    #[serde(rename = "Content-MD5")]
+    content_md5: Option<String>,
+}
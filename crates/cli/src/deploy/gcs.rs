@@ -0,0 +1,186 @@
+//! This module defines the functionality of the deploy CLI subcommand for the
+//! Google Cloud Storage provider.
+
+use super::{cache_control, md5sum, upload_objects, Checksum, Key, INDEX_DOCUMENT};
+use anyhow::{bail, Context, Result};
+use base64::{engine::general_purpose::STANDARD as b64, Engine as _};
+use gcp_auth::TokenProvider;
+use mime_guess::mime;
+use reqwest::StatusCode;
+use serde::Deserialize;
+use serde_json::json;
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::Instant,
+};
+use tracing::{debug, info, instrument};
+
+/// Google Cloud Storage JSON API base url.
+const GCS_API_URL: &str = "https://storage.googleapis.com/storage/v1";
+
+/// Google Cloud Storage upload API base url.
+const GCS_UPLOAD_API_URL: &str = "https://storage.googleapis.com/upload/storage/v1";
+
+/// OAuth scope required to read and write objects.
+const GCS_SCOPE: &str = "https://www.googleapis.com/auth/devstorage.read_write";
+
+/// Boundary used in the multipart upload requests.
+const MULTIPART_BOUNDARY: &str = "landscape2_object_boundary";
+
+/// Google Cloud Storage provider arguments.
+#[derive(clap::Args)]
+pub struct Args {
+    /// Bucket to copy the landscape website files to.
+    #[arg(long)]
+    pub bucket: String,
+
+    /// Location of the landscape website files (build subcommand output).
+    #[arg(long)]
+    pub landscape_dir: PathBuf,
+}
+
+/// Deploy landscape website to Google Cloud Storage.
+#[instrument(skip_all, err)]
+pub async fn deploy(args: &Args) -> Result<()> {
+    info!("deploying landscape website..");
+    let start = Instant::now();
+
+    // Setup GCS client
+    let gcs_client = Client {
+        http_client: reqwest::Client::new(),
+        token_provider: gcp_auth::provider().await.context("error setting up gcp authentication")?,
+        bucket: args.bucket.clone(),
+    };
+
+    // Get objects already deployed
+    let deployed_objects = gcs_client.get_deployed_objects().await?;
+
+    // Upload landscape website files (except index document)
+    upload_objects(&args.landscape_dir, &deployed_objects, |key, file| {
+        let gcs_client = &gcs_client;
+        async move { gcs_client.upload_object(&key, &file).await }
+    })
+    .await?;
+
+    // Upload index document if all the other files were uploaded successfully
+    let file = args.landscape_dir.join(INDEX_DOCUMENT);
+    let index_remote_checksum = deployed_objects.get(INDEX_DOCUMENT).and_then(|checksum| checksum.as_ref());
+    if index_remote_checksum != Some(&md5sum(&file)?) {
+        gcs_client
+            .upload_object(INDEX_DOCUMENT, &file)
+            .await
+            .context("error uploading index document")?;
+        debug!("index document uploaded");
+    }
+
+    let duration = start.elapsed().as_secs_f64();
+    info!("landscape website deployed! (took: {:.3}s)", duration);
+
+    Ok(())
+}
+
+/// Google Cloud Storage client (JSON API).
+struct Client {
+    http_client: reqwest::Client,
+    token_provider: Arc<dyn TokenProvider>,
+    bucket: String,
+}
+
+impl Client {
+    /// Get deployed objects returning their key and checksum.
+    #[instrument(skip_all, err)]
+    async fn get_deployed_objects(&self) -> Result<HashMap<Key, Option<Checksum>>> {
+        let mut deployed_objects = HashMap::new();
+
+        let url = format!("{GCS_API_URL}/b/{}/o", self.bucket);
+        let mut page_token: Option<String> = None;
+        loop {
+            let mut query = vec![("fields", "items(name,md5Hash),nextPageToken".to_string())];
+            if let Some(token) = page_token {
+                query.push(("pageToken", token));
+            }
+            let resp =
+                self.http_client.get(&url).bearer_auth(self.token().await?).query(&query).send().await?;
+            if resp.status() != StatusCode::OK {
+                bail!("unexpected status code listing objects: {}", resp.status());
+            }
+            let output: ListObjectsOutput = resp.json().await?;
+            for object in output.items.unwrap_or_default() {
+                // GCS provides the MD5 digest encoded in base64
+                let checksum =
+                    object.md5_hash.and_then(|md5_hash| b64.decode(md5_hash).ok()).map(hex::encode);
+                deployed_objects.insert(object.name, checksum);
+            }
+            if output.next_page_token.is_none() {
+                break;
+            }
+            page_token = output.next_page_token;
+        }
+
+        Ok(deployed_objects)
+    }
+
+    /// Upload the file provided to the bucket using the key given. The
+    /// object's content type and cache control metadata are set as well.
+    async fn upload_object(&self, key: &str, file: &Path) -> Result<()> {
+        // Prepare multipart request body (object metadata + object data)
+        let content_type = mime_guess::from_path(key).first().unwrap_or(mime::APPLICATION_OCTET_STREAM);
+        let metadata = json!({
+            "name": key,
+            "contentType": content_type.essence_str(),
+            "cacheControl": cache_control(key),
+        });
+        let mut body = format!(
+            "--{MULTIPART_BOUNDARY}\r\nContent-Type: application/json; charset=UTF-8\r\n\r\n{metadata}\r\n--{MULTIPART_BOUNDARY}\r\nContent-Type: {content_type}\r\n\r\n"
+        )
+        .into_bytes();
+        body.extend(fs::read(file)?);
+        body.extend(format!("\r\n--{MULTIPART_BOUNDARY}--").into_bytes());
+
+        // Upload object
+        let url = format!("{GCS_UPLOAD_API_URL}/b/{}/o", self.bucket);
+        let resp = self
+            .http_client
+            .post(url)
+            .bearer_auth(self.token().await?)
+            .query(&[("uploadType", "multipart")])
+            .header(
+                reqwest::header::CONTENT_TYPE,
+                format!("multipart/related; boundary={MULTIPART_BOUNDARY}"),
+            )
+            .body(body)
+            .send()
+            .await?;
+        if resp.status() != StatusCode::OK {
+            bail!("unexpected status code uploading object: {}", resp.status());
+        }
+
+        Ok(())
+    }
+
+    /// Get an access token to authenticate requests (tokens are cached and
+    /// refreshed automatically by the provider).
+    async fn token(&self) -> Result<String> {
+        let token = self.token_provider.token(&[GCS_SCOPE]).await?;
+        Ok(token.as_str().to_string())
+    }
+}
+
+/// List objects response.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ListObjectsOutput {
+    items: Option<Vec<Object>>,
+    next_page_token: Option<String>,
+}
+
+/// Object information.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Object {
+    name: String,
+    md5_hash: Option<String>,
+}
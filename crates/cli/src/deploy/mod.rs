@@ -1,9 +1,46 @@
 //! This module defines the functionality of the deploy CLI subcommand.
 
+use anyhow::{bail, format_err, Context, Result};
 use clap::Subcommand;
+use futures::stream::{self, StreamExt};
+use md5::{Digest, Md5};
+use std::{
+    collections::HashMap,
+    fmt::Write,
+    fs,
+    future::Future,
+    path::{Path, PathBuf},
+};
+use tracing::{debug, instrument};
+use walkdir::WalkDir;
 
+pub mod azure;
+pub mod gcs;
+pub mod rsync;
 pub mod s3;
 
+/// File name of the index document.
+const INDEX_DOCUMENT: &str = "index.html";
+
+/// Cache control header value used for files whose names include a hash of
+/// their content (they can be cached forever).
+const CACHE_CONTROL_IMMUTABLE: &str = "max-age=31536000, immutable";
+
+/// Cache control header value used for the index document.
+const CACHE_CONTROL_INDEX: &str = "max-age=60";
+
+/// Cache control header value used for any other files.
+const CACHE_CONTROL_DEFAULT: &str = "max-age=300";
+
+/// Number of files to upload concurrently.
+const UPLOAD_FILES_CONCURRENCY: usize = 50;
+
+/// Type alias to represent an object's checksum.
+pub(crate) type Checksum = String;
+
+/// Type alias to represent an object's key.
+pub(crate) type Key = String;
+
 /// Deploy command arguments.
 #[derive(clap::Args)]
 #[command(args_conflicts_with_subcommands = true)]
@@ -16,6 +53,113 @@ pub struct DeployArgs {
 /// Provider used to deploy the landscape website.
 #[derive(Subcommand)]
 pub enum Provider {
+    /// Deploy landscape website to Azure Blob Storage (static website).
+    Azure(azure::Args),
+
+    /// Deploy landscape website to Google Cloud Storage.
+    Gcs(gcs::Args),
+
+    /// Deploy landscape website to a remote host using rsync over SSH.
+    Rsync(rsync::Args),
+
     /// Deploy landscape website to AWS S3.
     S3(s3::Args),
 }
+
+/// Get the cache control header value that should be used for the object key
+/// provided.
+pub(crate) fn cache_control(key: &str) -> &'static str {
+    match key {
+        INDEX_DOCUMENT => CACHE_CONTROL_INDEX,
+        k if k.starts_with("assets/") => CACHE_CONTROL_IMMUTABLE,
+        k if k.starts_with("embed/assets/") => CACHE_CONTROL_IMMUTABLE,
+        k if k.starts_with("logos/") => CACHE_CONTROL_IMMUTABLE,
+        _ => CACHE_CONTROL_DEFAULT,
+    }
+}
+
+/// Calculate the MD5 digest of a file.
+pub(crate) fn md5sum(path: &Path) -> Result<String> {
+    let mut hasher = Md5::new();
+    hasher.update(fs::read(path)?);
+    let result = hasher.finalize();
+
+    Ok(format!("{result:x}"))
+}
+
+/// Upload the landscape website files (except the index document) using the
+/// upload function provided, returning the number of files uploaded. Objects
+/// already deployed that haven't changed are skipped.
+#[instrument(skip_all, err)]
+pub(crate) async fn upload_objects<F, Fut>(
+    landscape_dir: &Path,
+    deployed_objects: &HashMap<Key, Option<Checksum>>,
+    upload_object: F,
+) -> Result<usize>
+where
+    F: Fn(Key, PathBuf) -> Fut,
+    Fut: Future<Output = Result<()>>,
+{
+    // Upload files in the landscape directory
+    let results: Vec<Result<bool>> = stream::iter(WalkDir::new(landscape_dir))
+        .map(|entry| async {
+            // Check if the entry is a regular file
+            let entry = entry?;
+            if !entry.file_type().is_file() {
+                return Ok(false);
+            }
+
+            // Prepare object key
+            let file = entry.path();
+            let key = file
+                .display()
+                .to_string()
+                .trim_start_matches(landscape_dir.display().to_string().as_str())
+                .trim_start_matches('/')
+                .to_string();
+
+            // We'll upload the index document at the end when all the other
+            // files have been uploaded successfully
+            if key == INDEX_DOCUMENT {
+                return Ok(false);
+            }
+
+            // Skip files that start with a dot
+            if key.starts_with('.') {
+                return Ok(false);
+            }
+
+            // Skip objects that haven't changed
+            if let Some(Some(remote_checksum)) = deployed_objects.get(&key) {
+                if md5sum(file)? == *remote_checksum {
+                    return Ok(false);
+                }
+            }
+
+            // Upload file
+            upload_object(key.clone(), file.to_path_buf())
+                .await
+                .context(format_err!("error uploading file {key}"))?;
+
+            debug!(?key, "file uploaded");
+            Ok(true)
+        })
+        .buffer_unordered(UPLOAD_FILES_CONCURRENCY)
+        .collect()
+        .await;
+
+    // Process results
+    let mut errors = String::new();
+    let mut objects_uploaded = 0;
+    for result in results {
+        match result {
+            Ok(uploaded) => objects_uploaded += usize::from(uploaded),
+            Err(err) => writeln!(errors, "- {err:?}")?,
+        }
+    }
+    if !errors.is_empty() {
+        bail!("{errors}");
+    }
+
+    Ok(objects_uploaded)
+}
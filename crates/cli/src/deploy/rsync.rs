@@ -0,0 +1,94 @@
+//! This module defines the functionality of the deploy CLI subcommand for the
+//! rsync provider (remote hosts accessible over SSH).
+
+use super::INDEX_DOCUMENT;
+use anyhow::{bail, Context, Result};
+use std::{path::PathBuf, time::Instant};
+use tokio::process::Command;
+use tracing::{debug, info, instrument};
+
+/// Rsync provider arguments.
+#[derive(clap::Args)]
+pub struct Args {
+    /// Destination to copy the landscape website files to, using the rsync
+    /// remote format (i.e. user@host:/var/www/landscape).
+    #[arg(long)]
+    pub destination: String,
+
+    /// Delete files in the destination that are not present in the landscape
+    /// website files anymore.
+    #[arg(long, default_value_t = false)]
+    pub delete: bool,
+
+    /// Display the changes that would be applied without applying them.
+    #[arg(long, default_value_t = false)]
+    pub dry_run: bool,
+
+    /// Location of the landscape website files (build subcommand output).
+    #[arg(long)]
+    pub landscape_dir: PathBuf,
+
+    /// SSH port used to connect to the remote host.
+    #[arg(long)]
+    pub ssh_port: Option<u16>,
+}
+
+/// Deploy landscape website to a remote host using rsync over SSH.
+#[instrument(skip_all, err)]
+pub async fn deploy(args: &Args) -> Result<()> {
+    info!("deploying landscape website..");
+    let start = Instant::now();
+
+    // Sync landscape website files (except index document)
+    let source = format!("{}/", args.landscape_dir.display());
+    let destination = format!("{}/", args.destination.trim_end_matches('/'));
+    let mut cmd = rsync_cmd(args);
+    if args.delete {
+        cmd.arg("--delete");
+    }
+    cmd.arg(format!("--exclude=/{INDEX_DOCUMENT}")).arg("--exclude=/.*");
+    run(cmd.arg(&source).arg(&destination)).await.context("error syncing files")?;
+
+    // Sync index document if all the other files were synced successfully
+    let index_source = args.landscape_dir.join(INDEX_DOCUMENT);
+    run(rsync_cmd(args).arg(&index_source).arg(&destination))
+        .await
+        .context("error syncing index document")?;
+
+    let duration = start.elapsed().as_secs_f64();
+    info!("landscape website deployed! (took: {:.3}s)", duration);
+
+    Ok(())
+}
+
+/// Prepare the base rsync command (options shared by all invocations).
+fn rsync_cmd(args: &Args) -> Command {
+    let mut cmd = Command::new("rsync");
+
+    // Only transfer files whose content has changed (delta transfer)
+    cmd.arg("--archive").arg("--compress").arg("--checksum").arg("--itemize-changes");
+
+    // Use SSH as the remote shell
+    let ssh = match args.ssh_port {
+        Some(port) => format!("ssh -p {port}"),
+        None => "ssh".to_string(),
+    };
+    cmd.arg("--rsh").arg(ssh);
+
+    if args.dry_run {
+        cmd.arg("--dry-run");
+    }
+
+    cmd
+}
+
+/// Run the rsync command provided, checking it completed successfully.
+async fn run(cmd: &mut Command) -> Result<()> {
+    debug!(?cmd, "running rsync");
+    let status = cmd.status().await.context("error running rsync (is it installed?)")?;
+    if !status.success() {
+        bail!("rsync exited with status: {status}");
+    }
+
+    Ok(())
+}
@@ -1,31 +1,18 @@
 //! This module defines the functionality of the deploy CLI subcommand for the
 //! AWS S3 provider.
 
-use anyhow::{bail, format_err, Context, Result};
+use super::{cache_control, md5sum, upload_objects, Checksum, Key, INDEX_DOCUMENT};
+use anyhow::{bail, Context, Result};
+use aws_sdk_cloudfront::types::{InvalidationBatch, Paths};
 use aws_sdk_s3::primitives::ByteStream;
-use futures::stream::{self, StreamExt};
-use md5::{Digest, Md5};
 use mime_guess::mime;
 use std::{
     collections::HashMap,
-    env, fs,
+    env,
     path::{Path, PathBuf},
-    time::Instant,
+    time::{Instant, SystemTime, UNIX_EPOCH},
 };
 use tracing::{debug, info, instrument};
-use walkdir::WalkDir;
-
-/// File name of the index document.
-const INDEX_DOCUMENT: &str = "index.html";
-
-/// Number of files to upload concurrently.
-const UPLOAD_FILES_CONCURRENCY: usize = 50;
-
-/// Type alias to represent an object's checksum.
-type Checksum = String;
-
-/// Type alias to represent an object's key.
-type Key = String;
 
 /// AWS S3 provider arguments.
 #[derive(clap::Args)]
@@ -34,6 +21,11 @@ pub struct Args {
     #[arg(long)]
     pub bucket: String,
 
+    /// CloudFront distribution to invalidate once the files have been
+    /// uploaded (optional).
+    #[arg(long)]
+    pub cloudfront_distribution_id: Option<String>,
+
     /// Location of the landscape website files (build subcommand output).
     #[arg(long)]
     pub landscape_dir: PathBuf,
@@ -56,11 +48,14 @@ pub async fn deploy(args: &Args) -> Result<()> {
     let deployed_objects = get_deployed_objects(&s3_client, &args.bucket).await?;
 
     // Upload landscape website files (except index document)
-    upload_objects(&s3_client, &args.bucket, &args.landscape_dir, &deployed_objects).await?;
+    let objects_uploaded = upload_objects(&args.landscape_dir, &deployed_objects, |key, file| {
+        upload_object(&s3_client, &args.bucket, key, file)
+    })
+    .await?;
 
     // Upload index document if all the other files were uploaded successfully
     let index_remote_checksum = deployed_objects.get(INDEX_DOCUMENT).and_then(|checksum| checksum.as_ref());
-    upload_index_document(
+    let index_uploaded = upload_index_document(
         &s3_client,
         &args.bucket,
         &args.landscape_dir,
@@ -68,6 +63,14 @@ pub async fn deploy(args: &Args) -> Result<()> {
     )
     .await?;
 
+    // Invalidate CloudFront distribution if needed
+    if let Some(distribution_id) = &args.cloudfront_distribution_id {
+        if objects_uploaded > 0 || index_uploaded {
+            let cloudfront_client = aws_sdk_cloudfront::Client::new(&config);
+            invalidate_cloudfront_distribution(&cloudfront_client, distribution_id).await?;
+        }
+    }
+
     let duration = start.elapsed().as_secs_f64();
     info!("landscape website deployed! (took: {:.3}s)", duration);
 
@@ -120,100 +123,36 @@ async fn get_deployed_objects(
     Ok(deployed_objects)
 }
 
-/// Upload landscape website files to S3 bucket.
-#[instrument(skip_all, err)]
-async fn upload_objects(
-    s3_client: &aws_sdk_s3::Client,
-    bucket: &str,
-    landscape_dir: &PathBuf,
-    deployed_objects: &HashMap<Key, Option<Checksum>>,
-) -> Result<()> {
-    // Upload files in the landscape directory to the bucket provided
-    let results: Vec<Result<()>> = stream::iter(WalkDir::new(landscape_dir))
-        .map(|entry| async {
-            // Check if the entry is a regular file
-            let entry = entry?;
-            if !entry.file_type().is_file() {
-                return Ok(());
-            }
-
-            // Prepare object key
-            let file = entry.path();
-            let key = file
-                .display()
-                .to_string()
-                .trim_start_matches(landscape_dir.display().to_string().as_str())
-                .trim_start_matches('/')
-                .to_string();
-
-            // We'll upload the index document at the end when all the other
-            // files have been uploaded successfully
-            if key == INDEX_DOCUMENT {
-                return Ok(());
-            }
-
-            // Skip files that start with a dot
-            if key.starts_with('.') {
-                return Ok(());
-            }
-
-            // Skip objects that don't need to be uploaded again
-            if deployed_objects.contains_key(&key) {
-                // Skip any other objects that haven't changed
-                let checksum = md5sum(file)?;
-                if let Some(remote_checksum) = deployed_objects.get(&key).expect("object to be present") {
-                    if checksum == *remote_checksum {
-                        return Ok(());
-                    }
-                }
-            }
+/// Upload the file provided to the S3 bucket using the key given. The object's
+/// content type and cache control metadata are set as well.
+async fn upload_object(s3_client: &aws_sdk_s3::Client, bucket: &str, key: Key, file: PathBuf) -> Result<()> {
+    // Prepare object's body and content type
+    let body = ByteStream::from_path(file).await?;
+    let content_type = mime_guess::from_path(&key).first().unwrap_or(mime::APPLICATION_OCTET_STREAM);
 
-            // Prepare object's body and content type
-            let body = ByteStream::from_path(file).await?;
-            let content_type = mime_guess::from_path(&key).first().unwrap_or(mime::APPLICATION_OCTET_STREAM);
-
-            // Upload file
-            s3_client
-                .put_object()
-                .bucket(bucket)
-                .key(&key)
-                .body(body)
-                .content_type(content_type.essence_str())
-                .send()
-                .await
-                .context(format_err!("error uploading file {}", key))?;
-
-            debug!(?key, "file uploaded");
-            Ok(())
-        })
-        .buffer_unordered(UPLOAD_FILES_CONCURRENCY)
-        .collect()
-        .await;
-
-    // Process results
-    let mut errors_found = false;
-    let mut errors = String::new();
-    for result in results {
-        if let Err(err) = result {
-            errors_found = true;
-            errors.push_str(&format!("- {err:?}\n"));
-        }
-    }
-    if errors_found {
-        bail!("{errors}");
-    }
+    // Upload file
+    s3_client
+        .put_object()
+        .bucket(bucket)
+        .key(&key)
+        .body(body)
+        .content_type(content_type.essence_str())
+        .cache_control(cache_control(&key))
+        .send()
+        .await?;
 
     Ok(())
 }
 
-/// Upload landscape website index document to S3 bucket.
+/// Upload landscape website index document to S3 bucket, returning whether
+/// it was uploaded or not (it's skipped when the remote copy is up to date).
 #[instrument(skip_all, err)]
 async fn upload_index_document(
     s3_client: &aws_sdk_s3::Client,
     bucket: &str,
     landscape_dir: &Path,
     remote_checksum: Option<&Checksum>,
-) -> Result<()> {
+) -> Result<bool> {
     // Prepare object's checksum, key, body and content type
     let file = landscape_dir.join(INDEX_DOCUMENT);
     let checksum = md5sum(&file)?;
@@ -224,7 +163,7 @@ async fn upload_index_document(
     // Check if the remote copy is up to date
     if let Some(remote_checksum) = remote_checksum {
         if checksum == *remote_checksum {
-            return Ok(());
+            return Ok(false);
         }
     }
 
@@ -235,19 +174,37 @@ async fn upload_index_document(
         .key(key)
         .body(body)
         .content_type(content_type)
+        .cache_control(cache_control(INDEX_DOCUMENT))
         .send()
         .await
         .context("error uploading index document")?;
 
     debug!("index document uploaded");
-    Ok(())
+    Ok(true)
 }
 
-/// Calculate the MD5 digest of a file.
-fn md5sum(path: &Path) -> Result<String> {
-    let mut hasher = Md5::new();
-    hasher.update(fs::read(path)?);
-    let result = hasher.finalize();
+/// Invalidate all the paths in the CloudFront distribution provided, so that
+/// the new version of the landscape website is served right away.
+#[instrument(skip(cloudfront_client), err)]
+async fn invalidate_cloudfront_distribution(
+    cloudfront_client: &aws_sdk_cloudfront::Client,
+    distribution_id: &str,
+) -> Result<()> {
+    // Prepare invalidation batch (the caller reference must be unique)
+    let caller_reference = SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis().to_string();
+    let paths = Paths::builder().quantity(1).items("/*").build()?;
+    let invalidation_batch =
+        InvalidationBatch::builder().caller_reference(caller_reference).paths(paths).build()?;
+
+    // Create invalidation
+    cloudfront_client
+        .create_invalidation()
+        .distribution_id(distribution_id)
+        .invalidation_batch(invalidation_batch)
+        .send()
+        .await
+        .context("error creating cloudfront invalidation")?;
 
-    Ok(format!("{result:x}"))
+    debug!("cloudfront distribution invalidated");
+    Ok(())
 }
@@ -6,7 +6,9 @@
 )]
 
 pub mod build;
+pub mod cache;
 pub mod deploy;
 pub mod new;
+pub mod schema;
 pub mod serve;
 pub mod validate;
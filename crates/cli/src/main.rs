@@ -6,15 +6,22 @@
 )]
 
 use anyhow::Result;
-use clap::{Parser, Subcommand};
+use clap::{error::ErrorKind, CommandFactory, Parser, Subcommand};
+use landscape2::build::profile::init_tracing_with_profile;
+use landscape2::build::watch::{self};
 use landscape2::build::{build, BuildArgs};
-use landscape2::deploy::s3::{self};
+use landscape2::cache::{self as cache_cmd, CacheArgs, Operation};
+use landscape2::deploy::{azure, gcs, rsync, s3};
 use landscape2::deploy::{DeployArgs, Provider};
 use landscape2::new::{new, NewArgs};
+use landscape2::schema::{schema, SchemaArgs};
 use landscape2::serve::{serve, ServeArgs};
 use landscape2::validate::{
-    validate_data, validate_games, validate_guide, validate_settings, Target, ValidateArgs,
+    validate_data, validate_games, validate_guide, validate_settings, InvalidFile, Target, ValidateArgs,
 };
+use std::process::ExitCode;
+use tokio::sync::broadcast;
+use tracing::error;
 
 /// CLI arguments.
 #[derive(Parser)]
@@ -36,12 +43,19 @@ enum Command {
     /// Build landscape website.
     Build(BuildArgs),
 
+    /// Inspect and prune the cache used to store data collected from
+    /// external services.
+    Cache(CacheArgs),
+
     /// Deploy landscape website (experimental).
     Deploy(DeployArgs),
 
     /// Create a new landscape from the built-in template.
     New(NewArgs),
 
+    /// Print the JSON Schema of the datasets generated (base or full).
+    Schema(SchemaArgs),
+
     /// Serve landscape website.
     Serve(ServeArgs),
 
@@ -50,37 +64,117 @@ enum Command {
 }
 
 #[tokio::main]
-async fn main() -> Result<()> {
+async fn main() -> Result<ExitCode> {
     let cli = Cli::parse();
 
-    // Setup logging
+    // Only a single build can be profiled, so profiling is not supported when
+    // the landscape is rebuilt on changes (serve args are checked here, as
+    // clap conflicts cannot span subcommands)
+    let build_args = match &cli.command {
+        Command::Build(args) => Some(args),
+        Command::Serve(args) => args.build_args(),
+        _ => None,
+    };
+    if let Command::Serve(args) = &cli.command {
+        if args.watch && build_args.is_some_and(|build_args| build_args.profile.is_some()) {
+            Cli::command()
+                .error(
+                    ErrorKind::ArgumentConflict,
+                    "the argument '--profile <PROFILE>' cannot be used with 'serve --watch'",
+                )
+                .exit();
+        }
+    }
+
+    // Setup logging (recording the build profile when requested)
+    let mut profile_writer = None;
     match &cli.command {
         Command::Build(_) | Command::Deploy(_) | Command::New(_) | Command::Serve(_) => {
             if std::env::var_os("RUST_LOG").is_none() {
                 std::env::set_var("RUST_LOG", "landscape2=debug");
             }
-            tracing_subscriber::fmt::init();
+            match build_args.and_then(|build_args| build_args.profile.as_ref()) {
+                Some(file) => profile_writer = Some(init_tracing_with_profile(file)),
+                None => tracing_subscriber::fmt::init(),
+            }
         }
-        Command::Validate(_) => {}
+        Command::Cache(_) | Command::Schema(_) | Command::Validate(_) => {}
     }
 
     // Run command
     match &cli.command {
-        Command::Build(args) => build(args).await?,
+        Command::Build(args) => {
+            let result = build(args).await;
+            if let Some(profile_writer) = &profile_writer {
+                profile_writer.write()?;
+            }
+            if args.watch {
+                // Keep watching for changes when the initial build fails, as
+                // they may fix the problem
+                if let Err(err) = result {
+                    error!(?err, "error building landscape website");
+                }
+                watch::watch(args, || {}).await?;
+            } else {
+                result?;
+            }
+        }
+        Command::Cache(args) => match &args.operation {
+            Operation::List => cache_cmd::list(args)?,
+            Operation::Prune(prune_args) => cache_cmd::prune(args, prune_args)?,
+            Operation::Stats => cache_cmd::stats(args)?,
+        },
         Command::Deploy(args) => {
             match &args.provider {
+                Provider::Azure(args) => azure::deploy(args).await?,
+                Provider::Gcs(args) => gcs::deploy(args).await?,
+                Provider::Rsync(args) => rsync::deploy(args).await?,
                 Provider::S3(args) => s3::deploy(args).await?,
             };
         }
         Command::New(args) => new(args)?,
-        Command::Serve(args) => serve(args).await?,
-        Command::Validate(args) => match &args.target {
-            Target::Data(src) => validate_data(src).await?,
-            Target::Games(src) => validate_games(src).await?,
-            Target::Guide(src) => validate_guide(src).await?,
-            Target::Settings(src) => validate_settings(src).await?,
+        Command::Schema(args) => schema(args)?,
+        Command::Serve(args) => match args.build_args() {
+            Some(build_args) => {
+                let result = build(build_args).await;
+                if let Some(profile_writer) = &profile_writer {
+                    profile_writer.write()?;
+                }
+                if args.watch {
+                    if let Err(err) = result {
+                        error!(?err, "error building landscape website");
+                    }
+
+                    // Reload the landscape in the browser after each rebuild
+                    let (reload_tx, _) = broadcast::channel(1);
+                    let on_rebuild = || {
+                        _ = reload_tx.send(());
+                    };
+                    tokio::try_join!(
+                        serve(args, Some(reload_tx.clone())),
+                        watch::watch(build_args, on_rebuild)
+                    )?;
+                } else {
+                    result?;
+                    serve(args, None).await?;
+                }
+            }
+            None => serve(args, None).await?,
         },
+        Command::Validate(args) => {
+            let result = match &args.target {
+                Target::Data(src) => validate_data(src, args.output_format).await,
+                Target::Games(src) => validate_games(src, args.output_format).await,
+                Target::Guide(src) => validate_guide(src, args.output_format).await,
+                Target::Settings(src) => validate_settings(src, args.output_format).await,
+            };
+            match result {
+                // The errors have already been displayed
+                Err(err) if err.is::<InvalidFile>() => return Ok(ExitCode::FAILURE),
+                result => result?,
+            }
+        }
     }
 
-    Ok(())
+    Ok(ExitCode::SUCCESS)
 }
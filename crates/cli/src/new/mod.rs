@@ -1,6 +1,7 @@
 //! This module defines the functionality of the create CLI subcommand.
 
 use anyhow::Result;
+use clap::ValueEnum;
 use rust_embed::RustEmbed;
 use std::{
     fs::{self, File},
@@ -10,6 +11,9 @@ use std::{
 };
 use tracing::{info, instrument};
 
+/// Path of the template files shared by all templates.
+const COMMON_TEMPLATE_PATH: &str = "common";
+
 /// Embed landscape template files into binary.
 #[derive(RustEmbed)]
 #[folder = "src/new/template"]
@@ -21,12 +25,44 @@ pub struct NewArgs {
     /// Output directory to write files to.
     #[arg(long)]
     output_dir: PathBuf,
+
+    /// Built-in template to use.
+    #[arg(long, value_enum, default_value_t = Template::Cncf)]
+    template: Template,
+}
+
+/// Built-in templates available.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Template {
+    /// CNCF-style landscape with some sample categories, items, guide and games.
+    Cncf,
+
+    /// Minimal landscape with a single category and item.
+    Minimal,
+}
+
+impl Template {
+    /// Path of the template files in the embedded templates directory.
+    fn path(self) -> &'static str {
+        match self {
+            Template::Cncf => "cncf",
+            Template::Minimal => "minimal",
+        }
+    }
+
+    /// Command used to build a landscape created from this template.
+    fn build_cmd(self) -> &'static str {
+        match self {
+            Template::Cncf => "landscape2 build --data-file data.yml --settings-file settings.yml --guide-file guide.yml --games-file games.yml --logos-path logos --output-dir build",
+            Template::Minimal => "landscape2 build --data-file data.yml --settings-file settings.yml --logos-path logos --output-dir build",
+        }
+    }
 }
 
 /// Create a new landscape from the built-in template.
 #[instrument(skip_all)]
 pub fn new(args: &NewArgs) -> Result<()> {
-    info!("creating new landscape from the built-in template..");
+    info!(template = ?args.template, "creating new landscape from the built-in template..");
     let start = Instant::now();
 
     // Setup output directory
@@ -34,13 +70,20 @@ pub fn new(args: &NewArgs) -> Result<()> {
         fs::create_dir_all(&args.output_dir)?;
     }
 
-    // Copy template files to the output directory
+    // Copy template files (and the ones shared by all templates) to the
+    // output directory
     for file_path in TemplateFiles::iter() {
+        let Some(relative_path) = [COMMON_TEMPLATE_PATH, args.template.path()]
+            .into_iter()
+            .find_map(|prefix| Path::new(file_path.as_ref()).strip_prefix(prefix).ok())
+        else {
+            continue;
+        };
         if let Some(embedded_file) = TemplateFiles::get(&file_path) {
-            if let Some(parent_path) = Path::new(file_path.as_ref()).parent() {
+            if let Some(parent_path) = relative_path.parent() {
                 fs::create_dir_all(args.output_dir.join(parent_path))?;
             }
-            let mut file = File::create(args.output_dir.join(file_path.as_ref()))?;
+            let mut file = File::create(args.output_dir.join(relative_path))?;
             file.write_all(&embedded_file.data)?;
         }
     }
@@ -48,19 +91,21 @@ pub fn new(args: &NewArgs) -> Result<()> {
     // Display success message and build instructions
     let duration = start.elapsed().as_secs_f64();
     info!("landscape created! (took: {:.3}s)", duration);
-    display_success_msg(&args.output_dir.to_string_lossy());
+    display_success_msg(&args.output_dir.to_string_lossy(), args.template.build_cmd());
 
     Ok(())
 }
 
 /// Display success message.
-fn display_success_msg(output_dir: &str) {
+fn display_success_msg(output_dir: &str, build_cmd: &str) {
     println!(
-"\n✅ Landscape created successfully!
+        "\n✅ Landscape created successfully!
 
 You can build it by running the following command:
 
-👉 cd {output_dir} && landscape2 build --data-file data.yml --settings-file settings.yml --guide-file guide.yml --games-file games.yml --logos-path logos --output-dir build
+👉 cd {output_dir} && {build_cmd}
+
+An example GitHub Actions workflow to build it has been included as well (.github/workflows/build.yml).
 "
     );
 }
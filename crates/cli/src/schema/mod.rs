@@ -0,0 +1,35 @@
+//! This module defines the functionality of the schema CLI subcommand.
+
+use anyhow::Result;
+use clap::ValueEnum;
+use landscape2_core::datasets::{base::Base, full::Full, Versioned};
+use schemars::schema_for;
+
+/// Schema command arguments.
+#[derive(clap::Args)]
+pub struct SchemaArgs {
+    /// Dataset to generate the JSON Schema for.
+    #[arg(value_enum)]
+    pub dataset: Dataset,
+}
+
+/// Datasets a JSON Schema can be generated for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Dataset {
+    /// Base dataset (base.json).
+    Base,
+
+    /// Full dataset (full.json).
+    Full,
+}
+
+/// Write the JSON Schema of the dataset provided to stdout.
+pub fn schema(args: &SchemaArgs) -> Result<()> {
+    let schema = match args.dataset {
+        Dataset::Base => schema_for!(Versioned<'static, Base>),
+        Dataset::Full => schema_for!(Versioned<'static, Full>),
+    };
+    println!("{}", serde_json::to_string_pretty(&schema)?);
+
+    Ok(())
+}
@@ -1,18 +1,37 @@
 //! This module defines the functionality of the serve CLI subcommand.
 
-use anyhow::Result;
+use crate::build::BuildArgs;
+use anyhow::{bail, Result};
 use axum::{
-    extract::Request,
-    http::{header::CACHE_CONTROL, HeaderValue},
+    body::{self, Body},
+    extract::{Request, State},
+    handler::Handler,
+    http::{
+        header::{ACCEPT, CACHE_CONTROL, CONTENT_LENGTH, CONTENT_TYPE},
+        HeaderValue, StatusCode,
+    },
     middleware::{self, Next},
-    response::IntoResponse,
+    response::{
+        sse::{Event, KeepAlive, Sse},
+        IntoResponse, Response,
+    },
+    routing::get,
     Router,
 };
-use std::{env, net::SocketAddr, path::PathBuf};
-use tokio::{net::TcpListener, signal};
+use futures::stream::{self, Stream};
+use std::{convert::Infallible, env, net::SocketAddr, path::PathBuf};
+use tokio::{
+    net::TcpListener,
+    signal,
+    sync::broadcast::{self, error::RecvError},
+};
+use tower::ServiceExt;
 use tower_http::services::{ServeDir, ServeFile};
 use tracing::{info, instrument};
 
+/// Path of the live reload events endpoint.
+const LIVE_RELOAD_PATH: &str = "/__livereload";
+
 /// Serve arguments.
 #[derive(clap::Args)]
 pub struct ServeArgs {
@@ -20,40 +39,105 @@ pub struct ServeArgs {
     #[arg(long, default_value = "127.0.0.1:8000")]
     pub addr: String,
 
+    /// Base path the landscape website is served from. When none is provided
+    /// and the landscape is built before serving it, the build base path will
+    /// be used.
+    #[arg(long)]
+    pub base_path: Option<String>,
+
+    /// Build the landscape before serving it.
+    #[command(subcommand)]
+    pub build: Option<ServeBuild>,
+
     /// Whether the server should stop gracefully or not.
     #[arg(long, default_value_t = false)]
     pub graceful_shutdown: bool,
 
     /// Location of the landscape website files (build subcommand output).
-    /// The current path will be used when none is provided.
+    /// When none is provided, the build output directory will be used if the
+    /// landscape is built before serving it, or the current path otherwise.
     #[arg(long)]
     pub landscape_dir: Option<PathBuf>,
 
     /// Enable silent mode.
     #[arg(long, default_value_t = false)]
     pub silent: bool,
+
+    /// Rebuild the landscape when its local data sources change, reloading it
+    /// in the browser after each successful rebuild (the landscape must be
+    /// built before serving it).
+    #[arg(long, default_value_t = false)]
+    pub watch: bool,
+}
+
+/// Build the landscape before serving it.
+#[derive(clap::Subcommand)]
+pub enum ServeBuild {
+    /// Build landscape website (the output directory is served).
+    Build(BuildArgs),
 }
 
-/// Serve landscape website.
+impl ServeArgs {
+    /// Return the build arguments, when the landscape must be built before
+    /// serving it.
+    pub fn build_args(&self) -> Option<&BuildArgs> {
+        self.build.as_ref().map(|ServeBuild::Build(args)| args)
+    }
+}
+
+/// Serve landscape website. When a reload sender is provided, the landscape
+/// is reloaded in the browser every time a message is sent on it.
 #[instrument(skip_all)]
-pub async fn serve(args: &ServeArgs) -> Result<()> {
+pub async fn serve(args: &ServeArgs, reload_tx: Option<broadcast::Sender<()>>) -> Result<()> {
+    // Only the landscapes built by this subcommand can be rebuilt on changes
+    if args.watch && args.build_args().is_none() {
+        bail!("watch mode requires building the landscape before serving it (serve --watch build ..)");
+    }
+
     // Setup router
-    let landscape_dir = args.landscape_dir.clone().unwrap_or(env::current_dir()?);
+    let landscape_dir = match (&args.landscape_dir, args.build_args()) {
+        (Some(landscape_dir), _) => landscape_dir.clone(),
+        (None, Some(build_args)) => build_args.output_dir.clone(),
+        (None, None) => env::current_dir()?,
+    };
+    let base_path = args
+        .base_path
+        .as_ref()
+        .or(args.build_args().and_then(|build_args| build_args.base_path.as_ref()))
+        .map(|base_path| base_path.trim_end_matches('/').to_string())
+        .unwrap_or_default();
     let index_path = landscape_dir.join("index.html");
-    let router: Router<()> = Router::new()
-        .nest_service(
-            "/",
-            ServeDir::new(&landscape_dir).not_found_service(ServeFile::new(&index_path)),
-        )
-        .fallback_service(ServeFile::new(index_path))
+    let fallback = index_fallback.with_state(index_path.clone());
+    let mut router: Router<()> = Router::new()
+        .nest_service("/", ServeDir::new(&landscape_dir).fallback(fallback.clone()))
+        .fallback_service(fallback)
         .route_layer(middleware::from_fn(set_cache_control_header));
 
+    // Setup live reload when requested
+    if let Some(reload_tx) = reload_tx {
+        router = router
+            .route(
+                LIVE_RELOAD_PATH,
+                get(move || {
+                    let reload_rx = reload_tx.subscribe();
+                    async move { Sse::new(live_reload_events(reload_rx)).keep_alive(KeepAlive::default()) }
+                }),
+            )
+            .layer(middleware::from_fn_with_state(
+                live_reload_script(&base_path),
+                inject_live_reload_script,
+            ));
+    }
+    if !base_path.is_empty() {
+        router = Router::new().nest(&base_path, router);
+    }
+
     // Setup and launch HTTP server
     let addr: SocketAddr = args.addr.parse()?;
     let listener = TcpListener::bind(addr).await?;
     if !args.silent {
         info!("http server running (press ctrl+c to stop)");
-        println!("\n🔗 Landscape available at: http://{addr}\n");
+        println!("\n🔗 Landscape available at: http://{addr}{base_path}/\n");
     }
     if args.graceful_shutdown {
         axum::serve(listener, router).with_graceful_shutdown(shutdown_signal()).await?;
@@ -64,6 +148,27 @@ pub async fn serve(args: &ServeArgs) -> Result<()> {
     Ok(())
 }
 
+/// Handler used when the path requested doesn't match any file. Unknown
+/// navigation routes are handled by the web application, so we serve the
+/// index document with a success status code (SPA-style fallback). Missing
+/// assets (i.e. datasets or logos) get a not found response instead.
+async fn index_fallback(State(index_path): State<PathBuf>, req: Request) -> Response {
+    let has_extension = req.uri().path().rsplit('/').next().is_some_and(|segment| segment.contains('.'));
+    let accepts_html = req
+        .headers()
+        .get(ACCEPT)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.contains("text/html"));
+    if has_extension && !accepts_html {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+
+    match ServeFile::new(index_path).oneshot(req).await {
+        Ok(resp) => resp.into_response(),
+        Err(err) => match err {},
+    }
+}
+
 /// Middleware that sets the cache control header in the response.
 async fn set_cache_control_header(req: Request, next: Next) -> impl IntoResponse {
     // Prepare header value (based on the request uri)
@@ -83,6 +188,54 @@ async fn set_cache_control_header(req: Request, next: Next) -> impl IntoResponse
     resp
 }
 
+/// Stream of live reload events sent to the browser (server-sent events).
+fn live_reload_events(reload_rx: broadcast::Receiver<()>) -> impl Stream<Item = Result<Event, Infallible>> {
+    stream::unfold(reload_rx, |mut reload_rx| async move {
+        match reload_rx.recv().await {
+            Ok(()) | Err(RecvError::Lagged(_)) => Some((Ok(Event::default().data("reload")), reload_rx)),
+            Err(RecvError::Closed) => None,
+        }
+    })
+}
+
+/// Prepare the script injected in the html documents to reload the page when
+/// the landscape website files change.
+fn live_reload_script(base_path: &str) -> String {
+    format!(
+        r#"<script>new EventSource("{base_path}{LIVE_RELOAD_PATH}").onmessage = () => window.location.reload();</script>"#
+    )
+}
+
+/// Middleware that injects the live reload script in the html documents.
+async fn inject_live_reload_script(State(script): State<String>, req: Request, next: Next) -> Response {
+    let resp = next.run(req).await;
+
+    // Only html documents are modified
+    let is_html = resp
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with("text/html"));
+    if !is_html {
+        return resp;
+    }
+
+    // Inject script before the last closing body tag (or at the end of the
+    // document when it's missing)
+    let (mut parts, body) = resp.into_parts();
+    let Ok(bytes) = body::to_bytes(body, usize::MAX).await else {
+        return Response::from_parts(parts, Body::empty());
+    };
+    let mut html = String::from_utf8_lossy(&bytes).into_owned();
+    match html.rfind("</body>") {
+        Some(pos) => html.insert_str(pos, &script),
+        None => html.push_str(&script),
+    }
+    parts.headers.remove(CONTENT_LENGTH);
+
+    Response::from_parts(parts, Body::from(html))
+}
+
 /// Return a future that will complete when the program is asked to stop via a
 /// ctrl+c or terminate signal.
 async fn shutdown_signal() {
@@ -1,24 +1,39 @@
 //! This module defines the functionality of the validate CLI subcommand.
 
 use anyhow::{Context, Result};
-use clap::Subcommand;
+use clap::{Subcommand, ValueEnum};
 use landscape2_core::{
     data::{DataSource, LandscapeData},
     games::{GamesSource, LandscapeGames},
     guide::{GuideSource, LandscapeGuide},
     settings::{LandscapeSettings, SettingsSource},
 };
+use serde::Serialize;
+use std::{fmt, path::PathBuf};
 use tracing::instrument;
 
 /// Validate command arguments.
 #[derive(clap::Args)]
-#[command(args_conflicts_with_subcommands = true)]
 pub struct ValidateArgs {
+    /// Format used to display the validation results.
+    #[arg(long, value_enum, default_value_t = OutputFormat::Text, global = true)]
+    pub output_format: OutputFormat,
+
     /// Landscape file to validate.
     #[command(subcommand)]
     pub target: Target,
 }
 
+/// Format used to display the validation results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Machine-readable output (JSON document written to stdout).
+    Json,
+
+    /// Human-readable output.
+    Text,
+}
+
 /// Landscape file to validate.
 #[derive(Subcommand)]
 pub enum Target {
@@ -29,52 +44,170 @@ pub enum Target {
     Games(GamesSource),
 
     /// Validate landscape guide file.
-    Guide(GuideSource),
+    Guide(GuideArgs),
 
     /// Validate landscape settings file.
-    Settings(SettingsSource),
+    Settings(SettingsArgs),
+}
+
+/// Validate guide arguments.
+#[derive(clap::Args)]
+pub struct GuideArgs {
+    /// Guide source.
+    #[command(flatten)]
+    pub guide_source: GuideSource,
+
+    /// Landscape data file local path (used to check the guide against it).
+    #[arg(long)]
+    pub data_file: Option<PathBuf>,
+
+    /// Landscape data file url (used to check the guide against it).
+    #[arg(long, conflicts_with = "data_file")]
+    pub data_url: Option<String>,
+}
+
+/// Validate settings arguments.
+#[derive(clap::Args)]
+pub struct SettingsArgs {
+    /// Settings source.
+    #[command(flatten)]
+    pub settings_source: SettingsSource,
+
+    /// Landscape data file local path (used to check the settings against it).
+    #[arg(long)]
+    pub data_file: Option<PathBuf>,
+
+    /// Landscape data file url (used to check the settings against it).
+    #[arg(long, conflicts_with = "data_file")]
+    pub data_url: Option<String>,
+}
+
+/// Error returned when the file validated is not valid and the errors found
+/// have already been displayed (i.e. in the json report), so that the caller
+/// only has to set the exit status.
+#[derive(Debug)]
+pub struct InvalidFile;
+
+impl fmt::Display for InvalidFile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the file provided is not valid")
+    }
+}
+
+impl std::error::Error for InvalidFile {}
+
+/// Validation report (used when the output format is json).
+#[derive(Debug, Clone, Default, Serialize)]
+struct Report {
+    valid: bool,
+    errors: Vec<String>,
+    warnings: Vec<String>,
 }
 
 /// Validate landscape data file.
 #[instrument(skip_all)]
-pub async fn validate_data(data_source: &DataSource) -> Result<()> {
-    LandscapeData::new(data_source)
+pub async fn validate_data(data_source: &DataSource, output_format: OutputFormat) -> Result<()> {
+    let result = LandscapeData::validate(data_source)
         .await
-        .context("the landscape data file provided is not valid")?;
+        .map(|unknown_keys| unknown_keys.into_iter().map(|key| format!("unknown key found: {key}")).collect())
+        .context("the landscape data file provided is not valid");
 
-    println!("The landscape data file provided is valid!");
-    Ok(())
+    display_results("data", result, output_format)
 }
 
 /// Validate landscape games file.
 #[instrument(skip_all)]
-pub async fn validate_games(games_source: &GamesSource) -> Result<()> {
-    LandscapeGames::new(games_source)
+pub async fn validate_games(games_source: &GamesSource, output_format: OutputFormat) -> Result<()> {
+    let result = LandscapeGames::new(games_source)
         .await
-        .context("the landscape games file provided is not valid")?;
+        .map(|_| vec![])
+        .context("the landscape games file provided is not valid");
 
-    println!("The landscape games file provided is valid!");
-    Ok(())
+    display_results("games", result, output_format)
 }
 
-/// Validate landscape guide file.
+/// Validate landscape guide file. When a landscape data file is provided, the
+/// guide will also be checked against it.
 #[instrument(skip_all)]
-pub async fn validate_guide(guide_source: &GuideSource) -> Result<()> {
-    LandscapeGuide::new(guide_source)
-        .await
-        .context("the landscape guide file provided is not valid")?;
+pub async fn validate_guide(args: &GuideArgs, output_format: OutputFormat) -> Result<()> {
+    let result = async {
+        let guide = LandscapeGuide::new(&args.guide_source).await?;
+        let data_source = DataSource {
+            data_file: args.data_file.clone(),
+            data_url: args.data_url.clone(),
+        };
+        if let Some(guide) = guide {
+            if data_source.data_file.is_some() || data_source.data_url.is_some() {
+                let landscape_data = LandscapeData::new(&data_source).await?;
+                guide.validate_against_data(&landscape_data)?;
+            }
+        }
+        Ok::<_, anyhow::Error>(vec![])
+    }
+    .await
+    .context("the landscape guide file provided is not valid");
 
-    println!("The landscape guide file provided is valid!");
-    Ok(())
+    display_results("guide", result, output_format)
 }
 
-/// Validate landscape settings file.
+/// Validate landscape settings file. When a landscape data file is provided,
+/// the settings will also be checked against it.
 #[instrument(skip_all)]
-pub async fn validate_settings(settings_source: &SettingsSource) -> Result<()> {
-    LandscapeSettings::new(settings_source)
-        .await
-        .context("the landscape settings file provided is not valid")?;
+pub async fn validate_settings(args: &SettingsArgs, output_format: OutputFormat) -> Result<()> {
+    let result = async {
+        let settings = LandscapeSettings::new(&args.settings_source).await?;
+        let data_source = DataSource {
+            data_file: args.data_file.clone(),
+            data_url: args.data_url.clone(),
+        };
+        if data_source.data_file.is_some() || data_source.data_url.is_some() {
+            let landscape_data = LandscapeData::new(&data_source).await?;
+            settings.validate_against_data(&landscape_data)?;
+        }
+        Ok::<_, anyhow::Error>(vec![])
+    }
+    .await
+    .context("the landscape settings file provided is not valid");
+
+    display_results("settings", result, output_format)
+}
+
+/// Display the results of a validation (a list of warnings when the file is
+/// valid) using the output format provided. When the file is not valid, an
+/// error is returned (in json mode, an [`InvalidFile`] error, as the errors
+/// found are already included in the report).
+fn display_results(file_kind: &str, result: Result<Vec<String>>, output_format: OutputFormat) -> Result<()> {
+    match output_format {
+        OutputFormat::Json => {
+            let report = match &result {
+                Ok(warnings) => Report {
+                    valid: true,
+                    warnings: warnings.clone(),
+                    ..Default::default()
+                },
+                Err(err) => Report {
+                    valid: false,
+                    errors: err.chain().map(ToString::to_string).collect(),
+                    ..Default::default()
+                },
+            };
+            println!("{}", serde_json::to_string_pretty(&report)?);
+
+            // The errors are already included in the report, so we only need
+            // to let the caller know that the file is not valid
+            if !report.valid {
+                return Err(InvalidFile.into());
+            }
+        }
+        OutputFormat::Text => {
+            if let Ok(warnings) = &result {
+                for warning in warnings {
+                    println!("Warning: {warning}");
+                }
+                println!("The landscape {file_kind} file provided is valid!");
+            }
+        }
+    }
 
-    println!("The landscape settings file provided is valid!");
-    Ok(())
+    result.map(|_| ())
 }
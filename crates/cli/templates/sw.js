@@ -0,0 +1,93 @@
+// Service worker generated by landscape2 (version: {{ version }})
+
+const CACHE_NAME = "landscape-{{ version }}";
+const RUNTIME_CACHE_NAME = "landscape-runtime-{{ version }}";
+const PRECACHE_URLS = {{ urls|json|safe }};
+const RUNTIME_CACHE_PATHS = {{ runtime_cache_paths|json|safe }};
+
+// Pre-cache the app shell and the base dataset. Urls are added one by one, so
+// that a single file that cannot be fetched doesn't abort the installation.
+self.addEventListener("install", (event) => {
+  event.waitUntil(
+    caches
+      .open(CACHE_NAME)
+      .then((cache) =>
+        Promise.all(
+          PRECACHE_URLS.map((url) =>
+            cache.add(url).catch((err) => console.warn(`error pre-caching ${url}`, err))
+          )
+        )
+      )
+      .then(() => self.skipWaiting())
+  );
+});
+
+// Delete the caches of previous builds
+self.addEventListener("activate", (event) => {
+  event.waitUntil(
+    caches
+      .keys()
+      .then((keys) =>
+        Promise.all(
+          keys
+            .filter((key) => key !== CACHE_NAME && key !== RUNTIME_CACHE_NAME)
+            .map((key) => caches.delete(key))
+        )
+      )
+      .then(() => self.clients.claim())
+  );
+});
+
+// Check if the url provided must be cached at runtime (datasets and logos)
+const isRuntimeCached = (url) => {
+  const path = url.pathname.slice(new URL(self.registration.scope).pathname.length);
+  return RUNTIME_CACHE_PATHS.some((runtimePath) => path.startsWith(`${runtimePath}/`));
+};
+
+// Serve the request from the runtime cache when available, updating it from
+// the network in the background (stale-while-revalidate).
+const staleWhileRevalidate = (event) =>
+  caches.open(RUNTIME_CACHE_NAME).then((cache) =>
+    cache.match(event.request).then((cachedResponse) => {
+      const networkResponse = fetch(event.request).then((response) => {
+        if (response.ok) {
+          cache.put(event.request, response.clone());
+        }
+        return response;
+      });
+      if (cachedResponse) {
+        event.waitUntil(networkResponse.catch(() => {}));
+        return cachedResponse;
+      }
+      return networkResponse;
+    })
+  );
+
+// Navigation requests go to the network first, falling back to the cache when
+// offline. Datasets and logos are cached at runtime, and the rest of the
+// requests are served from the pre-cache when available.
+self.addEventListener("fetch", (event) => {
+  const request = event.request;
+  const url = new URL(request.url);
+  if (request.method !== "GET" || url.origin !== self.location.origin) {
+    return;
+  }
+
+  if (request.mode === "navigate") {
+    event.respondWith(
+      fetch(request).catch(() =>
+        caches.match(request, { ignoreSearch: true }).then((response) => response || caches.match("./"))
+      )
+    );
+    return;
+  }
+
+  if (isRuntimeCached(url)) {
+    event.respondWith(
+      caches.match(request, { cacheName: CACHE_NAME }).then((response) => response || staleWhileRevalidate(event))
+    );
+    return;
+  }
+
+  event.respondWith(caches.match(request).then((response) => response || fetch(request)));
+});
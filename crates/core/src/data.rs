@@ -12,10 +12,14 @@ use crate::util::normalize_name;
 use anyhow::{bail, Context, Result};
 use chrono::{DateTime, NaiveDate, Utc};
 use clap::Args;
+use lazy_static::lazy_static;
+use regex::Regex;
 use reqwest::StatusCode;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::{
-    collections::{BTreeMap, HashMap},
+    cmp::Reverse,
+    collections::{BTreeMap, HashMap, HashSet},
     fs,
     path::{Path, PathBuf},
 };
@@ -27,21 +31,109 @@ mod legacy;
 #[allow(dead_code)]
 pub const DATE_FORMAT: &str = "%Y-%m-%d";
 
+/// Number of weeks of commit activity tracked for each item.
+pub const ACTIVITY_WEEKS: usize = 52;
+
+/// Maximum number of posts included in the landscape news.
+pub const NEWS_MAX_POSTS: usize = 50;
+
+/// Container registries supported.
+pub const CONTAINER_REGISTRIES: [&str; 3] = ["docker.io", "ghcr.io", "quay.io"];
+
+/// Registry used when a container image reference doesn't include one.
+const DEFAULT_CONTAINER_REGISTRY: &str = "docker.io";
+
+lazy_static! {
+    /// Container image path component regular expression.
+    static ref CONTAINER_IMAGE_COMPONENT: Regex = Regex::new(r"^[a-z0-9]+(?:(?:[._]|__|-+)[a-z0-9]+)*$")
+        .expect("exprs in CONTAINER_IMAGE_COMPONENT to be valid");
+
+    /// Container image tag regular expression.
+    static ref CONTAINER_IMAGE_TAG: Regex = Regex::new(r"^\w[\w.-]{0,127}$")
+        .expect("exprs in CONTAINER_IMAGE_TAG to be valid");
+
+    /// Container image digest regular expression.
+    static ref CONTAINER_IMAGE_DIGEST: Regex =
+        Regex::new(r"^[A-Za-z][A-Za-z0-9]*(?:[-_+.][A-Za-z][A-Za-z0-9]*)*:[0-9a-fA-F]{32,}$")
+            .expect("exprs in CONTAINER_IMAGE_DIGEST to be valid");
+}
+
+/// Type alias to represent some projects' OpenSSF Best Practices data.
+pub type BestPracticesData = BTreeMap<BestPracticesUrl, BestPracticesBadge>;
+
+/// Type alias to represent an OpenSSF Best Practices project url.
+pub type BestPracticesUrl = String;
+
 /// Type alias to represent a category name.
 pub type CategoryName = String;
 
+/// Type alias to represent some packages' ClearlyDefined definitions.
+pub type ClearlyDefinedData = BTreeMap<PackageId, ClearlyDefinedDefinition>;
+
+/// Type alias to represent a container image reference (see
+/// [`ContainerImageRef`] for the format supported).
+pub type ContainerImage = String;
+
+/// Type alias to represent some container images' registry data (keyed by the
+/// image repository, see [`ContainerImageRef::repository`]).
+pub type ContainerImagesData = BTreeMap<ContainerImage, ContainerImageData>;
+
 /// Type alias to represent some organizations' Crunchbase data.
 pub type CrunchbaseData = BTreeMap<CrunchbaseUrl, Organization>;
 
 /// Type alias to represent a crunchbase url.
 pub type CrunchbaseUrl = String;
 
+/// Type alias to represent a blog or news feed url (RSS or Atom).
+pub type FeedUrl = String;
+
+/// Type alias to represent some feeds' data.
+pub type FeedsData = BTreeMap<FeedUrl, FeedData>;
+
 /// Type alias to represent some repositories' GitHub data.
 pub type GithubData = BTreeMap<RepositoryUrl, RepositoryGithubData>;
 
+/// Type alias to represent an item id.
+pub type ItemId = String;
+
+/// Type alias to represent the weekly commit activity of some items (oldest
+/// week first).
+pub type ItemsActivity = BTreeMap<ItemId, Vec<i64>>;
+
+/// Type alias to represent a GitHub organization url.
+pub type GithubOrgUrl = String;
+
+/// Type alias to represent some GitHub organizations' aggregated data.
+pub type GithubOrgsData = BTreeMap<GithubOrgUrl, GithubOrgData>;
+
+/// Type alias to represent a LinkedIn organization url.
+pub type LinkedInUrl = String;
+
+/// Type alias to represent some organizations' LinkedIn data.
+pub type LinkedInData = BTreeMap<LinkedInUrl, LinkedInOrganizationData>;
+
+/// Type alias to represent a package id (registry/name).
+pub type PackageId = String;
+
+/// Type alias to represent some packages' registry data.
+pub type PackagesData = BTreeMap<PackageId, PackageData>;
+
 /// Type alias to represent a GitHub repository url.
 pub type RepositoryUrl = String;
 
+/// Type alias to represent a social account url.
+pub type SocialAccountUrl = String;
+
+/// Type alias to represent some social accounts' data.
+pub type SocialData = BTreeMap<SocialAccountUrl, SocialAccountData>;
+
+/// Type alias to represent a sponsorship platform url (GitHub Sponsors or Open
+/// Collective).
+pub type SponsorshipUrl = String;
+
+/// Type alias to represent some sponsorship platforms' data.
+pub type SponsorshipsData = BTreeMap<SponsorshipUrl, SponsorshipData>;
+
 /// Type alias to represent a subcategory name.
 pub type SubcategoryName = String;
 
@@ -70,7 +162,7 @@ impl DataSource {
 }
 
 /// Landscape data.
-#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct LandscapeData {
     pub categories: Vec<Category>,
     pub items: Vec<Item>,
@@ -95,6 +187,26 @@ impl LandscapeData {
         bail!("data file or url not provided");
     }
 
+    /// Validate the landscape data from the source provided, returning the
+    /// list of unknown keys found in it (if any).
+    #[instrument(skip_all, err)]
+    pub async fn validate(src: &DataSource) -> Result<Vec<String>> {
+        // Get raw data from the source provided
+        let raw_data = if let Some(file) = &src.data_file {
+            fs::read_to_string(file)?
+        } else if let Some(url) = &src.data_url {
+            LandscapeData::get_raw_data_from_url(url).await?
+        } else {
+            bail!("data file or url not provided");
+        };
+
+        // Check the landscape data is valid and look for unknown keys
+        LandscapeData::new_from_raw_data(&raw_data)?;
+        let unknown_keys = legacy::find_unknown_keys(&raw_data)?;
+
+        Ok(unknown_keys)
+    }
+
     /// Create a new landscape data instance from the file provided.
     fn new_from_file(file: &Path) -> Result<Self> {
         let raw_data = fs::read_to_string(file)?;
@@ -105,6 +217,14 @@ impl LandscapeData {
 
     /// Create a new landscape data instance from the url provided.
     async fn new_from_url(url: &str) -> Result<Self> {
+        let raw_data = LandscapeData::get_raw_data_from_url(url).await?;
+        let landscape_data = LandscapeData::new_from_raw_data(&raw_data)?;
+
+        Ok(landscape_data)
+    }
+
+    /// Get the raw landscape data from the url provided.
+    async fn get_raw_data_from_url(url: &str) -> Result<String> {
         let resp = reqwest::get(url).await?;
         if resp.status() != StatusCode::OK {
             bail!(
@@ -113,9 +233,8 @@ impl LandscapeData {
             );
         }
         let raw_data = resp.text().await?;
-        let landscape_data = LandscapeData::new_from_raw_data(&raw_data)?;
 
-        Ok(landscape_data)
+        Ok(raw_data)
     }
 
     /// Create a new landscape data instance from the raw legacy data provided.
@@ -137,6 +256,68 @@ impl LandscapeData {
                     item.crunchbase_data = Some(org_crunchbase_data.clone());
                 }
             }
+
+            // Organization data provided manually takes precedence
+            if let Some(organization) = &item.organization {
+                organization.apply(item.crunchbase_data.get_or_insert_with(Organization::default));
+            }
+        }
+    }
+
+    /// Add items OpenSSF Best Practices badge level to the landscape data.
+    pub fn add_best_practices_data(&mut self, best_practices_data: &BestPracticesData) {
+        for item in &mut self.items {
+            if let Some(url) = item.openssf_best_practices_url.as_ref() {
+                if let Some(badge) = best_practices_data.get(url) {
+                    item.openssf_best_practices_badge_level = badge.level.clone();
+                }
+            }
+        }
+    }
+
+    /// Add items license details collected from ClearlyDefined. The
+    /// definition of the first package declared by the item is used.
+    #[instrument(skip_all)]
+    pub fn add_clearlydefined_data(&mut self, clearlydefined_data: &ClearlyDefinedData) {
+        for item in &mut self.items {
+            let Some(package) = item.packages.as_ref().and_then(|packages| packages.first()) else {
+                continue;
+            };
+            if let Some(definition) = clearlydefined_data.get(&package.id()) {
+                item.license_details = Some(LicenseDetails {
+                    declared: definition.declared.clone(),
+                    discovered: definition.discovered.clone(),
+                    discrepancy: definition.has_license_discrepancy(),
+                });
+            }
+        }
+    }
+
+    /// Add items container images stats to the landscape data, aggregating
+    /// the data collected for each of the item's images.
+    pub fn add_container_images_data(&mut self, container_images_data: &ContainerImagesData) {
+        for item in &mut self.items {
+            let Some(container_images) = &item.container_images else {
+                continue;
+            };
+
+            // Images are aggregated by repository, so that references to
+            // different tags or digests of the same image are counted once
+            let repositories: HashSet<String> = container_images
+                .iter()
+                .filter_map(|image| ContainerImageRef::parse(image).ok())
+                .map(|image_ref| image_ref.repository())
+                .collect();
+
+            let mut stats: Option<ContainerImagesStats> = None;
+            for repository in &repositories {
+                if let Some(image_data) = container_images_data.get(repository) {
+                    let stats = stats.get_or_insert_with(ContainerImagesStats::default);
+                    stats.pulls += image_data.pulls.unwrap_or_default();
+                    stats.stars += image_data.stars.unwrap_or_default();
+                }
+            }
+            item.container_images_stats = stats;
         }
     }
 
@@ -150,30 +331,16 @@ impl LandscapeData {
         };
 
         for rule in rules {
-            match rule.field.as_str() {
-                "maturity" => {
-                    for item in &mut self.items {
-                        if let Some(item_maturity) = item.maturity.as_ref() {
-                            if let Some(option) = rule.options.iter().find(|o| o.value == *item_maturity) {
-                                item.featured = Some(ItemFeatured {
-                                    order: option.order,
-                                    label: option.label.clone(),
-                                });
-                            }
-                        }
-                    }
-                }
-                "subcategory" => {
-                    for item in &mut self.items {
-                        if let Some(option) = rule.options.iter().find(|o| o.value == item.subcategory) {
-                            item.featured = Some(ItemFeatured {
-                                order: option.order,
-                                label: option.label.clone(),
-                            });
-                        }
-                    }
+            for item in &mut self.items {
+                let Some(value) = item.featured_rule_field_value(&rule.field) else {
+                    continue;
+                };
+                if let Some(option) = rule.options.iter().find(|o| o.value == *value) {
+                    item.featured = Some(ItemFeatured {
+                        order: option.order.or(rule.order),
+                        label: option.label.clone().or_else(|| rule.label.clone()),
+                    });
                 }
-                _ => {}
             }
         }
     }
@@ -187,13 +354,18 @@ impl LandscapeData {
                 let mut repositories = vec![];
                 for mut repo in item.repositories.clone().unwrap_or_default() {
                     if let Some(repo_github_data) = github_data.get(&repo.url) {
-                        repo.github_data = Some(repo_github_data.clone());
+                        let mut repo_github_data = repo_github_data.clone();
+                        repo_github_data.set_languages_breakdown();
+                        repo.github_data = Some(repo_github_data);
                     }
                     repositories.push(repo);
                 }
                 item.repositories = Some(repositories);
             }
 
+            // Aggregate repositories stats when the item has multiple repositories
+            item.repositories_stats = RepositoriesStats::from_repositories(item.repositories.as_deref());
+
             // Set item's oss field
             if item
                 .primary_repository()
@@ -206,6 +378,30 @@ impl LandscapeData {
         }
     }
 
+    /// Add items LinkedIn data.
+    #[instrument(skip_all)]
+    pub fn add_linkedin_data(&mut self, linkedin_data: &LinkedInData) {
+        for item in &mut self.items {
+            if let Some(linkedin_url) = item.linkedin_url.as_ref() {
+                if let Some(org_linkedin_data) = linkedin_data.get(linkedin_url) {
+                    item.linkedin_data = Some(org_linkedin_data.clone());
+                }
+            }
+        }
+    }
+
+    /// Add items feeds data.
+    #[instrument(skip_all)]
+    pub fn add_feeds_data(&mut self, feeds_data: &FeedsData) {
+        for item in &mut self.items {
+            if let Some(feed_url) = item.feed_url.as_ref() {
+                if let Some(feed_data) = feeds_data.get(feed_url) {
+                    item.feed_data = Some(feed_data.clone());
+                }
+            }
+        }
+    }
+
     /// Add items member subcategory.
     #[instrument(skip_all)]
     pub fn add_member_subcategory(&mut self, members_category: &Option<String>) {
@@ -317,6 +513,152 @@ impl LandscapeData {
             }
         }
     }
+
+    /// Set items membership level based on their member subcategory and the
+    /// membership levels defined in the settings.
+    #[instrument(skip_all)]
+    pub fn set_member_level(&mut self, settings: &LandscapeSettings) {
+        for item in &mut self.items {
+            if let Some(member_subcategory) = &item.member_subcategory {
+                if let Some(level) = settings.membership_level(member_subcategory) {
+                    item.member_level = Some(level.name.clone());
+                }
+            }
+        }
+    }
+
+    /// Set the status of the items repositories that are no longer active
+    /// (archived or without commits in the configured inactivity window).
+    #[instrument(skip_all)]
+    pub fn set_repositories_status(&mut self, settings: &LandscapeSettings) {
+        let stale_repositories = &settings.stale_repositories;
+        let cutoff = Utc::now() - chrono::Duration::days(stale_repositories.inactivity_days);
+
+        for item in &mut self.items {
+            for repo in item.repositories.iter_mut().flatten() {
+                let Some(gh_data) = &repo.github_data else {
+                    continue;
+                };
+                repo.status = if gh_data.archived == Some(true) {
+                    Some(RepositoryStatus::Archived)
+                } else if gh_data.latest_commit.ts.is_some_and(|ts| ts < cutoff) {
+                    Some(RepositoryStatus::Stale)
+                } else {
+                    None
+                };
+
+                if let Some(status) = repo.status.filter(|_| stale_repositories.warn) {
+                    warn!(item = ?item.name, repository = ?repo.url, ?status, "inactive repository found");
+                }
+            }
+        }
+    }
+
+    /// Return the feeds data of the items in the landscape.
+    #[must_use]
+    pub fn feeds_data(&self) -> FeedsData {
+        let mut feeds_data = FeedsData::new();
+        for item in &self.items {
+            if let (Some(feed_url), Some(feed_data)) = (&item.feed_url, &item.feed_data) {
+                feeds_data.insert(feed_url.clone(), feed_data.clone());
+            }
+        }
+
+        feeds_data
+    }
+
+    /// Aggregate the items repositories data at the GitHub organization level.
+    /// Only organizations owning more than one of the repositories in the
+    /// landscape are included.
+    #[must_use]
+    pub fn github_orgs_data(&self) -> GithubOrgsData {
+        let mut github_orgs_data = GithubOrgsData::new();
+        let mut processed_repositories = HashSet::new();
+        for item in &self.items {
+            for repo in item.repositories.iter().flatten() {
+                let Some(gh_data) = &repo.github_data else {
+                    continue;
+                };
+                let Some((org_url, _)) = repo.url.trim_end_matches('/').rsplit_once('/') else {
+                    continue;
+                };
+
+                // Track items using repositories owned by the organization
+                let org_data = github_orgs_data.entry(org_url.to_string()).or_default();
+                if !org_data.items.contains(&item.id) {
+                    org_data.items.push(item.id.clone());
+                }
+
+                // Repositories used by multiple items are only counted once
+                if processed_repositories.insert(&repo.url) {
+                    org_data.contributors += gh_data.contributors.as_ref().map_or(0, |c| c.count);
+                    org_data.repositories += 1;
+                    org_data.stars += gh_data.stars;
+                }
+            }
+        }
+        github_orgs_data.retain(|_, org_data| org_data.repositories > 1);
+
+        github_orgs_data
+    }
+
+    /// Return the weekly commit activity of the items in the landscape for
+    /// the last year (oldest week first). The activity of all the repositories
+    /// of an item is added up. Items with no activity data are not included.
+    #[must_use]
+    pub fn items_activity(&self) -> ItemsActivity {
+        let mut items_activity = ItemsActivity::new();
+        for item in &self.items {
+            let mut activity = vec![];
+            for repo in item.repositories.iter().flatten() {
+                let Some(gh_data) = &repo.github_data else {
+                    continue;
+                };
+                if gh_data.participation_stats.is_empty() {
+                    continue;
+                }
+
+                // Align the participation stats to the most recent week
+                activity.resize(ACTIVITY_WEEKS, 0);
+                let stats = &gh_data.participation_stats;
+                let stats = &stats[stats.len().saturating_sub(ACTIVITY_WEEKS)..];
+                let offset = ACTIVITY_WEEKS - stats.len();
+                for (total, count) in activity[offset..].iter_mut().zip(stats) {
+                    *total += count;
+                }
+            }
+            if !activity.is_empty() {
+                items_activity.insert(item.id.clone(), activity);
+            }
+        }
+
+        items_activity
+    }
+
+    /// Return the most recent posts published in the feeds of the items in the
+    /// landscape (most recent first). Posts without a publication date are not
+    /// included.
+    #[must_use]
+    pub fn news(&self) -> Vec<NewsPost> {
+        let mut news = vec![];
+        for item in &self.items {
+            let Some(feed_data) = &item.feed_data else {
+                continue;
+            };
+            for post in &feed_data.posts {
+                if post.published_at.is_some() {
+                    news.push(NewsPost {
+                        item_id: item.id.clone(),
+                        post: post.clone(),
+                    });
+                }
+            }
+        }
+        news.sort_by_key(|news_post| Reverse(news_post.post.published_at));
+        news.truncate(NEWS_MAX_POSTS);
+
+        news
+    }
 }
 
 impl From<legacy::LandscapeData> for LandscapeData {
@@ -351,6 +693,7 @@ impl From<legacy::LandscapeData> for LandscapeData {
                         joined_at: legacy_item.joined,
                         homepage_url: legacy_item.homepage_url,
                         logo: legacy_item.logo,
+                        logo_dark: legacy_item.logo_dark,
                         maturity: legacy_item.project,
                         openssf_best_practices_url: legacy_item.url_for_bestpractices,
                         subcategory: legacy_subcategory.name.clone(),
@@ -396,6 +739,7 @@ impl From<legacy::LandscapeData> for LandscapeData {
                             branch: legacy_item.branch,
                             github_data: None,
                             primary: Some(true),
+                            status: None,
                         });
                     }
                     if let Some(additional_repos) = legacy_item.additional_repos {
@@ -405,6 +749,7 @@ impl From<legacy::LandscapeData> for LandscapeData {
                                 branch: entry.branch,
                                 github_data: None,
                                 primary: Some(false),
+                                status: None,
                             });
                         }
                     }
@@ -420,13 +765,17 @@ impl From<legacy::LandscapeData> for LandscapeData {
                         item.artwork_url = extra.artwork_url;
                         item.audits = extra.audits;
                         item.blog_url = extra.blog_url;
+                        item.bluesky_url = extra.bluesky_url;
                         item.chat_channel = extra.chat_channel;
                         item.clomonitor_name = extra.clomonitor_name;
+                        item.container_images = extra.container_images;
                         item.devstats_url = extra.dev_stats_url;
                         item.discord_url = extra.discord_url;
                         item.docker_url = extra.docker_url;
                         item.documentation_url = extra.documentation_url;
+                        item.feed_url = extra.feed_url;
                         item.github_discussions_url = extra.github_discussions_url;
+                        item.github_sponsors_url = extra.github_sponsors_url;
                         item.gitter_url = extra.gitter_url;
                         item.graduated_at = extra.graduated;
                         item.incubating_at = extra.incubating;
@@ -434,8 +783,12 @@ impl From<legacy::LandscapeData> for LandscapeData {
                         item.latest_annual_review_url = extra.annual_review_url;
                         item.linkedin_url = extra.linkedin_url;
                         item.mailing_list_url = extra.mailing_list_url;
+                        item.mastodon_url = extra.mastodon_url;
+                        item.open_collective_url = extra.open_collective_url;
+                        item.organization = extra.organization;
                         item.other_links = extra.other_links;
                         item.package_manager_url = extra.package_manager_url;
+                        item.packages = extra.packages;
                         item.parent_project = extra.parent_project;
                         item.slack_url = extra.slack_url;
                         item.specification = extra.specification;
@@ -487,7 +840,7 @@ impl From<legacy::LandscapeData> for LandscapeData {
 }
 
 /// Landscape category.
-#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct Category {
     pub name: CategoryName,
     pub normalized_name: CategoryName,
@@ -512,14 +865,25 @@ impl From<&settings::Category> for Category {
 }
 
 /// Landscape subcategory.
-#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct Subcategory {
     pub name: SubcategoryName,
     pub normalized_name: SubcategoryName,
 }
 
+/// Repositories data aggregated at the GitHub organization level.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct GithubOrgData {
+    /// Sum of the contributors of each of the organization's repositories.
+    pub contributors: usize,
+    /// Ids of the items using any of the organization's repositories.
+    pub items: Vec<String>,
+    pub repositories: usize,
+    pub stars: i64,
+}
+
 /// Landscape item (project, product, member, etc).
-#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct Item {
     pub category: String,
     pub homepage_url: String,
@@ -550,6 +914,9 @@ pub struct Item {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub blog_url: Option<String>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bluesky_url: Option<String>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub chat_channel: Option<String>,
 
@@ -559,6 +926,15 @@ pub struct Item {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub clomonitor_report_summary: Option<String>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub clomonitor_score: Option<ClomonitorScore>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub container_images: Option<Vec<ContainerImage>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub container_images_stats: Option<ContainerImagesStats>,
+
     #[serde(skip_serializing)]
     pub crunchbase_data: Option<Organization>,
 
@@ -586,9 +962,18 @@ pub struct Item {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub featured: Option<ItemFeatured>,
 
+    #[serde(skip_serializing)]
+    pub feed_data: Option<FeedData>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub feed_url: Option<String>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub github_discussions_url: Option<String>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub github_sponsors_url: Option<String>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub gitter_url: Option<String>,
 
@@ -601,15 +986,33 @@ pub struct Item {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub joined_at: Option<NaiveDate>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub license_details: Option<LicenseDetails>,
+
+    #[serde(skip_serializing)]
+    pub linkedin_data: Option<LinkedInOrganizationData>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub linkedin_url: Option<String>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logo_dark: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logo_png: Option<String>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub mailing_list_url: Option<String>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mastodon_url: Option<String>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub maturity: Option<String>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub member_level: Option<String>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub member_subcategory: Option<String>,
 
@@ -619,9 +1022,18 @@ pub struct Item {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub latest_annual_review_url: Option<String>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub open_collective_url: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub openssf_best_practices_badge_level: Option<String>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub openssf_best_practices_url: Option<String>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub organization: Option<OrganizationOverrides>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub oss: Option<bool>,
 
@@ -631,15 +1043,27 @@ pub struct Item {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub package_manager_url: Option<String>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub packages: Option<Vec<ItemPackage>>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub parent_project: Option<String>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub qr_code: Option<String>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub repositories: Option<Vec<Repository>>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repositories_stats: Option<RepositoriesStats>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub slack_url: Option<String>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub social_card: Option<String>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub specification: Option<bool>,
 
@@ -690,6 +1114,20 @@ impl Item {
         description
     }
 
+    /// Get the value of the item's field used by featured items rules.
+    pub(crate) fn featured_rule_field_value(&self, field: &str) -> Option<&String> {
+        match field {
+            "id" => Some(&self.id),
+            "maturity" => self.maturity.as_ref(),
+            "member_level" => self.member_level.as_ref(),
+            "member_subcategory" => self.member_subcategory.as_ref(),
+            "name" => Some(&self.name),
+            "subcategory" => Some(&self.subcategory),
+            "tag" => self.tag.as_ref(),
+            _ => None,
+        }
+    }
+
     /// Get primary repository if available.
     #[must_use]
     pub fn primary_repository(&self) -> Option<&Repository> {
@@ -727,10 +1165,23 @@ impl Item {
 
         self.website.clone_from(&self.homepage_url);
     }
+
+    /// Get item's social accounts, along with the social network they belong
+    /// to.
+    pub fn social_accounts(&self) -> impl Iterator<Item = (&'static str, &String)> {
+        [
+            ("bluesky", &self.bluesky_url),
+            ("mastodon", &self.mastodon_url),
+            ("twitter", &self.twitter_url),
+            ("youtube", &self.youtube_url),
+        ]
+        .into_iter()
+        .filter_map(|(platform, url)| url.as_ref().map(|url| (platform, url)))
+    }
 }
 
 /// Crunchbase acquisition details.
-#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct Acquisition {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub acquiree_cb_permalink: Option<String>,
@@ -745,100 +1196,337 @@ pub struct Acquisition {
     pub price: Option<u64>,
 }
 
+/// Details of the acquisition of an organization.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct AcquiredBy {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub acquirer_cb_permalink: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub acquirer_name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub announced_on: Option<NaiveDate>,
+}
+
 /// Additional category/subcategory an item can belong to.
-#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct AdditionalCategory {
     pub category: CategoryName,
     pub subcategory: SubcategoryName,
 }
 
-/// Commit information.
-#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
-pub struct Commit {
-    pub ts: Option<DateTime<Utc>>,
-    pub url: String,
-}
+/// OpenSSF Best Practices badge information.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct BestPracticesBadge {
+    pub generated_at: DateTime<Utc>,
 
-/// Contributors information.
-#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
-pub struct Contributors {
-    pub count: usize,
-    pub url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub level: Option<String>,
 }
 
-/// Crunchbase funding round details.
-#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
-pub struct FundingRound {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub amount: Option<u64>,
+/// Package definition collected from ClearlyDefined (only license related
+/// information is tracked).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct ClearlyDefinedDefinition {
+    pub generated_at: DateTime<Utc>,
+    pub revision: String,
 
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub announced_on: Option<NaiveDate>,
+    pub declared: Option<String>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub kind: Option<String>,
+    pub discovered: Option<Vec<String>>,
 }
 
-/// Landscape item audit information.
-#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
-pub struct ItemAudit {
-    pub date: NaiveDate,
-    #[serde(rename = "type")]
-    pub kind: String,
-    pub url: String,
-    pub vendor: String,
+impl ClearlyDefinedDefinition {
+    /// Check if the declared license doesn't match any of the licenses
+    /// detected in the package files.
+    #[must_use]
+    pub fn has_license_discrepancy(&self) -> bool {
+        let (Some(declared), Some(discovered)) = (&self.declared, &self.discovered) else {
+            return false;
+        };
+        !discovered.is_empty() && !discovered.iter().any(|expression| expression.contains(declared.as_str()))
+    }
 }
 
-/// Landscape item featured information.
-#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
-pub struct ItemFeatured {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub label: Option<String>,
+/// CLOMonitor project score.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct ClomonitorScore {
+    pub global: f64,
 
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub order: Option<usize>,
-}
-
-/// Landscape item link.
-#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
-pub struct ItemLink {
-    pub name: String,
-    pub url: String,
-}
+    pub best_practices: Option<f64>,
 
-/// Landscape item summary.
-#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
-pub struct ItemSummary {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub business_use_case: Option<String>,
+    pub documentation: Option<f64>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub integration: Option<String>,
+    pub legal: Option<f64>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub integrations: Option<String>,
+    pub license: Option<f64>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub intro_url: Option<String>,
+    pub security: Option<f64>,
+}
 
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub personas: Option<Vec<String>>,
+/// Commit information.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct Commit {
+    pub ts: Option<DateTime<Utc>>,
+    pub url: String,
+}
 
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub release_rate: Option<String>,
+/// Container image information collected from its registry.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct ContainerImageData {
+    pub generated_at: DateTime<Utc>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub tags: Option<Vec<String>>,
+    pub pulls: Option<u64>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub use_case: Option<String>,
+    pub stars: Option<u64>,
 }
 
-/// Organization information collected from Crunchbase.
-#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+/// Container image reference, in the format used by docker and most container
+/// tools: `[registry/][namespace/]name[:tag][@digest]`. When the registry is
+/// not provided Docker Hub is used, and official images on Docker Hub (the
+/// ones without a namespace) use the `library` namespace.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ContainerImageRef {
+    pub registry: String,
+    pub namespace: String,
+    pub name: String,
+    pub tag: Option<String>,
+    pub digest: Option<String>,
+}
+
+impl ContainerImageRef {
+    /// Parse the container image reference provided.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the components of the reference (path,
+    /// tag or digest) is not valid.
+    pub fn parse(image: &str) -> Result<Self> {
+        // Digest
+        let (image, digest) = match image.split_once('@') {
+            Some((image, digest)) => {
+                if !CONTAINER_IMAGE_DIGEST.is_match(digest) {
+                    bail!("invalid digest: {digest}");
+                }
+                (image, Some(digest.to_string()))
+            }
+            None => (image, None),
+        };
+
+        // Tag (it follows the last colon, as long as it is not part of the
+        // registry host port)
+        let (image, tag) = match image.rsplit_once(':') {
+            Some((path, tag)) if !tag.contains('/') => {
+                if !CONTAINER_IMAGE_TAG.is_match(tag) {
+                    bail!("invalid tag: {tag}");
+                }
+                (path, Some(tag.to_string()))
+            }
+            _ => (image, None),
+        };
+
+        // Registry (the first component is only considered a registry host
+        // when it looks like one)
+        let mut components: Vec<&str> = image.split('/').collect();
+        let registry = if components.len() > 1
+            && (components[0].contains('.') || components[0].contains(':') || components[0] == "localhost")
+        {
+            match components.remove(0) {
+                "index.docker.io" | "registry-1.docker.io" => DEFAULT_CONTAINER_REGISTRY,
+                registry => registry,
+            }
+        } else {
+            DEFAULT_CONTAINER_REGISTRY
+        };
+
+        // Namespace and name
+        for component in &components {
+            if !CONTAINER_IMAGE_COMPONENT.is_match(component) {
+                bail!("invalid path component: {component}");
+            }
+        }
+        let name = components.pop().unwrap_or_default().to_string();
+        let namespace = if components.is_empty() && registry == DEFAULT_CONTAINER_REGISTRY {
+            "library".to_string()
+        } else {
+            components.join("/")
+        };
+
+        Ok(Self {
+            registry: registry.to_string(),
+            namespace,
+            name,
+            tag,
+            digest,
+        })
+    }
+
+    /// Return the repository of the image (`registry/namespace/name`), which
+    /// is shared by all its tags and digests.
+    #[must_use]
+    pub fn repository(&self) -> String {
+        if self.namespace.is_empty() {
+            return format!("{}/{}", self.registry, self.name);
+        }
+        format!("{}/{}/{}", self.registry, self.namespace, self.name)
+    }
+}
+
+/// Container images stats (aggregated for all the images of an item).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct ContainerImagesStats {
+    pub pulls: u64,
+    pub stars: u64,
+}
+
+/// Contributor information.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct Contributor {
+    pub contributions: usize,
+    pub login: String,
+    pub url: String,
+}
+
+/// Contributors information.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct Contributors {
+    pub count: usize,
+    pub url: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top: Option<Vec<Contributor>>,
+}
+
+/// Blog or news feed information.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct FeedData {
+    pub generated_at: DateTime<Utc>,
+    pub posts: Vec<FeedPost>,
+}
+
+/// Blog or news feed post details.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct FeedPost {
+    pub title: String,
+    pub url: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub published_at: Option<DateTime<Utc>>,
+}
+
+/// Crunchbase funding round details.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct FundingRound {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub announced_on: Option<NaiveDate>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lead_investors: Option<Vec<String>>,
+}
+
+/// Landscape item audit information.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct ItemAudit {
+    pub date: NaiveDate,
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub url: String,
+    pub vendor: String,
+}
+
+/// Landscape item featured information.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct ItemFeatured {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order: Option<usize>,
+}
+
+/// Landscape item link.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct ItemLink {
+    pub name: String,
+    pub url: String,
+}
+
+/// Landscape item package (published in a package registry).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct ItemPackage {
+    pub name: String,
+    pub registry: String,
+}
+
+impl ItemPackage {
+    /// Return the package id (registry/name).
+    #[must_use]
+    pub fn id(&self) -> PackageId {
+        format!("{}/{}", self.registry, self.name)
+    }
+}
+
+/// Landscape item summary.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct ItemSummary {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_use_case: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub integration: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub integrations: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub intro_url: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub personas: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub release_rate: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_case: Option<String>,
+}
+
+/// Post published in the feed of one of the landscape items.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct NewsPost {
+    pub item_id: ItemId,
+
+    #[serde(flatten)]
+    pub post: FeedPost,
+}
+
+/// Organization information collected from Crunchbase.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct Organization {
     pub generated_at: DateTime<Utc>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub acquired_by: Option<AcquiredBy>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub acquisitions: Option<Vec<Acquisition>>,
 
@@ -894,15 +1582,105 @@ pub struct Organization {
     pub twitter_url: Option<String>,
 }
 
+/// Item license details (collected from ClearlyDefined).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct LicenseDetails {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub declared: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discovered: Option<Vec<String>>,
+
+    pub discrepancy: bool,
+}
+
+/// Organization information collected from LinkedIn.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct LinkedInOrganizationData {
+    pub generated_at: DateTime<Utc>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub followers: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_employees_max: Option<i64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_employees_min: Option<i64>,
+}
+
+/// Organization data provided manually in the landscape data file. When
+/// available, these values take precedence over the ones collected from
+/// Crunchbase.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct OrganizationOverrides {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub city: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub funding: Option<i64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_employees_max: Option<i64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_employees_min: Option<i64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+}
+
+impl OrganizationOverrides {
+    /// Apply the values provided to the organization data.
+    fn apply(&self, org: &mut Organization) {
+        if self.city.is_some() {
+            org.city.clone_from(&self.city);
+        }
+        if self.country.is_some() {
+            org.country.clone_from(&self.country);
+        }
+        if self.funding.is_some() {
+            org.funding = self.funding;
+        }
+        if self.num_employees_max.is_some() || self.num_employees_min.is_some() {
+            org.num_employees_max = self.num_employees_max;
+            org.num_employees_min = self.num_employees_min;
+        }
+        if self.region.is_some() {
+            org.region.clone_from(&self.region);
+        }
+    }
+}
+
+/// Package information collected from its registry.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct PackageData {
+    pub generated_at: DateTime<Utc>,
+    pub name: String,
+    pub registry: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub downloads: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latest_version: Option<String>,
+}
+
 /// Release information.
-#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct Release {
     pub ts: Option<DateTime<Utc>>,
     pub url: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
 }
 
 /// Repository information.
-#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct Repository {
     pub url: String,
 
@@ -914,12 +1692,77 @@ pub struct Repository {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub primary: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<RepositoryStatus>,
+}
+
+/// Repository status (only set when the repository is no longer active).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum RepositoryStatus {
+    Archived,
+    Stale,
+}
+
+/// Repositories stats (aggregated for all the repositories of an item).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct RepositoriesStats {
+    pub contributors: usize,
+    pub forks: i64,
+    pub participation_stats: Vec<i64>,
+    pub repositories: usize,
+    pub stars: i64,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latest_commit_ts: Option<DateTime<Utc>>,
+}
+
+impl RepositoriesStats {
+    /// Aggregate the stats of the repositories provided. Stats are only
+    /// returned when there are at least two repositories with data available.
+    #[must_use]
+    pub fn from_repositories(repositories: Option<&[Repository]>) -> Option<Self> {
+        let gh_data: Vec<&RepositoryGithubData> =
+            repositories?.iter().filter_map(|r| r.github_data.as_ref()).collect();
+        if gh_data.len() < 2 {
+            return None;
+        }
+
+        let mut stats = RepositoriesStats {
+            repositories: gh_data.len(),
+            ..Default::default()
+        };
+        for data in gh_data {
+            stats.contributors += data.contributors.as_ref().map_or(0, |c| c.count);
+            stats.forks += data.forks.unwrap_or_default();
+            stats.stars += data.stars;
+            if data.latest_commit.ts > stats.latest_commit_ts {
+                stats.latest_commit_ts = data.latest_commit.ts;
+            }
+            if stats.participation_stats.len() < data.participation_stats.len() {
+                stats.participation_stats.resize(data.participation_stats.len(), 0);
+            }
+            for (total, count) in stats.participation_stats.iter_mut().zip(&data.participation_stats) {
+                *total += count;
+            }
+        }
+
+        Some(stats)
+    }
+}
+
+/// Number of open issues in a repository labeled as suitable for new
+/// contributors.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct ContributionIssues {
+    pub good_first_issue: usize,
+    pub help_wanted: usize,
 }
 
 /// Repository information collected from GitHub.
-#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct RepositoryGithubData {
-    pub contributors: Contributors,
     pub description: String,
     pub generated_at: DateTime<Utc>,
     pub latest_commit: Commit,
@@ -929,27 +1772,201 @@ pub struct RepositoryGithubData {
     pub topics: Vec<String>,
     pub url: String,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub archived: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contribution_issues: Option<ContributionIssues>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contributors: Option<Contributors>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub first_commit: Option<Commit>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forks: Option<i64>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub languages: Option<BTreeMap<String, i64>>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub languages_breakdown: Option<BTreeMap<String, f64>>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub latest_release: Option<Release>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub license: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub releases: Option<Vec<Release>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub security_advisories: Option<SecurityAdvisories>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stars_history: Option<StarsHistory>,
+}
+
+impl RepositoryGithubData {
+    /// Set the languages breakdown (percentage of the code written in each of
+    /// the languages used, rounded to two decimals) from the languages data.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn set_languages_breakdown(&mut self) {
+        let Some(languages) = &self.languages else {
+            return;
+        };
+        let total_bytes: i64 = languages.values().sum();
+        if total_bytes <= 0 {
+            return;
+        }
+        let breakdown = languages
+            .iter()
+            .map(|(language, bytes)| {
+                let percentage = *bytes as f64 * 100.0 / total_bytes as f64;
+                (language.clone(), (percentage * 100.0).round() / 100.0)
+            })
+            .collect();
+        self.languages_breakdown = Some(breakdown);
+    }
+
+    /// Update the stars history adding a sample with the current stars count
+    /// to the history collected in previous builds (if any).
+    pub fn update_stars_history(&mut self, previous_history: Option<&StarsHistory>) {
+        let mut stars_history = previous_history.cloned().unwrap_or_default();
+        stars_history.insert(self.generated_at.date_naive(), self.stars);
+        self.stars_history = Some(stars_history);
+    }
+}
+
+/// Type alias to represent a repository stars history (stars count per day).
+pub type StarsHistory = BTreeMap<NaiveDate, i64>;
+
+/// Repository security advisories information.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct SecurityAdvisories {
+    pub count: usize,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latest: Option<SecurityAdvisory>,
+}
+
+/// Security advisory information.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct SecurityAdvisory {
+    pub id: String,
+    pub url: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub published_at: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+}
+
+/// Social account information collected from its social network.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct SocialAccountData {
+    pub generated_at: DateTime<Utc>,
+    pub platform: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub followers: Option<u64>,
+}
+
+/// Sponsorship information collected from a sponsorship platform (GitHub
+/// Sponsors or Open Collective).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct SponsorshipData {
+    pub generated_at: DateTime<Utc>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub backers: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub monthly_budget: Option<i64>,
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::settings::{EndUserRule, FeaturedItemRule, FeaturedItemRuleOption, TagRule};
+    use crate::settings::{
+        EndUserRule, FeaturedItemRule, FeaturedItemRuleOption, MembershipLevel, StaleRepositories, TagRule,
+    };
 
     const DATA_FILE: &str = "data.yml";
     const TESTS_DATA_FILE: &str = "src/testdata/data.yml";
 
+    #[test]
+    fn container_image_ref_parse_full_reference() {
+        assert_eq!(
+            ContainerImageRef::parse("ghcr.io/org/image:v1.0.0@sha256:0123456789abcdef0123456789abcdef")
+                .unwrap(),
+            ContainerImageRef {
+                registry: "ghcr.io".to_string(),
+                namespace: "org".to_string(),
+                name: "image".to_string(),
+                tag: Some("v1.0.0".to_string()),
+                digest: Some("sha256:0123456789abcdef0123456789abcdef".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn container_image_ref_parse_docker_hub_defaults() {
+        assert_eq!(
+            ContainerImageRef::parse("nginx:latest").unwrap(),
+            ContainerImageRef {
+                registry: "docker.io".to_string(),
+                namespace: "library".to_string(),
+                name: "nginx".to_string(),
+                tag: Some("latest".to_string()),
+                digest: None,
+            }
+        );
+        assert_eq!(
+            ContainerImageRef::parse("org/image").unwrap(),
+            ContainerImageRef {
+                registry: "docker.io".to_string(),
+                namespace: "org".to_string(),
+                name: "image".to_string(),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn container_image_ref_parse_registry_with_port() {
+        let image_ref = ContainerImageRef::parse("localhost:5000/org/sub/image").unwrap();
+        assert_eq!(image_ref.registry, "localhost:5000");
+        assert_eq!(image_ref.namespace, "org/sub");
+        assert_eq!(image_ref.name, "image");
+        assert_eq!(image_ref.tag, None);
+    }
+
+    #[test]
+    fn container_image_ref_repository() {
+        let image_ref = ContainerImageRef::parse("nginx:latest").unwrap();
+        assert_eq!(image_ref.repository(), "docker.io/library/nginx");
+
+        let image_ref =
+            ContainerImageRef::parse("quay.io/image@sha256:0123456789abcdef0123456789abcdef").unwrap();
+        assert_eq!(image_ref.repository(), "quay.io/image");
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid path component")]
+    fn container_image_ref_parse_invalid_component() {
+        ContainerImageRef::parse("docker.io/Org/image").unwrap();
+    }
+
     #[test]
     fn datasource_new_from_url() {
         let url = "https://example.url/data.yml";
@@ -1032,6 +2049,123 @@ mod tests {
         let _ = LandscapeData::new_from_raw_data(&raw_data).unwrap();
     }
 
+    #[tokio::test]
+    async fn landscape_data_validate() {
+        let src = DataSource {
+            data_file: Some(PathBuf::from(TESTS_DATA_FILE)),
+            ..Default::default()
+        };
+        let unknown_keys = LandscapeData::validate(&src).await.unwrap();
+        assert!(unknown_keys.is_empty());
+    }
+
+    #[test]
+    fn landscape_data_add_best_practices_data() {
+        let mut landscape_data = LandscapeData::default();
+        let best_practices_url = "https://www.bestpractices.dev/projects/1234".to_string();
+        landscape_data.items.push(Item {
+            openssf_best_practices_url: Some(best_practices_url.clone()),
+            ..Default::default()
+        });
+
+        let mut best_practices_data = BestPracticesData::default();
+        let badge = BestPracticesBadge {
+            level: Some("silver".to_string()),
+            ..Default::default()
+        };
+        best_practices_data.insert(best_practices_url, badge);
+
+        landscape_data.add_best_practices_data(&best_practices_data);
+        assert_eq!(
+            landscape_data.items[0].openssf_best_practices_badge_level,
+            Some("silver".to_string())
+        );
+    }
+
+    #[test]
+    fn landscape_data_add_clearlydefined_data() {
+        let mut landscape_data = LandscapeData::default();
+        landscape_data.items.push(Item {
+            packages: Some(vec![ItemPackage {
+                name: "package".to_string(),
+                registry: "npm".to_string(),
+            }]),
+            ..Default::default()
+        });
+
+        let mut clearlydefined_data = ClearlyDefinedData::default();
+        clearlydefined_data.insert(
+            "npm/package".to_string(),
+            ClearlyDefinedDefinition {
+                declared: Some("MIT".to_string()),
+                discovered: Some(vec!["Apache-2.0".to_string()]),
+                ..Default::default()
+            },
+        );
+
+        landscape_data.add_clearlydefined_data(&clearlydefined_data);
+        assert_eq!(
+            landscape_data.items[0].license_details,
+            Some(LicenseDetails {
+                declared: Some("MIT".to_string()),
+                discovered: Some(vec!["Apache-2.0".to_string()]),
+                discrepancy: true,
+            })
+        );
+    }
+
+    #[test]
+    fn landscape_data_add_container_images_data() {
+        let mut landscape_data = LandscapeData::default();
+        landscape_data.items.push(Item {
+            container_images: Some(vec![
+                "docker.io/org/image1".to_string(),
+                "quay.io/org/image2".to_string(),
+                "ghcr.io/org/image3".to_string(),
+            ]),
+            ..Default::default()
+        });
+        landscape_data.items.push(Item {
+            container_images: Some(vec!["ghcr.io/org/image4".to_string()]),
+            ..Default::default()
+        });
+        landscape_data.items.push(Item {
+            container_images: Some(vec![
+                "org/image1:v1".to_string(),
+                "docker.io/org/image1@sha256:0123456789abcdef0123456789abcdef".to_string(),
+            ]),
+            ..Default::default()
+        });
+
+        let mut container_images_data = ContainerImagesData::default();
+        container_images_data.insert(
+            "docker.io/org/image1".to_string(),
+            ContainerImageData {
+                pulls: Some(100),
+                stars: Some(5),
+                ..Default::default()
+            },
+        );
+        container_images_data.insert(
+            "quay.io/org/image2".to_string(),
+            ContainerImageData {
+                pulls: Some(50),
+                ..Default::default()
+            },
+        );
+
+        landscape_data.add_container_images_data(&container_images_data);
+        assert_eq!(
+            landscape_data.items[0].container_images_stats,
+            Some(ContainerImagesStats { pulls: 150, stars: 5 })
+        );
+        assert_eq!(landscape_data.items[1].container_images_stats, None);
+        assert_eq!(
+            landscape_data.items[2].container_images_stats,
+            Some(ContainerImagesStats { pulls: 100, stars: 5 })
+        );
+    }
+
     #[test]
     fn landscape_data_add_crunchbase_data() {
         let mut landscape_data = LandscapeData::default();
@@ -1052,6 +2186,82 @@ mod tests {
         assert_eq!(landscape_data.items[0].crunchbase_data, Some(org));
     }
 
+    #[test]
+    fn landscape_data_add_crunchbase_data_organization_overrides() {
+        let mut landscape_data = LandscapeData::default();
+        let crunchbase_url = "https://crunchbase.url/test".to_string();
+        let organization = OrganizationOverrides {
+            country: Some("Spain".to_string()),
+            num_employees_min: Some(11),
+            num_employees_max: Some(50),
+            ..Default::default()
+        };
+        landscape_data.items.push(Item {
+            crunchbase_url: Some(crunchbase_url.clone()),
+            organization: Some(organization.clone()),
+            ..Default::default()
+        });
+        landscape_data.items.push(Item {
+            organization: Some(organization),
+            ..Default::default()
+        });
+
+        let mut crunchbase_data = CrunchbaseData::default();
+        crunchbase_data.insert(
+            crunchbase_url,
+            Organization {
+                country: Some("United States".to_string()),
+                name: Some("test".to_string()),
+                num_employees_min: Some(1),
+                num_employees_max: Some(10),
+                ..Default::default()
+            },
+        );
+
+        landscape_data.add_crunchbase_data(&crunchbase_data);
+        assert_eq!(
+            landscape_data.items[0].crunchbase_data,
+            Some(Organization {
+                country: Some("Spain".to_string()),
+                name: Some("test".to_string()),
+                num_employees_min: Some(11),
+                num_employees_max: Some(50),
+                ..Default::default()
+            })
+        );
+        assert_eq!(
+            landscape_data.items[1].crunchbase_data,
+            Some(Organization {
+                country: Some("Spain".to_string()),
+                num_employees_min: Some(11),
+                num_employees_max: Some(50),
+                ..Default::default()
+            })
+        );
+    }
+
+    #[test]
+    fn landscape_data_add_linkedin_data() {
+        let linkedin_url = "https://www.linkedin.com/company/test".to_string();
+        let mut landscape_data = LandscapeData::default();
+        landscape_data.items.push(Item {
+            linkedin_url: Some(linkedin_url.clone()),
+            ..Default::default()
+        });
+
+        let org = LinkedInOrganizationData {
+            followers: Some(100),
+            num_employees_max: Some(50),
+            num_employees_min: Some(11),
+            ..Default::default()
+        };
+        let mut linkedin_data = LinkedInData::default();
+        linkedin_data.insert(linkedin_url, org.clone());
+
+        landscape_data.add_linkedin_data(&linkedin_data);
+        assert_eq!(landscape_data.items[0].linkedin_data, Some(org));
+    }
+
     #[test]
     fn landscape_data_add_featured_items_data_maturity() {
         let mut landscape_data = LandscapeData::default();
@@ -1068,6 +2278,7 @@ mod tests {
                     label: Some("Graduated".to_string()),
                     order: Some(1),
                 }],
+                ..Default::default()
             }]),
             ..Default::default()
         };
@@ -1098,6 +2309,7 @@ mod tests {
                     label: Some("VIP category".to_string()),
                     order: Some(1),
                 }],
+                ..Default::default()
             }]),
             ..Default::default()
         };
@@ -1112,6 +2324,85 @@ mod tests {
         );
     }
 
+    #[test]
+    fn landscape_data_add_featured_items_data_rule_defaults() {
+        let mut landscape_data = LandscapeData::default();
+        landscape_data.items.push(Item {
+            member_subcategory: Some("Platinum".to_string()),
+            ..Default::default()
+        });
+        landscape_data.items.push(Item {
+            name: "Item".to_string(),
+            ..Default::default()
+        });
+
+        let settings = LandscapeSettings {
+            featured_items: Some(vec![
+                FeaturedItemRule {
+                    field: "member_subcategory".to_string(),
+                    options: vec![FeaturedItemRuleOption {
+                        value: "Platinum".to_string(),
+                        ..Default::default()
+                    }],
+                    label: Some("Platinum member".to_string()),
+                    order: Some(1),
+                },
+                FeaturedItemRule {
+                    field: "name".to_string(),
+                    options: vec![FeaturedItemRuleOption {
+                        value: "Item".to_string(),
+                        order: Some(3),
+                        ..Default::default()
+                    }],
+                    label: Some("Spotlight".to_string()),
+                    order: Some(2),
+                },
+            ]),
+            ..Default::default()
+        };
+
+        landscape_data.add_featured_items_data(&settings);
+        assert_eq!(
+            landscape_data.items[0].featured,
+            Some(ItemFeatured {
+                label: Some("Platinum member".to_string()),
+                order: Some(1)
+            })
+        );
+        assert_eq!(
+            landscape_data.items[1].featured,
+            Some(ItemFeatured {
+                label: Some("Spotlight".to_string()),
+                order: Some(3)
+            })
+        );
+    }
+
+    #[test]
+    fn landscape_data_add_feeds_data() {
+        let feed_url = "https://blog.url/feed.xml".to_string();
+        let mut landscape_data = LandscapeData::default();
+        landscape_data.items.push(Item {
+            feed_url: Some(feed_url.clone()),
+            ..Default::default()
+        });
+
+        let feed = FeedData {
+            posts: vec![FeedPost {
+                title: "Post".to_string(),
+                url: "https://blog.url/post".to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let mut feeds_data = FeedsData::default();
+        feeds_data.insert(feed_url.clone(), feed.clone());
+
+        landscape_data.add_feeds_data(&feeds_data);
+        assert_eq!(landscape_data.items[0].feed_data, Some(feed.clone()));
+        assert_eq!(landscape_data.feeds_data(), feeds_data);
+    }
+
     #[test]
     fn landscape_data_add_github_data() {
         let mut landscape_data = LandscapeData::default();
@@ -1129,6 +2420,10 @@ mod tests {
         let mut github_data = GithubData::default();
         let repository_github_data = RepositoryGithubData {
             description: "test".to_string(),
+            languages: Some(BTreeMap::from([
+                ("Go".to_string(), 200),
+                ("Rust".to_string(), 100),
+            ])),
             license: Some("Apache-2.0".to_string()),
             ..Default::default()
         };
@@ -1138,13 +2433,89 @@ mod tests {
         assert_eq!(
             landscape_data.items[0].repositories,
             Some(vec![Repository {
-                github_data: Some(repository_github_data),
+                github_data: Some(RepositoryGithubData {
+                    languages_breakdown: Some(BTreeMap::from([
+                        ("Go".to_string(), 66.67),
+                        ("Rust".to_string(), 33.33)
+                    ])),
+                    ..repository_github_data
+                }),
                 ..repository
             }])
         );
         assert_eq!(landscape_data.items[0].oss, Some(true));
     }
 
+    #[test]
+    fn landscape_data_add_github_data_multiple_repositories() {
+        let mut landscape_data = LandscapeData::default();
+        landscape_data.items.push(Item {
+            repositories: Some(vec![
+                Repository {
+                    url: "https://repo.url/test1".to_string(),
+                    primary: Some(true),
+                    ..Default::default()
+                },
+                Repository {
+                    url: "https://repo.url/test2".to_string(),
+                    primary: Some(false),
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        });
+
+        let ts1 = DateTime::parse_from_rfc3339("2024-02-10T10:00:00Z").unwrap().with_timezone(&Utc);
+        let ts2 = DateTime::parse_from_rfc3339("2024-03-10T10:00:00Z").unwrap().with_timezone(&Utc);
+        let mut github_data = GithubData::default();
+        github_data.insert(
+            "https://repo.url/test1".to_string(),
+            RepositoryGithubData {
+                contributors: Some(Contributors {
+                    count: 10,
+                    ..Default::default()
+                }),
+                forks: Some(3),
+                latest_commit: Commit {
+                    ts: Some(ts1),
+                    ..Default::default()
+                },
+                participation_stats: vec![1, 2, 3],
+                stars: 100,
+                ..Default::default()
+            },
+        );
+        github_data.insert(
+            "https://repo.url/test2".to_string(),
+            RepositoryGithubData {
+                contributors: Some(Contributors {
+                    count: 5,
+                    ..Default::default()
+                }),
+                latest_commit: Commit {
+                    ts: Some(ts2),
+                    ..Default::default()
+                },
+                participation_stats: vec![1, 1],
+                stars: 50,
+                ..Default::default()
+            },
+        );
+
+        landscape_data.add_github_data(&github_data);
+        assert_eq!(
+            landscape_data.items[0].repositories_stats,
+            Some(RepositoriesStats {
+                contributors: 15,
+                forks: 3,
+                participation_stats: vec![2, 3, 3],
+                repositories: 2,
+                stars: 150,
+                latest_commit_ts: Some(ts2),
+            })
+        );
+    }
+
     #[test]
     fn landscape_data_add_member_subcategory() {
         let mut landscape_data = LandscapeData::default();
@@ -1272,6 +2643,32 @@ mod tests {
         assert_eq!(landscape_data.items[0].tag, Some("tag2".to_string()));
     }
 
+    #[test]
+    fn landscape_data_set_member_level() {
+        let mut landscape_data = LandscapeData::default();
+        landscape_data.items.push(Item {
+            member_subcategory: Some("Platinum".to_string()),
+            ..Default::default()
+        });
+        landscape_data.items.push(Item {
+            member_subcategory: Some("Associate".to_string()),
+            ..Default::default()
+        });
+
+        let settings = LandscapeSettings {
+            members_category: Some("Members".to_string()),
+            membership_levels: Some(vec![MembershipLevel {
+                name: "Top tier".to_string(),
+                subcategories: vec!["Platinum".to_string()],
+            }]),
+            ..Default::default()
+        };
+
+        landscape_data.set_member_level(&settings);
+        assert_eq!(landscape_data.items[0].member_level, Some("Top tier".to_string()));
+        assert_eq!(landscape_data.items[1].member_level, None);
+    }
+
     #[test]
     fn landscape_data_set_enduser_flag_category_match() {
         let mut landscape_data = LandscapeData::default();
@@ -1340,6 +2737,189 @@ mod tests {
         assert_eq!(landscape_data.items[0].enduser, Some(false));
     }
 
+    #[test]
+    fn landscape_data_set_repositories_status() {
+        let repository = |archived: bool, days_since_latest_commit: i64| Repository {
+            github_data: Some(RepositoryGithubData {
+                archived: Some(archived),
+                latest_commit: Commit {
+                    ts: Some(Utc::now() - chrono::Duration::days(days_since_latest_commit)),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let mut landscape_data = LandscapeData::default();
+        landscape_data.items.push(Item {
+            repositories: Some(vec![
+                repository(true, 1),
+                repository(false, 200),
+                repository(false, 10),
+                Repository::default(),
+            ]),
+            ..Default::default()
+        });
+
+        let settings = LandscapeSettings {
+            stale_repositories: StaleRepositories {
+                inactivity_days: 180,
+                warn: false,
+            },
+            ..Default::default()
+        };
+
+        landscape_data.set_repositories_status(&settings);
+        let statuses: Vec<Option<RepositoryStatus>> =
+            landscape_data.items[0].repositories.iter().flatten().map(|r| r.status).collect();
+        assert_eq!(
+            statuses,
+            vec![
+                Some(RepositoryStatus::Archived),
+                Some(RepositoryStatus::Stale),
+                None,
+                None
+            ]
+        );
+    }
+
+    #[test]
+    fn landscape_data_github_orgs_data() {
+        let repository = |url: &str, stars: i64| Repository {
+            url: url.to_string(),
+            github_data: Some(RepositoryGithubData {
+                contributors: Some(Contributors {
+                    count: 5,
+                    ..Default::default()
+                }),
+                stars,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let landscape_data = LandscapeData {
+            categories: vec![],
+            items: vec![
+                Item {
+                    id: "item1".to_string(),
+                    repositories: Some(vec![
+                        repository("https://github.com/org1/repo1", 10),
+                        repository("https://github.com/org2/repo1", 10),
+                    ]),
+                    ..Default::default()
+                },
+                Item {
+                    id: "item2".to_string(),
+                    repositories: Some(vec![
+                        repository("https://github.com/org1/repo1", 10),
+                        repository("https://github.com/org1/repo2", 20),
+                    ]),
+                    ..Default::default()
+                },
+            ],
+        };
+
+        let github_orgs_data = landscape_data.github_orgs_data();
+        assert_eq!(
+            github_orgs_data,
+            GithubOrgsData::from([(
+                "https://github.com/org1".to_string(),
+                GithubOrgData {
+                    contributors: 10,
+                    items: vec!["item1".to_string(), "item2".to_string()],
+                    repositories: 2,
+                    stars: 30,
+                }
+            )])
+        );
+    }
+
+    #[test]
+    fn landscape_data_items_activity() {
+        let repository = |participation_stats: Vec<i64>| Repository {
+            github_data: Some(RepositoryGithubData {
+                participation_stats,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let landscape_data = LandscapeData {
+            categories: vec![],
+            items: vec![
+                Item {
+                    id: "item1".to_string(),
+                    repositories: Some(vec![repository(vec![1; 52]), repository(vec![2, 3])]),
+                    ..Default::default()
+                },
+                Item {
+                    id: "item2".to_string(),
+                    repositories: Some(vec![repository(vec![])]),
+                    ..Default::default()
+                },
+                Item {
+                    id: "item3".to_string(),
+                    ..Default::default()
+                },
+            ],
+        };
+
+        let mut expected_activity = vec![1; 52];
+        expected_activity[50] = 3;
+        expected_activity[51] = 4;
+        assert_eq!(
+            landscape_data.items_activity(),
+            ItemsActivity::from([("item1".to_string(), expected_activity)])
+        );
+    }
+
+    #[test]
+    fn landscape_data_news() {
+        let post = |title: &str, day: u32| FeedPost {
+            title: title.to_string(),
+            url: format!("https://blog.url/{title}"),
+            published_at: NaiveDate::from_ymd_opt(2024, 5, day)
+                .and_then(|date| date.and_hms_opt(0, 0, 0))
+                .map(|ts| ts.and_utc()),
+        };
+        let mut landscape_data = LandscapeData::default();
+        landscape_data.items.push(Item {
+            id: "item1".to_string(),
+            feed_data: Some(FeedData {
+                posts: vec![
+                    post("post1", 1),
+                    FeedPost {
+                        title: "undated".to_string(),
+                        ..Default::default()
+                    },
+                ],
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+        landscape_data.items.push(Item {
+            id: "item2".to_string(),
+            feed_data: Some(FeedData {
+                posts: vec![post("post2", 2)],
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+
+        assert_eq!(
+            landscape_data.news(),
+            vec![
+                NewsPost {
+                    item_id: "item2".to_string(),
+                    post: post("post2", 2),
+                },
+                NewsPost {
+                    item_id: "item1".to_string(),
+                    post: post("post1", 1),
+                },
+            ]
+        );
+    }
+
     #[test]
     #[allow(clippy::too_many_lines)]
     fn landscape_data_from_legacy_data() {
@@ -1375,23 +2955,37 @@ mod tests {
                             annual_review_url: Some("annual_review_url".to_string()),
                             artwork_url: Some("artwork_url".to_string()),
                             blog_url: Some("blog_url".to_string()),
+                            bluesky_url: Some("bluesky_url".to_string()),
                             chat_channel: Some("chat_channel".to_string()),
                             clomonitor_name: Some("clomonitor_name".to_string()),
+                            container_images: Some(vec!["docker.io/org/image".to_string()]),
                             dev_stats_url: Some("dev_stats_url".to_string()),
                             discord_url: Some("discord_url".to_string()),
                             docker_url: Some("docker_url".to_string()),
                             documentation_url: Some("documentation_url".to_string()),
+                            feed_url: Some("feed_url".to_string()),
                             github_discussions_url: Some("github_discussions_url".to_string()),
+                            github_sponsors_url: Some("github_sponsors_url".to_string()),
                             gitter_url: Some("gitter_url".to_string()),
                             graduated: Some(date),
                             incubating: Some(date),
                             linkedin_url: Some("linkedin_url".to_string()),
                             mailing_list_url: Some("mailing_list_url".to_string()),
+                            mastodon_url: Some("mastodon_url".to_string()),
+                            open_collective_url: Some("open_collective_url".to_string()),
+                            organization: Some(OrganizationOverrides {
+                                country: Some("country".to_string()),
+                                ..Default::default()
+                            }),
                             other_links: Some(vec![ItemLink {
                                 name: "name".to_string(),
                                 url: "https://link.url".to_string(),
                             }]),
                             package_manager_url: Some("package_manager_url".to_string()),
+                            packages: Some(vec![ItemPackage {
+                                name: "package".to_string(),
+                                registry: "npm".to_string(),
+                            }]),
                             parent_project: Some("parent_project".to_string()),
                             slack_url: Some("slack_url".to_string()),
                             specification: Some(false),
@@ -1410,6 +3004,7 @@ mod tests {
                             youtube_url: Some("youtube_url".to_string()),
                         }),
                         joined: Some(date),
+                        logo_dark: Some("logo_dark".to_string()),
                         project: Some("graduated".to_string()),
                         repo_url: Some("repo_url".to_string()),
                         second_path: Some(vec!["category2 / subcategory2.1".to_string()]),
@@ -1452,9 +3047,13 @@ mod tests {
                     vendor: "vendor".to_string(),
                 }]),
                 blog_url: Some("blog_url".to_string()),
+                bluesky_url: Some("bluesky_url".to_string()),
                 chat_channel: Some("chat_channel".to_string()),
                 clomonitor_name: Some("clomonitor_name".to_string()),
                 clomonitor_report_summary: None,
+                clomonitor_score: None,
+                container_images: Some(vec!["docker.io/org/image".to_string()]),
+                container_images_stats: None,
                 crunchbase_data: None,
                 crunchbase_url: Some("crunchbase_url".to_string()),
                 description: Some("description".to_string()),
@@ -1464,40 +3063,64 @@ mod tests {
                 documentation_url: Some("documentation_url".to_string()),
                 enduser: Some(false),
                 featured: None,
+                feed_data: None,
+                feed_url: Some("feed_url".to_string()),
                 github_discussions_url: Some("github_discussions_url".to_string()),
+                github_sponsors_url: Some("github_sponsors_url".to_string()),
                 gitter_url: Some("gitter_url".to_string()),
                 graduated_at: Some(date),
                 incubating_at: Some(date),
                 joined_at: Some(date),
+                license_details: None,
+                linkedin_data: None,
                 linkedin_url: Some("linkedin_url".to_string()),
+                logo_dark: Some("logo_dark".to_string()),
+                logo_png: None,
                 mailing_list_url: Some("mailing_list_url".to_string()),
+                mastodon_url: Some("mastodon_url".to_string()),
                 maturity: Some("graduated".to_string()),
+                member_level: None,
                 member_subcategory: None,
                 latest_annual_review_at: Some(date),
                 latest_annual_review_url: Some("annual_review_url".to_string()),
+                open_collective_url: Some("open_collective_url".to_string()),
+                openssf_best_practices_badge_level: None,
                 openssf_best_practices_url: Some("url_for_bestpractices".to_string()),
+                organization: Some(OrganizationOverrides {
+                    country: Some("country".to_string()),
+                    ..Default::default()
+                }),
                 oss: None,
                 other_links: Some(vec![ItemLink {
                     name: "name".to_string(),
                     url: "https://link.url".to_string(),
                 }]),
                 package_manager_url: Some("package_manager_url".to_string()),
+                packages: Some(vec![ItemPackage {
+                    name: "package".to_string(),
+                    registry: "npm".to_string(),
+                }]),
                 parent_project: Some("parent_project".to_string()),
+                qr_code: None,
                 repositories: Some(vec![
                     Repository {
                         url: "repo_url".to_string(),
                         branch: Some("branch".to_string()),
                         github_data: None,
                         primary: Some(true),
+                        status: None,
                     },
                     Repository {
                         url: "additional_repo_url".to_string(),
                         branch: Some("branch".to_string()),
                         github_data: None,
                         primary: Some(false),
+                        status: None,
                     },
                 ]),
+                repositories_stats: None,
                 slack_url: Some("slack_url".to_string()),
+                social_card: None,
                 specification: Some(false),
                 stack_overflow_url: Some("stack_overflow_url".to_string()),
                 summary: Some(ItemSummary {
@@ -1679,4 +3302,57 @@ mod tests {
         item.set_website();
         assert_eq!(item.website, "homepage_url".to_string());
     }
+
+    #[test]
+    fn item_social_accounts() {
+        let item = Item {
+            mastodon_url: Some("https://mastodon.social/@project".to_string()),
+            twitter_url: Some("https://twitter.com/project".to_string()),
+            ..Default::default()
+        };
+
+        let accounts: Vec<(&str, &String)> = item.social_accounts().collect();
+        assert_eq!(
+            accounts,
+            vec![
+                ("mastodon", &"https://mastodon.social/@project".to_string()),
+                ("twitter", &"https://twitter.com/project".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn clearlydefined_definition_has_license_discrepancy() {
+        let mut definition = ClearlyDefinedDefinition {
+            declared: Some("Apache-2.0".to_string()),
+            discovered: Some(vec!["Apache-2.0 AND MIT".to_string()]),
+            ..Default::default()
+        };
+        assert!(!definition.has_license_discrepancy());
+
+        definition.discovered = Some(vec!["MIT".to_string()]);
+        assert!(definition.has_license_discrepancy());
+
+        definition.declared = None;
+        assert!(!definition.has_license_discrepancy());
+    }
+
+    #[test]
+    fn repository_github_data_update_stars_history() {
+        let mut repository_github_data = RepositoryGithubData {
+            generated_at: DateTime::parse_from_rfc3339("2024-02-10T10:00:00Z").unwrap().with_timezone(&Utc),
+            stars: 20,
+            ..Default::default()
+        };
+        let previous_history = StarsHistory::from([(NaiveDate::from_ymd_opt(2024, 2, 3).unwrap(), 10)]);
+
+        repository_github_data.update_stars_history(Some(&previous_history));
+        assert_eq!(
+            repository_github_data.stars_history,
+            Some(StarsHistory::from([
+                (NaiveDate::from_ymd_opt(2024, 2, 3).unwrap(), 10),
+                (NaiveDate::from_ymd_opt(2024, 2, 10).unwrap(), 20),
+            ]))
+        );
+    }
 }
@@ -1,20 +1,27 @@
 //! This module defines some types used to parse the landscape data file in
 //! legacy format and convert it to the new one.
 
-use super::{ItemAudit, ItemLink};
+use super::{
+    ContainerImageRef, ItemAudit, ItemLink, ItemPackage, OrganizationOverrides, CONTAINER_REGISTRIES,
+};
 use crate::util::validate_url;
 use anyhow::{bail, format_err, Context, Result};
 use chrono::NaiveDate;
 use lazy_static::lazy_static;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use serde_yaml::Value;
+use std::{collections::HashMap, str::Chars};
+use yaml_rust2::{parser::Parser, Event};
 
 lazy_static! {
     /// TAG name regular expression.
     static ref TAG_NAME: Regex = Regex::new(r"^[a-z\-]+$").expect("exprs in TAG_NAME to be valid");
 }
 
+/// Package registries supported.
+const PACKAGE_REGISTRIES: [&str; 4] = ["crates", "maven", "npm", "pypi"];
+
 /// Landscape data (legacy format).
 #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub(super) struct LandscapeData {
@@ -88,6 +95,32 @@ impl LandscapeData {
                             }
                         }
 
+                        // Check container images
+                        if let Some(container_images) = &extra.container_images {
+                            for image in container_images {
+                                validate_container_image(image).context(ctx.clone())?;
+                            }
+                        }
+
+                        // Check organization employees range
+                        if let Some(organization) = &extra.organization {
+                            if let (Some(min), Some(max)) =
+                                (organization.num_employees_min, organization.num_employees_max)
+                            {
+                                if min > max {
+                                    return Err(format_err!("invalid organization employees range"))
+                                        .context(ctx);
+                                }
+                            }
+                        }
+
+                        // Check packages
+                        if let Some(packages) = &extra.packages {
+                            for package in packages {
+                                validate_package(package).context(ctx.clone())?;
+                            }
+                        }
+
                         // Check tag name
                         if let Some(tag) = &extra.tag {
                             if !TAG_NAME.is_match(tag) {
@@ -136,6 +169,7 @@ pub(super) struct Item {
     pub enduser: Option<bool>,
     pub extra: Option<ItemExtra>,
     pub joined: Option<NaiveDate>,
+    pub logo_dark: Option<String>,
     pub project: Option<String>,
     pub repo_url: Option<String>,
     pub second_path: Option<Vec<String>>,
@@ -155,20 +189,28 @@ pub(super) struct ItemExtra {
     pub annual_review_url: Option<String>,
     pub artwork_url: Option<String>,
     pub blog_url: Option<String>,
+    pub bluesky_url: Option<String>,
     pub chat_channel: Option<String>,
     pub clomonitor_name: Option<String>,
+    pub container_images: Option<Vec<String>>,
     pub dev_stats_url: Option<String>,
     pub discord_url: Option<String>,
     pub docker_url: Option<String>,
     pub documentation_url: Option<String>,
+    pub feed_url: Option<String>,
     pub github_discussions_url: Option<String>,
+    pub github_sponsors_url: Option<String>,
     pub gitter_url: Option<String>,
     pub graduated: Option<NaiveDate>,
     pub incubating: Option<NaiveDate>,
     pub linkedin_url: Option<String>,
     pub mailing_list_url: Option<String>,
+    pub mastodon_url: Option<String>,
+    pub open_collective_url: Option<String>,
+    pub organization: Option<OrganizationOverrides>,
     pub other_links: Option<Vec<ItemLink>>,
     pub package_manager_url: Option<String>,
+    pub packages: Option<Vec<ItemPackage>>,
     pub parent_project: Option<String>,
     pub slack_url: Option<String>,
     pub specification: Option<bool>,
@@ -194,6 +236,40 @@ pub(super) struct Repository {
     pub branch: Option<String>,
 }
 
+/// Validate the container image provided.
+fn validate_container_image(image: &str) -> Result<()> {
+    let image_ref = ContainerImageRef::parse(image).context(format!("invalid container image: {image}"))?;
+    if !CONTAINER_REGISTRIES.contains(&image_ref.registry.as_str()) {
+        bail!(
+            "invalid container image: {image} (supported registries: {})",
+            CONTAINER_REGISTRIES.join(", ")
+        );
+    }
+    Ok(())
+}
+
+/// Validate the package provided.
+fn validate_package(package: &ItemPackage) -> Result<()> {
+    if package.name.is_empty() {
+        bail!("package name is required");
+    }
+    if !PACKAGE_REGISTRIES.contains(&package.registry.as_str()) {
+        bail!(
+            "invalid package registry: {} (supported: {})",
+            package.registry,
+            PACKAGE_REGISTRIES.join(", ")
+        );
+    }
+    if package.registry == "maven" && package.name.split(':').count() != 2 {
+        bail!(
+            "invalid maven package name: {} (expected: group:artifact)",
+            package.name
+        );
+    }
+
+    Ok(())
+}
+
 /// Validate the urls of the item provided.
 fn validate_urls(item: &Item) -> Result<()> {
     // Check urls in item
@@ -223,15 +299,20 @@ fn validate_urls(item: &Item) -> Result<()> {
             ("annual_review", &extra.annual_review_url),
             ("artwork", &extra.artwork_url),
             ("blog", &extra.blog_url),
+            ("bluesky", &extra.bluesky_url),
             ("dev_stats", &extra.dev_stats_url),
             ("discord", &extra.discord_url),
             ("docker", &extra.docker_url),
             ("documentation", &extra.documentation_url),
+            ("feed", &extra.feed_url),
             ("github_discussions", &extra.github_discussions_url),
+            ("github_sponsors", &extra.github_sponsors_url),
             ("gitter", &extra.gitter_url),
             ("linkedin", &extra.linkedin_url),
             ("package_manager", &extra.package_manager_url),
             ("mailing_list", &extra.mailing_list_url),
+            ("mastodon", &extra.mastodon_url),
+            ("open_collective", &extra.open_collective_url),
             ("slack", &extra.slack_url),
             ("stack_overflow", &extra.stack_overflow_url),
             ("youtube", &extra.youtube_url),
@@ -260,10 +341,249 @@ fn validate_urls(item: &Item) -> Result<()> {
     Ok(())
 }
 
+/// Keys used as entries markers in the legacy format (they have no value).
+const LEGACY_MARKERS: [&str; 3] = ["category", "subcategory", "item"];
+
+/// Find the keys in the raw landscape data provided that are not supported
+/// (i.e. misspelled fields). These keys are ignored silently when parsing the
+/// landscape data, so it's useful to report them when validating the file.
+/// The line where each of them is defined is included when it can be found.
+pub(super) fn find_unknown_keys(raw_data: &str) -> Result<Vec<String>> {
+    // Parse raw data and serialize it back using the supported fields only
+    let raw_value: Value = serde_yaml::from_str(raw_data).context("invalid yaml file")?;
+    let legacy_data: LandscapeData = serde_yaml::from_str(raw_data).context("invalid yaml file")?;
+    let mut known_value = serde_yaml::to_value(legacy_data)?;
+    if let Value::Mapping(known_value) = &mut known_value {
+        if let Some(landscape) = known_value.get("landscape").cloned() {
+            known_value.insert(Value::from("categories"), landscape);
+        }
+    }
+
+    // Any key present in the raw data that is missing in the known data is
+    // not supported
+    let keys_lines = KeysLines::parse(raw_data).context("invalid yaml file")?;
+    let mut unknown_keys = vec![];
+    collect_unknown_keys(&raw_value, &known_value, Some(&keys_lines), "", &mut unknown_keys);
+
+    Ok(unknown_keys)
+}
+
+/// Collect the paths of the keys present in the raw value that are missing in
+/// the known value (walking both values recursively). The lines where the keys
+/// are defined are walked along with the values, so that they can be included
+/// in the paths reported.
+fn collect_unknown_keys(
+    raw_value: &Value,
+    known_value: &Value,
+    keys_lines: Option<&KeysLines>,
+    path: &str,
+    unknown_keys: &mut Vec<String>,
+) {
+    match (raw_value, known_value) {
+        (Value::Mapping(raw_mapping), Value::Mapping(known_mapping)) => {
+            for (key, raw_entry) in raw_mapping {
+                let key_name = key.as_str().map_or_else(|| format!("{key:?}"), ToString::to_string);
+
+                // Skip empty markers used in the legacy format (i.e. `- item:`)
+                if raw_entry.is_null() && LEGACY_MARKERS.contains(&key_name.as_str()) {
+                    continue;
+                }
+
+                let (line, entry_lines) = keys_lines.and_then(|kl| kl.key(&key_name)).unzip();
+                let key_path = if path.is_empty() {
+                    key_name
+                } else {
+                    format!("{path}.{key_name}")
+                };
+                match known_mapping.get(key) {
+                    Some(known_entry) => {
+                        collect_unknown_keys(raw_entry, known_entry, entry_lines, &key_path, unknown_keys);
+                    }
+                    None => match line {
+                        Some(line) => unknown_keys.push(format!("{key_path} (line {line})")),
+                        None => unknown_keys.push(key_path),
+                    },
+                }
+            }
+        }
+        (Value::Sequence(raw_seq), Value::Sequence(known_seq)) => {
+            for (index, (raw_entry, known_entry)) in raw_seq.iter().zip(known_seq).enumerate() {
+                // Entries are identified by their name when available
+                let entry_id = raw_entry
+                    .get("name")
+                    .and_then(Value::as_str)
+                    .map_or_else(|| index.to_string(), ToString::to_string);
+                let entry_path = format!("{path}[{entry_id}]");
+                let entry_lines = keys_lines.and_then(|kl| kl.entry(index));
+                collect_unknown_keys(raw_entry, known_entry, entry_lines, &entry_path, unknown_keys);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Lines (starting from 1) where the keys of a YAML document are defined, as
+/// reported by the YAML parser.
+#[derive(Debug)]
+enum KeysLines {
+    Mapping(Vec<(String, usize, KeysLines)>),
+    Sequence(Vec<KeysLines>),
+    Other,
+}
+
+impl KeysLines {
+    /// Parse the YAML document provided, collecting the lines of its keys.
+    fn parse(raw_data: &str) -> Result<Self> {
+        let mut parser = Parser::new_from_str(raw_data);
+        loop {
+            match parser.next_token()?.0 {
+                Event::StreamStart | Event::DocumentStart => {}
+                Event::StreamEnd => return Ok(Self::Other),
+                event => return Self::parse_node(&mut parser, &event),
+            }
+        }
+    }
+
+    /// Parse the node that starts with the event provided.
+    fn parse_node(parser: &mut Parser<Chars>, event: &Event) -> Result<Self> {
+        match event {
+            Event::MappingStart(..) => {
+                let mut entries = vec![];
+                loop {
+                    let (event, mark) = parser.next_token()?;
+                    let key = match event {
+                        Event::MappingEnd => break,
+                        Event::Scalar(key, ..) => key,
+                        // Complex keys are not supported (they are skipped)
+                        event => {
+                            Self::parse_node(parser, &event)?;
+                            let (event, _) = parser.next_token()?;
+                            Self::parse_node(parser, &event)?;
+                            continue;
+                        }
+                    };
+                    let (event, _) = parser.next_token()?;
+                    let value = Self::parse_node(parser, &event)?;
+                    entries.push((key, mark.line(), value));
+                }
+                Ok(Self::Mapping(entries))
+            }
+            Event::SequenceStart(..) => {
+                let mut entries = vec![];
+                loop {
+                    match parser.next_token()?.0 {
+                        Event::SequenceEnd => break,
+                        event => entries.push(Self::parse_node(parser, &event)?),
+                    }
+                }
+                Ok(Self::Sequence(entries))
+            }
+            _ => Ok(Self::Other),
+        }
+    }
+
+    /// Return the line of the key provided and the lines of its value (when
+    /// this is a mapping).
+    fn key(&self, key: &str) -> Option<(usize, &KeysLines)> {
+        match self {
+            Self::Mapping(entries) => {
+                entries.iter().find(|(name, _, _)| name == key).map(|(_, line, value)| (*line, value))
+            }
+            _ => None,
+        }
+    }
+
+    /// Return the lines of the entry at the index provided (when this is a
+    /// sequence).
+    fn entry(&self, index: usize) -> Option<&KeysLines> {
+        match self {
+            Self::Sequence(entries) => entries.get(index),
+            _ => None,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn find_unknown_keys_none_found() {
+        let raw_data = r"
+categories:
+  - name: Category
+    subcategories:
+      - name: Subcategory
+        items:
+          - name: Item
+            homepage_url: https://example.com
+            logo: logo.svg
+            extra:
+              annotations:
+                key: value
+";
+
+        assert!(find_unknown_keys(raw_data).unwrap().is_empty());
+    }
+
+    #[test]
+    fn find_unknown_keys_some_found() {
+        let raw_data = r"
+landscape:
+  - category:
+    name: Category
+    subcategories:
+      - subcategory:
+        name: Subcategory
+        items:
+          - item:
+            name: Item
+            homepage_url: https://example.com
+            logo: logo.svg
+            repo: https://github.com/owner/repo
+            extra:
+              blog: https://blog.url
+other: value
+";
+
+        assert_eq!(
+            find_unknown_keys(raw_data).unwrap(),
+            vec![
+                "landscape[Category].subcategories[Subcategory].items[Item].repo (line 13)".to_string(),
+                "landscape[Category].subcategories[Subcategory].items[Item].extra.blog (line 15)".to_string(),
+                "other (line 16)".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn find_unknown_keys_line_from_parser() {
+        let raw_data = r"
+landscape:
+  - category:
+    name: Category
+    subcategories:
+      - subcategory:
+        name: Subcategory
+        items:
+          - item:
+            name: Item 1
+            homepage_url: https://example.com
+            logo: logo.svg
+            description: 'repo: not a key'
+          - item:
+            name: Item 2
+            homepage_url: https://example.com
+            logo: logo.svg
+            repo: https://github.com/owner/repo
+";
+
+        assert_eq!(
+            find_unknown_keys(raw_data).unwrap(),
+            vec!["landscape[Category].subcategories[Subcategory].items[Item 2].repo (line 18)".to_string()]
+        );
+    }
+
     #[test]
     fn landscape_data_validate_succeeds() {
         let mut landscape = LandscapeData::default();
@@ -456,6 +776,84 @@ mod tests {
         landscape.validate().unwrap();
     }
 
+    #[test]
+    #[should_panic(expected = "invalid container image")]
+    fn landscape_data_validate_invalid_container_image() {
+        let mut landscape = LandscapeData::default();
+        landscape.landscape.push(Category {
+            name: "Category".to_string(),
+            subcategories: vec![SubCategory {
+                name: "Subcategory".to_string(),
+                items: vec![Item {
+                    name: "Item".to_string(),
+                    homepage_url: "https://example.com".to_string(),
+                    logo: "logo".to_string(),
+                    extra: Some(ItemExtra {
+                        container_images: Some(vec!["registry.example.com/org/image".to_string()]),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }],
+            }],
+        });
+
+        landscape.validate().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid package registry")]
+    fn landscape_data_validate_invalid_package_registry() {
+        let mut landscape = LandscapeData::default();
+        landscape.landscape.push(Category {
+            name: "Category".to_string(),
+            subcategories: vec![SubCategory {
+                name: "Subcategory".to_string(),
+                items: vec![Item {
+                    name: "Item".to_string(),
+                    homepage_url: "https://example.com".to_string(),
+                    logo: "logo".to_string(),
+                    extra: Some(ItemExtra {
+                        packages: Some(vec![ItemPackage {
+                            name: "package".to_string(),
+                            registry: "unknown".to_string(),
+                        }]),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }],
+            }],
+        });
+
+        landscape.validate().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid maven package name")]
+    fn landscape_data_validate_invalid_maven_package_name() {
+        let mut landscape = LandscapeData::default();
+        landscape.landscape.push(Category {
+            name: "Category".to_string(),
+            subcategories: vec![SubCategory {
+                name: "Subcategory".to_string(),
+                items: vec![Item {
+                    name: "Item".to_string(),
+                    homepage_url: "https://example.com".to_string(),
+                    logo: "logo".to_string(),
+                    extra: Some(ItemExtra {
+                        packages: Some(vec![ItemPackage {
+                            name: "package".to_string(),
+                            registry: "maven".to_string(),
+                        }]),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }],
+            }],
+        });
+
+        landscape.validate().unwrap();
+    }
+
     #[test]
     #[should_panic(expected = "invalid tag")]
     fn landscape_data_validate_invalid_tag() {
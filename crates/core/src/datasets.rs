@@ -3,17 +3,46 @@
 //! as from data collected from other external sources like GitHub. Some of
 //! these datasets may be embedded in the index document (or used while
 //! rendering it), whereas others will be written to the output directory so
-//! that they can be fetched when needed. These datasets are not meant to be
-//! consumed by other applications, as they can change at any time.
+//! that they can be fetched when needed. These datasets are mainly meant to be
+//! consumed by the web application, but they include a schema version that
+//! other applications can rely on to detect breaking changes.
 
-use self::{base::Base, embed::Embed, full::Full};
+use self::{base::Base, embed::Embed, full::Full, search::Search};
 use crate::{
-    data::{CrunchbaseData, GithubData, LandscapeData},
+    data::{
+        CrunchbaseData, GithubData, LandscapeData, LinkedInData, PackagesData, SocialData, SponsorshipsData,
+    },
     games::LandscapeGames,
     guide::LandscapeGuide,
     settings::LandscapeSettings,
     stats::Stats,
 };
+use schemars::JsonSchema;
+use serde::Serialize;
+
+/// Current version of the datasets schema. It must be bumped every time a
+/// breaking change is introduced in any of the datasets.
+pub const SCHEMA_VERSION: &str = "1";
+
+/// Wrapper used to include the schema version when serializing a dataset.
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct Versioned<'a, T> {
+    pub schema_version: &'static str,
+
+    #[serde(flatten)]
+    pub dataset: &'a T,
+}
+
+impl<'a, T> Versioned<'a, T> {
+    /// Create a new Versioned instance for the dataset provided.
+    #[must_use]
+    pub fn new(dataset: &'a T) -> Self {
+        Self {
+            schema_version: SCHEMA_VERSION,
+            dataset,
+        }
+    }
+}
 
 /// Input used to create a new Datasets instance.
 #[derive(Debug, Clone)]
@@ -23,8 +52,12 @@ pub struct NewDatasetsInput<'a> {
     pub github_data: &'a GithubData,
     pub guide: &'a Option<LandscapeGuide>,
     pub landscape_data: &'a LandscapeData,
+    pub linkedin_data: &'a LinkedInData,
+    pub packages_data: &'a PackagesData,
     pub qr_code: &'a String,
     pub settings: &'a LandscapeSettings,
+    pub social_data: &'a SocialData,
+    pub sponsorships_data: &'a SponsorshipsData,
 }
 
 /// Datasets collection.
@@ -39,6 +72,9 @@ pub struct Datasets {
     /// #[full]
     pub full: Full,
 
+    /// #[search]
+    pub search: Search,
+
     /// #[crate::stats]
     pub stats: Stats,
 }
@@ -50,7 +86,16 @@ impl Datasets {
         Datasets {
             base: Base::new(i.landscape_data, i.settings, i.guide, i.games, i.qr_code),
             embed: Embed::new(i.landscape_data, i.settings),
-            full: Full::new(i.landscape_data, i.crunchbase_data, i.github_data),
+            full: Full::new(
+                i.landscape_data,
+                i.crunchbase_data,
+                i.github_data,
+                i.linkedin_data,
+                i.packages_data,
+                i.social_data,
+                i.sponsorships_data,
+            ),
+            search: Search::new(i.landscape_data),
             stats: Stats::new(i.landscape_data, i.settings),
         }
     }
@@ -66,15 +111,17 @@ pub mod base {
         games::LandscapeGames,
         guide::LandscapeGuide,
         settings::{
-            Colors, Footer, GridItemsSize, Group, Header, Images, LandscapeSettings, UpcomingEvent, ViewMode,
+            Colors, Footer, GridItemsSize, Group, Header, Images, LandscapeSettings, MembershipLevel,
+            TranslationsCatalog, UpcomingEvent, ViewMode,
         },
     };
+    use schemars::JsonSchema;
     use serde::{Deserialize, Serialize};
     use std::collections::BTreeMap;
 
     /// Base dataset information.
     #[allow(clippy::struct_field_names)]
-    #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+    #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
     pub struct Base {
         pub finances_available: bool,
         pub foundation: String,
@@ -95,6 +142,9 @@ pub mod base {
         #[serde(skip_serializing_if = "Option::is_none")]
         pub footer: Option<Footer>,
 
+        #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+        pub full_dataset_shards: BTreeMap<CategoryName, String>,
+
         #[serde(skip_serializing_if = "Option::is_none")]
         pub games_available: Option<Vec<String>>,
 
@@ -116,9 +166,18 @@ pub mod base {
         #[serde(default, skip_serializing_if = "Vec::is_empty")]
         pub items: Vec<Item>,
 
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub locale: Option<String>,
+
         #[serde(skip_serializing_if = "Option::is_none")]
         pub members_category: Option<String>,
 
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        pub membership_levels: Vec<MembershipLevel>,
+
+        #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+        pub translations: TranslationsCatalog,
+
         #[serde(skip_serializing_if = "Option::is_none")]
         pub upcoming_event: Option<UpcomingEvent>,
 
@@ -145,6 +204,7 @@ pub mod base {
                 categories_overridden: vec![],
                 colors: settings.colors.clone(),
                 footer: settings.footer.clone(),
+                full_dataset_shards: BTreeMap::new(),
                 games_available: None,
                 grid_items_size: settings.grid_items_size.clone(),
                 groups: settings.groups.clone().unwrap_or_default(),
@@ -152,7 +212,14 @@ pub mod base {
                 header: settings.header.clone(),
                 images: settings.images.clone(),
                 items: vec![],
+                locale: settings.i18n.as_ref().map(|i18n| i18n.locale.clone()),
                 members_category: settings.members_category.clone(),
+                membership_levels: settings.membership_levels.clone().unwrap_or_default(),
+                translations: settings
+                    .i18n
+                    .as_ref()
+                    .and_then(|i18n| i18n.translations.clone())
+                    .unwrap_or_default(),
                 upcoming_event: settings.upcoming_event.clone(),
                 view_mode: settings.view_mode.clone(),
             };
@@ -217,7 +284,7 @@ pub mod base {
     }
 
     /// Base dataset item information.
-    #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+    #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
     pub struct Item {
         pub category: String,
         pub id: String,
@@ -231,9 +298,21 @@ pub mod base {
         #[serde(skip_serializing_if = "Option::is_none")]
         pub featured: Option<ItemFeatured>,
 
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub logo_dark: Option<String>,
+
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub logo_png: Option<String>,
+
         #[serde(skip_serializing_if = "Option::is_none")]
         pub maturity: Option<String>,
 
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub member_level: Option<String>,
+
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub openssf_best_practices_badge_level: Option<String>,
+
         #[serde(skip_serializing_if = "Option::is_none")]
         pub oss: Option<bool>,
 
@@ -250,8 +329,12 @@ pub mod base {
                 id: data_item.id.clone(),
                 name: data_item.name.clone(),
                 logo: data_item.logo.clone(),
+                logo_dark: data_item.logo_dark.clone(),
+                logo_png: data_item.logo_png.clone(),
                 maturity: data_item.maturity.clone(),
+                member_level: data_item.member_level.clone(),
                 subcategory: data_item.subcategory.clone(),
+                openssf_best_practices_badge_level: data_item.openssf_best_practices_badge_level.clone(),
                 oss: data_item.oss,
                 tag: data_item.tag.clone(),
             }
@@ -269,13 +352,14 @@ pub mod base {
 pub mod embed {
     use crate::{
         data::{self, AdditionalCategory, Category, LandscapeData},
-        settings::LandscapeSettings,
+        settings::{EmbedViewOptions, LandscapeSettings},
     };
+    use schemars::JsonSchema;
     use serde::{Deserialize, Serialize};
     use std::collections::HashMap;
 
     /// Embed dataset information.
-    #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+    #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
     pub struct Embed {
         #[serde(default, flatten, skip_serializing_if = "HashMap::is_empty")]
         pub views: HashMap<EmbedKey, EmbedView>,
@@ -285,13 +369,28 @@ pub mod embed {
         /// Create a new Embed instance from the data provided.
         pub fn new(landscape_data: &LandscapeData, settings: &LandscapeSettings) -> Self {
             let mut views = HashMap::new();
+            let embed_categories = settings.embed.as_ref().and_then(|embed| embed.categories.as_ref());
 
             for category in &landscape_data.categories {
+                // Skip categories not included in the embed configuration
+                // (when provided) and get the view options for this category
+                let options = match embed_categories {
+                    Some(embed_categories) => {
+                        let Some(embed_category) = embed_categories.iter().find(|c| c.name == category.name)
+                        else {
+                            continue;
+                        };
+                        embed_category.options.clone()
+                    }
+                    None => None,
+                };
+
                 // Full category view
                 let key = category.normalized_name.clone();
                 let view = EmbedView {
                     foundation: settings.foundation.clone(),
                     category: category.clone(),
+                    options: options.clone(),
                     items: landscape_data
                         .items
                         .iter()
@@ -316,6 +415,7 @@ pub mod embed {
                             normalized_name: category.normalized_name.clone(),
                             subcategories: vec![subcategory.clone()],
                         },
+                        options: options.clone(),
                         items: landscape_data
                             .items
                             .iter()
@@ -337,23 +437,58 @@ pub mod embed {
 
             Self { views }
         }
+
+        /// Return an index of the embed views available, sorted by key.
+        #[must_use]
+        pub fn index(&self) -> Vec<EmbedViewSummary> {
+            let mut index: Vec<EmbedViewSummary> = self
+                .views
+                .iter()
+                .map(|(key, view)| EmbedViewSummary {
+                    category: view.category.name.clone(),
+                    items: view.items.len(),
+                    key: key.clone(),
+                    subcategory: if key.contains("--") {
+                        view.category.subcategories.first().map(|sc| sc.name.clone())
+                    } else {
+                        None
+                    },
+                })
+                .collect();
+            index.sort_by(|a, b| a.key.cmp(&b.key));
+            index
+        }
     }
 
     /// Type alias to represent a embed key.
     pub type EmbedKey = String;
 
     /// Embed view information.
-    #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+    #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
     pub struct EmbedView {
         pub category: Category,
         pub foundation: String,
 
         #[serde(default, skip_serializing_if = "Vec::is_empty")]
         pub items: Vec<Item>,
+
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub options: Option<EmbedViewOptions>,
+    }
+
+    /// Embed view summary, used in the embed views index.
+    #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+    pub struct EmbedViewSummary {
+        pub category: String,
+        pub items: usize,
+        pub key: EmbedKey,
+
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub subcategory: Option<String>,
     }
 
     /// Embed dataset item information.
-    #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+    #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
     pub struct Item {
         pub category: String,
         pub id: String,
@@ -368,6 +503,12 @@ pub mod embed {
         #[serde(skip_serializing_if = "Option::is_none")]
         pub description: Option<String>,
 
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub logo_dark: Option<String>,
+
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub logo_png: Option<String>,
+
         #[serde(skip_serializing_if = "Option::is_none")]
         pub maturity: Option<String>,
 
@@ -390,6 +531,8 @@ pub mod embed {
                 id: data_item.id.clone(),
                 name: data_item.name.clone(),
                 logo: data_item.logo.clone(),
+                logo_dark: data_item.logo_dark.clone(),
+                logo_png: data_item.logo_png.clone(),
                 maturity: data_item.maturity.clone(),
                 member_subcategory: data_item.member_subcategory.clone(),
                 organization_name: data_item.crunchbase_data.as_ref().and_then(|org| org.name.clone()),
@@ -407,21 +550,49 @@ pub mod embed {
 /// information is used by the web application to power features that require
 /// some extra data not available in the base dataset.
 pub mod full {
-    use crate::data::{CrunchbaseData, GithubData, Item, LandscapeData};
+    use crate::data::{
+        CrunchbaseData, FeedsData, GithubData, GithubOrgsData, Item, ItemsActivity, LandscapeData,
+        LinkedInData, NewsPost, PackagesData, SocialData, SponsorshipsData,
+    };
+    use schemars::JsonSchema;
     use serde::{Deserialize, Serialize};
     use std::collections::BTreeMap;
 
     /// Full dataset information.
-    #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+    #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
     pub struct Full {
         #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
         pub crunchbase_data: CrunchbaseData,
 
+        #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+        pub feeds_data: FeedsData,
+
         #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
         pub github_data: GithubData,
 
+        #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+        pub github_orgs_data: GithubOrgsData,
+
         #[serde(default, skip_serializing_if = "Vec::is_empty")]
         pub items: Vec<Item>,
+
+        #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+        pub items_activity: ItemsActivity,
+
+        #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+        pub linkedin_data: LinkedInData,
+
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        pub news: Vec<NewsPost>,
+
+        #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+        pub packages: PackagesData,
+
+        #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+        pub social: SocialData,
+
+        #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+        pub sponsorships_data: SponsorshipsData,
     }
 
     impl Full {
@@ -431,11 +602,100 @@ pub mod full {
             landscape_data: &LandscapeData,
             crunchbase_data: &CrunchbaseData,
             github_data: &GithubData,
+            linkedin_data: &LinkedInData,
+            packages_data: &PackagesData,
+            social_data: &SocialData,
+            sponsorships_data: &SponsorshipsData,
         ) -> Self {
             Full {
                 crunchbase_data: crunchbase_data.clone(),
+                feeds_data: landscape_data.feeds_data(),
                 github_data: github_data.clone(),
+                github_orgs_data: landscape_data.github_orgs_data(),
                 items: landscape_data.items.clone(),
+                items_activity: landscape_data.items_activity(),
+                linkedin_data: linkedin_data.clone(),
+                news: landscape_data.news(),
+                packages: packages_data.clone(),
+                social: social_data.clone(),
+                sponsorships_data: sponsorships_data.clone(),
+            }
+        }
+    }
+}
+
+/// Search dataset.
+///
+/// This dataset contains a compact index of the landscape items, including
+/// only the information needed to power the search feature in the web
+/// application without having to load the full dataset.
+pub mod search {
+    use crate::data::{self, LandscapeData};
+    use schemars::JsonSchema;
+    use serde::{Deserialize, Serialize};
+
+    /// Search dataset information.
+    #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+    pub struct Search {
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        pub items: Vec<Item>,
+    }
+
+    impl Search {
+        /// Create a new Search instance from the landscape data provided.
+        #[must_use]
+        pub fn new(landscape_data: &LandscapeData) -> Self {
+            Search {
+                items: landscape_data.items.iter().map(Item::from).collect(),
+            }
+        }
+    }
+
+    /// Search dataset item information.
+    #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+    pub struct Item {
+        pub category: String,
+        pub id: String,
+        pub name: String,
+        pub subcategory: String,
+
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub description: Option<String>,
+
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        pub keywords: Vec<String>,
+    }
+
+    impl From<&data::Item> for Item {
+        fn from(data_item: &data::Item) -> Self {
+            // Keywords are collected from the item's summary tags and the
+            // primary repository topics
+            let mut keywords: Vec<String> = data_item
+                .summary
+                .as_ref()
+                .and_then(|summary| summary.tags.clone())
+                .unwrap_or_default()
+                .into_iter()
+                .chain(
+                    data_item
+                        .primary_repository()
+                        .and_then(|repo| repo.github_data.as_ref())
+                        .map(|gh_data| gh_data.topics.clone())
+                        .unwrap_or_default(),
+                )
+                .map(|keyword| keyword.trim().to_lowercase())
+                .filter(|keyword| !keyword.is_empty())
+                .collect();
+            keywords.sort();
+            keywords.dedup();
+
+            Item {
+                category: data_item.category.clone(),
+                description: data_item.description().cloned(),
+                id: data_item.id.clone(),
+                keywords,
+                name: data_item.name.clone(),
+                subcategory: data_item.subcategory.clone(),
             }
         }
     }
@@ -446,7 +706,7 @@ mod tests {
     use super::*;
     use crate::{
         data::{self, *},
-        datasets::base,
+        datasets::{base, embed::Embed},
         games::Quiz,
         guide::{self, LandscapeGuide},
         settings::{self, *},
@@ -462,8 +722,12 @@ mod tests {
             github_data: &GithubData::default(),
             guide: &None,
             landscape_data: &LandscapeData::default(),
+            linkedin_data: &LinkedInData::default(),
+            packages_data: &PackagesData::default(),
             qr_code: &String::default(),
             settings: &LandscapeSettings::default(),
+            social_data: &SocialData::default(),
+            sponsorships_data: &SponsorshipsData::default(),
         };
 
         let datasets = Datasets::new(&input);
@@ -532,6 +796,10 @@ mod tests {
             favicon: Some("https://favicon.url".to_string()),
             ..Default::default()
         });
+        let membership_levels = vec![MembershipLevel {
+            name: "Platinum".to_string(),
+            subcategories: vec!["Platinum".to_string()],
+        }];
         let upcoming_event = UpcomingEvent {
             name: "Event".to_string(),
             start: NaiveDate::from_ymd_opt(2024, 5, 2).unwrap(),
@@ -551,8 +819,17 @@ mod tests {
             grid_items_size: Some(GridItemsSize::Small),
             groups: Some(groups.clone()),
             header: header.clone(),
+            i18n: Some(I18n {
+                locale: "es".to_string(),
+                translations: Some(std::collections::BTreeMap::from([(
+                    "header.explore".to_string(),
+                    "Explorar".to_string(),
+                )])),
+                ..Default::default()
+            }),
             images: images.clone(),
             members_category: Some("Members".to_string()),
+            membership_levels: Some(membership_levels.clone()),
             upcoming_event: Some(upcoming_event.clone()),
             view_mode: Some(ViewMode::Grid),
             ..Default::default()
@@ -595,6 +872,7 @@ mod tests {
             categories_overridden: vec!["Category 1".to_string()],
             colors,
             footer,
+            full_dataset_shards: std::collections::BTreeMap::new(),
             games_available: Some(vec!["quiz".to_string()]),
             grid_items_size: Some(GridItemsSize::Small),
             groups,
@@ -604,7 +882,13 @@ mod tests {
             header,
             images,
             items: vec![(&item).into()],
+            locale: Some("es".to_string()),
             members_category: Some("Members".to_string()),
+            membership_levels,
+            translations: std::collections::BTreeMap::from([(
+                "header.explore".to_string(),
+                "Explorar".to_string(),
+            )]),
             upcoming_event: Some(upcoming_event),
             view_mode: Some(ViewMode::Grid),
         };
@@ -625,8 +909,12 @@ mod tests {
             }),
             id: "id".to_string(),
             logo: "logo.svg".to_string(),
+            logo_dark: Some("logo-dark.svg".to_string()),
+            logo_png: Some("logo.png".to_string()),
             maturity: Some("graduated".to_string()),
+            member_level: Some("Platinum".to_string()),
             name: "Item".to_string(),
+            openssf_best_practices_badge_level: Some("passing".to_string()),
             oss: Some(true),
             subcategory: "Subcategory 1".to_string(),
             tag: Some("tag1".to_string()),
@@ -646,8 +934,12 @@ mod tests {
             }),
             id: "id".to_string(),
             logo: "logo.svg".to_string(),
+            logo_dark: Some("logo-dark.svg".to_string()),
+            logo_png: Some("logo.png".to_string()),
             maturity: Some("graduated".to_string()),
+            member_level: Some("Platinum".to_string()),
             name: "Item".to_string(),
+            openssf_best_practices_badge_level: Some("passing".to_string()),
             oss: Some(true),
             subcategory: "Subcategory 1".to_string(),
             tag: Some("tag1".to_string()),
@@ -708,6 +1000,7 @@ mod tests {
                 }],
             },
             items: vec![(&item).into()],
+            options: None,
         };
         let expected_embed_view_c2 = EmbedView {
             foundation: "Foundation".to_string(),
@@ -720,6 +1013,7 @@ mod tests {
                 }],
             },
             items: vec![(&item).into()],
+            options: None,
         };
         let expected_embed = embed::Embed {
             views: vec![
@@ -734,6 +1028,63 @@ mod tests {
         pretty_assertions::assert_eq!(embed, expected_embed);
     }
 
+    #[test]
+    fn embed_new_using_embed_settings() {
+        let landscape_data = LandscapeData {
+            categories: vec![
+                data::Category {
+                    name: "Category 1".to_string(),
+                    normalized_name: "category-1".to_string(),
+                    subcategories: vec![Subcategory {
+                        name: "Subcategory 1".to_string(),
+                        normalized_name: "subcategory-1".to_string(),
+                    }],
+                },
+                data::Category {
+                    name: "Category 2".to_string(),
+                    normalized_name: "category-2".to_string(),
+                    subcategories: vec![],
+                },
+            ],
+            items: vec![],
+        };
+        let options = EmbedViewOptions {
+            items_style: Some("card".to_string()),
+            ..Default::default()
+        };
+        let settings = LandscapeSettings {
+            foundation: "Foundation".to_string(),
+            embed: Some(settings::Embed {
+                categories: Some(vec![EmbedCategory {
+                    name: "Category 1".to_string(),
+                    options: Some(options.clone()),
+                }]),
+            }),
+            ..Default::default()
+        };
+
+        let embed = Embed::new(&landscape_data, &settings);
+        assert_eq!(embed.views.len(), 2);
+        assert!(embed.views.values().all(|view| view.options == Some(options.clone())));
+        pretty_assertions::assert_eq!(
+            embed.index(),
+            vec![
+                embed::EmbedViewSummary {
+                    category: "Category 1".to_string(),
+                    items: 0,
+                    key: "category-1".to_string(),
+                    subcategory: None,
+                },
+                embed::EmbedViewSummary {
+                    category: "Category 1".to_string(),
+                    items: 0,
+                    key: "category-1--subcategory-1".to_string(),
+                    subcategory: Some("Subcategory 1".to_string()),
+                },
+            ]
+        );
+    }
+
     #[test]
     fn embed_item_from_data_item() {
         let data_item = data::Item {
@@ -749,6 +1100,8 @@ mod tests {
             description: Some("Description".to_string()),
             id: "id".to_string(),
             logo: "logo.svg".to_string(),
+            logo_dark: Some("logo-dark.svg".to_string()),
+            logo_png: Some("logo.png".to_string()),
             maturity: Some("graduated".to_string()),
             member_subcategory: Some("Member subcategory".to_string()),
             name: "Item".to_string(),
@@ -772,6 +1125,8 @@ mod tests {
             description: Some("Description".to_string()),
             id: "id".to_string(),
             logo: "logo.svg".to_string(),
+            logo_dark: Some("logo-dark.svg".to_string()),
+            logo_png: Some("logo.png".to_string()),
             maturity: Some("graduated".to_string()),
             member_subcategory: Some("Member subcategory".to_string()),
             name: "Item".to_string(),
@@ -802,13 +1157,82 @@ mod tests {
         crunchbase_data.insert("https:://crunchbase.url".to_string(), Organization::default());
         let mut github_data = GithubData::default();
         github_data.insert("https:://github.url".to_string(), RepositoryGithubData::default());
-
-        let full = Full::new(&landscape_data, &crunchbase_data, &github_data);
+        let mut linkedin_data = LinkedInData::default();
+        linkedin_data.insert(
+            "https://www.linkedin.com/company/test".to_string(),
+            LinkedInOrganizationData::default(),
+        );
+        let mut packages_data = PackagesData::default();
+        packages_data.insert("npm/package".to_string(), PackageData::default());
+        let mut social_data = SocialData::default();
+        social_data.insert(
+            "https://bsky.app/profile/project.org".to_string(),
+            SocialAccountData::default(),
+        );
+        let mut sponsorships_data = SponsorshipsData::default();
+        sponsorships_data.insert(
+            "https://opencollective.com/project".to_string(),
+            SponsorshipData::default(),
+        );
+
+        let full = Full::new(
+            &landscape_data,
+            &crunchbase_data,
+            &github_data,
+            &linkedin_data,
+            &packages_data,
+            &social_data,
+            &sponsorships_data,
+        );
         let expected_full = Full {
             crunchbase_data,
+            feeds_data: FeedsData::default(),
             github_data,
+            github_orgs_data: GithubOrgsData::default(),
             items: vec![item],
+            items_activity: ItemsActivity::default(),
+            linkedin_data,
+            news: vec![],
+            packages: packages_data,
+            social: social_data,
+            sponsorships_data,
         };
         pretty_assertions::assert_eq!(full, expected_full);
     }
+
+    #[test]
+    fn search_item_from_data_item() {
+        let data_item = data::Item {
+            category: "Category 1".to_string(),
+            description: Some("Description".to_string()),
+            id: "id".to_string(),
+            name: "Item".to_string(),
+            repositories: Some(vec![Repository {
+                url: "https://repository.url".to_string(),
+                primary: Some(true),
+                github_data: Some(RepositoryGithubData {
+                    topics: vec!["Topic1".to_string(), "tag1".to_string()],
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }]),
+            subcategory: "Subcategory 1".to_string(),
+            summary: Some(ItemSummary {
+                tags: Some(vec!["tag1".to_string(), "tag2".to_string()]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let item = search::Item::from(&data_item);
+        let expected_item = search::Item {
+            category: "Category 1".to_string(),
+            description: Some("Description".to_string()),
+            id: "id".to_string(),
+            keywords: vec!["tag1".to_string(), "tag2".to_string(), "topic1".to_string()],
+            name: "Item".to_string(),
+            subcategory: "Subcategory 1".to_string(),
+        };
+        pretty_assertions::assert_eq!(item, expected_item);
+    }
 }
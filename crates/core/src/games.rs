@@ -4,6 +4,7 @@
 use anyhow::{bail, format_err, Context, Result};
 use clap::Args;
 use reqwest::StatusCode;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::{
     fs,
@@ -43,7 +44,7 @@ impl GamesSource {
 }
 
 /// Landscape games data.
-#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct LandscapeGames {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub quiz: Option<Quiz>,
@@ -169,14 +170,14 @@ impl LandscapeGames {
 }
 
 /// Quiz game data.
-#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct Quiz {
     pub questions: Vec<Question>,
 }
 
 /// Quiz question details.
 #[wasm_bindgen]
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct Question {
     #[wasm_bindgen(readonly, getter_with_clone)]
     pub title: String,
@@ -187,7 +188,7 @@ pub struct Question {
 
 /// Question option details.
 #[wasm_bindgen]
-#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize, JsonSchema)]
 pub struct QuestionOption {
     #[wasm_bindgen(readonly, getter_with_clone)]
     pub item: String,
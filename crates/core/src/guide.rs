@@ -1,9 +1,11 @@
 //! This module defines the types used to represent the landscape guide content
 //! that must be provided from a YAML file (guide.yml).
 
+use crate::{data::LandscapeData, settings::check_category_exists};
 use anyhow::{bail, format_err, Context, Result};
 use clap::Args;
 use reqwest::StatusCode;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::{
     fs,
@@ -36,7 +38,7 @@ impl GuideSource {
 }
 
 /// Landscape guide content.
-#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct LandscapeGuide {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub categories: Option<Vec<Category>>,
@@ -112,6 +114,41 @@ impl LandscapeGuide {
         Ok(guide)
     }
 
+    /// Check the guide is consistent with the landscape data provided, making
+    /// sure the categories and subcategories it references exist in the data.
+    /// Categories without subcategories are not checked, as they can be used
+    /// to provide some general content (i.e. an introduction section).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error listing all the categories and subcategories
+    /// referenced in the guide that could not be found (one per line).
+    pub fn validate_against_data(&self, landscape_data: &LandscapeData) -> Result<()> {
+        let Some(categories) = &self.categories else {
+            return Ok(());
+        };
+
+        let mut errors = vec![];
+        for category in categories {
+            let Some(subcategories) = &category.subcategories else {
+                continue;
+            };
+            let subcategories: Vec<String> = subcategories.iter().map(|sc| sc.subcategory.clone()).collect();
+            check_category_exists(
+                landscape_data,
+                "guide",
+                &category.category,
+                &subcategories,
+                &mut errors,
+            );
+        }
+
+        if !errors.is_empty() {
+            bail!(errors.join("\n"));
+        }
+        Ok(())
+    }
+
     /// Validate landscape guide.
     fn validate(&self) -> Result<()> {
         if let Some(categories) = &self.categories {
@@ -182,7 +219,7 @@ impl LandscapeGuide {
 }
 
 /// Guide category.
-#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct Category {
     #[allow(clippy::struct_field_names)]
     pub category: String,
@@ -198,7 +235,7 @@ pub struct Category {
 }
 
 /// Guide subcategory.
-#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct Subcategory {
     #[allow(clippy::struct_field_names)]
     pub subcategory: String,
@@ -212,6 +249,7 @@ pub struct Subcategory {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::data;
 
     const GUIDE_FILE: &str = "guide.yml";
     const TESTS_GUIDE_FILE: &str = "src/testdata/guide.yml";
@@ -319,6 +357,62 @@ mod tests {
         guide.validate().unwrap();
     }
 
+    #[test]
+    fn guide_validate_against_data_succeeds() {
+        let guide = LandscapeGuide {
+            categories: Some(vec![
+                Category {
+                    category: "Introduction".to_string(),
+                    content: Some("content".to_string()),
+                    ..Default::default()
+                },
+                Category {
+                    category: "Category".to_string(),
+                    subcategories: Some(vec![Subcategory {
+                        subcategory: "Subcategory".to_string(),
+                        content: "content".to_string(),
+                        ..Default::default()
+                    }]),
+                    ..Default::default()
+                },
+            ]),
+        };
+
+        guide.validate_against_data(&sample_landscape_data()).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "guide: category [Category 2] not found in landscape data")]
+    fn guide_validate_against_data_category_not_found() {
+        let guide = LandscapeGuide {
+            categories: Some(vec![Category {
+                category: "Category 2".to_string(),
+                subcategories: Some(vec![]),
+                ..Default::default()
+            }]),
+        };
+
+        guide.validate_against_data(&sample_landscape_data()).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "guide: subcategory [Subcategory 2] not found in category [Category]")]
+    fn guide_validate_against_data_subcategory_not_found() {
+        let guide = LandscapeGuide {
+            categories: Some(vec![Category {
+                category: "Category".to_string(),
+                subcategories: Some(vec![Subcategory {
+                    subcategory: "Subcategory 2".to_string(),
+                    content: "content".to_string(),
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            }]),
+        };
+
+        guide.validate_against_data(&sample_landscape_data()).unwrap();
+    }
+
     #[test]
     #[should_panic(expected = "category cannot be empty")]
     fn guide_validate_empty_category() {
@@ -412,4 +506,18 @@ mod tests {
 
         guide.validate().unwrap();
     }
+
+    fn sample_landscape_data() -> LandscapeData {
+        LandscapeData {
+            categories: vec![data::Category {
+                name: "Category".to_string(),
+                normalized_name: "category".to_string(),
+                subcategories: vec![data::Subcategory {
+                    name: "Subcategory".to_string(),
+                    normalized_name: "subcategory".to_string(),
+                }],
+            }],
+            items: vec![],
+        }
+    }
 }
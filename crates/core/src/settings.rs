@@ -7,7 +7,7 @@
 //! NOTE: the landscape settings file uses a new format that is not backwards
 //! compatible with the legacy settings file used by existing landscapes.
 
-use super::data::{CategoryName, SubcategoryName};
+use super::data::{CategoryName, LandscapeData, SubcategoryName};
 use crate::util::{normalize_name, validate_url};
 use anyhow::{bail, format_err, Context, Result};
 use chrono::NaiveDate;
@@ -15,9 +15,10 @@ use clap::Args;
 use lazy_static::lazy_static;
 use regex::Regex;
 use reqwest::StatusCode;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::{
-    collections::BTreeMap,
+    collections::{BTreeMap, HashSet},
     fs,
     path::{Path, PathBuf},
 };
@@ -48,7 +49,7 @@ impl SettingsSource {
 }
 
 /// Landscape settings.
-#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct LandscapeSettings {
     pub foundation: String,
     pub url: String,
@@ -56,6 +57,9 @@ pub struct LandscapeSettings {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub analytics: Option<Analytics>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub annotations: Option<Vec<AnnotationRule>>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub base_path: Option<String>,
 
@@ -65,6 +69,12 @@ pub struct LandscapeSettings {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub colors: Option<Colors>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_html: Option<CustomHtml>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub embed: Option<Embed>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub enduser: Option<Vec<EndUserRule>>,
 
@@ -83,6 +93,9 @@ pub struct LandscapeSettings {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub groups: Option<Vec<Group>>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub i18n: Option<I18n>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub images: Option<Images>,
 
@@ -95,12 +108,30 @@ pub struct LandscapeSettings {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub members_category: Option<String>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub membership_levels: Option<Vec<MembershipLevel>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub meta: Option<Meta>,
+
+    #[serde(default)]
+    pub organizations_data: OrganizationsDataProvider,
+
+    #[serde(default)]
+    pub robots: Robots,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub screenshot_width: Option<u32>,
 
+    #[serde(default)]
+    pub stale_repositories: StaleRepositories,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tags: Option<BTreeMap<TagName, Vec<TagRule>>>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub theme: Option<Theme>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub upcoming_event: Option<UpcomingEvent>,
 
@@ -174,6 +205,23 @@ impl LandscapeSettings {
         Ok(())
     }
 
+    /// Get the membership level the members subcategory provided belongs to.
+    #[must_use]
+    pub fn membership_level(&self, subcategory: &str) -> Option<&MembershipLevel> {
+        self.membership_levels.as_ref().and_then(|levels| {
+            levels.iter().find(|level| level.subcategories.iter().any(|s| s == subcategory))
+        })
+    }
+
+    /// Set the base path, overriding the one defined in the settings file.
+    pub fn set_base_path(&mut self, base_path: &str) -> Result<()> {
+        self.base_path = Some(base_path.to_string());
+        self.validate_base_path()?;
+        self.remove_base_path_trailing_slash();
+
+        Ok(())
+    }
+
     /// Remove base_path trailing slash if present.
     fn remove_base_path_trailing_slash(&mut self) {
         if let Some(base_path) = &mut self.base_path {
@@ -202,18 +250,299 @@ impl LandscapeSettings {
         // Check url is valid
         validate_url("landscape", &Some(self.url.clone()))?;
 
+        self.validate_analytics()?;
+        self.validate_annotations()?;
         self.validate_base_path()?;
         self.validate_categories()?;
         self.validate_colors()?;
+        self.validate_custom_html()?;
+        self.validate_embed()?;
         self.validate_featured_items()?;
         self.validate_footer()?;
         self.validate_groups()?;
         self.validate_header()?;
+        self.validate_i18n()?;
         self.validate_images()?;
         self.validate_members_category()?;
+        self.validate_membership_levels()?;
+        self.validate_meta()?;
+        self.validate_organizations_data()?;
         self.validate_osano()?;
+        self.validate_robots()?;
         self.validate_screenshot_width()?;
         self.validate_tags()?;
+        self.validate_theme()?;
+
+        Ok(())
+    }
+
+    /// Check the settings are consistent with the landscape data provided,
+    /// making sure the categories, subcategories and values they reference
+    /// exist in the data. All the inconsistencies found are reported at once.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error listing all the inconsistencies found (one per line).
+    pub fn validate_against_data(&self, landscape_data: &LandscapeData) -> Result<()> {
+        let mut errors = vec![];
+
+        self.check_annotations_against_data(landscape_data, &mut errors);
+        self.check_categories_against_data(landscape_data, &mut errors);
+        self.check_embed_against_data(landscape_data, &mut errors);
+        self.check_enduser_against_data(landscape_data, &mut errors);
+        self.check_featured_items_against_data(landscape_data, &mut errors);
+        self.check_groups_against_data(landscape_data, &mut errors);
+        self.check_members_against_data(landscape_data, &mut errors);
+        self.check_tags_against_data(landscape_data, &mut errors);
+
+        if !errors.is_empty() {
+            bail!(errors.join("\n"));
+        }
+        Ok(())
+    }
+
+    /// Check the annotations used by the items match the annotations rules.
+    fn check_annotations_against_data(&self, landscape_data: &LandscapeData, errors: &mut Vec<String>) {
+        let Some(rules) = &self.annotations else {
+            return;
+        };
+
+        for item in &landscape_data.items {
+            let ctx = format!("item [{}]", item.name);
+            let annotations = item.annotations.clone().unwrap_or_default();
+
+            // Check all annotations used by the item have been defined
+            for key in annotations.keys() {
+                if !rules.iter().any(|rule| rule.key == *key) {
+                    errors.push(format!("{ctx}: annotation [{key}] not defined in settings"));
+                }
+            }
+
+            // Check the item's annotations match the rules
+            for rule in rules {
+                match annotations.get(&rule.key) {
+                    Some(value) => {
+                        if let Some(values) = &rule.values {
+                            if !values.contains(value) {
+                                errors.push(format!(
+                                    "{ctx}: annotation [{}] value [{value}] not allowed",
+                                    rule.key
+                                ));
+                            }
+                        }
+                    }
+                    None => {
+                        if rule.required.unwrap_or_default() {
+                            errors.push(format!("{ctx}: required annotation [{}] not found", rule.key));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Check the categories referenced in the categories section exist.
+    fn check_categories_against_data(&self, landscape_data: &LandscapeData, errors: &mut Vec<String>) {
+        let Some(categories) = &self.categories else {
+            return;
+        };
+
+        for category in categories {
+            check_category_exists(
+                landscape_data,
+                "categories",
+                &category.name,
+                &category.subcategories,
+                errors,
+            );
+        }
+    }
+
+    /// Check the categories referenced in the embed section exist.
+    fn check_embed_against_data(&self, landscape_data: &LandscapeData, errors: &mut Vec<String>) {
+        let Some(categories) = self.embed.as_ref().and_then(|embed| embed.categories.as_ref()) else {
+            return;
+        };
+
+        for category in categories {
+            check_category_exists(landscape_data, "embed", &category.name, &[], errors);
+        }
+    }
+
+    /// Check the categories referenced in the end user rules exist.
+    fn check_enduser_against_data(&self, landscape_data: &LandscapeData, errors: &mut Vec<String>) {
+        let Some(rules) = &self.enduser else {
+            return;
+        };
+
+        for rule in rules {
+            let subcategories = rule.subcategories.clone().unwrap_or_default();
+            check_category_exists(landscape_data, "enduser", &rule.category, &subcategories, errors);
+        }
+    }
+
+    /// Check the fields and values used in the featured items rules exist.
+    fn check_featured_items_against_data(&self, landscape_data: &LandscapeData, errors: &mut Vec<String>) {
+        let Some(rules) = &self.featured_items else {
+            return;
+        };
+
+        for rule in rules {
+            let ctx = format!("featured item rule [{}]", rule.field);
+            if !FEATURED_ITEMS_FIELDS.contains(&rule.field.as_str()) {
+                errors.push(format!(
+                    "{ctx}: unknown field (supported: {})",
+                    FEATURED_ITEMS_FIELDS.join(", ")
+                ));
+                continue;
+            }
+            if rule.field == "member_subcategory" && self.members_category.is_none() {
+                errors.push(format!("{ctx}: members_category must be set to use this field"));
+                continue;
+            }
+            for option in &rule.options {
+                let found = match rule.field.as_str() {
+                    "member_level" => self
+                        .membership_levels
+                        .as_ref()
+                        .is_some_and(|levels| levels.iter().any(|level| level.name == option.value)),
+                    "member_subcategory" => landscape_data.categories.iter().any(|c| {
+                        self.members_category.as_ref() == Some(&c.name)
+                            && c.subcategories.iter().any(|sc| sc.name == option.value)
+                    }),
+                    "subcategory" => landscape_data
+                        .categories
+                        .iter()
+                        .any(|c| c.subcategories.iter().any(|sc| sc.name == option.value)),
+                    "tag" => self.tags.as_ref().is_some_and(|tags| tags.contains_key(&option.value)),
+                    _ => landscape_data
+                        .items
+                        .iter()
+                        .any(|item| item.featured_rule_field_value(&rule.field) == Some(&option.value)),
+                };
+                if !found {
+                    errors.push(format!(
+                        "{ctx}: value [{}] not found in landscape data",
+                        option.value
+                    ));
+                }
+            }
+        }
+    }
+
+    /// Check the categories referenced in the groups exist.
+    fn check_groups_against_data(&self, landscape_data: &LandscapeData, errors: &mut Vec<String>) {
+        let Some(groups) = &self.groups else {
+            return;
+        };
+
+        for group in groups {
+            let ctx = format!("group [{}]", group.name);
+            for category in &group.categories {
+                check_category_exists(landscape_data, &ctx, category, &[], errors);
+            }
+        }
+    }
+
+    /// Check the members category, as well as the subcategories referenced in
+    /// the membership levels, exist.
+    fn check_members_against_data(&self, landscape_data: &LandscapeData, errors: &mut Vec<String>) {
+        let Some(members_category) = &self.members_category else {
+            return;
+        };
+
+        check_category_exists(landscape_data, "members_category", members_category, &[], errors);
+
+        // Membership levels
+        if let Some(levels) = &self.membership_levels {
+            for level in levels {
+                let ctx = format!("membership level [{}]", level.name);
+                check_category_exists(
+                    landscape_data,
+                    &ctx,
+                    members_category,
+                    &level.subcategories,
+                    errors,
+                );
+            }
+        }
+    }
+
+    /// Check the categories referenced in the tags rules exist.
+    fn check_tags_against_data(&self, landscape_data: &LandscapeData, errors: &mut Vec<String>) {
+        let Some(tags) = &self.tags else {
+            return;
+        };
+
+        for (tag, rules) in tags {
+            let ctx = format!("tag [{tag}]");
+            for rule in rules {
+                let subcategories = rule.subcategories.clone().unwrap_or_default();
+                check_category_exists(landscape_data, &ctx, &rule.category, &subcategories, errors);
+            }
+        }
+    }
+
+    /// Check analytics providers configuration is valid.
+    fn validate_analytics(&self) -> Result<()> {
+        let Some(analytics) = &self.analytics else {
+            return Ok(());
+        };
+
+        // Helper closure to check a value used in the analytics snippets
+        let check_value = |name: &str, value: &str| {
+            if value.is_empty() {
+                bail!("{name} cannot be empty");
+            }
+            if value.contains(['"', '<', '>']) {
+                bail!("{name} contains invalid characters");
+            }
+            Ok(())
+        };
+
+        // Plausible
+        if let Some(plausible) = &analytics.plausible {
+            check_value("plausible domain", &plausible.domain)?;
+            validate_url("plausible script", &plausible.script_url)?;
+        }
+
+        // Umami
+        if let Some(umami) = &analytics.umami {
+            check_value("umami website id", &umami.website_id)?;
+            validate_url("umami script", &Some(umami.script_url.clone()))?;
+        }
+
+        Ok(())
+    }
+
+    /// Check annotations rules are valid.
+    fn validate_annotations(&self) -> Result<()> {
+        let Some(rules) = &self.annotations else {
+            return Ok(());
+        };
+
+        for (i, rule) in rules.iter().enumerate() {
+            let rule_id = if rule.key.is_empty() {
+                format!("{i}")
+            } else {
+                rule.key.clone()
+            };
+
+            // Key
+            if rule.key.is_empty() {
+                bail!("annotation [{rule_id}] key cannot be empty");
+            }
+            if rules.iter().filter(|r| r.key == rule.key).count() > 1 {
+                bail!("annotation [{rule_id}] key is duplicated");
+            }
+
+            // Values
+            if let Some(values) = &rule.values {
+                if values.is_empty() {
+                    bail!("annotation [{rule_id}] values cannot be empty");
+                }
+            }
+        }
 
         Ok(())
     }
@@ -287,6 +616,63 @@ impl LandscapeSettings {
         Ok(())
     }
 
+    /// Check custom html snippets are valid.
+    fn validate_custom_html(&self) -> Result<()> {
+        let Some(custom_html) = &self.custom_html else {
+            return Ok(());
+        };
+
+        let snippets = [
+            ("footer", &custom_html.footer, &custom_html.footer_url),
+            ("header", &custom_html.header, &custom_html.header_url),
+        ];
+        for (name, html, url) in snippets {
+            if html.is_some() && url.is_some() {
+                bail!("custom html {name}: only one of {name} or {name}_url can be provided");
+            }
+            if let Some(html) = html {
+                if html.is_empty() {
+                    bail!("custom html {name} cannot be empty");
+                }
+            }
+            validate_url(&format!("custom html {name}"), url)?;
+        }
+
+        Ok(())
+    }
+
+    /// Check embed configuration is valid.
+    fn validate_embed(&self) -> Result<()> {
+        let Some(categories) = self.embed.as_ref().and_then(|embed| embed.categories.as_ref()) else {
+            return Ok(());
+        };
+
+        for (i, category) in categories.iter().enumerate() {
+            // Name
+            if category.name.is_empty() {
+                bail!("embed category [{i}] name cannot be empty");
+            }
+            let ctx = format!("embed category [{}] options are not valid", category.name);
+
+            // Options
+            let Some(options) = &category.options else {
+                continue;
+            };
+            if let Some(items_size) = &options.items_size {
+                if !EMBED_ITEMS_SIZES.contains(&items_size.as_str()) {
+                    return Err(format_err!("invalid items size: {items_size}")).context(ctx);
+                }
+            }
+            if let Some(items_style) = &options.items_style {
+                if !EMBED_ITEMS_STYLES.contains(&items_style.as_str()) {
+                    return Err(format_err!("invalid items style: {items_style}")).context(ctx);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     /// Check featured item rules are valid.
     fn validate_featured_items(&self) -> Result<()> {
         if let Some(featured_items) = &self.featured_items {
@@ -303,6 +689,13 @@ impl LandscapeSettings {
                     return Err(format_err!("field cannot be empty")).context(ctx);
                 }
 
+                // Label
+                if let Some(label) = &rule.label {
+                    if label.is_empty() {
+                        return Err(format_err!("label cannot be empty")).context(ctx);
+                    }
+                }
+
                 // Options
                 if rule.options.is_empty() {
                     return Err(format_err!("options cannot be empty")).context(ctx);
@@ -330,6 +723,16 @@ impl LandscapeSettings {
     fn validate_footer(&self) -> Result<()> {
         let Some(footer) = &self.footer else { return Ok(()) };
 
+        // Legal links
+        if let Some(legal_links) = &footer.legal_links {
+            for (i, link) in legal_links.iter().enumerate() {
+                if link.title.is_empty() {
+                    bail!("footer legal link [{i}] title cannot be empty");
+                }
+                validate_url("footer legal link", &Some(link.url.clone()))?;
+            }
+        }
+
         // Links
         if let Some(links) = &footer.links {
             let urls = [
@@ -366,6 +769,7 @@ impl LandscapeSettings {
     /// Check groups are valid.
     fn validate_groups(&self) -> Result<()> {
         if let Some(groups) = &self.groups {
+            let mut normalized_names = HashSet::new();
             for (i, group) in groups.iter().enumerate() {
                 let group_id = if group.name.is_empty() {
                     format!("{i}")
@@ -377,8 +781,14 @@ impl LandscapeSettings {
                 if group.name.is_empty() {
                     bail!("group [{group_id}] name cannot be empty");
                 }
+                if !normalized_names.insert(normalize_name(&group.name)) {
+                    bail!("group [{group_id}] name is duplicated");
+                }
 
                 // Categories
+                if group.categories.is_empty() {
+                    bail!("group [{group_id}] must contain at least one category");
+                }
                 for (category_index, category) in group.categories.iter().enumerate() {
                     if category.is_empty() {
                         bail!("group [{group_id}]: category [{category_index}] cannot be empty");
@@ -408,6 +818,27 @@ impl LandscapeSettings {
         Ok(())
     }
 
+    /// Check internationalization configuration is valid.
+    fn validate_i18n(&self) -> Result<()> {
+        let Some(i18n) = &self.i18n else { return Ok(()) };
+
+        // Locale
+        if !LOCALE.is_match(&i18n.locale) {
+            bail!("i18n locale [{}] is not valid", i18n.locale);
+        }
+
+        // Translations
+        if i18n.translations.is_some() && i18n.translations_url.is_some() {
+            bail!("i18n translations and translations_url cannot be used at the same time");
+        }
+        if let Some(translations) = &i18n.translations {
+            I18n::validate_translations(translations)?;
+        }
+        validate_url("i18n translations", &i18n.translations_url)?;
+
+        Ok(())
+    }
+
     /// Check images are valid.
     fn validate_images(&self) -> Result<()> {
         let Some(images) = &self.images else { return Ok(()) };
@@ -434,6 +865,88 @@ impl LandscapeSettings {
         Ok(())
     }
 
+    /// Check membership levels are valid.
+    fn validate_membership_levels(&self) -> Result<()> {
+        let Some(levels) = &self.membership_levels else {
+            return Ok(());
+        };
+
+        // Membership levels require the members category to be set
+        if self.members_category.is_none() {
+            bail!("membership levels require the members category to be set");
+        }
+
+        for (i, level) in levels.iter().enumerate() {
+            let level_id = if level.name.is_empty() {
+                format!("{i}")
+            } else {
+                level.name.clone()
+            };
+
+            // Name
+            if level.name.is_empty() {
+                bail!("membership level [{level_id}] name cannot be empty");
+            }
+            if levels.iter().filter(|l| l.name == level.name).count() > 1 {
+                bail!("membership level [{level_id}] name is duplicated");
+            }
+
+            // Subcategories
+            if level.subcategories.is_empty() {
+                bail!("membership level [{level_id}] subcategories cannot be empty");
+            }
+            for subcategory in &level.subcategories {
+                if subcategory.is_empty() {
+                    bail!("membership level [{level_id}] subcategory cannot be empty");
+                }
+                if levels.iter().filter(|l| l.subcategories.contains(subcategory)).count() > 1 {
+                    bail!("membership level [{level_id}]: subcategory [{subcategory}] is used in multiple levels");
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Check meta configuration is valid.
+    fn validate_meta(&self) -> Result<()> {
+        let Some(meta) = &self.meta else { return Ok(()) };
+
+        // Check title and description are not empty
+        if meta.title.as_ref().is_some_and(|title| title.trim().is_empty()) {
+            bail!("meta title cannot be empty");
+        }
+        if meta.description.as_ref().is_some_and(|description| description.trim().is_empty()) {
+            bail!("meta description cannot be empty");
+        }
+
+        // Check twitter site is a handle
+        if let Some(twitter_site) = &meta.twitter_site {
+            if !twitter_site.starts_with('@') || twitter_site.len() < 2 {
+                bail!("meta twitter site must be a handle starting with @");
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Check organizations data provider configuration is valid.
+    fn validate_organizations_data(&self) -> Result<()> {
+        match &self.organizations_data {
+            OrganizationsDataProvider::Api { url } => {
+                validate_url("organizations_data_api", &Some(url.clone()))?;
+            }
+            OrganizationsDataProvider::Crunchbase => {}
+            OrganizationsDataProvider::Csv { source } => {
+                if source.is_empty() {
+                    bail!("organizations data csv source cannot be empty");
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     /// Check Osano configuration is valid.
     fn validate_osano(&self) -> Result<()> {
         let Some(osano) = &self.osano else { return Ok(()) };
@@ -449,6 +962,17 @@ impl LandscapeSettings {
         Ok(())
     }
 
+    /// Check robots.txt configuration is valid.
+    fn validate_robots(&self) -> Result<()> {
+        for path in &self.robots.disallow {
+            if !path.starts_with('/') {
+                bail!("robots disallowed path must start with /: {path}");
+            }
+        }
+
+        Ok(())
+    }
+
     /// Check screenshot width is valid.
     fn validate_screenshot_width(&self) -> Result<()> {
         let Some(screenshot_width) = &self.screenshot_width else {
@@ -466,6 +990,15 @@ impl LandscapeSettings {
     fn validate_tags(&self) -> Result<()> {
         if let Some(tags) = &self.tags {
             for (i, tag_rules) in tags {
+                // Name
+                if i.is_empty() {
+                    bail!("tag name cannot be empty");
+                }
+
+                // Rules
+                if tag_rules.is_empty() {
+                    bail!("tag [{i}] must contain at least one rule");
+                }
                 for rule in tag_rules {
                     // Category
                     if rule.category.is_empty() {
@@ -484,17 +1017,94 @@ impl LandscapeSettings {
 
         Ok(())
     }
+
+    /// Check theme is valid.
+    fn validate_theme(&self) -> Result<()> {
+        let Some(theme) = &self.theme else { return Ok(()) };
+
+        // Colors
+        let colors = [
+            ("primary_color", &theme.primary_color),
+            ("secondary_color", &theme.secondary_color),
+        ];
+        for (name, value) in colors {
+            if let Some(value) = value {
+                if !RGBA.is_match(value) {
+                    bail!(r#"theme {name} is not valid (expected format: "rgba(0, 107, 204, 1)")"#);
+                }
+            }
+        }
+
+        // Font family
+        if let Some(font_family) = &theme.font_family {
+            if font_family.is_empty() {
+                bail!("theme font family cannot be empty");
+            }
+            if font_family.contains(['{', '}', ';', '<', '>']) {
+                bail!("theme font family contains invalid characters");
+            }
+        }
+
+        // Font url
+        validate_url("theme font", &theme.font_url)?;
+
+        Ok(())
+    }
+}
+
+/// Check the category provided (and optionally some of its subcategories)
+/// exists in the landscape data, adding the inconsistencies found to the
+/// errors list provided.
+pub(crate) fn check_category_exists(
+    landscape_data: &LandscapeData,
+    ctx: &str,
+    category: &str,
+    subcategories: &[SubcategoryName],
+    errors: &mut Vec<String>,
+) {
+    let Some(data_category) = landscape_data.categories.iter().find(|c| c.name == category) else {
+        errors.push(format!(
+            "{ctx}: category [{category}] not found in landscape data"
+        ));
+        return;
+    };
+    for subcategory in subcategories {
+        if !data_category.subcategories.iter().any(|sc| sc.name == *subcategory) {
+            errors.push(format!(
+                "{ctx}: subcategory [{subcategory}] not found in category [{category}]"
+            ));
+        }
+    }
 }
 
 /// Landscape analytics providers.
-#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct Analytics {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub gtm: Option<GoogleTagManager>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub plausible: Option<Plausible>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub umami: Option<Umami>,
+}
+
+/// Annotation rule. When annotations rules are provided, the annotations used
+/// by the items in the landscape data are checked against them.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct AnnotationRule {
+    pub key: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub required: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub values: Option<Vec<String>>,
 }
 
 /// Landscape category.
-#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct Category {
     pub name: CategoryName,
     pub subcategories: Vec<SubcategoryName>,
@@ -505,10 +1115,14 @@ lazy_static! {
     static ref RGBA: Regex =
         Regex::new(r"rgba?\(((25[0-5]|2[0-4]\d|1\d{1,2}|\d\d?)\s*,\s*?){2}(25[0-5]|2[0-4]\d|1\d{1,2}|\d\d?)\s*,?\s*([01]\.?\d*?)\)")
             .expect("exprs in RGBA to be valid");
+
+    /// Locale regular expression.
+    static ref LOCALE: Regex =
+        Regex::new(r"^[a-z]{2,3}(-[A-Za-z0-9]{2,8})*$").expect("exprs in LOCALE to be valid");
 }
 
 /// Colors used across the landscape UI.
-#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct Colors {
     pub color1: String,
     pub color2: String,
@@ -519,28 +1133,109 @@ pub struct Colors {
     pub color7: String,
 }
 
-/// Rule to automatically set the `enduser` flag on the items that belong to
-/// the category (and optionally subcategories) defined.
-#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
-pub struct EndUserRule {
-    pub category: CategoryName,
+/// Custom HTML snippets that will be injected in the index document (header at
+/// the beginning of the body and footer at the end of it). Each snippet can be
+/// provided inline or referenced by url (it will be fetched at build time).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct CustomHtml {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub footer: Option<String>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub subcategories: Option<Vec<SubcategoryName>>,
+    pub footer_url: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub header: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub header_url: Option<String>,
 }
 
-/// Featured item rule information. A featured item is specially highlighted in
-/// the web application, usually making it larger with some special styling.
-/// These rules are used to decide which items should be featured.
-#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
-pub struct FeaturedItemRule {
-    pub field: String,
-    pub options: Vec<FeaturedItemRuleOption>,
+/// Embed views configuration.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct Embed {
+    /// Categories embed views will be generated for (all categories are
+    /// included when not provided).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub categories: Option<Vec<EmbedCategory>>,
 }
 
-/// Featured item rule option.
-#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
-pub struct FeaturedItemRuleOption {
+/// Embed category configuration.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct EmbedCategory {
+    pub name: CategoryName,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<EmbedViewOptions>,
+}
+
+/// Fields supported by the featured items rules.
+pub const FEATURED_ITEMS_FIELDS: &[&str] = &[
+    "id",
+    "maturity",
+    "member_level",
+    "member_subcategory",
+    "name",
+    "subcategory",
+    "tag",
+];
+
+/// Items sizes supported in embed views.
+pub const EMBED_ITEMS_SIZES: &[&str] = &["xs", "sm", "md", "lg", "xl"];
+
+/// Items styles supported in embed views.
+pub const EMBED_ITEMS_STYLES: &[&str] = &["clean", "bordered", "shadowed", "card"];
+
+/// Default display options of an embed view. They can still be overridden by
+/// using the corresponding query parameters in the embed url.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct EmbedViewOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_header: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub items_size: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub items_style: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title_bgcolor: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title_fgcolor: Option<String>,
+}
+
+/// Rule to automatically set the `enduser` flag on the items that belong to
+/// the category (and optionally subcategories) defined.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct EndUserRule {
+    pub category: CategoryName,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subcategories: Option<Vec<SubcategoryName>>,
+}
+
+/// Featured item rule information. A featured item is specially highlighted in
+/// the web application, usually making it larger with some special styling.
+/// These rules are used to decide which items should be featured.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct FeaturedItemRule {
+    pub field: String,
+    pub options: Vec<FeaturedItemRuleOption>,
+
+    /// Default label for the options in this rule that don't define one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+
+    /// Default order for the options in this rule that don't define one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order: Option<usize>,
+}
+
+/// Featured item rule option.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct FeaturedItemRuleOption {
     pub value: String,
 
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -551,8 +1246,11 @@ pub struct FeaturedItemRuleOption {
 }
 
 /// Footer configuration.
-#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct Footer {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub legal_links: Option<Vec<FooterLegalLink>>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub links: Option<FooterLinks>,
 
@@ -563,8 +1261,15 @@ pub struct Footer {
     pub text: Option<String>,
 }
 
+/// Footer legal link (i.e. privacy policy, terms of use, trademark usage).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct FooterLegalLink {
+    pub title: String,
+    pub url: String,
+}
+
 /// Footer links.
-#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct FooterLinks {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub facebook: Option<String>,
@@ -601,14 +1306,23 @@ pub struct FooterLinks {
 }
 
 /// Google Tag Manager configuration.
-#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct GoogleTagManager {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub container_id: Option<String>,
 }
 
+/// Plausible Analytics configuration.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct Plausible {
+    pub domain: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub script_url: Option<String>,
+}
+
 /// Grid items size.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "kebab-case")]
 pub enum GridItemsSize {
     Small,
@@ -618,7 +1332,7 @@ pub enum GridItemsSize {
 
 /// Landscape group. A group provides a mechanism to organize sets of
 /// categories in the web application.
-#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct Group {
     pub name: String,
     pub normalized_name: Option<String>,
@@ -626,7 +1340,7 @@ pub struct Group {
 }
 
 /// Header configuration.
-#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct Header {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub links: Option<HeaderLinks>,
@@ -636,27 +1350,134 @@ pub struct Header {
 }
 
 /// Header links.
-#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct HeaderLinks {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub github: Option<String>,
 }
 
+/// Internationalization configuration.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct I18n {
+    /// Locale of the landscape web application (i.e. es, pt-BR).
+    pub locale: String,
+
+    /// Translations catalog used by the web application.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub translations: Option<TranslationsCatalog>,
+
+    /// Url of a YAML file containing the translations catalog.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub translations_url: Option<String>,
+}
+
+impl I18n {
+    /// Check the translations catalog provided is valid, making sure all the
+    /// keys used are supported by the web application.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the catalog contains an unknown key or an empty
+    /// translation.
+    pub fn validate_translations(translations: &TranslationsCatalog) -> Result<()> {
+        for (key, value) in translations {
+            if !TRANSLATION_KEYS.contains(&key.as_str()) {
+                bail!("i18n translation key [{key}] is not valid (not used by the web application)");
+            }
+            if value.trim().is_empty() {
+                bail!("i18n translation [{key}] cannot be empty");
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Translations catalog (translated strings indexed by key).
+pub type TranslationsCatalog = BTreeMap<String, String>;
+
+/// Keys of the strings that can be translated in the web application (they
+/// must be kept in sync with the ones defined in ui/webapp/src/utils/translate.ts).
+pub const TRANSLATION_KEYS: &[&str] = &[
+    "download.data_files",
+    "download.items_csv",
+    "download.landscape",
+    "download.landscape_pdf",
+    "download.landscape_png",
+    "download.projects_csv",
+    "explore.all",
+    "explore.no_items",
+    "explore.no_items_reset_prefix",
+    "explore.no_items_reset_suffix",
+    "filters.applied",
+    "filters.apply",
+    "filters.category",
+    "filters.extra",
+    "filters.language",
+    "filters.license",
+    "filters.location",
+    "filters.name",
+    "filters.none_available",
+    "filters.reset_all",
+    "filters.search_country",
+    "filters.search_industry",
+    "filters.search_organization",
+    "filters.status",
+    "filters.tag",
+    "filters.title",
+    "filters.type",
+    "footer.generator",
+    "footer.powered_by",
+    "header.explore",
+    "header.games",
+    "header.guide",
+    "header.stats",
+    "not_found.back_home",
+    "not_found.description",
+    "not_found.title",
+    "search.no_results",
+    "search.placeholder",
+];
+
 /// Images urls.
-#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct Images {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub favicon: Option<String>,
 
+    /// Icons generated during the build from the favicon (or the header logo
+    /// when no favicon is provided).
+    #[serde(skip_deserializing, skip_serializing_if = "Option::is_none")]
+    pub icons: Option<Icons>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub open_graph: Option<String>,
 }
 
+/// Icons generated from the favicon.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct Icons {
+    pub apple_touch_icon: String,
+    pub favicon_ico: String,
+    pub png: Vec<Icon>,
+}
+
+/// Icon in PNG format.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct Icon {
+    pub path: String,
+    pub size: u32,
+}
+
 /// Logos viewbox configuration.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct LogosViewbox {
     pub adjust: bool,
     pub exclude: Vec<String>,
+
+    /// Padding added around the logos (percentage of their largest side).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub padding: Option<f64>,
 }
 
 impl Default for LogosViewbox {
@@ -664,22 +1485,101 @@ impl Default for LogosViewbox {
         LogosViewbox {
             adjust: true,
             exclude: vec![],
+            padding: None,
         }
     }
 }
 
+/// Organizations data provider configuration.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "provider", rename_all = "lowercase")]
+pub enum OrganizationsDataProvider {
+    /// Data is collected from a foundation maintained API.
+    Api { url: String },
+
+    /// Data is collected from Crunchbase (default).
+    #[default]
+    Crunchbase,
+
+    /// Data is read from a CSV file (local path or url).
+    Csv { source: String },
+}
+
+/// Membership level. A membership level groups one or more subcategories of
+/// the members category (i.e. platinum, gold, silver, end user, etc). Levels
+/// are expected to be listed from the highest to the lowest one.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct MembershipLevel {
+    pub name: String,
+    pub subcategories: Vec<SubcategoryName>,
+}
+
+/// Meta tags configuration, used when the landscape links are shared (i.e.
+/// Open Graph and Twitter cards).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct Meta {
+    /// Title of the landscape (`<FOUNDATION> Landscape` by default).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Twitter handle of the foundation (i.e. @CloudNativeFdn).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub twitter_site: Option<String>,
+}
+
 /// Osano configuration.
-#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct Osano {
     pub customer_id: String,
     pub customer_configuration_id: String,
 }
 
+/// robots.txt file configuration.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct Robots {
+    /// Whether search engines are allowed to crawl the landscape (true by
+    /// default).
+    pub allow: bool,
+
+    /// Paths crawlers are not allowed to visit.
+    pub disallow: Vec<String>,
+}
+
+impl Default for Robots {
+    fn default() -> Self {
+        Robots {
+            allow: true,
+            disallow: vec![],
+        }
+    }
+}
+
+/// Stale repositories detection configuration.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct StaleRepositories {
+    pub inactivity_days: i64,
+    pub warn: bool,
+}
+
+impl Default for StaleRepositories {
+    fn default() -> Self {
+        StaleRepositories {
+            inactivity_days: 365,
+            warn: false,
+        }
+    }
+}
+
 /// Type alias to represent a TAG name.
 pub type TagName = String;
 
 /// TAG rule used to set the TAG that owns a project automatically.
-#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct TagRule {
     pub category: CategoryName,
 
@@ -687,8 +1587,32 @@ pub struct TagRule {
     pub subcategories: Option<Vec<SubcategoryName>>,
 }
 
+/// Theme configuration. The values provided are injected in the index
+/// document as CSS variables, overriding the default ones.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct Theme {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub font_family: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub font_url: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub primary_color: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secondary_color: Option<String>,
+}
+
+/// Umami Analytics configuration.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct Umami {
+    pub script_url: String,
+    pub website_id: String,
+}
+
 /// Upcoming event details.
-#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct UpcomingEvent {
     pub name: String,
     pub start: NaiveDate,
@@ -698,7 +1622,7 @@ pub struct UpcomingEvent {
 }
 
 /// Default view mode used in the web application.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "kebab-case")]
 pub enum ViewMode {
     Grid,
@@ -708,7 +1632,9 @@ pub enum ViewMode {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::data::{self, Item, Subcategory};
     use crate::settings::SettingsSource;
+    use std::collections::HashMap;
 
     const SETTINGS_FILE: &str = "settings.yml";
     const TESTS_SETTINGS_FILE: &str = "src/testdata/settings.yml";
@@ -875,6 +1801,149 @@ mod tests {
         settings.validate().unwrap();
     }
 
+    #[test]
+    fn settings_validate_analytics_succeeds() {
+        let settings = LandscapeSettings {
+            foundation: "Foundation".to_string(),
+            url: "https://example.url".to_string(),
+            analytics: Some(Analytics {
+                plausible: Some(Plausible {
+                    domain: "landscape.example.url".to_string(),
+                    script_url: None,
+                }),
+                umami: Some(Umami {
+                    script_url: "https://umami.example.url/script.js".to_string(),
+                    website_id: "94db1cb1-74f4-4a40-ad6c-962362670409".to_string(),
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        settings.validate().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "plausible domain cannot be empty")]
+    fn settings_validate_analytics_empty_plausible_domain() {
+        let settings = LandscapeSettings {
+            foundation: "Foundation".to_string(),
+            url: "https://example.url".to_string(),
+            analytics: Some(Analytics {
+                plausible: Some(Plausible::default()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        settings.validate().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid umami script url")]
+    fn settings_validate_analytics_invalid_umami_script_url() {
+        let settings = LandscapeSettings {
+            foundation: "Foundation".to_string(),
+            url: "https://example.url".to_string(),
+            analytics: Some(Analytics {
+                umami: Some(Umami {
+                    script_url: "invalid-url".to_string(),
+                    website_id: "94db1cb1-74f4-4a40-ad6c-962362670409".to_string(),
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        settings.validate().unwrap();
+    }
+
+    #[test]
+    fn settings_validate_annotations_succeeds() {
+        let settings = LandscapeSettings {
+            foundation: "Foundation".to_string(),
+            url: "https://example.url".to_string(),
+            annotations: Some(vec![AnnotationRule {
+                key: "key".to_string(),
+                required: Some(true),
+                values: Some(vec!["value".to_string()]),
+            }]),
+            ..Default::default()
+        };
+
+        settings.validate().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "annotation [0] key cannot be empty")]
+    fn settings_validate_annotations_empty_key() {
+        let settings = LandscapeSettings {
+            foundation: "Foundation".to_string(),
+            url: "https://example.url".to_string(),
+            annotations: Some(vec![AnnotationRule::default()]),
+            ..Default::default()
+        };
+
+        settings.validate().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "annotation [key] key is duplicated")]
+    fn settings_validate_annotations_duplicated_key() {
+        let settings = LandscapeSettings {
+            foundation: "Foundation".to_string(),
+            url: "https://example.url".to_string(),
+            annotations: Some(vec![
+                AnnotationRule {
+                    key: "key".to_string(),
+                    ..Default::default()
+                },
+                AnnotationRule {
+                    key: "key".to_string(),
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        };
+
+        settings.validate().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "annotation [key] values cannot be empty")]
+    fn settings_validate_annotations_empty_values() {
+        let settings = LandscapeSettings {
+            foundation: "Foundation".to_string(),
+            url: "https://example.url".to_string(),
+            annotations: Some(vec![AnnotationRule {
+                key: "key".to_string(),
+                values: Some(vec![]),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        };
+
+        settings.validate().unwrap();
+    }
+
+    #[test]
+    fn settings_set_base_path_works() {
+        let mut settings = LandscapeSettings {
+            base_path: Some("/base".to_string()),
+            ..Default::default()
+        };
+        settings.set_base_path("/landscape/").unwrap();
+
+        assert_eq!(settings.base_path, Some("/landscape".to_string()));
+    }
+
+    #[test]
+    #[should_panic(expected = "base_path must start with a slash")]
+    fn settings_set_base_path_invalid() {
+        let mut settings = LandscapeSettings::default();
+        settings.set_base_path("landscape").unwrap();
+    }
+
     #[test]
     fn settings_validate_base_path_succeeds() {
         let settings = LandscapeSettings {
@@ -1002,18 +2071,15 @@ mod tests {
     }
 
     #[test]
-    fn settings_validate_featured_items_succeeds() {
+    fn settings_validate_custom_html_succeeds() {
         let settings = LandscapeSettings {
             foundation: "Foundation".to_string(),
             url: "https://example.url".to_string(),
-            featured_items: Some(vec![FeaturedItemRule {
-                field: "Field".to_string(),
-                options: vec![FeaturedItemRuleOption {
-                    value: "Value".to_string(),
-                    label: Some("Label".to_string()),
-                    order: Some(1),
-                }],
-            }]),
+            custom_html: Some(CustomHtml {
+                footer_url: Some("https://example.url/footer.html".to_string()),
+                header: Some("<nav></nav>".to_string()),
+                ..Default::default()
+            }),
             ..Default::default()
         };
 
@@ -1021,15 +2087,16 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "field cannot be empty")]
-    fn settings_validate_featured_items_empty_field() {
+    #[should_panic(expected = "custom html header: only one of header or header_url can be provided")]
+    fn settings_validate_custom_html_inline_and_url() {
         let settings = LandscapeSettings {
             foundation: "Foundation".to_string(),
             url: "https://example.url".to_string(),
-            featured_items: Some(vec![FeaturedItemRule {
-                field: String::new(),
-                options: vec![],
-            }]),
+            custom_html: Some(CustomHtml {
+                header: Some("<nav></nav>".to_string()),
+                header_url: Some("https://example.url/header.html".to_string()),
+                ..Default::default()
+            }),
             ..Default::default()
         };
 
@@ -1037,15 +2104,20 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "options cannot be empty")]
-    fn settings_validate_featured_items_empty_options() {
+    fn settings_validate_embed_succeeds() {
         let settings = LandscapeSettings {
             foundation: "Foundation".to_string(),
             url: "https://example.url".to_string(),
-            featured_items: Some(vec![FeaturedItemRule {
-                field: "Field".to_string(),
-                options: vec![],
-            }]),
+            embed: Some(Embed {
+                categories: Some(vec![EmbedCategory {
+                    name: "Category".to_string(),
+                    options: Some(EmbedViewOptions {
+                        items_size: Some("lg".to_string()),
+                        items_style: Some("card".to_string()),
+                        ..Default::default()
+                    }),
+                }]),
+            }),
             ..Default::default()
         };
 
@@ -1053,18 +2125,14 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "option value cannot be empty")]
-    fn settings_validate_featured_items_empty_option_value() {
+    #[should_panic(expected = "embed category [0] name cannot be empty")]
+    fn settings_validate_embed_empty_category_name() {
         let settings = LandscapeSettings {
             foundation: "Foundation".to_string(),
             url: "https://example.url".to_string(),
-            featured_items: Some(vec![FeaturedItemRule {
-                field: "Field".to_string(),
-                options: vec![FeaturedItemRuleOption {
-                    value: String::new(),
-                    ..Default::default()
-                }],
-            }]),
+            embed: Some(Embed {
+                categories: Some(vec![EmbedCategory::default()]),
+            }),
             ..Default::default()
         };
 
@@ -1072,19 +2140,20 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "option label cannot be empty")]
-    fn settings_validate_featured_items_empty_option_label() {
+    #[should_panic(expected = "invalid items size: xxl")]
+    fn settings_validate_embed_invalid_items_size() {
         let settings = LandscapeSettings {
             foundation: "Foundation".to_string(),
             url: "https://example.url".to_string(),
-            featured_items: Some(vec![FeaturedItemRule {
-                field: "Field".to_string(),
-                options: vec![FeaturedItemRuleOption {
-                    value: "Value".to_string(),
-                    label: Some(String::new()),
-                    ..Default::default()
-                }],
-            }]),
+            embed: Some(Embed {
+                categories: Some(vec![EmbedCategory {
+                    name: "Category".to_string(),
+                    options: Some(EmbedViewOptions {
+                        items_size: Some("xxl".to_string()),
+                        ..Default::default()
+                    }),
+                }]),
+            }),
             ..Default::default()
         };
 
@@ -1092,11 +2161,110 @@ mod tests {
     }
 
     #[test]
-    fn settings_validate_footer_succeeds() {
+    fn settings_validate_featured_items_succeeds() {
+        let settings = LandscapeSettings {
+            foundation: "Foundation".to_string(),
+            url: "https://example.url".to_string(),
+            featured_items: Some(vec![FeaturedItemRule {
+                field: "Field".to_string(),
+                options: vec![FeaturedItemRuleOption {
+                    value: "Value".to_string(),
+                    label: Some("Label".to_string()),
+                    order: Some(1),
+                }],
+                ..Default::default()
+            }]),
+            ..Default::default()
+        };
+
+        settings.validate().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "field cannot be empty")]
+    fn settings_validate_featured_items_empty_field() {
+        let settings = LandscapeSettings {
+            foundation: "Foundation".to_string(),
+            url: "https://example.url".to_string(),
+            featured_items: Some(vec![FeaturedItemRule {
+                field: String::new(),
+                options: vec![],
+                ..Default::default()
+            }]),
+            ..Default::default()
+        };
+
+        settings.validate().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "options cannot be empty")]
+    fn settings_validate_featured_items_empty_options() {
+        let settings = LandscapeSettings {
+            foundation: "Foundation".to_string(),
+            url: "https://example.url".to_string(),
+            featured_items: Some(vec![FeaturedItemRule {
+                field: "Field".to_string(),
+                options: vec![],
+                ..Default::default()
+            }]),
+            ..Default::default()
+        };
+
+        settings.validate().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "option value cannot be empty")]
+    fn settings_validate_featured_items_empty_option_value() {
+        let settings = LandscapeSettings {
+            foundation: "Foundation".to_string(),
+            url: "https://example.url".to_string(),
+            featured_items: Some(vec![FeaturedItemRule {
+                field: "Field".to_string(),
+                options: vec![FeaturedItemRuleOption {
+                    value: String::new(),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }]),
+            ..Default::default()
+        };
+
+        settings.validate().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "option label cannot be empty")]
+    fn settings_validate_featured_items_empty_option_label() {
+        let settings = LandscapeSettings {
+            foundation: "Foundation".to_string(),
+            url: "https://example.url".to_string(),
+            featured_items: Some(vec![FeaturedItemRule {
+                field: "Field".to_string(),
+                options: vec![FeaturedItemRuleOption {
+                    value: "Value".to_string(),
+                    label: Some(String::new()),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }]),
+            ..Default::default()
+        };
+
+        settings.validate().unwrap();
+    }
+
+    #[test]
+    fn settings_validate_footer_succeeds() {
         let settings = LandscapeSettings {
             foundation: "Foundation".to_string(),
             url: "https://example.url".to_string(),
             footer: Some(Footer {
+                legal_links: Some(vec![FooterLegalLink {
+                    title: "Privacy policy".to_string(),
+                    url: "https://example.url/privacy".to_string(),
+                }]),
                 links: Some(FooterLinks {
                     github: Some("https://github.com".to_string()),
                     ..Default::default()
@@ -1110,6 +2278,44 @@ mod tests {
         settings.validate().unwrap();
     }
 
+    #[test]
+    #[should_panic(expected = "footer legal link [0] title cannot be empty")]
+    fn settings_validate_footer_empty_legal_link_title() {
+        let settings = LandscapeSettings {
+            foundation: "Foundation".to_string(),
+            url: "https://example.url".to_string(),
+            footer: Some(Footer {
+                legal_links: Some(vec![FooterLegalLink {
+                    title: String::new(),
+                    url: "https://example.url/privacy".to_string(),
+                }]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        settings.validate().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid footer legal link url")]
+    fn settings_validate_footer_invalid_legal_link_url() {
+        let settings = LandscapeSettings {
+            foundation: "Foundation".to_string(),
+            url: "https://example.url".to_string(),
+            footer: Some(Footer {
+                legal_links: Some(vec![FooterLegalLink {
+                    title: "Privacy policy".to_string(),
+                    url: "invalid-url".to_string(),
+                }]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        settings.validate().unwrap();
+    }
+
     #[test]
     #[should_panic(expected = "invalid github url")]
     fn settings_validate_footer_invalid_github_url() {
@@ -1211,6 +2417,47 @@ mod tests {
         settings.validate().unwrap();
     }
 
+    #[test]
+    #[should_panic(expected = "group [Group 1] must contain at least one category")]
+    fn settings_validate_groups_no_categories() {
+        let settings = LandscapeSettings {
+            foundation: "Foundation".to_string(),
+            url: "https://example.url".to_string(),
+            groups: Some(vec![Group {
+                name: "Group 1".to_string(),
+                categories: vec![],
+                ..Default::default()
+            }]),
+            ..Default::default()
+        };
+
+        settings.validate().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "group [group 1] name is duplicated")]
+    fn settings_validate_groups_duplicated_name() {
+        let settings = LandscapeSettings {
+            foundation: "Foundation".to_string(),
+            url: "https://example.url".to_string(),
+            groups: Some(vec![
+                Group {
+                    name: "Group 1".to_string(),
+                    categories: vec!["Category".to_string()],
+                    ..Default::default()
+                },
+                Group {
+                    name: "group 1".to_string(),
+                    categories: vec!["Category".to_string()],
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        };
+
+        settings.validate().unwrap();
+    }
+
     #[test]
     fn settings_validate_header_succeeds() {
         let settings = LandscapeSettings {
@@ -1263,13 +2510,17 @@ mod tests {
     }
 
     #[test]
-    fn settings_validate_images_succeeds() {
+    fn settings_validate_i18n_succeeds() {
         let settings = LandscapeSettings {
             foundation: "Foundation".to_string(),
             url: "https://example.url".to_string(),
-            images: Some(Images {
-                favicon: Some("https://favicon.url".to_string()),
-                open_graph: Some("https://open-graph.url".to_string()),
+            i18n: Some(I18n {
+                locale: "pt-BR".to_string(),
+                translations: Some(BTreeMap::from([(
+                    "header.explore".to_string(),
+                    "Explorar".to_string(),
+                )])),
+                ..Default::default()
             }),
             ..Default::default()
         };
@@ -1278,13 +2529,13 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "invalid favicon url")]
-    fn settings_validate_images_invalid_favicon_url() {
+    #[should_panic(expected = "i18n locale [Spanish] is not valid")]
+    fn settings_validate_i18n_invalid_locale() {
         let settings = LandscapeSettings {
             foundation: "Foundation".to_string(),
             url: "https://example.url".to_string(),
-            images: Some(Images {
-                favicon: Some("invalid-url".to_string()),
+            i18n: Some(I18n {
+                locale: "Spanish".to_string(),
                 ..Default::default()
             }),
             ..Default::default()
@@ -1294,11 +2545,16 @@ mod tests {
     }
 
     #[test]
-    fn settings_validate_members_category_succeeds() {
+    #[should_panic(expected = "i18n translations and translations_url cannot be used at the same time")]
+    fn settings_validate_i18n_translations_and_url() {
         let settings = LandscapeSettings {
             foundation: "Foundation".to_string(),
             url: "https://example.url".to_string(),
-            members_category: Some("Members".to_string()),
+            i18n: Some(I18n {
+                locale: "es".to_string(),
+                translations: Some(BTreeMap::new()),
+                translations_url: Some("https://example.url/es.yml".to_string()),
+            }),
             ..Default::default()
         };
 
@@ -1306,12 +2562,19 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "members category cannot be empty")]
-    fn settings_validate_members_category_empty() {
+    #[should_panic(expected = "i18n translation key [Header Explore] is not valid")]
+    fn settings_validate_i18n_invalid_translation_key() {
         let settings = LandscapeSettings {
             foundation: "Foundation".to_string(),
             url: "https://example.url".to_string(),
-            members_category: Some(String::new()),
+            i18n: Some(I18n {
+                locale: "es".to_string(),
+                translations: Some(BTreeMap::from([(
+                    "Header Explore".to_string(),
+                    "Explorar".to_string(),
+                )])),
+                ..Default::default()
+            }),
             ..Default::default()
         };
 
@@ -1319,13 +2582,18 @@ mod tests {
     }
 
     #[test]
-    fn settings_validate_osano_succeeds() {
+    #[should_panic(expected = "i18n translation key [header.about] is not valid")]
+    fn settings_validate_i18n_unknown_translation_key() {
         let settings = LandscapeSettings {
             foundation: "Foundation".to_string(),
             url: "https://example.url".to_string(),
-            osano: Some(Osano {
-                customer_id: "customer_id".to_string(),
-                customer_configuration_id: "customer_configuration_id".to_string(),
+            i18n: Some(I18n {
+                locale: "es".to_string(),
+                translations: Some(BTreeMap::from([(
+                    "header.about".to_string(),
+                    "Acerca de".to_string(),
+                )])),
+                ..Default::default()
             }),
             ..Default::default()
         };
@@ -1334,13 +2602,14 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "osano customer id cannot be empty")]
-    fn settings_validate_osano_empty_customer_id() {
+    #[should_panic(expected = "i18n translation [header.explore] cannot be empty")]
+    fn settings_validate_i18n_empty_translation() {
         let settings = LandscapeSettings {
             foundation: "Foundation".to_string(),
             url: "https://example.url".to_string(),
-            osano: Some(Osano {
-                customer_id: String::new(),
+            i18n: Some(I18n {
+                locale: "es".to_string(),
+                translations: Some(BTreeMap::from([("header.explore".to_string(), " ".to_string())])),
                 ..Default::default()
             }),
             ..Default::default()
@@ -1350,14 +2619,14 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "osano customer configuration id cannot be empty")]
-    fn settings_validate_osano_empty_customer_configuration_id() {
+    fn settings_validate_images_succeeds() {
         let settings = LandscapeSettings {
             foundation: "Foundation".to_string(),
             url: "https://example.url".to_string(),
-            osano: Some(Osano {
-                customer_id: "customer_id".to_string(),
-                customer_configuration_id: String::new(),
+            images: Some(Images {
+                favicon: Some("https://favicon.url".to_string()),
+                open_graph: Some("https://open-graph.url".to_string()),
+                ..Default::default()
             }),
             ..Default::default()
         };
@@ -1366,11 +2635,15 @@ mod tests {
     }
 
     #[test]
-    fn settings_validate_screenshot_width_succeeds() {
+    #[should_panic(expected = "invalid favicon url")]
+    fn settings_validate_images_invalid_favicon_url() {
         let settings = LandscapeSettings {
             foundation: "Foundation".to_string(),
             url: "https://example.url".to_string(),
-            screenshot_width: Some(2000),
+            images: Some(Images {
+                favicon: Some("invalid-url".to_string()),
+                ..Default::default()
+            }),
             ..Default::default()
         };
 
@@ -1378,12 +2651,11 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "screenshot width must be greater than 1000")]
-    fn settings_validate_screenshot_width_invalid() {
+    fn settings_validate_members_category_succeeds() {
         let settings = LandscapeSettings {
             foundation: "Foundation".to_string(),
             url: "https://example.url".to_string(),
-            screenshot_width: Some(1000),
+            members_category: Some("Members".to_string()),
             ..Default::default()
         };
 
@@ -1391,17 +2663,12 @@ mod tests {
     }
 
     #[test]
-    fn settings_validate_tags_succeeds() {
+    #[should_panic(expected = "members category cannot be empty")]
+    fn settings_validate_members_category_empty() {
         let settings = LandscapeSettings {
             foundation: "Foundation".to_string(),
             url: "https://example.url".to_string(),
-            tags: Some(BTreeMap::from_iter(vec![(
-                "tag1".to_string(),
-                vec![TagRule {
-                    category: "Category".to_string(),
-                    subcategories: Some(vec!["Subcategory".to_string()]),
-                }],
-            )])),
+            members_category: Some(String::new()),
             ..Default::default()
         };
 
@@ -1409,18 +2676,15 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "tag [tag1] category cannot be empty")]
-    fn settings_validate_tags_empty_category() {
+    fn settings_validate_membership_levels_succeeds() {
         let settings = LandscapeSettings {
             foundation: "Foundation".to_string(),
             url: "https://example.url".to_string(),
-            tags: Some(BTreeMap::from_iter(vec![(
-                "tag1".to_string(),
-                vec![TagRule {
-                    category: String::new(),
-                    subcategories: None,
-                }],
-            )])),
+            members_category: Some("Members".to_string()),
+            membership_levels: Some(vec![MembershipLevel {
+                name: "Platinum".to_string(),
+                subcategories: vec!["Platinum".to_string()],
+            }]),
             ..Default::default()
         };
 
@@ -1428,21 +2692,592 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "tag [tag1] subcategories cannot be empty")]
-    fn settings_validate_tags_empty_subcategories() {
+    #[should_panic(expected = "membership levels require the members category to be set")]
+    fn settings_validate_membership_levels_members_category_not_set() {
         let settings = LandscapeSettings {
             foundation: "Foundation".to_string(),
             url: "https://example.url".to_string(),
-            tags: Some(BTreeMap::from_iter(vec![(
-                "tag1".to_string(),
-                vec![TagRule {
-                    category: "Category".to_string(),
-                    subcategories: Some(vec![]),
-                }],
-            )])),
+            membership_levels: Some(vec![MembershipLevel {
+                name: "Platinum".to_string(),
+                subcategories: vec!["Platinum".to_string()],
+            }]),
+            ..Default::default()
+        };
+
+        settings.validate().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "membership level [Platinum] subcategories cannot be empty")]
+    fn settings_validate_membership_levels_empty_subcategories() {
+        let settings = LandscapeSettings {
+            foundation: "Foundation".to_string(),
+            url: "https://example.url".to_string(),
+            members_category: Some("Members".to_string()),
+            membership_levels: Some(vec![MembershipLevel {
+                name: "Platinum".to_string(),
+                subcategories: vec![],
+            }]),
+            ..Default::default()
+        };
+
+        settings.validate().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "membership level [Gold]: subcategory [Gold] is used in multiple levels")]
+    fn settings_validate_membership_levels_subcategory_in_multiple_levels() {
+        let settings = LandscapeSettings {
+            foundation: "Foundation".to_string(),
+            url: "https://example.url".to_string(),
+            members_category: Some("Members".to_string()),
+            membership_levels: Some(vec![
+                MembershipLevel {
+                    name: "Gold".to_string(),
+                    subcategories: vec!["Gold".to_string()],
+                },
+                MembershipLevel {
+                    name: "Silver".to_string(),
+                    subcategories: vec!["Gold".to_string(), "Silver".to_string()],
+                },
+            ]),
             ..Default::default()
         };
 
         settings.validate().unwrap();
     }
+    #[test]
+    fn settings_validate_meta_succeeds() {
+        let settings = LandscapeSettings {
+            foundation: "Foundation".to_string(),
+            url: "https://example.url".to_string(),
+            meta: Some(Meta {
+                title: Some("Title".to_string()),
+                description: Some("Description".to_string()),
+                twitter_site: Some("@foundation".to_string()),
+            }),
+            ..Default::default()
+        };
+
+        settings.validate().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "meta description cannot be empty")]
+    fn settings_validate_meta_empty_description() {
+        let settings = LandscapeSettings {
+            foundation: "Foundation".to_string(),
+            url: "https://example.url".to_string(),
+            meta: Some(Meta {
+                description: Some(String::new()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        settings.validate().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "meta twitter site must be a handle starting with @")]
+    fn settings_validate_meta_invalid_twitter_site() {
+        let settings = LandscapeSettings {
+            foundation: "Foundation".to_string(),
+            url: "https://example.url".to_string(),
+            meta: Some(Meta {
+                twitter_site: Some("foundation".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        settings.validate().unwrap();
+    }
+
+    #[test]
+    fn settings_validate_osano_succeeds() {
+        let settings = LandscapeSettings {
+            foundation: "Foundation".to_string(),
+            url: "https://example.url".to_string(),
+            osano: Some(Osano {
+                customer_id: "customer_id".to_string(),
+                customer_configuration_id: "customer_configuration_id".to_string(),
+            }),
+            ..Default::default()
+        };
+
+        settings.validate().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "osano customer id cannot be empty")]
+    fn settings_validate_osano_empty_customer_id() {
+        let settings = LandscapeSettings {
+            foundation: "Foundation".to_string(),
+            url: "https://example.url".to_string(),
+            osano: Some(Osano {
+                customer_id: String::new(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        settings.validate().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "osano customer configuration id cannot be empty")]
+    fn settings_validate_osano_empty_customer_configuration_id() {
+        let settings = LandscapeSettings {
+            foundation: "Foundation".to_string(),
+            url: "https://example.url".to_string(),
+            osano: Some(Osano {
+                customer_id: "customer_id".to_string(),
+                customer_configuration_id: String::new(),
+            }),
+            ..Default::default()
+        };
+
+        settings.validate().unwrap();
+    }
+
+    #[test]
+    fn settings_validate_robots_succeeds() {
+        let settings = LandscapeSettings {
+            foundation: "Foundation".to_string(),
+            url: "https://example.url".to_string(),
+            robots: Robots {
+                allow: true,
+                disallow: vec!["/data/".to_string()],
+            },
+            ..Default::default()
+        };
+
+        settings.validate().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "robots disallowed path must start with /")]
+    fn settings_validate_robots_invalid_disallowed_path() {
+        let settings = LandscapeSettings {
+            foundation: "Foundation".to_string(),
+            url: "https://example.url".to_string(),
+            robots: Robots {
+                allow: true,
+                disallow: vec!["data/".to_string()],
+            },
+            ..Default::default()
+        };
+
+        settings.validate().unwrap();
+    }
+
+    #[test]
+    fn settings_validate_screenshot_width_succeeds() {
+        let settings = LandscapeSettings {
+            foundation: "Foundation".to_string(),
+            url: "https://example.url".to_string(),
+            screenshot_width: Some(2000),
+            ..Default::default()
+        };
+
+        settings.validate().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "screenshot width must be greater than 1000")]
+    fn settings_validate_screenshot_width_invalid() {
+        let settings = LandscapeSettings {
+            foundation: "Foundation".to_string(),
+            url: "https://example.url".to_string(),
+            screenshot_width: Some(1000),
+            ..Default::default()
+        };
+
+        settings.validate().unwrap();
+    }
+
+    #[test]
+    fn settings_validate_tags_succeeds() {
+        let settings = LandscapeSettings {
+            foundation: "Foundation".to_string(),
+            url: "https://example.url".to_string(),
+            tags: Some(BTreeMap::from_iter(vec![(
+                "tag1".to_string(),
+                vec![TagRule {
+                    category: "Category".to_string(),
+                    subcategories: Some(vec!["Subcategory".to_string()]),
+                }],
+            )])),
+            ..Default::default()
+        };
+
+        settings.validate().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "tag [tag1] must contain at least one rule")]
+    fn settings_validate_tags_no_rules() {
+        let settings = LandscapeSettings {
+            foundation: "Foundation".to_string(),
+            url: "https://example.url".to_string(),
+            tags: Some(BTreeMap::from_iter(vec![("tag1".to_string(), vec![])])),
+            ..Default::default()
+        };
+
+        settings.validate().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "tag [tag1] category cannot be empty")]
+    fn settings_validate_tags_empty_category() {
+        let settings = LandscapeSettings {
+            foundation: "Foundation".to_string(),
+            url: "https://example.url".to_string(),
+            tags: Some(BTreeMap::from_iter(vec![(
+                "tag1".to_string(),
+                vec![TagRule {
+                    category: String::new(),
+                    subcategories: None,
+                }],
+            )])),
+            ..Default::default()
+        };
+
+        settings.validate().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "tag [tag1] subcategories cannot be empty")]
+    fn settings_validate_tags_empty_subcategories() {
+        let settings = LandscapeSettings {
+            foundation: "Foundation".to_string(),
+            url: "https://example.url".to_string(),
+            tags: Some(BTreeMap::from_iter(vec![(
+                "tag1".to_string(),
+                vec![TagRule {
+                    category: "Category".to_string(),
+                    subcategories: Some(vec![]),
+                }],
+            )])),
+            ..Default::default()
+        };
+
+        settings.validate().unwrap();
+    }
+
+    #[test]
+    fn settings_validate_theme_succeeds() {
+        let settings = LandscapeSettings {
+            foundation: "Foundation".to_string(),
+            url: "https://example.url".to_string(),
+            theme: Some(Theme {
+                font_family: Some("'Open Sans', sans-serif".to_string()),
+                font_url: Some("https://fonts.example.url/open-sans.css".to_string()),
+                primary_color: Some("rgba(0, 107, 204, 1)".to_string()),
+                secondary_color: Some("rgba(214, 34, 147, 1)".to_string()),
+            }),
+            ..Default::default()
+        };
+
+        settings.validate().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "theme primary_color is not valid")]
+    fn settings_validate_theme_invalid_color() {
+        let settings = LandscapeSettings {
+            foundation: "Foundation".to_string(),
+            url: "https://example.url".to_string(),
+            theme: Some(Theme {
+                primary_color: Some("invalid-color".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        settings.validate().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "theme font family contains invalid characters")]
+    fn settings_validate_theme_invalid_font_family() {
+        let settings = LandscapeSettings {
+            foundation: "Foundation".to_string(),
+            url: "https://example.url".to_string(),
+            theme: Some(Theme {
+                font_family: Some("serif; } body { display: none".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        settings.validate().unwrap();
+    }
+
+    #[test]
+    fn settings_validate_against_data_succeeds() {
+        let settings = LandscapeSettings {
+            categories: Some(vec![Category {
+                name: "Category".to_string(),
+                subcategories: vec!["Subcategory".to_string()],
+            }]),
+            featured_items: Some(vec![FeaturedItemRule {
+                field: "maturity".to_string(),
+                options: vec![FeaturedItemRuleOption {
+                    value: "graduated".to_string(),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }]),
+            groups: Some(vec![Group {
+                name: "Group".to_string(),
+                categories: vec!["Category".to_string()],
+                ..Default::default()
+            }]),
+            members_category: Some("Category".to_string()),
+            ..Default::default()
+        };
+
+        settings.validate_against_data(&sample_landscape_data()).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "item [Item]: annotation [key] not defined in settings")]
+    fn settings_validate_against_data_annotation_not_defined() {
+        let mut landscape_data = sample_landscape_data();
+        landscape_data.items[0].annotations = Some(HashMap::from([("key".to_string(), "value".to_string())]));
+        let settings = LandscapeSettings {
+            annotations: Some(vec![AnnotationRule {
+                key: "other".to_string(),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        };
+
+        settings.validate_against_data(&landscape_data).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "item [Item]: annotation [key] value [value] not allowed")]
+    fn settings_validate_against_data_annotation_value_not_allowed() {
+        let mut landscape_data = sample_landscape_data();
+        landscape_data.items[0].annotations = Some(HashMap::from([("key".to_string(), "value".to_string())]));
+        let settings = LandscapeSettings {
+            annotations: Some(vec![AnnotationRule {
+                key: "key".to_string(),
+                values: Some(vec!["other".to_string()]),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        };
+
+        settings.validate_against_data(&landscape_data).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "item [Item]: required annotation [key] not found")]
+    fn settings_validate_against_data_required_annotation_not_found() {
+        let settings = LandscapeSettings {
+            annotations: Some(vec![AnnotationRule {
+                key: "key".to_string(),
+                required: Some(true),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        };
+
+        settings.validate_against_data(&sample_landscape_data()).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "categories: category [Category 2] not found in landscape data")]
+    fn settings_validate_against_data_category_not_found() {
+        let settings = LandscapeSettings {
+            categories: Some(vec![Category {
+                name: "Category 2".to_string(),
+                subcategories: vec![],
+            }]),
+            ..Default::default()
+        };
+
+        settings.validate_against_data(&sample_landscape_data()).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "tag [tag1]: subcategory [Subcategory 2] not found in category [Category]")]
+    fn settings_validate_against_data_subcategory_not_found() {
+        let settings = LandscapeSettings {
+            tags: Some(BTreeMap::from_iter(vec![(
+                "tag1".to_string(),
+                vec![TagRule {
+                    category: "Category".to_string(),
+                    subcategories: Some(vec!["Subcategory 2".to_string()]),
+                }],
+            )])),
+            ..Default::default()
+        };
+
+        settings.validate_against_data(&sample_landscape_data()).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "categories: category [Category 2] not found in landscape data\n\
+                               featured item rule [field]: unknown field")]
+    fn settings_validate_against_data_all_errors_reported() {
+        let settings = LandscapeSettings {
+            categories: Some(vec![Category {
+                name: "Category 2".to_string(),
+                subcategories: vec![],
+            }]),
+            featured_items: Some(vec![FeaturedItemRule {
+                field: "field".to_string(),
+                options: vec![FeaturedItemRuleOption {
+                    value: "value".to_string(),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }]),
+            ..Default::default()
+        };
+
+        settings.validate_against_data(&sample_landscape_data()).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "featured item rule [maturity]: value [sandbox] not found in landscape data")]
+    fn settings_validate_against_data_featured_item_value_not_found() {
+        let settings = LandscapeSettings {
+            featured_items: Some(vec![FeaturedItemRule {
+                field: "maturity".to_string(),
+                options: vec![FeaturedItemRuleOption {
+                    value: "sandbox".to_string(),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }]),
+            ..Default::default()
+        };
+
+        settings.validate_against_data(&sample_landscape_data()).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "featured item rule [field]: unknown field (supported: id, maturity")]
+    fn settings_validate_against_data_featured_item_field_not_supported() {
+        let settings = LandscapeSettings {
+            featured_items: Some(vec![FeaturedItemRule {
+                field: "field".to_string(),
+                options: vec![FeaturedItemRuleOption {
+                    value: "value".to_string(),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }]),
+            ..Default::default()
+        };
+
+        settings.validate_against_data(&sample_landscape_data()).unwrap();
+    }
+
+    #[test]
+    fn settings_validate_against_data_featured_item_other_fields_succeeds() {
+        let settings = LandscapeSettings {
+            featured_items: Some(vec![
+                FeaturedItemRule {
+                    field: "id".to_string(),
+                    options: vec![FeaturedItemRuleOption {
+                        value: "category--subcategory--item".to_string(),
+                        ..Default::default()
+                    }],
+                    ..Default::default()
+                },
+                FeaturedItemRule {
+                    field: "member_subcategory".to_string(),
+                    options: vec![FeaturedItemRuleOption {
+                        value: "Subcategory".to_string(),
+                        ..Default::default()
+                    }],
+                    ..Default::default()
+                },
+                FeaturedItemRule {
+                    field: "name".to_string(),
+                    options: vec![FeaturedItemRuleOption {
+                        value: "Item".to_string(),
+                        ..Default::default()
+                    }],
+                    ..Default::default()
+                },
+            ]),
+            members_category: Some("Category".to_string()),
+            ..Default::default()
+        };
+
+        settings.validate_against_data(&sample_landscape_data()).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "featured item rule [member_subcategory]: members_category must be set")]
+    fn settings_validate_against_data_featured_item_members_category_not_set() {
+        let settings = LandscapeSettings {
+            featured_items: Some(vec![FeaturedItemRule {
+                field: "member_subcategory".to_string(),
+                options: vec![FeaturedItemRuleOption {
+                    value: "Subcategory".to_string(),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }]),
+            ..Default::default()
+        };
+
+        settings.validate_against_data(&sample_landscape_data()).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "membership level [Gold]: subcategory [Gold] not found in category [Category]")]
+    fn settings_validate_against_data_membership_level_subcategory_not_found() {
+        let settings = LandscapeSettings {
+            members_category: Some("Category".to_string()),
+            membership_levels: Some(vec![MembershipLevel {
+                name: "Gold".to_string(),
+                subcategories: vec!["Gold".to_string()],
+            }]),
+            ..Default::default()
+        };
+
+        settings.validate_against_data(&sample_landscape_data()).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "members_category: category [Members] not found in landscape data")]
+    fn settings_validate_against_data_members_category_not_found() {
+        let settings = LandscapeSettings {
+            members_category: Some("Members".to_string()),
+            ..Default::default()
+        };
+
+        settings.validate_against_data(&sample_landscape_data()).unwrap();
+    }
+
+    fn sample_landscape_data() -> LandscapeData {
+        LandscapeData {
+            categories: vec![data::Category {
+                name: "Category".to_string(),
+                normalized_name: "category".to_string(),
+                subcategories: vec![Subcategory {
+                    name: "Subcategory".to_string(),
+                    normalized_name: "subcategory".to_string(),
+                }],
+            }],
+            items: vec![Item {
+                category: "Category".to_string(),
+                id: "category--subcategory--item".to_string(),
+                maturity: Some("graduated".to_string()),
+                name: "Item".to_string(),
+                subcategory: "Subcategory".to_string(),
+                ..Default::default()
+            }],
+        }
+    }
 }
@@ -8,6 +8,7 @@ use super::{
 use crate::data::LandscapeData;
 use chrono::{Datelike, Utc};
 use itertools::Itertools;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::collections::{BTreeMap, HashSet};
 
@@ -21,8 +22,16 @@ type Year = String;
 type YearMonth = String;
 
 /// Landscape stats.
-#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct Stats {
+    /// End user organizations stats.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_users: Option<EndUsersStats>,
+
+    /// Landscape items stats.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub items: Option<ItemsStats>,
+
     /// Foundation members stats.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub members: Option<MembersStats>,
@@ -46,6 +55,8 @@ impl Stats {
     #[must_use]
     pub fn new(landscape_data: &LandscapeData, settings: &LandscapeSettings) -> Self {
         Self {
+            end_users: EndUsersStats::new(landscape_data),
+            items: ItemsStats::new(landscape_data),
             members: MembersStats::new(landscape_data, settings),
             organizations: OrganizationsStats::new(landscape_data),
             projects: ProjectsStats::new(landscape_data),
@@ -54,8 +65,91 @@ impl Stats {
     }
 }
 
+/// Some stats about the end user organizations in the landscape.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct EndUsersStats {
+    /// Number of end users per country.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub country: BTreeMap<String, u64>,
+
+    /// Total number of end users.
+    pub end_users: u64,
+
+    /// Number of end users per industry.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub industry: BTreeMap<String, u64>,
+}
+
+impl EndUsersStats {
+    /// Create a new EndUsersStats instance from the information available in
+    /// the landscape.
+    fn new(landscape_data: &LandscapeData) -> Option<Self> {
+        let mut stats = EndUsersStats::default();
+
+        // Collect stats from landscape items flagged as end users
+        for item in landscape_data.items.iter().filter(|i| i.enduser.unwrap_or_default()) {
+            // Total number of end users
+            stats.end_users += 1;
+
+            if let Some(crunchbase_data) = &item.crunchbase_data {
+                // Number of end users per country
+                if let Some(country) = &crunchbase_data.country {
+                    increment(&mut stats.country, country, 1);
+                }
+
+                // Number of end users per industry
+                if let Some(industries) = &crunchbase_data.categories {
+                    for industry in industries {
+                        increment(&mut stats.industry, industry, 1);
+                    }
+                }
+            }
+        }
+
+        // Return stats collected
+        if stats != EndUsersStats::default() {
+            return Some(stats);
+        }
+        None
+    }
+}
+
+/// Some stats about all the items in the landscape.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct ItemsStats {
+    /// Number of items per category.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub category: BTreeMap<CategoryName, u64>,
+
+    /// Total number of items.
+    pub items: u64,
+}
+
+impl ItemsStats {
+    /// Create a new ItemsStats instance from the information available in the
+    /// landscape.
+    fn new(landscape_data: &LandscapeData) -> Option<Self> {
+        let mut stats = ItemsStats::default();
+
+        // Collect stats from landscape items
+        for item in &landscape_data.items {
+            // Total number of items
+            stats.items += 1;
+
+            // Number of items per category
+            increment(&mut stats.category, &item.category, 1);
+        }
+
+        // Return stats collected
+        if stats != ItemsStats::default() {
+            return Some(stats);
+        }
+        None
+    }
+}
+
 /// Some stats about the foundation's members.
-#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct MembersStats {
     /// Number of members joined per year-month.
     #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
@@ -65,6 +159,10 @@ pub struct MembersStats {
     #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
     pub joined_at_rt: BTreeMap<YearMonth, u64>,
 
+    /// Number of members per membership level.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub levels: BTreeMap<String, u64>,
+
     /// Total number of members.
     pub members: u64,
 
@@ -92,6 +190,11 @@ impl MembersStats {
                         increment(&mut stats.joined_at, &year_month, 1);
                     }
 
+                    // Number of members per membership level
+                    if let Some(level) = settings.membership_level(&item.subcategory) {
+                        increment(&mut stats.levels, &level.name, 1);
+                    }
+
                     // Number of members per subcategory
                     increment(&mut stats.subcategories, &item.subcategory, 1);
                 }
@@ -108,7 +211,7 @@ impl MembersStats {
 }
 
 /// Some stats about the organizations in the landscape.
-#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct OrganizationsStats {
     /// Total number of acquisitions per year across all organizations.
     #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
@@ -118,6 +221,9 @@ pub struct OrganizationsStats {
     #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
     pub acquisitions_price: BTreeMap<Year, u64>,
 
+    /// Total funding across all organizations.
+    pub funding: u64,
+
     /// Total number of funding rounds per year across all organizations.
     #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
     pub funding_rounds: BTreeMap<Year, u64>,
@@ -159,6 +265,11 @@ impl OrganizationsStats {
                 }
             }
 
+            // Funding
+            if let Some(funding) = item.crunchbase_data.as_ref().and_then(|d| d.funding) {
+                stats.funding += funding.unsigned_abs();
+            }
+
             // Funding rounds
             if let Some(funding_rounds) =
                 item.crunchbase_data.as_ref().and_then(|d| d.funding_rounds.as_ref())
@@ -191,7 +302,7 @@ impl OrganizationsStats {
 }
 
 /// Some stats about the landscape projects.
-#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct ProjectsStats {
     /// Number of projects accepted per year-month.
     #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
@@ -318,7 +429,7 @@ impl ProjectsStats {
 }
 
 /// Some stats about the projects in a category and its subcategories.
-#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct CategoryProjectsStats {
     /// Number of projects in the category.
     pub projects: u64,
@@ -329,7 +440,7 @@ pub struct CategoryProjectsStats {
 }
 
 /// Some stats about the repositories listed in the landscape.
-#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct RepositoriesStats {
     /// Source code bytes.
     pub bytes: u64,
@@ -382,7 +493,9 @@ impl RepositoriesStats {
 
                     if let Some(gh_data) = &repo.github_data {
                         // Contributors
-                        stats.contributors += gh_data.contributors.count as u64;
+                        if let Some(contributors) = &gh_data.contributors {
+                            stats.contributors += contributors.count as u64;
+                        }
 
                         // Languages
                         if let Some(languages) = &gh_data.languages {
@@ -491,6 +604,7 @@ mod tests {
         Acquisition, Contributors, FundingRound, Item, ItemAudit, Organization, Repository,
         RepositoryGithubData,
     };
+    use crate::settings::MembershipLevel;
     use chrono::NaiveDate;
 
     #[test]
@@ -502,6 +616,84 @@ mod tests {
         assert_eq!(stats, Stats::default());
     }
 
+    #[test]
+    fn end_users_stats_new() {
+        let landscape_data = LandscapeData {
+            categories: vec![],
+            items: vec![
+                Item {
+                    name: "End user 1".to_string(),
+                    crunchbase_data: Some(Organization {
+                        categories: Some(vec!["Banking".to_string(), "Finance".to_string()]),
+                        country: Some("Spain".to_string()),
+                        ..Default::default()
+                    }),
+                    enduser: Some(true),
+                    ..Default::default()
+                },
+                Item {
+                    name: "End user 2".to_string(),
+                    crunchbase_data: Some(Organization {
+                        categories: Some(vec!["Finance".to_string()]),
+                        country: Some("Spain".to_string()),
+                        ..Default::default()
+                    }),
+                    enduser: Some(true),
+                    ..Default::default()
+                },
+                Item {
+                    name: "Item".to_string(),
+                    crunchbase_data: Some(Organization {
+                        country: Some("Spain".to_string()),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                },
+            ],
+        };
+
+        let end_users_stats = EndUsersStats::new(&landscape_data);
+        let expected_end_users_stats = Some(EndUsersStats {
+            country: vec![("Spain".to_string(), 2)].into_iter().collect(),
+            end_users: 2,
+            industry: vec![("Banking".to_string(), 1), ("Finance".to_string(), 2)].into_iter().collect(),
+        });
+        pretty_assertions::assert_eq!(end_users_stats, expected_end_users_stats);
+    }
+
+    #[test]
+    fn items_stats_new() {
+        let landscape_data = LandscapeData {
+            categories: vec![],
+            items: vec![
+                Item {
+                    name: "Item 1".to_string(),
+                    category: "Category 1".to_string(),
+                    ..Default::default()
+                },
+                Item {
+                    name: "Item 2".to_string(),
+                    category: "Category 1".to_string(),
+                    ..Default::default()
+                },
+                Item {
+                    name: "Item 3".to_string(),
+                    category: "Category 2".to_string(),
+                    ..Default::default()
+                },
+            ],
+        };
+
+        let items_stats = ItemsStats::new(&landscape_data);
+        let expected_items_stats = Some(ItemsStats {
+            category: vec![("Category 1".to_string(), 2), ("Category 2".to_string(), 1)]
+                .into_iter()
+                .collect(),
+            items: 3,
+        });
+        pretty_assertions::assert_eq!(items_stats, expected_items_stats);
+    }
+
     #[test]
     fn members_stats_new() {
         let landscape_data = LandscapeData {
@@ -525,6 +717,10 @@ mod tests {
         };
         let settings = LandscapeSettings {
             members_category: Some("Members".to_string()),
+            membership_levels: Some(vec![MembershipLevel {
+                name: "Level".to_string(),
+                subcategories: vec!["Subcategory".to_string()],
+            }]),
             ..Default::default()
         };
 
@@ -532,6 +728,7 @@ mod tests {
         let expected_members_stats = Some(MembersStats {
             joined_at: vec![("2024-04".to_string(), 1), ("2024-05".to_string(), 1)].into_iter().collect(),
             joined_at_rt: vec![("2024-04".to_string(), 1), ("2024-05".to_string(), 2)].into_iter().collect(),
+            levels: vec![("Level".to_string(), 2)].into_iter().collect(),
             members: 2,
             subcategories: vec![("Subcategory".to_string(), 2)].into_iter().collect(),
         });
@@ -546,6 +743,7 @@ mod tests {
                 Item {
                     name: "Organization 1".to_string(),
                     crunchbase_data: Some(Organization {
+                        funding: Some(1000),
                         acquisitions: Some(vec![
                             Acquisition {
                                 announced_on: NaiveDate::from_ymd_opt(2023, 5, 1),
@@ -586,6 +784,7 @@ mod tests {
                 Item {
                     name: "Organization 2".to_string(),
                     crunchbase_data: Some(Organization {
+                        funding: Some(500),
                         acquisitions: Some(vec![Acquisition {
                             announced_on: NaiveDate::from_ymd_opt(2024, 5, 3),
                             price: Some(300),
@@ -605,6 +804,7 @@ mod tests {
                     // This org will be ignored as it has the same crunchbase URL as the previous one
                     name: "Organization 3".to_string(),
                     crunchbase_data: Some(Organization {
+                        funding: Some(500),
                         acquisitions: Some(vec![Acquisition {
                             announced_on: NaiveDate::from_ymd_opt(2024, 5, 3),
                             price: Some(300),
@@ -624,6 +824,7 @@ mod tests {
             acquisitions_price: vec![("2023".to_string(), 100), ("2024".to_string(), 500)]
                 .into_iter()
                 .collect(),
+            funding: 1500,
             funding_rounds: vec![("2023".to_string(), 1), ("2024".to_string(), 2)].into_iter().collect(),
             funding_rounds_money_raised: vec![("2023".to_string(), 100), ("2024".to_string(), 500)]
                 .into_iter()
@@ -720,10 +921,10 @@ mod tests {
                     repositories: Some(vec![Repository {
                         url: "https://repository1.url".to_string(),
                         github_data: Some(RepositoryGithubData {
-                            contributors: Contributors {
+                            contributors: Some(Contributors {
                                 count: 1,
                                 ..Default::default()
-                            },
+                            }),
                             languages: Some(
                                 vec![
                                     ("Rust".to_string(), 100),
@@ -757,10 +958,10 @@ mod tests {
                         Repository {
                             url: "https://repository2.url".to_string(),
                             github_data: Some(RepositoryGithubData {
-                                contributors: Contributors {
+                                contributors: Some(Contributors {
                                     count: 2,
                                     ..Default::default()
-                                },
+                                }),
                                 languages: Some(
                                     vec![
                                         ("Rust".to_string(), 200),
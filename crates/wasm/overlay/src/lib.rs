@@ -3,7 +3,7 @@
 use anyhow::{bail, Context, Result};
 use landscape2_core::{
     data::{DataSource, Item, LandscapeData},
-    datasets::{base::Base, full::Full},
+    datasets::{base::Base, full::Full, search::Search},
     games::{GamesSource, LandscapeGames},
     guide::{GuideSource, LandscapeGuide},
     settings::{LandscapeSettings, SettingsSource},
@@ -53,6 +53,7 @@ struct OverlayData {
 struct Datasets {
     base: Base,
     full: Full,
+    search: Search,
     stats: Stats,
 }
 
@@ -104,16 +105,23 @@ pub async fn get_overlay_data(input: JsValue) -> Result<String, String> {
         LandscapeGames::new(&games_src).await.ok().flatten()
     };
 
-    // Get Crunchbase and GitHub data from deployed full dataset
+    // Get Crunchbase, GitHub and packages data from deployed full dataset
     let deployed_full_dataset = get_full_dataset(&input.landscape_url).await.map_err(to_str)?;
     let deployed_items = deployed_full_dataset.items;
     let crunchbase_data = deployed_full_dataset.crunchbase_data;
+    let feeds_data = deployed_full_dataset.feeds_data;
     let github_data = deployed_full_dataset.github_data;
+    let linkedin_data = deployed_full_dataset.linkedin_data;
+    let packages_data = deployed_full_dataset.packages;
+    let social_data = deployed_full_dataset.social;
+    let sponsorships_data = deployed_full_dataset.sponsorships_data;
 
     // Enrich landscape data with some extra information
     landscape_data.add_crunchbase_data(&crunchbase_data);
     landscape_data.add_featured_items_data(&settings);
+    landscape_data.add_feeds_data(&feeds_data);
     landscape_data.add_github_data(&github_data);
+    landscape_data.add_linkedin_data(&linkedin_data);
     landscape_data.add_member_subcategory(&settings.members_category);
     landscape_data.add_tags(&settings);
     landscape_data.set_enduser_flag(&settings);
@@ -124,7 +132,16 @@ pub async fn get_overlay_data(input: JsValue) -> Result<String, String> {
     let qr_code = String::new();
     let datasets = Datasets {
         base: Base::new(&landscape_data, &settings, &guide, &games, &qr_code),
-        full: Full::new(&landscape_data, &crunchbase_data, &github_data),
+        full: Full::new(
+            &landscape_data,
+            &crunchbase_data,
+            &github_data,
+            &linkedin_data,
+            &packages_data,
+            &social_data,
+            &sponsorships_data,
+        ),
+        search: Search::new(&landscape_data),
         stats: Stats::new(&landscape_data, &settings),
     };
 
@@ -167,16 +184,17 @@ fn set_clomonitor_report_summary(landscape_data: &mut LandscapeData, deployed_it
 /// items. Otherwise, we'll use the current logo url from the deployed items.
 fn set_logos_url(landscape_data: &mut LandscapeData, logos_url: Option<String>, deployed_items: &[Item]) {
     if let Some(logos_url) = logos_url {
+        let logos_url = logos_url.trim_end_matches('/');
         for item in &mut landscape_data.items {
-            item.logo = format!("{}/{}", logos_url.trim_end_matches('/'), item.logo);
+            item.logo = format!("{logos_url}/{}", item.logo);
+            item.logo_dark = item.logo_dark.as_ref().map(|logo_dark| format!("{logos_url}/{logo_dark}"));
         }
     } else {
         for item in &mut landscape_data.items {
-            item.logo = deployed_items
-                .iter()
-                .find(|x| x.id == item.id)
-                .map(|x| x.logo.clone())
-                .unwrap_or_default();
+            let deployed_item = deployed_items.iter().find(|x| x.id == item.id);
+            item.logo = deployed_item.map(|x| x.logo.clone()).unwrap_or_default();
+            item.logo_dark = deployed_item.and_then(|x| x.logo_dark.clone());
+            item.logo_png = deployed_item.and_then(|x| x.logo_png.clone());
         }
     }
 }
@@ -149,12 +149,14 @@ const RepositoryInfo = (props: RepoProps) => {
             description="Stars number"
           />
 
-          <Box
-            class={props.boxClass}
-            value={prettifyNumber(props.repository.github_data!.contributors.count)}
-            legend="Contributors"
-            description="Contributors number"
-          />
+          <Show when={!isUndefined(props.repository.github_data!.contributors)}>
+            <Box
+              class={props.boxClass}
+              value={prettifyNumber(props.repository.github_data!.contributors!.count)}
+              legend="Contributors"
+              description="Contributors number"
+            />
+          </Show>
 
           <Box
             class={props.boxClass}
@@ -116,7 +116,7 @@ export interface Repository {
 }
 
 export interface GithubRepository {
-  contributors: Contributors;
+  contributors?: Contributors;
   description: string;
   first_commit: Commit;
   generated_at: number;
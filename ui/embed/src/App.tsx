@@ -151,6 +151,31 @@ const App = () => {
     });
   };
 
+  // Apply the view options defined in the landscape settings (only when the
+  // corresponding url params haven't been provided)
+  const applyViewOptions = (viewData: Data) => {
+    const options = viewData.options;
+    if (!options) return;
+
+    const urlParams = new URLSearchParams(window.location.search);
+    if (options.display_header !== undefined && urlParams.get(DISPLAY_HEADER_PARAM) === null) {
+      setDisplayHeader(options.display_header);
+    }
+    if (options.items_style && urlParams.get(ITEMS_STYLE_PARAM) === null) {
+      setItemsStyleView(options.items_style);
+    }
+    if (options.items_size && urlParams.get(ITEMS_SIZE_PARAM) === null) {
+      setItemsSize(options.items_size);
+    }
+    if (options.title_bgcolor && urlParams.get(TITLE_BGCOLOR_PARAM) === null) {
+      setTitleBgColor(options.title_bgcolor);
+      setIsBgTransparent(options.title_bgcolor === 'transparent');
+    }
+    if (options.title_fgcolor && urlParams.get(TITLE_FGCOLOR_PARAM) === null) {
+      setTitleFgColor(options.title_fgcolor);
+    }
+  };
+
   onMount(() => {
     const urlParams = new URLSearchParams(window.location.search);
     const basePathParam = urlParams.get(BASE_PATH_PARAM);
@@ -266,7 +291,10 @@ const App = () => {
               throw new Error('Something went wrong');
             })
             .then((res) => {
-              setData(res);
+              batch(() => {
+                applyViewOptions(res);
+                setData(res);
+              });
             })
             .catch(() => {
               setData(null);
@@ -21,6 +21,15 @@ export interface Data {
   foundation: string;
   category: Category;
   items: BaseItem[];
+  options?: ViewOptions;
+}
+
+export interface ViewOptions {
+  display_header?: boolean;
+  items_size?: Size;
+  items_style?: Style;
+  title_bgcolor?: string;
+  title_fgcolor?: string;
 }
 
 export interface Category {
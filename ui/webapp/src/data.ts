@@ -197,6 +197,7 @@ export const FILTER_CATEGORIES_PER_TITLE: FilterCategoriesPerTitle = {
     FilterCategory.Maturity,
     FilterCategory.TAG,
     FilterCategory.License,
+    FilterCategory.Language,
     FilterCategory.Category,
     FilterCategory.Extra,
   ],
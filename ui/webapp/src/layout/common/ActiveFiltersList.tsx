@@ -7,6 +7,7 @@ import { For, Match, Show, Switch } from 'solid-js';
 import { REGEX_UNDERSCORE } from '../../data';
 import { ActiveFilters, FilterCategory } from '../../types';
 import getFoundationNameLabel from '../../utils/getFoundationNameLabel';
+import translate from '../../utils/translate';
 import styles from './ActiveFiltersList.module.css';
 
 interface Props {
@@ -202,13 +203,13 @@ const ActiveFiltersList = (props: Props) => {
         <div
           class={`d-flex flex-row align-items-center text-nowrap text-muted text-uppercase me-3 mt-2 ${styles.btnLegend}`}
         >
-          <small>Filters applied</small>
+          <small>{translate('filters.applied')}</small>
           <button
             class={`btn btn-link btn-sm text-muted p-0 ps-1 ${styles.btnReset}`}
             onClick={() => props.resetFilters()}
             aria-label="Reset all filters"
           >
-            (reset all)
+            ({translate('filters.reset_all')})
           </button>
           <small>:</small>
         </div>
@@ -3,6 +3,7 @@ import isUndefined from 'lodash/isUndefined';
 import { createSignal, Show } from 'solid-js';
 
 import { BANNER_ID } from '../../data';
+import translate from '../../utils/translate';
 import styles from './DownloadDropdown.module.css';
 
 enum DocType {
@@ -110,7 +111,7 @@ const DownloadDropdown = () => {
         <div class={`d-block position-absolute ${styles.arrow}`} />
         <ul class={`m-0 p-0 ${styles.menuList}`}>
           <li>
-            <div class={`text-uppercase text-center fw-semibold p-2 ${styles.dropdownHeader}`}>Landscape</div>
+            <div class={`text-uppercase text-center fw-semibold p-2 ${styles.dropdownHeader}`}>{translate('download.landscape')}</div>
           </li>
           <li>
             <button
@@ -143,7 +144,7 @@ const DownloadDropdown = () => {
                 </div>
                 <div class={styles.contentBtn}>
                   <div class="fw-semibold mb-2">landscape.pdf</div>
-                  <div class={`text-wrap text-muted fst-italic ${styles.legend}`}>Landscape in PDF format</div>
+                  <div class={`text-wrap text-muted fst-italic ${styles.legend}`}>{translate('download.landscape_pdf')}</div>
                 </div>
               </div>
             </button>
@@ -178,13 +179,13 @@ const DownloadDropdown = () => {
                 </div>
                 <div class={styles.contentBtn}>
                   <div class="fw-semibold mb-2">landscape.png</div>
-                  <div class={`text-wrap text-muted fst-italic ${styles.legend}`}>Landscape in PNG format</div>
+                  <div class={`text-wrap text-muted fst-italic ${styles.legend}`}>{translate('download.landscape_png')}</div>
                 </div>
               </div>
             </button>
           </li>
           <li>
-            <div class={`text-uppercase text-center fw-semibold p-2 ${styles.dropdownHeader}`}>Data files</div>
+            <div class={`text-uppercase text-center fw-semibold p-2 ${styles.dropdownHeader}`}>{translate('download.data_files')}</div>
           </li>
           <li>
             <button
@@ -211,7 +212,7 @@ const DownloadDropdown = () => {
                 <div class={styles.contentBtn}>
                   <div class="fw-semibold mb-2">items.csv</div>
                   <div class={`text-wrap text-muted fst-italic ${styles.legend}`}>
-                    CSV file that contains information about all items available in the landscape
+                    {translate('download.items_csv')}
                   </div>
                 </div>
               </div>
@@ -242,7 +243,7 @@ const DownloadDropdown = () => {
                 <div class={styles.contentBtn}>
                   <div class="fw-semibold mb-2">projects.csv</div>
                   <div class={`text-wrap text-muted fst-italic ${styles.legend}`}>
-                    CSV file that contains information about all the projects that are part of the foundation
+                    {translate('download.projects_csv')}
                   </div>
                 </div>
               </div>
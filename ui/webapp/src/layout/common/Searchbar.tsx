@@ -5,7 +5,9 @@ import { SearchResult } from 'minisearch';
 import { batch, createEffect, createSignal, For, on, onCleanup, Show } from 'solid-js';
 
 import { BANNER_ID, FOUNDATION } from '../../data';
+import itemsDataGetter from '../../utils/itemsDataGetter';
 import searchEngine from '../../utils/search';
+import translate from '../../utils/translate';
 import { useUpdateActiveItemId } from '../stores/activeItem';
 import HoverableItem from './HoverableItem';
 import styles from './Searchbar.module.css';
@@ -175,6 +177,8 @@ const Searchbar = (props: Props) => {
       const isInputFocused = inputEl() === document.activeElement;
       if (isInputFocused) {
         if (value().length >= MIN_CHARACTERS_SEARCH) {
+          // The search index is prepared from the full data
+          itemsDataGetter.loadFullData();
           cleanTimeout();
           setDropdownTimeout(
             setTimeout(() => {
@@ -210,7 +214,7 @@ const Searchbar = (props: Props) => {
           autocorrect="off"
           autocapitalize="none"
           spellcheck={false}
-          placeholder="Search items"
+          placeholder={translate('search.placeholder')}
           onKeyDown={onKeyDown}
           onInput={(e) => setValue(e.target.value)}
         />
@@ -247,7 +251,7 @@ const Searchbar = (props: Props) => {
             when={itemsList()!.length > 0}
             fallback={
               <div class="p-4 text-center fst-italic text-muted">
-                <small>{error() || `We couldn't find any items that match that criteria.`}</small>
+                <small>{error() || translate('search.no_results')}</small>
               </div>
             }
           >
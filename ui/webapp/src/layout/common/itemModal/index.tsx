@@ -4,7 +4,7 @@ import isUndefined from 'lodash/isUndefined';
 import { createEffect, createSignal, Show } from 'solid-js';
 
 import { BASE_PATH, FOUNDATION, ITEM_VIEW, SMALL_DEVICES_BREAKPOINTS } from '../../../data';
-import { Item } from '../../../types';
+import { BaseItem, Item } from '../../../types';
 import itemsDataGetter from '../../../utils/itemsDataGetter';
 import { useActiveItemId, useUpdateActiveItemId } from '../../stores/activeItem';
 import { useFullDataReady } from '../../stores/fullData';
@@ -23,9 +23,11 @@ const ItemModal = () => {
   createEffect(() => {
     async function fetchItemInfo() {
       try {
-        const itemTmp = await itemsDataGetter.getItemById(visibleItemId()! as string);
+        const itemId = visibleItemId();
+        const itemTmp = await itemsDataGetter.loadItem((i: BaseItem) => i.id === itemId);
         if (!isUndefined(itemTmp) && !isUndefined(itemTmp!.parent_project)) {
-          const parentItem = await itemsDataGetter.getItemByName(itemTmp.parent_project);
+          const parentProject = itemTmp.parent_project;
+          const parentItem = await itemsDataGetter.loadItem((i: BaseItem) => i.name === parentProject);
           if (!isUndefined(parentItem)) {
             setParentInfo(parentItem);
           }
@@ -38,7 +40,9 @@ const ItemModal = () => {
       }
     }
 
-    if (visibleItemId() && fullDataReady()) {
+    // When the full dataset is split, the item's data can be loaded before
+    // the full data is ready
+    if (visibleItemId() && (fullDataReady() || itemsDataGetter.hasShards())) {
       fetchItemInfo();
     } else {
       setItemInfo(undefined);
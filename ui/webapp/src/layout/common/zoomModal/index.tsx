@@ -59,6 +59,10 @@ const ZoomModal = () => {
       }
     }
 
+    if (visibleZoomSection()) {
+      itemsDataGetter.loadFullData();
+    }
+
     if (visibleZoomSection() && fullDataReady()) {
       if (isUndefined(items())) {
         fetchItems();
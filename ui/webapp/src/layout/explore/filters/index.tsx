@@ -21,6 +21,7 @@ import {
 } from '../../../types';
 import getFoundationNameLabel from '../../../utils/getFoundationNameLabel';
 import getFiltersPerGroup, { FiltersOptions, FiltersPerGroup } from '../../../utils/prepareFilters';
+import translate from '../../../utils/translate';
 import Section from '../../common/Section';
 import { useViewMode } from '../../stores/viewMode';
 import styles from './Filters.module.css';
@@ -185,7 +186,7 @@ const Filters = (props: Props) => {
     <>
       <div class="position-relative">
         <button
-          title="Filters"
+          title={translate('filters.title')}
           class={`position-relative btn btn-sm btn-secondary text-white btn-sm rounded-0 py-0 me-0 me-lg-4 ${styles.filterBtn} btnIconMobile`}
           classList={{ disabled: disabledBtn() }}
           onClick={() => setVisibleFiltersModal(true)}
@@ -193,7 +194,7 @@ const Filters = (props: Props) => {
         >
           <div class="d-flex flex-row align-items-center">
             <SVGIcon kind={SVGIconKind.Filters} />
-            <div class="d-none d-lg-block fw-semibold ps-2">Filters</div>
+            <div class="d-none d-lg-block fw-semibold ps-2">{translate('filters.title')}</div>
           </div>
         </button>
         <Show when={!isEmpty(props.initialActiveFilters())}>
@@ -203,11 +204,11 @@ const Filters = (props: Props) => {
         </Show>
       </div>
       <Modal
-        title="Filters"
+        title={translate('filters.title')}
         modalDialogClass={styles.modal}
         header={
           <div class="d-flex flex-row align-items-baseline">
-            <div>Filters</div>
+            <div>{translate('filters.title')}</div>
             <Show when={!isEmpty(props.initialActiveFilters())}>
               <button
                 type="button"
@@ -219,7 +220,7 @@ const Filters = (props: Props) => {
                 }}
                 aria-label="Reset all filters"
               >
-                (reset all)
+                ({translate('filters.reset_all')})
               </button>
             </Show>
           </div>
@@ -241,7 +242,7 @@ const Filters = (props: Props) => {
                 }}
                 aria-label="Apply filters"
               >
-                Apply
+                {translate('filters.apply')}
               </button>
             </div>
           </div>
@@ -325,14 +326,14 @@ const Filters = (props: Props) => {
               </Show>
             </div>
             <Show when={isEmpty(visibleTitles())}>
-              <NoData>There are no filters available that can be applied to the current set of items</NoData>
+              <NoData>{translate('filters.none_available')}</NoData>
             </Show>
             <Show when={visibleTitles().includes(FilterTitle.Project)}>
               <div class={`border-bottom text-uppercase fw-semibold ${styles.title}`}>{FilterTitle.Project}</div>
 
               <div class="row g-4 g-lg-5 mb-4 mb-lg-5">
                 <Section
-                  title="Status"
+                  title={translate('filters.status')}
                   section={getSectionInPredefinedFilters(
                     FilterCategory.Maturity,
                     filtersOptions().itemsWithoutMaturity
@@ -344,7 +345,7 @@ const Filters = (props: Props) => {
                   sectionClass={`overflow-auto visibleScroll ${styles.section}`}
                 />
                 <Section
-                  title="TAG"
+                  title={translate('filters.tag')}
                   section={getSection(FilterCategory.TAG)}
                   activeFilters={{ ...tmpActiveFilters() }[FilterCategory.TAG]}
                   updateActiveFilters={updateActiveFilters}
@@ -353,7 +354,7 @@ const Filters = (props: Props) => {
                 />
                 <Show when={!isUndefined(getSection(FilterCategory.License))}>
                   <Section
-                    title="License"
+                    title={translate('filters.license')}
                     section={getSectionInPredefinedFilters(
                       FilterCategory.License,
                       filtersOptions().itemsWithoutLicense
@@ -366,7 +367,15 @@ const Filters = (props: Props) => {
                   />
                 </Show>
                 <Section
-                  title="Category"
+                  title={translate('filters.language')}
+                  section={getSection(FilterCategory.Language)}
+                  activeFilters={{ ...tmpActiveFilters() }[FilterCategory.Language]}
+                  updateActiveFilters={updateActiveFilters}
+                  resetFilter={resetFilter}
+                  sectionClass={`overflow-auto visibleScroll ${styles.section}`}
+                />
+                <Section
+                  title={translate('filters.category')}
                   section={getSection(FilterCategory.Category)}
                   activeFilters={{ ...tmpActiveFilters() }[FilterCategory.Category]}
                   updateActiveFilters={updateActiveFilters}
@@ -374,7 +383,7 @@ const Filters = (props: Props) => {
                   sectionClass={`overflow-auto visibleScroll ${styles.section}`}
                 />
                 <Section
-                  title="Extra"
+                  title={translate('filters.extra')}
                   section={getSection(FilterCategory.Extra)}
                   activeFilters={{ ...tmpActiveFilters() }[FilterCategory.Extra]}
                   updateActiveFilters={updateActiveFilters}
@@ -389,8 +398,8 @@ const Filters = (props: Props) => {
 
               <div class="row g-4 g-lg-5 mb-4 mb-lg-5">
                 <SearchbarSection
-                  title="Name"
-                  placeholder="Search organization"
+                  title={translate('filters.name')}
+                  placeholder={translate('filters.search_organization')}
                   section={getSection(FilterCategory.Organization)}
                   initialActiveFilters={tmpActiveFilters}
                   updateActiveFilters={updateActiveFilters}
@@ -399,14 +408,14 @@ const Filters = (props: Props) => {
 
                 <SearchbarSection
                   section={getSection(FilterCategory.Industry)}
-                  placeholder="Search industry"
+                  placeholder={translate('filters.search_industry')}
                   initialActiveFilters={tmpActiveFilters}
                   updateActiveFilters={updateActiveFilters}
                   resetFilter={resetFilter}
                 />
 
                 <Section
-                  title="Type"
+                  title={translate('filters.type')}
                   section={getSectionInPredefinedFilters(FilterCategory.OrgType)}
                   activeFilters={{ ...tmpActiveFilters() }[FilterCategory.OrgType]}
                   updateActiveFilters={updateActiveFilters}
@@ -415,8 +424,8 @@ const Filters = (props: Props) => {
                 />
 
                 <SearchbarSection
-                  title="Location"
-                  placeholder="Search country"
+                  title={translate('filters.location')}
+                  placeholder={translate('filters.search_country')}
                   section={getSection(FilterCategory.Country)}
                   initialActiveFilters={tmpActiveFilters}
                   updateActiveFilters={updateActiveFilters}
@@ -38,6 +38,7 @@ import getFoundationNameLabel from '../../utils/getFoundationNameLabel';
 import getNormalizedName from '../../utils/getNormalizedName';
 import itemsDataGetter, { ClassifyAndSortOptions, GroupData } from '../../utils/itemsDataGetter';
 import scrollToTop from '../../utils/scrollToTop';
+import translate from '../../utils/translate';
 import ActiveFiltersList from '../common/ActiveFiltersList';
 import Footer from '../navigation/Footer';
 import { useFullDataReady } from '../stores/fullData';
@@ -402,6 +403,12 @@ const Explore = (props: Props) => {
     }
   }
 
+  createEffect(() => {
+    if (checkIfFullDataRequired()) {
+      itemsDataGetter.loadFullData();
+    }
+  });
+
   createEffect(
     on(fullDataReady, () => {
       if (fullDataReady()) {
@@ -653,7 +660,7 @@ const Explore = (props: Props) => {
                     </For>
                     <Show when={viewMode() === ViewMode.Card}>
                       <button
-                        title="All"
+                        title={translate('explore.all')}
                         class={`btn btn-outline-primary btn-sm rounded-0 fw-semibold text-nowrap ${styles.navLink}`}
                         classList={{
                           [`active ${styles.active}`]:
@@ -670,7 +677,7 @@ const Explore = (props: Props) => {
                         }}
                         aria-label="All"
                       >
-                        All
+                        {translate('explore.all')}
                       </button>
                     </Show>
                   </div>
@@ -701,7 +708,7 @@ const Explore = (props: Props) => {
                         }}
                       </For>
                       <Show when={viewMode() === ViewMode.Card}>
-                        <option value={ALL_OPTION}>All</option>
+                        <option value={ALL_OPTION}>{translate('explore.all')}</option>
                       </Show>
                     </select>
                   </div>
@@ -906,18 +913,18 @@ const Explore = (props: Props) => {
           <div class="pt-5">
             <NoData>
               <>
-                <div class="fs-4">We couldn't find any items that match the criteria selected.</div>
+                <div class="fs-4">{translate('explore.no_items')}</div>
                 <p class="h6 my-4 lh-base">
-                  You can update them and try again or{' '}
+                  {translate('explore.no_items_reset_prefix')}{' '}
                   <button
                     type="button"
                     class="btn btn-link lh-1 p-0 text-reset align-baseline"
                     onClick={resetFilters}
                     aria-label="Reset filters"
                   >
-                    reset all
+                    {translate('filters.reset_all')}
                   </button>{' '}
-                  the filters.
+                  {translate('explore.no_items_reset_suffix')}
                 </p>
               </>
             </NoData>
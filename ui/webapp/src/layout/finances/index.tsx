@@ -310,6 +310,7 @@ const Finances = () => {
 
   onMount(() => {
     prepareQuery();
+    itemsDataGetter.loadFullData();
     if (!isUndefined(financesData())) {
       setTimeout(() => {
         setData(financesData());
@@ -1,7 +1,7 @@
 import { Image, Loading } from 'common';
 import isUndefined from 'lodash/isUndefined';
 import orderBy from 'lodash/orderBy';
-import { createEffect, createSignal, For, on, Show } from 'solid-js';
+import { createEffect, createSignal, For, on, onMount, Show } from 'solid-js';
 
 import { FilterOption, Item, Option } from '../../types';
 import itemsDataGetter, { LogosOptionsGroup, LogosPreviewOptions } from '../../utils/itemsDataGetter';
@@ -109,6 +109,10 @@ const Logos = () => {
     }
   };
 
+  onMount(() => {
+    itemsDataGetter.loadFullData();
+  });
+
   createEffect(
     on(fullDataReady, () => {
       if (fullDataReady()) {
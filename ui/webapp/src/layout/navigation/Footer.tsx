@@ -1,7 +1,10 @@
 import { ExternalLink, Image, SVGIcon, SVGIconKind } from 'common';
 import isEmpty from 'lodash/isEmpty';
 import isUndefined from 'lodash/isUndefined';
-import { JSXElement, Show } from 'solid-js';
+import { For, JSXElement, Show } from 'solid-js';
+
+import { FooterLegalLink } from '../../types';
+import translate from '../../utils/translate';
 
 import styles from './Footer.module.css';
 
@@ -121,13 +124,24 @@ const Footer = () => {
                 {/* eslint-disable-next-line solid/no-innerhtml */}
                 <div class={`pt-2 ${styles.legend}`} innerHTML={window.baseDS.footer!.text} />
               </Show>
+              <Show when={!isUndefined(window.baseDS.footer) && !isEmpty(window.baseDS.footer!.legal_links)}>
+                <div class={`d-flex flex-row flex-wrap pt-2 ${styles.legend}`}>
+                  <For each={window.baseDS.footer!.legal_links}>
+                    {(link: FooterLegalLink) => (
+                      <ExternalLink class="p-0 me-3 text-white text-underline" href={link.url}>
+                        {link.title}
+                      </ExternalLink>
+                    )}
+                  </For>
+                </div>
+              </Show>
               <div class={`pt-2 ${styles.legend}`}>
-                Powered by{' '}
+                {translate('footer.powered_by')}{' '}
                 <ExternalLink
                   class="p-0 fw-semibold text-white text-underline"
                   href="https://github.com/cncf/landscape2"
                 >
-                  CNCF interactive landscapes generator
+                  {translate('footer.generator')}
                 </ExternalLink>
                 .
               </div>
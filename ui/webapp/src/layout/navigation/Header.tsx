@@ -17,6 +17,7 @@ import isExploreSection from '../../utils/isExploreSection';
 import itemsDataGetter from '../../utils/itemsDataGetter';
 import prepareLink from '../../utils/prepareLink';
 import scrollToTop from '../../utils/scrollToTop';
+import translate from '../../utils/translate';
 import DownloadDropdown from '../common/DownloadDropdown';
 import Searchbar from '../common/Searchbar';
 import { useSetGroupActive } from '../stores/groupActive';
@@ -92,7 +93,7 @@ const Header = (props: Props) => {
               }}
               aria-label='Go to "Explore" page'
             >
-              Explore
+              {translate('header.explore')}
             </button>
 
             <Show when={!isUndefined(window.baseDS.guide_summary) && !isEmpty(window.baseDS.guide_summary)}>
@@ -111,7 +112,7 @@ const Header = (props: Props) => {
                 }}
                 aria-label='Go to "Guide" page'
               >
-                Guide
+                {translate('header.guide')}
               </button>
             </Show>
 
@@ -131,7 +132,7 @@ const Header = (props: Props) => {
                 }}
                 aria-label='Go to "Stats" page'
               >
-                Stats
+                {translate('header.stats')}
               </button>
             </Show>
           </div>
@@ -2,6 +2,7 @@ import { ExternalLink } from 'common';
 import isUndefined from 'lodash/isUndefined';
 import { Show } from 'solid-js';
 
+import translate from '../../utils/translate';
 import styles from './Footer.module.css';
 
 const MiniFooter = () => {
@@ -16,12 +17,12 @@ const MiniFooter = () => {
                 <div class={`pb-2 ${styles.legend}`} innerHTML={window.baseDS.footer!.text} />
               </Show>
               <div class={styles.legend}>
-                Powered by{' '}
+                {translate('footer.powered_by')}{' '}
                 <ExternalLink
                   class="p-0 fw-semibold text-white text-underline"
                   href="https://github.com/cncf/landscape2"
                 >
-                  CNCF interactive landscapes generator
+                  {translate('footer.generator')}
                 </ExternalLink>
                 .
               </div>
@@ -5,6 +5,7 @@ import isUndefined from 'lodash/isUndefined';
 import { createSignal, Show } from 'solid-js';
 
 import { EXPLORE_PATH, GAMES_PATH, GUIDE_PATH, STATS_PATH } from '../../data';
+import translate from '../../utils/translate';
 import styles from './MobileDropdown.module.css';
 
 interface Props {
@@ -47,7 +48,7 @@ const MobileDropdown = (props: Props) => {
             aria-label="Go to Explore page"
             end
           >
-            Explore
+            {translate('header.explore')}
           </A>
         </div>
         <Show when={!isUndefined(window.baseDS.guide_summary) && !isEmpty(window.baseDS.guide_summary)}>
@@ -59,7 +60,7 @@ const MobileDropdown = (props: Props) => {
               state={{ from: 'mobileHeader' }}
               onClick={closeDropdown}
             >
-              Guide
+              {translate('header.guide')}
             </A>
           </div>
         </Show>
@@ -71,7 +72,7 @@ const MobileDropdown = (props: Props) => {
               href={STATS_PATH}
               onClick={closeDropdown}
             >
-              Stats
+              {translate('header.stats')}
             </A>
           </div>
         </Show>
@@ -83,7 +84,7 @@ const MobileDropdown = (props: Props) => {
               href={GAMES_PATH}
               onClick={closeDropdown}
             >
-              Games
+              {translate('header.games')}
             </A>
           </div>
         </Show>
@@ -2,6 +2,7 @@ import { A } from '@solidjs/router';
 import { SVGIcon, SVGIconKind } from 'common';
 
 import { EXPLORE_PATH } from '../../data';
+import translate from '../../utils/translate';
 import Footer from '../navigation/Footer';
 import styles from './NotFound.module.css';
 
@@ -15,14 +16,14 @@ const NotFound = () => {
         <div class={`m-3 ${styles.icon}`}>
           <SVGIcon kind={SVGIconKind.Warning} />
         </div>
-        <div class="h1 text-center mb-4">Error 404 - Page Not Found</div>
-        <div class="h3 text-center mb-5">The page you were looking for wasn't found</div>
+        <div class="h1 text-center mb-4">{translate('not_found.title')}</div>
+        <div class="h3 text-center mb-5">{translate('not_found.description')}</div>
         <A
           href={EXPLORE_PATH}
           class="btn btn-primary rounded-0 text-white btn-lg text-decoration-none"
           aria-label="Back home"
         >
-          Back Home
+          {translate('not_found.back_home')}
         </A>
       </main>
       <Footer />
@@ -296,6 +296,7 @@ const Projects = () => {
 
   onMount(() => {
     prepareQuery();
+    itemsDataGetter.loadFullData();
   });
 
   return (
@@ -211,6 +211,45 @@ const Content = () => {
             </div>
           </Show>
 
+          {/* End users */}
+          <Show when={!isUndefined(stats()!.end_users)}>
+            <div class="mb-2 mb-lg-5">
+              <div class={`text-dark fw-bold text-uppercase text-center mb-3 mb-lg-4 ${styles.title}`}>End users</div>
+              <div class="d-flex flex-row justify-content-center flex-wrap w-100 pt-4">
+                <Box data={stats()!.end_users!.end_users} label="Total" description="Number total of end users" />
+              </div>
+
+              <Show when={!isEmpty(stats()!.end_users!.industry) || !isEmpty(stats()!.end_users!.country)}>
+                <div class={`text-dark text-center mt-2 mt-lg-0 mb-0 mb-lg-4 fw-bold ${styles.subtitle}`}>
+                  Distribution by industry and country
+                </div>
+                <div class="py-4">
+                  <div class="row g-3 g-lg-4 g-xxl-5 justify-content-center">
+                    <Show when={!isEmpty(stats()!.end_users!.industry)}>
+                      <div class="col-12 col-sm-6">
+                        <HorizontalBarChart
+                          name="By industry"
+                          data={stats()!.end_users!.industry}
+                          total={stats()!.end_users!.end_users}
+                        />
+                      </div>
+                    </Show>
+
+                    <Show when={!isEmpty(stats()!.end_users!.country)}>
+                      <div class="col-12 col-sm-6">
+                        <HorizontalBarChart
+                          name="By country"
+                          data={stats()!.end_users!.country}
+                          total={stats()!.end_users!.end_users}
+                        />
+                      </div>
+                    </Show>
+                  </div>
+                </div>
+              </Show>
+            </div>
+          </Show>
+
           {/* Repositories */}
           <Show when={!isUndefined(stats()!.repositories)}>
             <div
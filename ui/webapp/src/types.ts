@@ -12,12 +12,21 @@ export interface BaseData {
   categories: Category[];
   categories_overridden?: string[];
   items: BaseItem[];
+  locale?: string;
   members_category?: string;
+  membership_levels?: MembershipLevel[];
   qr_code?: string;
   upcoming_event?: Event;
   view_mode?: ViewMode;
   header?: HeaderContent;
   footer?: FooterContent;
+  full_dataset_shards?: { [key: string]: string };
+  translations?: { [key: string]: string };
+}
+
+export interface FooterLegalLink {
+  title: string;
+  url: string;
 }
 
 export interface HeaderContent {
@@ -28,6 +37,7 @@ export interface HeaderContent {
 }
 
 export interface FooterContent {
+  legal_links?: FooterLegalLink[];
   links?: {
     facebook?: string;
     flickr?: string;
@@ -51,6 +61,11 @@ export interface Group {
   categories: string[];
 }
 
+export interface MembershipLevel {
+  name: string;
+  subcategories: string[];
+}
+
 export interface GuideSummary {
   [key: string]: string[];
 }
@@ -69,13 +84,17 @@ export interface Subcategory {
 export interface BaseItem {
   id: string;
   category: string;
+  openssf_best_practices_badge_level?: string;
   oss?: boolean;
   name: string;
   logo: string;
+  logo_dark?: string;
+  logo_png?: string;
   subcategory: string;
   description?: string;
   featured?: Featured;
   maturity?: string;
+  member_level?: string;
   tag?: string;
   additional_categories?: AdditionalCategory[];
 }
@@ -91,9 +110,13 @@ export interface Item extends BaseItem {
   homepage_url?: string;
   artwork_url?: string;
   blog_url?: string;
+  bluesky_url?: string;
   chat_channel?: string;
   clomonitor_name?: string;
   clomonitor_report_summary?: string;
+  clomonitor_score?: ClomonitorScore;
+  container_images?: string[];
+  container_images_stats?: ContainerImagesStats;
   member_subcategory?: string;
   crunchbase_data?: Organization;
   crunchbase_url?: string;
@@ -102,18 +125,27 @@ export interface Item extends BaseItem {
   docker_url?: string;
   documentation_url?: string;
   enduser?: boolean;
+  feed_url?: string;
   github_discussions_url?: string;
+  github_sponsors_url?: string;
   graduated_at?: string;
   incubating_at?: string;
   archived_at?: string;
   joined_at?: string;
+  license_details?: LicenseDetails;
   mailing_list_url?: string;
+  mastodon_url?: string;
+  open_collective_url?: string;
   package_manager_url?: string;
+  packages?: Package[];
   latest_annual_review_at?: string;
   latest_annual_review_url?: string;
   openssf_best_practices_url?: string;
   repositories?: Repository[];
+  repositories_stats?: RepositoriesStats;
   slack_url?: string;
+  social_card?: string;
+  qr_code?: string;
   specification?: boolean;
   stack_overflow_url?: string;
   summary?: ItemSummary;
@@ -122,10 +154,16 @@ export interface Item extends BaseItem {
   youtube_url?: string;
   linkedin_url?: string;
   audits?: SecurityAudit[];
+  organization?: OrganizationOverrides;
   parent_project?: string;
   other_links?: OtherLink[];
 }
 
+export interface Package {
+  name: string;
+  registry: string;
+}
+
 export interface OtherLink {
   name: string;
   url: string;
@@ -161,10 +199,26 @@ export interface Organization {
   stock_exchange?: string;
   ticker?: string;
   twitter_url?: string;
+  acquired_by?: AcquiredBy;
   acquisitions?: Acquisition[];
   funding_rounds?: FundingRound[];
 }
 
+export interface AcquiredBy {
+  acquirer_cb_permalink?: string;
+  acquirer_name?: string;
+  announced_on?: string;
+}
+
+export interface OrganizationOverrides {
+  city?: string;
+  country?: string;
+  funding?: number;
+  num_employees_max?: number;
+  num_employees_min?: number;
+  region?: string;
+}
+
 export interface Acquisition {
   announced_on?: string;
   acquiree_name?: string;
@@ -176,6 +230,7 @@ export interface FundingRound {
   amount?: number;
   announced_on?: string;
   kind?: string;
+  lead_investors?: string[];
 }
 
 export interface ItemSummary {
@@ -194,26 +249,87 @@ export interface Repository {
   branch?: string;
   github_data?: GithubRepository;
   primary: boolean;
+  status?: RepositoryStatus;
+}
+
+export enum RepositoryStatus {
+  Archived = 'archived',
+  Stale = 'stale',
+}
+
+export interface RepositoriesStats {
+  contributors: number;
+  forks: number;
+  latest_commit_ts?: string;
+  participation_stats: number[];
+  repositories: number;
+  stars: number;
 }
 
 export interface GithubRepository {
-  contributors: Contributors;
+  archived?: boolean;
+  contribution_issues?: ContributionIssues;
+  contributors?: Contributors;
   description: string;
   first_commit: Commit;
+  forks?: number;
   generated_at: number;
   languages?: { [key: string]: number };
+  languages_breakdown?: { [key: string]: number };
   latest_commit: Commit;
   latest_release?: Release;
   license: string;
   participation_stats: number[];
+  releases?: Release[];
+  security_advisories?: SecurityAdvisories;
   stars: number;
+  stars_history?: { [key: string]: number };
   topics: string[];
   url: string;
 }
 
+export interface ContributionIssues {
+  good_first_issue: number;
+  help_wanted: number;
+}
+
+export interface SecurityAdvisories {
+  count: number;
+  latest?: SecurityAdvisory;
+}
+
+export interface SecurityAdvisory {
+  id: string;
+  url: string;
+  published_at?: string;
+  severity?: string;
+  summary?: string;
+}
+
 export interface Contributors {
   count: number;
   url: string;
+  top?: Contributor[];
+}
+
+export interface Contributor {
+  contributions: number;
+  login: string;
+  url: string;
+}
+
+export interface ClomonitorScore {
+  global: number;
+  best_practices?: number;
+  documentation?: number;
+  legal?: number;
+  license?: number;
+  security?: number;
+}
+
+export interface ContainerImagesStats {
+  pulls: number;
+  stars: number;
 }
 
 export interface Commit {
@@ -224,23 +340,112 @@ export interface Commit {
 export interface Release {
   ts: string;
   url: string;
+  tag?: string;
 }
 
 export interface LandscapeData {
   categories: Category[];
   items?: Item[];
   crunchbase_data?: CrunchbaseData;
+  feeds_data?: FeedsData;
   github_data?: GithubData;
+  github_orgs_data?: GithubOrgsData;
+  items_activity?: ItemsActivity;
+  linkedin_data?: LinkedInData;
+  news?: NewsPost[];
+  packages?: PackagesData;
+  social?: SocialData;
+  sponsorships_data?: SponsorshipsData;
+}
+
+export interface ItemsActivity {
+  [key: string]: number[];
 }
 
 export interface CrunchbaseData {
   [key: string]: Organization;
 }
 
+export interface LicenseDetails {
+  declared?: string;
+  discovered?: string[];
+  discrepancy: boolean;
+}
+
+export interface FeedsData {
+  [key: string]: FeedData;
+}
+
+export interface FeedData {
+  posts: FeedPost[];
+}
+
+export interface FeedPost {
+  title: string;
+  url: string;
+  published_at?: string;
+}
+
+export interface NewsPost extends FeedPost {
+  item_id: string;
+}
+
+export interface LinkedInData {
+  [key: string]: LinkedInOrganizationData;
+}
+
+export interface LinkedInOrganizationData {
+  generated_at?: number;
+  followers?: number;
+  num_employees_max?: number;
+  num_employees_min?: number;
+}
+
 export interface GithubData {
   [key: string]: GithubRepository;
 }
 
+export interface GithubOrgsData {
+  [key: string]: GithubOrgData;
+}
+
+export interface GithubOrgData {
+  contributors: number;
+  items: string[];
+  repositories: number;
+  stars: number;
+}
+
+export interface PackagesData {
+  [key: string]: PackageData;
+}
+
+export interface PackageData {
+  name: string;
+  registry: string;
+  downloads?: number;
+  latest_version?: string;
+}
+
+export interface SocialData {
+  [key: string]: SocialAccountData;
+}
+
+export interface SocialAccountData {
+  platform: string;
+  followers?: number;
+}
+
+export interface SponsorshipsData {
+  [key: string]: SponsorshipData;
+}
+
+export interface SponsorshipData {
+  backers?: number;
+  currency?: string;
+  monthly_budget?: number;
+}
+
 export interface FilterSection {
   value: FilterCategory;
   placeholder?: string;
@@ -332,6 +537,7 @@ export enum FilterCategory {
   Maturity = 'project',
   Organization = 'organization',
   License = 'license',
+  Language = 'language',
   Country = 'country',
   City = 'city',
   Region = 'region',
@@ -345,10 +551,29 @@ export enum FilterCategory {
   ProjectMaturity = 'maturity',
 }
 
+export interface SearchItem {
+  category: string;
+  description?: string;
+  id: string;
+  keywords?: string[];
+  name: string;
+  subcategory: string;
+}
+
 export interface Stats {
+  end_users?: {
+    country: { [key: string]: number };
+    end_users: number;
+    industry: { [key: string]: number };
+  };
+  items?: {
+    category: { [key: string]: number };
+    items: number;
+  };
   members?: {
     joined_at: { [key: string]: number };
     joined_at_rt: { [key: string]: number };
+    levels?: { [key: string]: number };
     members: number;
     subcategories: { [key: string]: number };
   };
@@ -381,6 +606,7 @@ export interface Stats {
   organizations?: {
     acquisitions: { [key: string]: number };
     acquisitions_price: { [key: string]: number };
+    funding: number;
     funding_rounds: { [key: string]: number };
     funding_rounds_money_raised: { [key: string]: number };
   };
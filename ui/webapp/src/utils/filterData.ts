@@ -2,6 +2,7 @@ import isUndefined from 'lodash/isUndefined';
 
 import { ActiveFilters, FilterCategory, Item, Repository } from '../types';
 import getFoundationNameLabel from './getFoundationNameLabel';
+import getItemLanguages from './getItemLanguages';
 
 const filterData = (items: Item[], activeFilters: ActiveFilters): Item[] => {
   if (Object.keys(activeFilters).length > 0) {
@@ -81,6 +82,14 @@ const filterData = (items: Item[], activeFilters: ActiveFilters): Item[] => {
         }
       }
 
+      // Filter Language
+      if (activeFilters[FilterCategory.Language]) {
+        const languages = getItemLanguages(item);
+        if (!languages.some((l: string) => activeFilters[FilterCategory.Language]?.includes(l))) {
+          return false;
+        }
+      }
+
       // Filter CompanyType
       if (activeFilters[FilterCategory.OrgType]) {
         if (isUndefined(item.crunchbase_data) || isUndefined(item.crunchbase_data.company_type)) {
@@ -0,0 +1,23 @@
+import { Item, Repository } from '../types';
+
+// Minimum percentage of the code of a repository a language must represent
+// to be considered one of the item's languages
+const MIN_LANGUAGE_PERCENTAGE = 5;
+
+const getItemLanguages = (item: Item): string[] => {
+  const languages: string[] = [];
+  if (item.repositories) {
+    item.repositories.forEach((r: Repository) => {
+      if (r.github_data && r.github_data.languages_breakdown) {
+        Object.entries(r.github_data.languages_breakdown).forEach(([language, percentage]) => {
+          if (percentage >= MIN_LANGUAGE_PERCENTAGE) {
+            languages.push(language);
+          }
+        });
+      }
+    });
+  }
+  return [...new Set(languages)];
+};
+
+export default getItemLanguages;
@@ -34,6 +34,8 @@ import filterData from './filterData';
 import search from './search';
 import sortMenuOptions from './sortMenuOptions';
 
+const DATA_PATH = import.meta.env.MODE === 'development' ? '../../static/data' : './data';
+
 export interface ItemsDataStatus {
   updateStatus(status: boolean): void;
 }
@@ -84,6 +86,8 @@ export class ItemsDataGetter {
   private landscapeData?: LandscapeData;
   private classifyAndSortOptions: { [key: string]: ClassifyAndSortOptions } | undefined;
   private allDataGrouped: { [key: string]: (Item | BaseItem)[] } | undefined;
+  private loadedShards: { [key: string]: Promise<LandscapeData> } = {};
+  private loadingFullData = false;
 
   // Subscribe to the updateStatus
   public subscribe(updateStatus: ItemsDataStatus) {
@@ -96,8 +100,10 @@ export class ItemsDataGetter {
       if (landscapeData) {
         this.prepareGroups();
         this.initialDataPreparation(landscapeData);
-      } else {
-        fetch(import.meta.env.MODE === 'development' ? '../../static/data/full.json' : './data/full.json')
+      } else if (!this.hasShards()) {
+        // When the full dataset is split, its shards are loaded on demand
+        // instead (when an item is opened or the full dataset is required)
+        fetch(`${DATA_PATH}/full.json`)
           .then((res) => res.json())
           .then((data: LandscapeData) => {
             this.initialDataPreparation(data);
@@ -106,6 +112,90 @@ export class ItemsDataGetter {
     }
   }
 
+  // Load the full dataset shard of the category provided (only once)
+  private loadShard(category: string): Promise<LandscapeData> {
+    if (isUndefined(this.loadedShards[category])) {
+      this.loadedShards[category] = fetch(`${DATA_PATH}/${this.getShards()![category]}`)
+        .then((res) => {
+          if (!res.ok) {
+            throw new Error(`error loading full dataset shard: ${res.status}`);
+          }
+          return res.json();
+        })
+        .catch((err) => {
+          // Allow loading the shard again later
+          delete this.loadedShards[category];
+          return Promise.reject(err);
+        });
+    }
+    return this.loadedShards[category];
+  }
+
+  // Load all the full dataset shards (when split) and prepare the full data
+  // once all of them are available
+  public loadFullData() {
+    const shards = this.getShards();
+    if (this.ready || isUndefined(shards) || this.loadingFullData) return;
+
+    this.loadingFullData = true;
+    Promise.all(Object.keys(shards).map((category: string) => this.loadShard(category)))
+      .then((shardsData: LandscapeData[]) => {
+        this.initialDataPreparation(this.mergeShards(shardsData));
+      })
+      .catch(() => {
+        // Allow trying again the next time the full data is required
+        this.loadingFullData = false;
+      });
+  }
+
+  // Merge the full dataset shards provided
+  private mergeShards(shardsData: LandscapeData[]): LandscapeData {
+    const mergeMaps = <T>(maps: (T | undefined)[]): T => Object.assign({}, ...maps);
+    return {
+      categories: [],
+      items: shardsData.flatMap((shard: LandscapeData) => shard.items || []),
+      news: shardsData.flatMap((shard: LandscapeData) => shard.news || []),
+      crunchbase_data: mergeMaps(shardsData.map((shard: LandscapeData) => shard.crunchbase_data)),
+      feeds_data: mergeMaps(shardsData.map((shard: LandscapeData) => shard.feeds_data)),
+      github_data: mergeMaps(shardsData.map((shard: LandscapeData) => shard.github_data)),
+      github_orgs_data: mergeMaps(shardsData.map((shard: LandscapeData) => shard.github_orgs_data)),
+      items_activity: mergeMaps(shardsData.map((shard: LandscapeData) => shard.items_activity)),
+      linkedin_data: mergeMaps(shardsData.map((shard: LandscapeData) => shard.linkedin_data)),
+      packages: mergeMaps(shardsData.map((shard: LandscapeData) => shard.packages)),
+      social: mergeMaps(shardsData.map((shard: LandscapeData) => shard.social)),
+      sponsorships_data: mergeMaps(shardsData.map((shard: LandscapeData) => shard.sponsorships_data)),
+    };
+  }
+
+  // Get the full dataset shards (when the full dataset is split)
+  private getShards(): { [key: string]: string } | undefined {
+    const shards = window.baseDS && window.baseDS.full_dataset_shards;
+    return shards && !isEmpty(shards) ? shards : undefined;
+  }
+
+  // Check if the full dataset is split in shards
+  public hasShards(): boolean {
+    return !isUndefined(this.getShards());
+  }
+
+  // Load the full data of the item provided. When the full dataset is split
+  // and not ready yet, only the shard of the item's category is loaded.
+  public async loadItem(predicate: (item: BaseItem) => boolean): Promise<Item | undefined> {
+    const shards = this.getShards();
+    if (this.ready || isUndefined(shards)) {
+      return this.ready && this.landscapeData && this.landscapeData.items
+        ? this.landscapeData.items.find(predicate)
+        : undefined;
+    }
+
+    const baseItem = window.baseDS.items.find(predicate);
+    if (isUndefined(baseItem) || isUndefined(shards[baseItem.category])) return undefined;
+    const shard = await this.loadShard(baseItem.category);
+    const item = (shard.items || []).find((i: Item) => i.id === baseItem.id);
+    if (isUndefined(item)) return undefined;
+    return this.extendItemsData([item], shard.crunchbase_data, shard.github_data)[0];
+  }
+
   public prepareGroups() {
     const groups: string[] = [];
 
@@ -587,13 +677,6 @@ export class ItemsDataGetter {
     }
   }
 
-  // Get item by name
-  public getItemByName(name: string): Item | undefined {
-    if (this.ready && this.landscapeData && this.landscapeData.items) {
-      return this.landscapeData.items.find((i: Item) => name === i.name);
-    }
-  }
-
   // Get items by section
   public getItemsBySection(activeSection: ActiveSection): Item[] | undefined {
     if (this.ready && this.landscapeData && this.landscapeData.items) {
@@ -3,6 +3,7 @@ import isUndefined from 'lodash/isUndefined';
 
 import { FilterCategory, FilterSection, Item, Repository } from '../types';
 import checkIfCategoryInGroup from './checkIfCategoryInGroup';
+import getItemLanguages from './getItemLanguages';
 import itemsDataGetter from './itemsDataGetter';
 
 const cleanValue = (t: string): string => {
@@ -42,6 +43,7 @@ const prepareFilters = (items: Item[], group: string): PreparedFilters => {
   const tags: string[] = [];
   const organizations: string[] = [];
   const licenses: string[] = [];
+  let languages: string[] = [];
   const countries: string[] = [];
   const companyTypes: string[] = [];
   const extraTypes: string[] = [];
@@ -107,6 +109,8 @@ const prepareFilters = (items: Item[], group: string): PreparedFilters => {
       });
     }
 
+    languages = [...languages, ...getItemLanguages(i)];
+
     if (isUndefined(i.oss)) {
       nonOss = true;
     }
@@ -172,6 +176,17 @@ const prepareFilters = (items: Item[], group: string): PreparedFilters => {
     });
   }
 
+  if (languages.length > 0) {
+    filters.push({
+      value: FilterCategory.Language,
+      title: 'Language',
+      options: [...new Set(languages)].sort().map((language: string) => ({
+        value: cleanValue(language),
+        name: language,
+      })),
+    });
+  }
+
   if (countries.length > 0) {
     filters.push({
       value: FilterCategory.Country,
@@ -55,7 +55,7 @@ const sortItems = (items: Item[], option: SortOption, direction: SortDirection):
             if (item.repositories) {
               let contributors = 0;
               const primaryRepo = item.repositories.find((repo) => repo.primary === true);
-              if (primaryRepo && primaryRepo.github_data) {
+              if (primaryRepo && primaryRepo.github_data && primaryRepo.github_data.contributors) {
                 contributors += primaryRepo.github_data.contributors.count;
               }
               return contributors;
@@ -0,0 +1,54 @@
+// Default (English) strings used across the web application, indexed by the
+// key used to look them up in the translations catalog. Please keep these keys
+// in sync with TRANSLATION_KEYS in crates/core/src/settings.rs.
+const DEFAULT_STRINGS = {
+  'download.data_files': 'Data files',
+  'download.items_csv': 'CSV file that contains information about all items available in the landscape',
+  'download.landscape': 'Landscape',
+  'download.landscape_pdf': 'Landscape in PDF format',
+  'download.landscape_png': 'Landscape in PNG format',
+  'download.projects_csv': 'CSV file that contains information about all the projects that are part of the foundation',
+  'explore.all': 'All',
+  'explore.no_items': "We couldn't find any items that match the criteria selected.",
+  'explore.no_items_reset_prefix': 'You can update them and try again or',
+  'explore.no_items_reset_suffix': 'the filters.',
+  'filters.applied': 'Filters applied',
+  'filters.apply': 'Apply',
+  'filters.category': 'Category',
+  'filters.extra': 'Extra',
+  'filters.language': 'Language',
+  'filters.license': 'License',
+  'filters.location': 'Location',
+  'filters.name': 'Name',
+  'filters.none_available': 'There are no filters available that can be applied to the current set of items',
+  'filters.reset_all': 'reset all',
+  'filters.search_country': 'Search country',
+  'filters.search_industry': 'Search industry',
+  'filters.search_organization': 'Search organization',
+  'filters.status': 'Status',
+  'filters.tag': 'TAG',
+  'filters.title': 'Filters',
+  'filters.type': 'Type',
+  'footer.generator': 'CNCF interactive landscapes generator',
+  'footer.powered_by': 'Powered by',
+  'header.explore': 'Explore',
+  'header.games': 'Games',
+  'header.guide': 'Guide',
+  'header.stats': 'Stats',
+  'not_found.back_home': 'Back Home',
+  'not_found.description': "The page you were looking for wasn't found",
+  'not_found.title': 'Error 404 - Page Not Found',
+  'search.no_results': "We couldn't find any items that match that criteria.",
+  'search.placeholder': 'Search items',
+};
+
+export type TranslationKey = keyof typeof DEFAULT_STRINGS;
+
+// Get the translated string for the key provided (or the default one when no
+// translation is available in the catalog embedded in the base dataset)
+const translate = (key: TranslationKey): string => {
+  const translations = window.baseDS.translations;
+  return (translations && translations[key]) || DEFAULT_STRINGS[key];
+};
+
+export default translate;